@@ -6,7 +6,7 @@
 use crate::backends::traits::{
     MemoryBackend, SearchFilters, SearchQuery, SearchResult, WriteResult,
 };
-use crate::core::CompoundLearning;
+use crate::core::{CompoundLearning, LearningRevision};
 use crate::error::Result;
 use tracing::warn;
 
@@ -57,6 +57,7 @@ impl MemoryBackend for FallbackBackend {
                     "Primary backend '{}' unavailable, used fallback",
                     self.primary.name()
                 )),
+                failed_backends: vec![self.primary.name().to_string()],
             })
         } else {
             // Both failed
@@ -169,11 +170,30 @@ impl MemoryBackend for FallbackBackend {
             .restore(learning_id)
             .or_else(|_| self.fallback.restore(learning_id))
     }
+
+    fn update(&self, learning: &CompoundLearning) -> Result<()> {
+        // Try primary first, then fallback (learning could be in either)
+        self.primary
+            .update(learning)
+            .or_else(|_| self.fallback.update(learning))
+    }
+
+    fn record_revision(&self, revision: &LearningRevision) -> Result<u32> {
+        self.primary
+            .record_revision(revision)
+            .or_else(|_| self.fallback.record_revision(revision))
+    }
+
+    fn history(&self, learning_id: &str) -> Result<Vec<LearningRevision>> {
+        self.primary
+            .history(learning_id)
+            .or_else(|_| self.fallback.history(learning_id))
+    }
 }
 
 /// Parse a learning ID into its prefix (including trailing underscore) and counter.
 /// Format: cl_YYYYMMDD_NNN -> ("cl_YYYYMMDD_", NNN)
-fn parse_learning_id(id: &str) -> Option<(String, u32)> {
+pub(crate) fn parse_learning_id(id: &str) -> Option<(String, u32)> {
     let underscore_pos = id.rfind('_')?;
     let counter_str = &id[underscore_pos + 1..];
     let prefix = &id[..=underscore_pos]; // includes the trailing underscore
@@ -183,7 +203,7 @@ fn parse_learning_id(id: &str) -> Option<(String, u32)> {
 
 /// Return the learning ID with the higher counter.
 /// If IDs have different date prefixes, lexicographic comparison is used.
-fn max_learning_id(id1: &str, id2: &str) -> String {
+pub(crate) fn max_learning_id(id1: &str, id2: &str) -> String {
     match (parse_learning_id(id1), parse_learning_id(id2)) {
         (Some((prefix1, counter1)), Some((prefix2, counter2))) => {
             if prefix1 == prefix2 {
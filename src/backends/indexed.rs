@@ -0,0 +1,229 @@
+//! Search-index backend wrapper for Grove.
+//!
+//! This module provides a backend wrapper that keeps the project's on-disk
+//! search index (`.grove/index/`) in step with writes, archives, restores,
+//! and updates, so hooks don't have to re-index every learning.
+
+use std::path::{Path, PathBuf};
+
+use crate::backends::markdown::sanitize_learning;
+use crate::backends::traits::{
+    MemoryBackend, SearchFilters, SearchQuery, SearchResult, WriteResult,
+};
+use crate::core::{CompoundLearning, LearningRevision, LearningScope};
+use crate::error::Result;
+use crate::search::ProjectIndex;
+use tracing::warn;
+
+/// A backend wrapper that updates the persistent search index incrementally.
+///
+/// - Every call is delegated to the inner backend unchanged.
+/// - After a successful write, archive, restore, or update, the affected
+///   learning is re-indexed (or dropped) in place, sanitized as the backends
+///   store it. Ephemeral learnings are never stored, so never indexed.
+/// - The index is only touched once it has been built; until then, and on
+///   any index error, the change is left for the next automatic rebuild.
+pub struct IndexedBackend {
+    /// The backend that stores the learnings.
+    inner: Box<dyn MemoryBackend>,
+    /// The project's index directory.
+    index_dir: PathBuf,
+}
+
+impl IndexedBackend {
+    /// Create a new indexed backend.
+    pub fn new(inner: Box<dyn MemoryBackend>, index_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            inner,
+            index_dir: index_dir.into(),
+        }
+    }
+
+    /// The index directory this wrapper maintains.
+    pub fn index_dir(&self) -> &Path {
+        &self.index_dir
+    }
+
+    /// Apply a change to the index when one exists (fail-open).
+    fn with_index(&self, change: impl FnOnce(&mut ProjectIndex) -> Result<()>) {
+        if !ProjectIndex::exists(&self.index_dir) {
+            return;
+        }
+        let result = ProjectIndex::open(&self.index_dir).and_then(|mut index| change(&mut index));
+        if let Err(e) = result {
+            warn!(
+                "Search index update failed (will rebuild on next use): {}",
+                e
+            );
+        }
+    }
+}
+
+impl MemoryBackend for IndexedBackend {
+    fn write(&self, learning: &CompoundLearning) -> Result<WriteResult> {
+        let result = self.inner.write(learning)?;
+        if result.success && learning.scope != LearningScope::Ephemeral {
+            self.with_index(|index| index.upsert(&sanitize_learning(learning)));
+        }
+        Ok(result)
+    }
+
+    fn search(&self, query: &SearchQuery, filters: &SearchFilters) -> Result<Vec<SearchResult>> {
+        self.inner.search(query, filters)
+    }
+
+    fn ping(&self) -> bool {
+        self.inner.ping()
+    }
+
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn archive(&self, learning_id: &str) -> Result<()> {
+        self.inner.archive(learning_id)?;
+        self.with_index(|index| index.remove(learning_id));
+        Ok(())
+    }
+
+    fn restore(&self, learning_id: &str) -> Result<()> {
+        self.inner.restore(learning_id)?;
+        self.with_index(|index| {
+            match self
+                .inner
+                .list_all()?
+                .into_iter()
+                .find(|l| l.id == learning_id)
+            {
+                Some(learning) => index.upsert(&learning),
+                None => Ok(()),
+            }
+        });
+        Ok(())
+    }
+
+    fn update(&self, learning: &CompoundLearning) -> Result<()> {
+        self.inner.update(learning)?;
+        self.with_index(|index| index.upsert(&sanitize_learning(learning)));
+        Ok(())
+    }
+
+    fn record_revision(&self, revision: &LearningRevision) -> Result<u32> {
+        self.inner.record_revision(revision)
+    }
+
+    fn history(&self, learning_id: &str) -> Result<Vec<LearningRevision>> {
+        self.inner.history(learning_id)
+    }
+
+    fn list_all(&self) -> Result<Vec<CompoundLearning>> {
+        self.inner.list_all()
+    }
+
+    fn next_id(&self) -> String {
+        self.inner.next_id()
+    }
+
+    fn next_ids(&self, count: usize) -> Vec<String> {
+        self.inner.next_ids(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::MarkdownBackend;
+    use crate::core::{Confidence, LearningCategory, LearningScope, WriteGateCriterion};
+    use tempfile::TempDir;
+
+    fn learning(id: &str, summary: &str) -> CompoundLearning {
+        CompoundLearning::new(
+            LearningCategory::Pattern,
+            summary,
+            "Detail that is long enough to pass validation",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::BehaviorChanging],
+            vec!["index".to_string()],
+            "test-session",
+        )
+        .with_id(id)
+    }
+
+    #[test]
+    fn test_changes_update_existing_index() {
+        let temp = TempDir::new().unwrap();
+        let index_dir = temp.path().join("index");
+        let backend = IndexedBackend::new(
+            Box::new(MarkdownBackend::with_paths(
+                temp.path().join("learnings.md"),
+                temp.path().join("personal.md"),
+            )),
+            &index_dir,
+        );
+
+        // No index yet: writes leave it alone
+        backend
+            .write(&learning("cl_20260101_000", "Use atomic writes"))
+            .unwrap();
+        assert!(!ProjectIndex::exists(&index_dir));
+
+        ProjectIndex::open(&index_dir)
+            .unwrap()
+            .rebuild(&backend.list_all().unwrap())
+            .unwrap();
+
+        backend
+            .write(&learning("cl_20260101_001", "Avoid blocking hooks"))
+            .unwrap();
+        backend.archive("cl_20260101_000").unwrap();
+        let index = ProjectIndex::open(&index_dir).unwrap();
+        assert!(index.is_current(&backend.list_all().unwrap()));
+        assert_eq!(index.index().num_docs(), 1);
+        drop(index);
+
+        backend.restore("cl_20260101_000").unwrap();
+        let mut edited = learning("cl_20260101_001", "Never block in hooks");
+        edited.detail = "Hooks run synchronously before every tool call".to_string();
+        backend.update(&edited).unwrap();
+
+        let index = ProjectIndex::open(&index_dir).unwrap();
+        assert!(index.is_current(&backend.list_all().unwrap()));
+        assert_eq!(index.index().num_docs(), 2);
+        assert_eq!(backend.name(), "markdown");
+    }
+
+    #[test]
+    fn test_index_matches_stored_learnings() {
+        let temp = TempDir::new().unwrap();
+        let index_dir = temp.path().join("index");
+        let backend = IndexedBackend::new(
+            Box::new(MarkdownBackend::with_paths(
+                temp.path().join("learnings.md"),
+                temp.path().join("personal.md"),
+            )),
+            &index_dir,
+        );
+        ProjectIndex::open(&index_dir)
+            .unwrap()
+            .rebuild(&[])
+            .unwrap();
+
+        let sanitized = learning("cl_20260101_001", "Line one\n## injected | header");
+        backend.write(&sanitized).unwrap();
+        let mut ephemeral = learning("cl_20260101_002", "Scratch note");
+        ephemeral.scope = LearningScope::Ephemeral;
+        backend.write(&ephemeral).unwrap();
+
+        let index = ProjectIndex::open(&index_dir).unwrap();
+        assert!(index.is_current(&backend.list_all().unwrap()));
+        assert_eq!(index.index().num_docs(), 1);
+        drop(index);
+
+        let mut edited = backend.list_all().unwrap().remove(0);
+        edited.detail = "  ```rust\nunclosed fence".to_string();
+        backend.update(&edited).unwrap();
+        let index = ProjectIndex::open(&index_dir).unwrap();
+        assert!(index.is_current(&backend.list_all().unwrap()));
+    }
+}
@@ -0,0 +1,160 @@
+//! Sidecar-invalidating backend wrapper for Grove.
+//!
+//! This module provides a backend wrapper that deletes files derived from
+//! the corpus (such as the hook trigger index) whenever the corpus changes,
+//! so the next reader rebuilds them instead of using stale data.
+
+use std::path::PathBuf;
+
+use crate::backends::traits::{
+    MemoryBackend, SearchFilters, SearchQuery, SearchResult, WriteResult,
+};
+use crate::core::{CompoundLearning, LearningRevision};
+use crate::error::Result;
+use tracing::warn;
+
+/// A backend wrapper that removes derived files after every change.
+///
+/// - Every call is delegated to the inner backend unchanged.
+/// - After a successful write, archive, restore, or update, each derived
+///   file is deleted. A missing file is not an error; other failures are
+///   logged and ignored.
+pub struct InvalidatingBackend {
+    /// The backend that stores the learnings.
+    inner: Box<dyn MemoryBackend>,
+    /// Files derived from the corpus.
+    derived: Vec<PathBuf>,
+}
+
+impl InvalidatingBackend {
+    /// Create a new invalidating backend.
+    pub fn new(inner: Box<dyn MemoryBackend>, derived: Vec<PathBuf>) -> Self {
+        Self { inner, derived }
+    }
+
+    /// Delete every derived file (fail-open).
+    fn invalidate(&self) {
+        for path in &self.derived {
+            match std::fs::remove_file(path) {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => warn!("Failed to remove {}: {}", path.display(), e),
+            }
+        }
+    }
+}
+
+impl MemoryBackend for InvalidatingBackend {
+    fn write(&self, learning: &CompoundLearning) -> Result<WriteResult> {
+        let result = self.inner.write(learning)?;
+        if result.success {
+            self.invalidate();
+        }
+        Ok(result)
+    }
+
+    fn search(&self, query: &SearchQuery, filters: &SearchFilters) -> Result<Vec<SearchResult>> {
+        self.inner.search(query, filters)
+    }
+
+    fn ping(&self) -> bool {
+        self.inner.ping()
+    }
+
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn archive(&self, learning_id: &str) -> Result<()> {
+        self.inner.archive(learning_id)?;
+        self.invalidate();
+        Ok(())
+    }
+
+    fn restore(&self, learning_id: &str) -> Result<()> {
+        self.inner.restore(learning_id)?;
+        self.invalidate();
+        Ok(())
+    }
+
+    fn update(&self, learning: &CompoundLearning) -> Result<()> {
+        self.inner.update(learning)?;
+        self.invalidate();
+        Ok(())
+    }
+
+    fn record_revision(&self, revision: &LearningRevision) -> Result<u32> {
+        self.inner.record_revision(revision)
+    }
+
+    fn history(&self, learning_id: &str) -> Result<Vec<LearningRevision>> {
+        self.inner.history(learning_id)
+    }
+
+    fn list_all(&self) -> Result<Vec<CompoundLearning>> {
+        self.inner.list_all()
+    }
+
+    fn next_id(&self) -> String {
+        self.inner.next_id()
+    }
+
+    fn next_ids(&self, count: usize) -> Vec<String> {
+        self.inner.next_ids(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::MarkdownBackend;
+    use crate::core::{Confidence, LearningCategory, LearningScope, WriteGateCriterion};
+    use tempfile::TempDir;
+
+    fn learning(id: &str) -> CompoundLearning {
+        CompoundLearning::new(
+            LearningCategory::Pattern,
+            "Derived files follow the corpus",
+            "Detail that is long enough to pass validation",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::BehaviorChanging],
+            vec![],
+            "test-session",
+        )
+        .with_id(id)
+    }
+
+    #[test]
+    fn test_changes_remove_derived_files() {
+        let temp = TempDir::new().unwrap();
+        let derived = temp.path().join("trigger-index.json");
+        let backend = InvalidatingBackend::new(
+            Box::new(MarkdownBackend::with_paths(
+                temp.path().join("learnings.md"),
+                temp.path().join("personal.md"),
+            )),
+            vec![derived.clone()],
+        );
+
+        // Nothing to remove yet
+        backend.write(&learning("cl_20260101_001")).unwrap();
+
+        std::fs::write(&derived, "{}").unwrap();
+        backend
+            .search(&SearchQuery::new(), &SearchFilters::default())
+            .unwrap();
+        assert!(derived.exists(), "reads keep derived files");
+
+        backend.archive("cl_20260101_001").unwrap();
+        assert!(!derived.exists());
+
+        std::fs::write(&derived, "{}").unwrap();
+        backend.restore("cl_20260101_001").unwrap();
+        assert!(!derived.exists());
+
+        std::fs::write(&derived, "{}").unwrap();
+        backend.update(&learning("cl_20260101_001")).unwrap();
+        assert!(!derived.exists());
+    }
+}
@@ -0,0 +1,208 @@
+//! Layered backend wrapper for Grove.
+//!
+//! This module provides a backend wrapper that searches read-only layers
+//! (such as an organization-wide corpus) underneath the project's primary
+//! backend. Every write and status change goes to the primary only.
+
+use crate::backends::traits::{
+    MemoryBackend, SearchFilters, SearchQuery, SearchResult, WriteResult,
+};
+use crate::core::{CompoundLearning, LearningRevision};
+use crate::error::Result;
+use tracing::warn;
+
+/// A backend wrapper that layers read-only sources under a primary backend.
+///
+/// - Writes, updates, archive/restore, history, and ID allocation go to the
+///   primary backend only.
+/// - Searches query the primary and every layer and merge the results,
+///   deduplicated by source and ID. A layer learning that shares an ID with
+///   a project learning is still returned, tagged with its own source.
+/// - A failing layer is logged and skipped; primary errors are returned.
+pub struct LayeredBackend {
+    /// The project's own backend.
+    primary: Box<dyn MemoryBackend>,
+    /// Read-only layers searched underneath the primary, in priority order.
+    layers: Vec<Box<dyn MemoryBackend>>,
+}
+
+impl LayeredBackend {
+    /// Create a new layered backend.
+    pub fn new(primary: Box<dyn MemoryBackend>, layers: Vec<Box<dyn MemoryBackend>>) -> Self {
+        Self { primary, layers }
+    }
+
+    /// Names of the read-only layers, in order.
+    pub fn layer_names(&self) -> Vec<&'static str> {
+        self.layers.iter().map(|b| b.name()).collect()
+    }
+}
+
+impl MemoryBackend for LayeredBackend {
+    fn write(&self, learning: &CompoundLearning) -> Result<WriteResult> {
+        self.primary.write(learning)
+    }
+
+    fn search(&self, query: &SearchQuery, filters: &SearchFilters) -> Result<Vec<SearchResult>> {
+        let mut combined = self.primary.search(query, filters)?;
+
+        for layer in &self.layers {
+            match layer.search(query, filters) {
+                Ok(results) => {
+                    for result in results {
+                        if !combined.iter().any(|r| {
+                            r.source == result.source && r.learning.id == result.learning.id
+                        }) {
+                            combined.push(result);
+                        }
+                    }
+                }
+                Err(e) => warn!("Layered search failed on '{}': {}", layer.name(), e),
+            }
+        }
+
+        // Sort by relevance (highest first)
+        combined.sort_by(|a, b| {
+            b.relevance
+                .partial_cmp(&a.relevance)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        // Apply max_results limit
+        if let Some(limit) = filters.max_results {
+            combined.truncate(limit);
+        }
+
+        Ok(combined)
+    }
+
+    fn ping(&self) -> bool {
+        self.primary.ping()
+    }
+
+    fn name(&self) -> &'static str {
+        self.primary.name()
+    }
+
+    fn archive(&self, learning_id: &str) -> Result<()> {
+        self.primary.archive(learning_id)
+    }
+
+    fn restore(&self, learning_id: &str) -> Result<()> {
+        self.primary.restore(learning_id)
+    }
+
+    fn update(&self, learning: &CompoundLearning) -> Result<()> {
+        self.primary.update(learning)
+    }
+
+    fn record_revision(&self, revision: &LearningRevision) -> Result<u32> {
+        self.primary.record_revision(revision)
+    }
+
+    fn history(&self, learning_id: &str) -> Result<Vec<LearningRevision>> {
+        self.primary.history(learning_id)
+    }
+
+    fn list_all(&self) -> Result<Vec<CompoundLearning>> {
+        // Maintenance, export, and migration operate on the project's own learnings
+        self.primary.list_all()
+    }
+
+    fn next_id(&self) -> String {
+        self.primary.next_id()
+    }
+
+    fn next_ids(&self, count: usize) -> Vec<String> {
+        self.primary.next_ids(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::traits::LearningSource;
+    use crate::backends::{MarkdownBackend, OrgBackend};
+    use crate::core::{Confidence, LearningCategory, LearningScope, WriteGateCriterion};
+    use tempfile::TempDir;
+
+    fn learning(id: &str, summary: &str) -> CompoundLearning {
+        CompoundLearning::new(
+            LearningCategory::Pattern,
+            summary,
+            "Shared detail that is long enough to pass validation",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::BehaviorChanging],
+            vec!["shared".to_string()],
+            "test-session",
+        )
+        .with_id(id)
+    }
+
+    fn setup(temp: &TempDir) -> LayeredBackend {
+        let project = MarkdownBackend::with_paths(
+            temp.path().join("project.md"),
+            temp.path().join("personal.md"),
+        );
+        let org_path = temp.path().join("org.md");
+        let org = MarkdownBackend::with_paths(&org_path, temp.path().join("org-personal.md"));
+        org.write(&learning("cl_20260101_000", "Org copy")).unwrap();
+        org.write(&learning("cl_20260101_001", "Org only")).unwrap();
+        project
+            .write(&learning("cl_20260101_000", "Project copy"))
+            .unwrap();
+
+        LayeredBackend::new(Box::new(project), vec![Box::new(OrgBackend::new(org_path))])
+    }
+
+    #[test]
+    fn test_search_merges_layers_keeping_shared_ids_per_source() {
+        let temp = TempDir::new().unwrap();
+        let backend = setup(&temp);
+
+        let results = backend
+            .search(&SearchQuery::new(), &SearchFilters::default())
+            .unwrap();
+
+        assert_eq!(results.len(), 3);
+        let mut shared: Vec<(&str, LearningSource)> = results
+            .iter()
+            .filter(|r| r.learning.id == "cl_20260101_000")
+            .map(|r| (r.learning.summary.as_str(), r.source))
+            .collect();
+        shared.sort_by_key(|(_, source)| source.as_str());
+        assert_eq!(
+            shared,
+            vec![
+                ("Org copy", LearningSource::Org),
+                ("Project copy", LearningSource::Project)
+            ]
+        );
+        let org = results
+            .iter()
+            .find(|r| r.learning.id == "cl_20260101_001")
+            .unwrap();
+        assert_eq!(org.source, LearningSource::Org);
+    }
+
+    #[test]
+    fn test_writes_and_listing_use_primary_only() {
+        let temp = TempDir::new().unwrap();
+        let backend = setup(&temp);
+
+        backend
+            .write(&learning("cl_20260101_002", "New project learning"))
+            .unwrap();
+
+        let ids: Vec<String> = backend
+            .list_all()
+            .unwrap()
+            .into_iter()
+            .map(|l| l.id)
+            .collect();
+        assert_eq!(ids, vec!["cl_20260101_000", "cl_20260101_002"]);
+        assert_eq!(backend.name(), "markdown");
+        assert_eq!(backend.layer_names(), vec!["org"]);
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! This module provides an append-only markdown backend that stores learnings
 //! in `.grove/learnings.md` (or `~/.grove/personal-learnings.md` for personal scope).
+//! Revision history is kept next to each file as JSON lines
+//! (`.grove/learnings.history.jsonl`).
 
 use std::fs::{self, OpenOptions};
 use std::io::Write;
@@ -13,12 +15,16 @@ use crate::backends::traits::{
     MemoryBackend, SearchFilters, SearchQuery, SearchResult, WriteResult,
 };
 use crate::core::{
-    CompoundLearning, Confidence, LearningCategory, LearningScope, LearningStatus,
-    WriteGateCriterion,
+    parse_learning_date, AppliesTo, CommandGuard, CompoundLearning, Confidence, ExampleKind,
+    GuardSeverity, LearningCategory, LearningExample, LearningRelation, LearningRevision,
+    LearningScope, LearningStatus, Provenance, ProvenanceOrigin, RelationKind, WriteGateCriterion,
 };
 use crate::error::{GroveError, Result};
 use crate::util::{read_to_string_limited, sync_parent_dir};
 
+/// Start of an example header line (`**Example (kind):** caption`).
+pub(crate) const EXAMPLE_PREFIX: &str = "**Example";
+
 /// Relevance score weights for search matching.
 mod scores {
     /// Exact tag match score.
@@ -38,6 +44,8 @@ mod scores {
 /// - Content sanitization
 /// - Search with relevance scoring
 /// - In-place status updates for archiving
+/// - In-place edits of stored learnings
+/// - Revision history per learning
 #[derive(Debug, Clone)]
 pub struct MarkdownBackend {
     /// Path to the project learnings file (.grove/learnings.md).
@@ -119,11 +127,20 @@ impl MarkdownBackend {
 
     /// Update the status of a learning in-place.
     fn update_status(&self, learning_id: &str, new_status: LearningStatus) -> Result<()> {
+        self.modify_learning(learning_id, |learning| learning.status = new_status)
+    }
+
+    /// Apply a change to a stored learning and rewrite its file.
+    fn modify_learning(
+        &self,
+        learning_id: &str,
+        change: impl Fn(&mut CompoundLearning),
+    ) -> Result<()> {
         // Try project file first, then personal
-        if self.update_status_in_file(&self.project_path, learning_id, new_status)? {
+        if self.modify_in_file(&self.project_path, learning_id, &change)? {
             return Ok(());
         }
-        if self.update_status_in_file(&self.personal_path, learning_id, new_status)? {
+        if self.modify_in_file(&self.personal_path, learning_id, &change)? {
             return Ok(());
         }
 
@@ -133,14 +150,14 @@ impl MarkdownBackend {
         )))
     }
 
-    /// Update the status of a learning in a specific file.
+    /// Apply a change to a learning in a specific file.
     ///
     /// Returns true if the learning was found and updated.
-    fn update_status_in_file(
+    fn modify_in_file(
         &self,
         path: &Path,
         learning_id: &str,
-        new_status: LearningStatus,
+        change: &impl Fn(&mut CompoundLearning),
     ) -> Result<bool> {
         if !path.exists() {
             return Ok(false);
@@ -153,14 +170,14 @@ impl MarkdownBackend {
             return Ok(false);
         }
 
-        // Parse and rewrite the file with updated status
+        // Parse and rewrite the file with the changed learning
         let learnings = parse_learnings_from_markdown(&content)?;
         let mut found = false;
         let mut updated_learnings = Vec::new();
 
         for mut learning in learnings {
             if learning.id == learning_id {
-                learning.status = new_status;
+                change(&mut learning);
                 found = true;
             }
             updated_learnings.push(learning);
@@ -176,7 +193,42 @@ impl MarkdownBackend {
         Ok(true)
     }
 
-    /// Write all learnings to a file (used for status updates).
+    /// Path of the revision history file that sits next to a learnings file.
+    fn history_path(path: &Path) -> PathBuf {
+        path.with_extension("history.jsonl")
+    }
+
+    /// Read all revisions from a history file.
+    ///
+    /// Lines that fail to parse are skipped so one bad line doesn't hide
+    /// the rest of the history.
+    fn read_revisions(path: &Path) -> Result<Vec<LearningRevision>> {
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = read_to_string_limited(path)?;
+        Ok(content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect())
+    }
+
+    /// Pick the history file for a learning: personal learnings keep their
+    /// history next to the personal file, everything else next to the project file.
+    fn history_path_for(&self, learning_id: &str) -> Result<PathBuf> {
+        let header = format!("## {}", learning_id);
+        let in_personal = self.personal_path.exists()
+            && read_to_string_limited(&self.personal_path)?.contains(&header);
+        let path = if in_personal {
+            &self.personal_path
+        } else {
+            &self.project_path
+        };
+        Ok(Self::history_path(path))
+    }
+
+    /// Write all learnings to a file (used for status updates and edits).
     ///
     /// Uses atomic writes via temp file + rename pattern to prevent data
     /// corruption if the process crashes mid-write.
@@ -242,93 +294,6 @@ impl MarkdownBackend {
 
         Ok(())
     }
-
-    /// Compute relevance score for a learning against a query.
-    fn compute_relevance(&self, learning: &CompoundLearning, query: &SearchQuery) -> f64 {
-        let mut score = 0.0;
-        let mut matches = 0;
-
-        // Tag matching
-        for query_tag in &query.tags {
-            let query_tag_lower = query_tag.to_lowercase();
-            for learning_tag in &learning.tags {
-                let learning_tag_lower = learning_tag.to_lowercase();
-                if learning_tag_lower == query_tag_lower {
-                    score += scores::TAG_EXACT;
-                    matches += 1;
-                } else if learning_tag_lower.contains(&query_tag_lower)
-                    || query_tag_lower.contains(&learning_tag_lower)
-                {
-                    score += scores::TAG_PARTIAL;
-                    matches += 1;
-                }
-            }
-        }
-
-        // File overlap matching
-        if let Some(ref context_files) = learning.context_files {
-            for query_file in &query.files {
-                for context_file in context_files {
-                    if files_overlap(query_file, context_file) {
-                        score += scores::FILE_OVERLAP;
-                        matches += 1;
-                    }
-                }
-            }
-        }
-
-        // Keyword matching (whole word in summary and detail)
-        let summary_words: Vec<String> = learning
-            .summary
-            .to_lowercase()
-            .split(|c: char| !c.is_alphanumeric() && c != '-' && c != '_')
-            .filter(|w| !w.is_empty())
-            .map(|w| w.to_string())
-            .collect();
-        let detail_words: Vec<String> = learning
-            .detail
-            .to_lowercase()
-            .split(|c: char| !c.is_alphanumeric() && c != '-' && c != '_')
-            .filter(|w| !w.is_empty())
-            .map(|w| w.to_string())
-            .collect();
-        for keyword in &query.keywords {
-            let keyword_lower = keyword.to_lowercase();
-            // Split keyword into sub-words; all must be present as whole words
-            let keyword_parts: Vec<&str> = keyword_lower
-                .split_whitespace()
-                .filter(|w| !w.is_empty())
-                .collect();
-
-            if !keyword_parts.is_empty()
-                && keyword_parts.iter().all(|part| {
-                    summary_words.iter().any(|w| w == part)
-                        || detail_words.iter().any(|w| w == part)
-                })
-            {
-                score += scores::KEYWORD;
-                matches += 1;
-            }
-        }
-
-        // Ticket ID matching (exact)
-        if let Some(ref query_ticket) = query.ticket_id {
-            if let Some(ref learning_ticket) = learning.ticket_id {
-                if learning_ticket == query_ticket {
-                    score += scores::TAG_EXACT;
-                    matches += 1;
-                }
-            }
-        }
-
-        // Normalize score to 0.0 - 1.0 range if we have matches
-        if matches > 0 {
-            // Cap at 1.0
-            score.min(1.0)
-        } else {
-            0.0
-        }
-    }
 }
 
 impl MemoryBackend for MarkdownBackend {
@@ -389,6 +354,7 @@ impl MemoryBackend for MarkdownBackend {
             learning_id: learning.id.clone(),
             location: path.display().to_string(),
             message,
+            failed_backends: Vec::new(),
         })
     }
 
@@ -404,7 +370,7 @@ impl MemoryBackend for MarkdownBackend {
                     // If no query, all matching learnings are equally relevant
                     1.0
                 } else {
-                    self.compute_relevance(&learning, query)
+                    compute_relevance(&learning, query)
                 };
 
                 if relevance > 0.0 || query.is_empty() {
@@ -458,6 +424,65 @@ impl MemoryBackend for MarkdownBackend {
         self.update_status(learning_id, LearningStatus::Active)
     }
 
+    fn update(&self, learning: &CompoundLearning) -> Result<()> {
+        // Edited content goes through the same sanitization as new writes
+        let sanitized = sanitize_learning(learning);
+        self.modify_learning(&learning.id, |stored| *stored = sanitized.clone())
+    }
+
+    fn record_revision(&self, revision: &LearningRevision) -> Result<u32> {
+        let path = self.history_path_for(&revision.learning_id)?;
+
+        let rev = Self::read_revisions(&path)?
+            .iter()
+            .filter(|r| r.learning_id == revision.learning_id)
+            .map(|r| r.rev)
+            .max()
+            .unwrap_or(0)
+            + 1;
+
+        let mut recorded = revision.clone();
+        recorded.rev = rev;
+        let line =
+            serde_json::to_string(&recorded).map_err(|e| GroveError::serde(e.to_string()))?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| {
+                GroveError::backend(format!(
+                    "Failed to create directory {}: {}",
+                    parent.display(),
+                    e
+                ))
+            })?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| {
+                GroveError::backend(format!("Failed to open {}: {}", path.display(), e))
+            })?;
+        writeln!(file, "{}", line).map_err(|e| {
+            GroveError::backend(format!("Failed to write to {}: {}", path.display(), e))
+        })?;
+
+        Ok(rev)
+    }
+
+    fn history(&self, learning_id: &str) -> Result<Vec<LearningRevision>> {
+        let mut revisions = Vec::new();
+        for path in [&self.project_path, &self.personal_path] {
+            revisions.extend(
+                Self::read_revisions(&Self::history_path(path))?
+                    .into_iter()
+                    .filter(|r| r.learning_id == learning_id),
+            );
+        }
+        revisions.sort_by_key(|r| r.rev);
+        Ok(revisions)
+    }
+
     fn next_id(&self) -> String {
         // Scan existing learnings to find the highest counter for today
         let today = chrono::Utc::now().format("%Y%m%d").to_string();
@@ -484,6 +509,95 @@ impl MemoryBackend for MarkdownBackend {
     }
 }
 
+/// Compute relevance score for a learning against a query.
+///
+/// Shared by file-backed backends so tag, file, keyword, and ticket matches
+/// are weighted identically regardless of storage.
+pub(crate) fn compute_relevance(learning: &CompoundLearning, query: &SearchQuery) -> f64 {
+    let mut score = 0.0;
+    let mut matches = 0;
+
+    // Tag matching
+    for query_tag in &query.tags {
+        let query_tag_lower = query_tag.to_lowercase();
+        for learning_tag in &learning.tags {
+            let learning_tag_lower = learning_tag.to_lowercase();
+            if learning_tag_lower == query_tag_lower {
+                score += scores::TAG_EXACT;
+                matches += 1;
+            } else if learning_tag_lower.contains(&query_tag_lower)
+                || query_tag_lower.contains(&learning_tag_lower)
+            {
+                score += scores::TAG_PARTIAL;
+                matches += 1;
+            }
+        }
+    }
+
+    // File overlap matching
+    if let Some(ref context_files) = learning.context_files {
+        for query_file in &query.files {
+            for context_file in context_files {
+                if files_overlap(query_file, context_file) {
+                    score += scores::FILE_OVERLAP;
+                    matches += 1;
+                }
+            }
+        }
+    }
+
+    // Keyword matching (whole word in summary and detail)
+    let summary_words: Vec<String> = learning
+        .summary
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric() && c != '-' && c != '_')
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_string())
+        .collect();
+    let detail_words: Vec<String> = learning
+        .detail
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric() && c != '-' && c != '_')
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_string())
+        .collect();
+    for keyword in &query.keywords {
+        let keyword_lower = keyword.to_lowercase();
+        // Split keyword into sub-words; all must be present as whole words
+        let keyword_parts: Vec<&str> = keyword_lower
+            .split_whitespace()
+            .filter(|w| !w.is_empty())
+            .collect();
+
+        if !keyword_parts.is_empty()
+            && keyword_parts.iter().all(|part| {
+                summary_words.iter().any(|w| w == part) || detail_words.iter().any(|w| w == part)
+            })
+        {
+            score += scores::KEYWORD;
+            matches += 1;
+        }
+    }
+
+    // Ticket ID matching (exact)
+    if let Some(ref query_ticket) = query.ticket_id {
+        if let Some(ref learning_ticket) = learning.ticket_id {
+            if learning_ticket == query_ticket {
+                score += scores::TAG_EXACT;
+                matches += 1;
+            }
+        }
+    }
+
+    // Normalize score to 0.0 - 1.0 range if we have matches
+    if matches > 0 {
+        // Cap at 1.0
+        score.min(1.0)
+    } else {
+        0.0
+    }
+}
+
 /// Check if two file paths overlap (same file or one contains the other).
 ///
 /// When both paths have directory components, filename-only matching is
@@ -525,7 +639,7 @@ fn files_overlap(path1: &str, path2: &str) -> bool {
 }
 
 /// Sanitize a learning before writing.
-fn sanitize_learning(learning: &CompoundLearning) -> CompoundLearning {
+pub(crate) fn sanitize_learning(learning: &CompoundLearning) -> CompoundLearning {
     let mut sanitized = learning.clone();
     sanitized.summary = sanitize_summary(&learning.summary);
     sanitized.detail = sanitize_detail(&learning.detail);
@@ -534,7 +648,10 @@ fn sanitize_learning(learning: &CompoundLearning) -> CompoundLearning {
 }
 
 /// Check if any sanitization was applied.
-fn learning_was_sanitized(original: &CompoundLearning, sanitized: &CompoundLearning) -> bool {
+pub(crate) fn learning_was_sanitized(
+    original: &CompoundLearning,
+    sanitized: &CompoundLearning,
+) -> bool {
     original.summary != sanitized.summary
         || original.detail != sanitized.detail
         || original.tags != sanitized.tags
@@ -647,6 +764,47 @@ fn format_learning_as_markdown(learning: &CompoundLearning) -> String {
         md.push_str(&format!("**Relevance:** {}\n", ctx));
     }
 
+    // Relations
+    if !learning.relations.is_empty() {
+        md.push_str(&format!(
+            "**Relations:** {}\n",
+            format_relations(&learning.relations)
+        ));
+    }
+
+    // Applicability predicates
+    if let Some(ref applies_to) = learning.applies_to {
+        if !applies_to.is_empty() {
+            md.push_str(&format!("**Applies To:** {}\n", applies_to));
+        }
+    }
+
+    // Expiry and review dates
+    if let Some(expires_at) = learning.expires_at {
+        md.push_str(&format!(
+            "**Expires:** {}\n",
+            expires_at.format("%Y-%m-%dT%H:%M:%SZ")
+        ));
+    }
+    if let Some(review_by) = learning.review_by {
+        md.push_str(&format!(
+            "**Review By:** {}\n",
+            review_by.format("%Y-%m-%dT%H:%M:%SZ")
+        ));
+    }
+
+    // Provenance
+    if let Some(ref provenance) = learning.provenance {
+        md.push_str(&format!("**Provenance:** {}\n", provenance));
+    }
+
+    // Command guard
+    if let Some(ref guard) = learning.guard {
+        if !guard.is_empty() {
+            md.push_str(&format!("**Guard:** {}\n", guard));
+        }
+    }
+
     // Criteria met
     if !learning.criteria_met.is_empty() {
         let criteria: Vec<&str> = learning
@@ -666,6 +824,11 @@ fn format_learning_as_markdown(learning: &CompoundLearning) -> String {
     // Detail (the main content)
     md.push_str(&format!("\n{}\n", learning.detail));
 
+    // Examples
+    if !learning.examples.is_empty() {
+        md.push_str(&format!("\n{}\n", format_examples(&learning.examples)));
+    }
+
     // Separator
     md.push_str("\n---\n\n");
 
@@ -698,23 +861,31 @@ fn status_display(status: &LearningStatus) -> &'static str {
 }
 
 /// Parse learnings from markdown content.
-fn parse_learnings_from_markdown(content: &str) -> Result<Vec<CompoundLearning>> {
+pub(crate) fn parse_learnings_from_markdown(content: &str) -> Result<Vec<CompoundLearning>> {
     let mut learnings = Vec::new();
     let mut current_learning: Option<LearningBuilder> = None;
     let mut in_detail = false;
     let mut detail_lines = Vec::new();
+    let mut open_fence: Option<String> = None;
 
     for line in content.lines() {
+        // Inside a code fence, lines are detail content rather than structure
+        if let Some(ref fence) = open_fence {
+            if line.trim() == fence {
+                open_fence = None;
+            }
+            detail_lines.push(line);
+            continue;
+        }
+
         // Check for new learning header
         if let Some(id_part) = line.strip_prefix("## ") {
             // Save previous learning if any
-            if let Some(builder) = current_learning.take() {
+            if let Some(mut builder) = current_learning.take() {
                 if in_detail && !detail_lines.is_empty() {
-                    let detail = detail_lines.join("\n").trim().to_string();
-                    learnings.push(builder.with_detail(detail).build()?);
-                } else {
-                    learnings.push(builder.build()?);
+                    builder.set_detail(&detail_lines);
                 }
+                learnings.push(builder.build()?);
             }
 
             // Start new learning - validate ID to prevent path traversal
@@ -740,8 +911,7 @@ fn parse_learnings_from_markdown(content: &str) -> Result<Vec<CompoundLearning>>
         // Check for separator (end of learning)
         if line.trim() == "---" {
             if in_detail && !detail_lines.is_empty() {
-                let detail = detail_lines.join("\n").trim().to_string();
-                builder.detail = Some(detail);
+                builder.set_detail(&detail_lines);
             }
             in_detail = false;
             detail_lines.clear();
@@ -786,6 +956,18 @@ fn parse_learnings_from_markdown(content: &str) -> Result<Vec<CompoundLearning>>
                 builder.context_files = Some(parse_context_files(rest));
             } else if let Some(rest) = line.strip_prefix("**Relevance:**") {
                 builder.relevance_context = Some(rest.trim().to_string());
+            } else if let Some(rest) = line.strip_prefix("**Relations:**") {
+                builder.relations = parse_relations(rest);
+            } else if let Some(rest) = line.strip_prefix("**Applies To:**") {
+                builder.applies_to = parse_applies_to(rest);
+            } else if let Some(rest) = line.strip_prefix("**Expires:**") {
+                builder.expires_at = parse_learning_date(rest);
+            } else if let Some(rest) = line.strip_prefix("**Review By:**") {
+                builder.review_by = parse_learning_date(rest);
+            } else if let Some(rest) = line.strip_prefix("**Provenance:**") {
+                builder.provenance = parse_provenance(rest);
+            } else if let Some(rest) = line.strip_prefix("**Guard:**") {
+                builder.guard = parse_guard(rest);
             } else if let Some(rest) = line.strip_prefix("**Criteria:**") {
                 builder.criteria_met = parse_criteria(rest);
             } else if let Some(rest) = line.strip_prefix("**Created:**") {
@@ -795,7 +977,8 @@ fn parse_learnings_from_markdown(content: &str) -> Result<Vec<CompoundLearning>>
                 in_detail = true;
             }
         } else {
-            // Collecting detail lines
+            // Collecting detail lines (and example blocks)
+            open_fence = fence_marker(line);
             detail_lines.push(line);
         }
     }
@@ -804,8 +987,7 @@ fn parse_learnings_from_markdown(content: &str) -> Result<Vec<CompoundLearning>>
     if let Some(mut builder) = current_learning {
         // If we were collecting detail lines, set the detail on the builder
         if in_detail && !detail_lines.is_empty() {
-            let detail = detail_lines.join("\n").trim().to_string();
-            builder.detail = Some(detail);
+            builder.set_detail(&detail_lines);
         }
         // Always try to push the learning - build() will use defaults for missing fields
         // This ensures learnings without details (e.g., after a separator) aren't lost
@@ -831,6 +1013,13 @@ struct LearningBuilder {
     context_files: Option<Vec<String>>,
     relevance_context: Option<String>,
     criteria_met: Vec<WriteGateCriterion>,
+    relations: Vec<LearningRelation>,
+    applies_to: Option<AppliesTo>,
+    expires_at: Option<DateTime<Utc>>,
+    review_by: Option<DateTime<Utc>>,
+    examples: Vec<LearningExample>,
+    provenance: Option<Provenance>,
+    guard: Option<CommandGuard>,
     timestamp: Option<DateTime<Utc>>,
 }
 
@@ -850,13 +1039,22 @@ impl LearningBuilder {
             context_files: None,
             relevance_context: None,
             criteria_met: Vec::new(),
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            provenance: None,
+            guard: None,
             timestamp: None,
         }
     }
 
-    fn with_detail(mut self, detail: String) -> Self {
+    /// Set the detail from collected lines, splitting out example blocks.
+    fn set_detail(&mut self, lines: &[&str]) {
+        let (detail, examples) = split_examples(&lines.join("\n"));
         self.detail = Some(detail);
-        self
+        self.examples = examples;
     }
 
     fn build(self) -> Result<CompoundLearning> {
@@ -876,6 +1074,13 @@ impl LearningBuilder {
             context_files: self.context_files,
             relevance_context: self.relevance_context,
             status: self.status.unwrap_or(LearningStatus::Active),
+            relations: self.relations,
+            applies_to: self.applies_to,
+            expires_at: self.expires_at,
+            review_by: self.review_by,
+            examples: self.examples,
+            provenance: self.provenance,
+            guard: self.guard,
         })
     }
 }
@@ -963,6 +1168,210 @@ fn parse_criteria(value: &str) -> Vec<WriteGateCriterion> {
         .collect()
 }
 
+/// Format examples as captioned fenced code blocks, separated by blank lines.
+///
+/// Each example is written as:
+///
+/// ````text
+/// **Example (bad):** Caption text
+/// ```ruby
+/// code
+/// ```
+/// ````
+///
+/// The fence is lengthened when the code itself contains backtick runs.
+/// Shared by file-backed backends so examples are stored identically.
+pub(crate) fn format_examples(examples: &[LearningExample]) -> String {
+    examples
+        .iter()
+        .map(|example| {
+            let longest_run = example
+                .code
+                .split(|c| c != '`')
+                .map(str::len)
+                .max()
+                .unwrap_or(0);
+            let fence = "`".repeat((longest_run + 1).max(3));
+            let mut header = format!("{} ({}):**", EXAMPLE_PREFIX, example.kind.as_str());
+            if let Some(ref caption) = example.caption {
+                header.push(' ');
+                header.push_str(caption);
+            }
+            format!(
+                "{}\n{}{}\n{}\n{}",
+                header,
+                fence,
+                example.language.as_deref().unwrap_or_default(),
+                example.code.trim_end_matches('\n'),
+                fence
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Split example blocks out of free text.
+///
+/// Returns the remaining text (trimmed) and the examples, in order. A header
+/// not followed by a code fence is kept as ordinary text.
+pub(crate) fn split_examples(text: &str) -> (String, Vec<LearningExample>) {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut remaining = Vec::new();
+    let mut examples = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        if let Some(fence) = fence_marker(line) {
+            // Ordinary fenced block: copy it through untouched
+            let end = closing_fence(&lines, i + 1, &fence).unwrap_or(lines.len() - 1);
+            remaining.extend_from_slice(&lines[i..=end]);
+            i = end + 1;
+            continue;
+        }
+
+        let header = parse_example_header(line);
+        let opener = lines.get(i + 1).and_then(|l| fence_marker(l));
+        if let (Some((kind, caption)), Some(fence)) = (header, opener) {
+            let start = i + 2;
+            let end = closing_fence(&lines, start, &fence).unwrap_or(lines.len());
+            let language = lines[i + 1].trim()[fence.len()..].trim();
+            examples.push(LearningExample {
+                kind,
+                language: (!language.is_empty()).then(|| language.to_string()),
+                code: lines[start..end.max(start)].join("\n"),
+                caption,
+            });
+            i = end + 1;
+            continue;
+        }
+
+        remaining.push(line);
+        i += 1;
+    }
+
+    (remaining.join("\n").trim().to_string(), examples)
+}
+
+/// The backtick run opening a code fence on this line, if any.
+pub(crate) fn fence_marker(line: &str) -> Option<String> {
+    let trimmed = line.trim_start();
+    let ticks = trimmed.chars().take_while(|&c| c == '`').count();
+    (ticks >= 3).then(|| "`".repeat(ticks))
+}
+
+/// Index of the line closing `fence`, searching from `start`.
+fn closing_fence(lines: &[&str], start: usize, fence: &str) -> Option<usize> {
+    (start..lines.len()).find(|&j| lines[j].trim() == fence)
+}
+
+/// Parse an `**Example (kind):** caption` header line.
+fn parse_example_header(line: &str) -> Option<(ExampleKind, Option<String>)> {
+    let rest = line.trim().strip_prefix(EXAMPLE_PREFIX)?.trim_start();
+    let rest = rest.strip_prefix('(')?;
+    let (kind, caption) = rest.split_once("):**")?;
+    let caption = caption.trim();
+    Some((
+        ExampleKind::parse(kind)?,
+        (!caption.is_empty()).then(|| caption.to_string()),
+    ))
+}
+
+/// Format relations as comma-separated `kind target` pairs.
+///
+/// Shared by file-backed backends so relations are stored identically.
+pub(crate) fn format_relations(relations: &[LearningRelation]) -> String {
+    relations
+        .iter()
+        .map(|r| format!("{} {}", r.kind.as_str(), r.target))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Parse comma-separated `kind target` pairs.
+///
+/// Unknown kinds and invalid target IDs are skipped.
+pub(crate) fn parse_relations(value: &str) -> Vec<LearningRelation> {
+    value
+        .split(',')
+        .filter_map(|part| {
+            let (kind, target) = part.trim().split_once(' ')?;
+            let target = validate_learning_id(target.trim())?;
+            Some(LearningRelation::new(RelationKind::parse(kind)?, target))
+        })
+        .collect()
+}
+
+/// Parse `key: value; key: value` provenance (see `Provenance`'s `Display`).
+///
+/// Unknown keys are skipped; returns `None` without a recognized origin.
+pub(crate) fn parse_provenance(value: &str) -> Option<Provenance> {
+    let mut origin = None;
+    let (mut author, mut agent, mut model, mut transcript, mut grove) =
+        (None, None, None, None, None);
+    for pair in value.split("; ") {
+        let Some((key, value)) = pair.split_once(':') else {
+            continue;
+        };
+        let value = Some(value.trim().to_string());
+        match key.trim().to_lowercase().as_str() {
+            "origin" => origin = value.as_deref().and_then(ProvenanceOrigin::parse),
+            "author" => author = value,
+            "agent" => agent = value,
+            "model" => model = value,
+            "transcript" => transcript = value,
+            "grove" => grove = value.filter(|v| !v.is_empty()),
+            _ => {}
+        }
+    }
+    let mut provenance = Provenance::new(origin?)
+        .with_author(author)
+        .with_agent(agent)
+        .with_model(model)
+        .with_transcript_path(transcript);
+    provenance.grove_version = grove;
+    Some(provenance)
+}
+
+/// Parse a `severity: block; commands: [...]` command guard (see
+/// `CommandGuard`'s `Display`).
+///
+/// A missing or unknown severity means warn; returns `None` without commands.
+pub(crate) fn parse_guard(value: &str) -> Option<CommandGuard> {
+    let (head, commands) = value.split_once("commands:")?;
+    let severity = head
+        .trim()
+        .trim_end_matches(';')
+        .split_once(':')
+        .and_then(|(_, s)| GuardSeverity::parse(s))
+        .unwrap_or_default();
+    let commands: Vec<String> = serde_json::from_str(commands.trim()).ok()?;
+    let guard = CommandGuard { commands, severity }.normalized();
+    (!guard.is_empty()).then_some(guard)
+}
+
+/// Parse `key: a, b; key: c` applicability predicates (see `AppliesTo`'s `Display`).
+///
+/// Unknown keys are skipped; returns `None` when nothing was parsed.
+pub(crate) fn parse_applies_to(value: &str) -> Option<AppliesTo> {
+    let mut applies_to = AppliesTo::default();
+    for group in value.split(';') {
+        let Some((key, values)) = group.split_once(':') else {
+            continue;
+        };
+        let values: Vec<String> = values.split(',').map(|v| v.to_string()).collect();
+        match key.trim().to_lowercase().as_str() {
+            "paths" => applies_to.paths = values,
+            "languages" => applies_to.languages = values,
+            "branches" => applies_to.branches = values,
+            "tools" => applies_to.tools = values,
+            _ => {}
+        }
+    }
+    let applies_to = applies_to.normalized();
+    (!applies_to.is_empty()).then_some(applies_to)
+}
+
 fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
     DateTime::parse_from_rfc3339(value.trim())
         .ok()
@@ -978,6 +1387,7 @@ fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{ExampleKind, LearningExample, RevisionSource};
     use tempfile::TempDir;
 
     fn sample_learning() -> CompoundLearning {
@@ -1168,6 +1578,188 @@ mod tests {
         assert_eq!(parsed[0].status, LearningStatus::Active);
     }
 
+    #[test]
+    fn test_applies_to_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(".grove").join("learnings.md");
+        let backend = MarkdownBackend::new(&path);
+
+        let learning = sample_learning().with_applies_to(AppliesTo {
+            paths: vec!["migrations/**".to_string(), "*.sql".to_string()],
+            branches: vec!["release/*".to_string()],
+            ..Default::default()
+        });
+        backend.write(&learning).unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(
+            content.contains("**Applies To:** paths: migrations/**, *.sql; branches: release/*\n")
+        );
+        let parsed = backend.parse_learnings().unwrap();
+        assert_eq!(parsed[0].applies_to, learning.applies_to);
+    }
+
+    #[test]
+    fn test_expiry_and_review_dates_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(".grove").join("learnings.md");
+        let backend = MarkdownBackend::new(&path);
+
+        let learning = sample_learning()
+            .with_expires_at(parse_learning_date("2026-12-31").unwrap())
+            .with_review_by(parse_learning_date("2026-06-30T12:00:00Z").unwrap());
+        backend.write(&learning).unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains("**Expires:** 2026-12-31T00:00:00Z\n"));
+        assert!(content.contains("**Review By:** 2026-06-30T12:00:00Z\n"));
+        let parsed = backend.parse_learnings().unwrap();
+        assert_eq!(parsed[0].expires_at, learning.expires_at);
+        assert_eq!(parsed[0].review_by, learning.review_by);
+    }
+
+    #[test]
+    fn test_provenance_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(".grove").join("learnings.md");
+        let backend = MarkdownBackend::new(&path);
+
+        let mut provenance = Provenance::new(ProvenanceOrigin::Retroflect)
+            .with_author(Some("Jane Doe <jane@example.com>".to_string()))
+            .with_agent(Some("code-reviewer".to_string()))
+            .with_model(Some("ollama/llama3:8b".to_string()))
+            .with_transcript_path(Some("/home/jane/.claude/projects/p/abc.jsonl".to_string()));
+        provenance.grove_version = Some("0.10.0".to_string());
+        let learning = sample_learning().with_provenance(provenance);
+        backend.write(&learning).unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains(
+            "**Provenance:** origin: retroflect; author: Jane Doe <jane@example.com>; agent: code-reviewer; model: ollama/llama3:8b; transcript: /home/jane/.claude/projects/p/abc.jsonl; grove: 0.10.0\n"
+        ));
+        let parsed = backend.parse_learnings().unwrap();
+        assert_eq!(parsed[0].provenance, learning.provenance);
+
+        // Without a recognized origin the line is ignored
+        assert_eq!(parse_provenance("origin: import; agent: main"), None);
+    }
+
+    #[test]
+    fn test_guard_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(".grove").join("learnings.md");
+        let backend = MarkdownBackend::new(&path);
+
+        let learning = sample_learning().with_guard(CommandGuard {
+            commands: vec!["terraform apply".to_string(), "/; *rm -rf/".to_string()],
+            severity: GuardSeverity::Block,
+        });
+        backend.write(&learning).unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains(
+            "**Guard:** severity: block; commands: [\"terraform apply\",\"/; *rm -rf/\"]\n"
+        ));
+        let parsed = backend.parse_learnings().unwrap();
+        assert_eq!(parsed[0].guard, learning.guard);
+
+        // Severity defaults to warn; a line without commands is ignored
+        assert_eq!(
+            parse_guard(r#"commands: ["make deploy"]"#).map(|g| g.severity),
+            Some(GuardSeverity::Warn)
+        );
+        assert_eq!(parse_guard("severity: block; commands: []"), None);
+    }
+
+    #[test]
+    fn test_examples_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(".grove").join("learnings.md");
+        let backend = MarkdownBackend::new(&path);
+
+        let examples = vec![
+            LearningExample::new(ExampleKind::Bad, "users.each { |u| u.posts.count }")
+                .with_language("ruby")
+                .with_caption("One query per user"),
+            // Structural lines and backtick fences inside the code must survive
+            LearningExample::new(ExampleKind::Good, "---\n## not a heading\n```\nnested\n```"),
+        ];
+        let learning = sample_learning().with_examples(examples.clone());
+        backend.write(&learning).unwrap();
+        backend
+            .write(&sample_learning().with_id("cl_20260101_999"))
+            .unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains(
+            "**Example (bad):** One query per user\n```ruby\nusers.each { |u| u.posts.count }\n```\n"
+        ));
+        assert!(content.contains("**Example (good):**\n````\n---"));
+
+        let parsed = backend.parse_learnings().unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].examples, examples);
+        assert_eq!(parsed[0].detail, learning.detail);
+        assert!(parsed[1].examples.is_empty());
+    }
+
+    #[test]
+    fn test_split_examples_keeps_plain_fences_and_stray_headers() {
+        let text = "Detail text.\n\n```sh\nls\n```\n**Example (good):** no fence follows\n\n**Example (command):**\n```\nmake\n```";
+        let (rest, examples) = split_examples(text);
+        assert_eq!(
+            rest,
+            "Detail text.\n\n```sh\nls\n```\n**Example (good):** no fence follows"
+        );
+        assert_eq!(
+            examples,
+            vec![LearningExample::new(ExampleKind::Command, "make")]
+        );
+    }
+
+    #[test]
+    fn test_parse_applies_to_skips_unknown_keys() {
+        let applies_to =
+            parse_applies_to(" Languages: Rust, TOML ; owners: alice; tools: cargo").unwrap();
+        assert_eq!(applies_to.languages, vec!["rust", "toml"]);
+        assert_eq!(applies_to.tools, vec!["cargo"]);
+        assert!(applies_to.paths.is_empty());
+        assert!(parse_applies_to("owners: alice").is_none());
+    }
+
+    #[test]
+    fn test_relations_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(".grove").join("learnings.md");
+        let backend = MarkdownBackend::new(&path);
+
+        let learning = sample_learning().with_relations(vec![
+            LearningRelation::new(RelationKind::Supersedes, "cl_20260101_001"),
+            LearningRelation::new(RelationKind::DependsOn, "cl_20260101_002"),
+        ]);
+        backend.write(&learning).unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content
+            .contains("**Relations:** supersedes cl_20260101_001, depends_on cl_20260101_002"));
+
+        let parsed = backend.parse_learnings().unwrap();
+        assert_eq!(parsed[0].relations, learning.relations);
+    }
+
+    #[test]
+    fn test_parse_relations_skips_invalid_entries() {
+        let relations =
+            parse_relations(" refines cl_a, blocks cl_b, related ../etc, depends-on cl_c");
+        assert_eq!(
+            relations,
+            vec![
+                LearningRelation::new(RelationKind::Refines, "cl_a"),
+                LearningRelation::new(RelationKind::DependsOn, "cl_c"),
+            ]
+        );
+    }
+
     #[test]
     fn test_parse_empty_file() {
         let temp = TempDir::new().unwrap();
@@ -1345,6 +1937,88 @@ mod tests {
         assert_eq!(parsed[0].status, LearningStatus::Active);
     }
 
+    #[test]
+    fn test_update_learning_in_place() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(".grove").join("learnings.md");
+        let backend = MarkdownBackend::new(&path);
+
+        let first = sample_learning().with_id("cl_20260101_000");
+        let second = sample_learning().with_id("cl_20260101_001");
+        backend.write(&first).unwrap();
+        backend.write(&second).unwrap();
+
+        let mut edited = first.clone();
+        edited.summary = "Corrected summary text".to_string();
+        edited.tags = vec!["edited".to_string()];
+        edited.relevance_context = Some("when editing".to_string());
+        backend.update(&edited).unwrap();
+
+        let parsed = backend.parse_learnings().unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].id, "cl_20260101_000");
+        assert_eq!(parsed[0].summary, "Corrected summary text");
+        assert_eq!(parsed[0].tags, vec!["edited"]);
+        assert_eq!(parsed[0].relevance_context.as_deref(), Some("when editing"));
+        assert_eq!(parsed[1].summary, second.summary);
+    }
+
+    #[test]
+    fn test_update_nonexistent() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(".grove").join("learnings.md");
+        let backend = MarkdownBackend::new(&path);
+
+        assert!(backend.update(&sample_learning()).is_err());
+    }
+
+    #[test]
+    fn test_record_revision_numbers_per_learning() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(".grove").join("learnings.md");
+        let backend = MarkdownBackend::with_paths(&path, temp.path().join("personal.md"));
+
+        let learning = sample_learning();
+        backend.write(&learning).unwrap();
+        let mut edited = learning.clone();
+        edited.summary = "Edited summary for the sample learning".to_string();
+
+        let created = LearningRevision::created(&learning, RevisionSource::Reflect);
+        let changed = LearningRevision::changed(&learning, &edited, RevisionSource::Edit);
+        let mut other = created.clone();
+        other.learning_id = "cl_20260101_999".to_string();
+
+        assert_eq!(backend.record_revision(&created).unwrap(), 1);
+        assert_eq!(backend.record_revision(&other).unwrap(), 1);
+        assert_eq!(backend.record_revision(&changed).unwrap(), 2);
+
+        let history = backend.history(&learning.id).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].source, RevisionSource::Reflect);
+        assert_eq!(history[1].rev, 2);
+        assert_eq!(history[1].fields(), vec!["summary"]);
+        assert!(temp.path().join(".grove/learnings.history.jsonl").exists());
+    }
+
+    #[test]
+    fn test_personal_history_stored_next_to_personal_file() {
+        let temp = TempDir::new().unwrap();
+        let project = temp.path().join(".grove").join("learnings.md");
+        let personal = temp.path().join("home").join("personal-learnings.md");
+        let backend = MarkdownBackend::with_paths(&project, &personal);
+
+        let mut learning = sample_learning();
+        learning.scope = LearningScope::Personal;
+        backend.write(&learning).unwrap();
+
+        let revision = LearningRevision::created(&learning, RevisionSource::Retroflect);
+        backend.record_revision(&revision).unwrap();
+
+        assert!(personal.with_extension("history.jsonl").exists());
+        assert!(!project.with_extension("history.jsonl").exists());
+        assert_eq!(backend.history(&learning.id).unwrap().len(), 1);
+    }
+
     #[test]
     fn test_archive_nonexistent() {
         let temp = TempDir::new().unwrap();
@@ -0,0 +1,955 @@
+//! MCP memory server backend for Grove.
+//!
+//! This module provides a backend that stores learnings in a Model Context
+//! Protocol memory server (such as `@modelcontextprotocol/server-memory`).
+//! Grove launches the configured server command and speaks MCP JSON-RPC over
+//! its stdin/stdout. The session is opened on first use and reused by every
+//! later operation on the same backend.
+//!
+//! Each learning maps to one knowledge-graph entity:
+//! - `name`: the learning ID
+//! - `entityType`: `grove_learning`
+//! - `observations`: readable `key: value` lines (summary, detail, category,
+//!   scope, status, tags, ticket, files) plus a `grove_data:` line holding the
+//!   full learning as JSON, which is what Grove reads back
+//!
+//! Relevance scoring happens locally with the same weights as the markdown
+//! backend. Like Total Recall, an unreachable server never blocks: writes
+//! return a failure result (so [`FallbackBackend`](super::FallbackBackend)
+//! can take over) and searches return no results.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde_json::{json, Value};
+use tracing::warn;
+
+use crate::backends::markdown::{compute_relevance, learning_was_sanitized, sanitize_learning};
+use crate::backends::stdio::StdioProcess;
+use crate::backends::traits::{
+    MemoryBackend, SearchFilters, SearchQuery, SearchResult, WriteResult,
+};
+use crate::config::McpBackendConfig;
+use crate::core::{CompoundLearning, LearningScope, LearningStatus};
+use crate::error::{GroveError, Result};
+use crate::stats::tracker::GROVE_VERSION;
+
+/// MCP protocol revision requested during initialization.
+const PROTOCOL_VERSION: &str = "2024-11-05";
+
+/// Entity type used for all Grove learnings.
+pub const ENTITY_TYPE: &str = "grove_learning";
+
+/// Observation prefix carrying the serialized learning.
+const DATA_PREFIX: &str = "grove_data: ";
+
+/// Observation prefix carrying the learning status.
+const STATUS_PREFIX: &str = "status: ";
+
+/// MCP memory server backend.
+///
+/// Supports:
+/// - Writing learnings as knowledge-graph entities
+/// - Searching via `search_nodes` with local relevance scoring
+/// - Archiving/restoring by swapping status observations
+#[derive(Debug, Clone)]
+pub struct McpBackend {
+    /// Server executable.
+    command: String,
+    /// Server arguments.
+    args: Vec<String>,
+    /// Extra environment for the server process.
+    env: std::collections::HashMap<String, String>,
+    /// Working directory for the server process.
+    cwd: PathBuf,
+    /// Per-response timeout.
+    timeout: Duration,
+    /// Open session, reused across operations (shared by clones).
+    session: Arc<Mutex<Option<McpSession>>>,
+}
+
+impl McpBackend {
+    /// Create a backend from `[backends.mcp]` config, launching the server in `cwd`.
+    pub fn new(config: &McpBackendConfig, cwd: impl AsRef<Path>) -> Self {
+        Self {
+            command: config.command.clone(),
+            args: config.args.clone(),
+            env: config.env.clone(),
+            cwd: cwd.as_ref().to_path_buf(),
+            timeout: Duration::from_secs(config.timeout_seconds.max(1)),
+            session: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Run `f` with the open session, connecting first if needed.
+    ///
+    /// A failed operation drops the session so the next one reconnects.
+    fn with_session<T>(&self, f: impl FnOnce(&mut McpSession) -> Result<T>) -> Result<T> {
+        let mut guard = self
+            .session
+            .lock()
+            .map_err(|_| GroveError::backend("MCP session lock poisoned"))?;
+        let session = match guard.as_mut() {
+            Some(session) => session,
+            None => guard.insert(self.connect()?),
+        };
+
+        let result = f(session);
+        if result.is_err() {
+            *guard = None;
+        }
+        result
+    }
+
+    /// Open an initialized MCP session with the server.
+    fn connect(&self) -> Result<McpSession> {
+        let process = StdioProcess::spawn(
+            &self.command,
+            &self.args,
+            &self.env,
+            Some(&self.cwd),
+            self.timeout,
+        )?;
+        let mut session = McpSession {
+            process,
+            next_request_id: 1,
+        };
+
+        session.request(
+            "initialize",
+            json!({
+                "protocolVersion": PROTOCOL_VERSION,
+                "capabilities": {},
+                "clientInfo": { "name": "grove", "version": GROVE_VERSION },
+            }),
+        )?;
+        session.notify("notifications/initialized")?;
+
+        Ok(session)
+    }
+
+    /// Fetch all Grove entities from the server.
+    fn load_learnings(&self) -> Result<Vec<CompoundLearning>> {
+        let result = self.with_session(|session| {
+            session.call_tool("search_nodes", json!({ "query": ENTITY_TYPE }))
+        })?;
+        Ok(entities_from_result(&result)
+            .iter()
+            .filter_map(entity_to_learning)
+            .collect())
+    }
+
+    /// Update the status of a learning by replacing its status and data observations.
+    fn update_status(&self, learning_id: &str, new_status: LearningStatus) -> Result<()> {
+        self.with_session(|session| {
+            let entity = session.open_entity(learning_id)?.ok_or_else(|| {
+                GroveError::backend(format!("Learning {} not found", learning_id))
+            })?;
+
+            let mut learning = entity_to_learning(&entity).ok_or_else(|| {
+                GroveError::backend(format!("Learning {} has no grove data", learning_id))
+            })?;
+            learning.status = new_status;
+
+            let stale: Vec<String> = observations(&entity)
+                .into_iter()
+                .filter(|o| o.starts_with(STATUS_PREFIX) || o.starts_with(DATA_PREFIX))
+                .collect();
+
+            session.call_tool(
+                "delete_observations",
+                json!({ "deletions": [{ "entityName": learning_id, "observations": stale }] }),
+            )?;
+            session.call_tool(
+                "add_observations",
+                json!({
+                    "observations": [{
+                        "entityName": learning_id,
+                        "contents": [status_observation(&learning), data_observation(&learning)?],
+                    }]
+                }),
+            )?;
+
+            Ok(())
+        })
+    }
+
+    /// Replace every observation of a learning's entity with fresh ones.
+    fn replace_observations(&self, learning: &CompoundLearning) -> Result<()> {
+        let learning = &sanitize_learning(learning);
+        self.with_session(|session| {
+            let entity = session.open_entity(&learning.id)?.ok_or_else(|| {
+                GroveError::backend(format!("Learning {} not found", learning.id))
+            })?;
+            session.replace_entity(&entity, learning)
+        })
+    }
+}
+
+impl MemoryBackend for McpBackend {
+    fn write(&self, learning: &CompoundLearning) -> Result<WriteResult> {
+        if learning.scope == LearningScope::Ephemeral {
+            // Ephemeral scope - discard
+            return Ok(WriteResult::success_with_message(
+                &learning.id,
+                "ephemeral",
+                "Ephemeral learning discarded (not persisted)",
+            ));
+        }
+
+        // Same sanitization as the markdown backend, so stored content is
+        // interchangeable between the two
+        let sanitized = sanitize_learning(learning);
+
+        // The memory server ignores create_entities for names it already
+        // has, so an existing learning is overwritten observation by observation
+        let result = self.with_session(|session| match session.open_entity(&sanitized.id)? {
+            Some(existing) => session.replace_entity(&existing, &sanitized),
+            None => session
+                .call_tool(
+                    "create_entities",
+                    json!({ "entities": [learning_to_entity(&sanitized)?] }),
+                )
+                .map(|_| ()),
+        });
+
+        match result {
+            Ok(_) if learning_was_sanitized(learning, &sanitized) => {
+                Ok(WriteResult::success_with_message(
+                    &learning.id,
+                    format!("mcp:{}", self.command),
+                    "Content was sanitized",
+                ))
+            }
+            Ok(_) => Ok(WriteResult::success(
+                &learning.id,
+                format!("mcp:{}", self.command),
+            )),
+            Err(err) => {
+                // Fail-open: log warning, return failure result but don't block
+                warn!("MCP write failed: {}", err);
+                Ok(WriteResult::failure(&learning.id, "Backend unavailable"))
+            }
+        }
+    }
+
+    fn search(&self, query: &SearchQuery, filters: &SearchFilters) -> Result<Vec<SearchResult>> {
+        let learnings = match self.load_learnings() {
+            Ok(learnings) => learnings,
+            Err(err) => {
+                warn!("MCP search failed: {}", err);
+                return Ok(Vec::new());
+            }
+        };
+
+        let mut results: Vec<SearchResult> = learnings
+            .into_iter()
+            .filter(|learning| filters.matches(learning))
+            .filter_map(|learning| {
+                let relevance = if query.is_empty() {
+                    1.0
+                } else {
+                    compute_relevance(&learning, query)
+                };
+
+                if relevance > 0.0 || query.is_empty() {
+                    Some(SearchResult::new(learning, relevance))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        // Sort by relevance (highest first)
+        results.sort_by(|a, b| {
+            b.relevance
+                .partial_cmp(&a.relevance)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        if let Some(limit) = filters.max_results {
+            results.truncate(limit);
+        }
+
+        Ok(results)
+    }
+
+    fn ping(&self) -> bool {
+        self.with_session(|session| session.request("ping", json!({})))
+            .is_ok()
+    }
+
+    fn name(&self) -> &'static str {
+        "mcp"
+    }
+
+    fn archive(&self, learning_id: &str) -> Result<()> {
+        self.update_status(learning_id, LearningStatus::Archived)
+    }
+
+    fn restore(&self, learning_id: &str) -> Result<()> {
+        self.update_status(learning_id, LearningStatus::Active)
+    }
+
+    fn update(&self, learning: &CompoundLearning) -> Result<()> {
+        self.replace_observations(learning)
+    }
+
+    fn next_id(&self) -> String {
+        // Scan existing entities to find the highest counter for today.
+        // If the server is unreachable, start at 000 so a fallback
+        // backend's higher counter wins in FallbackBackend::next_id.
+        let today = chrono::Utc::now().format("%Y%m%d").to_string();
+        let today_prefix = format!("cl_{}_", today);
+
+        let next_counter = self
+            .load_learnings()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|l| l.id.strip_prefix(&today_prefix)?.parse::<u32>().ok())
+            .max()
+            .map(|max| max + 1)
+            .unwrap_or(0);
+
+        format!("cl_{}_{:03}", today, next_counter)
+    }
+}
+
+/// An initialized JSON-RPC session with an MCP server.
+#[derive(Debug)]
+struct McpSession {
+    /// The server process.
+    process: StdioProcess,
+    /// ID for the next request.
+    next_request_id: u64,
+}
+
+impl McpSession {
+    /// Send a request and wait for the response with the matching ID.
+    ///
+    /// Server notifications and non-JSON lines are skipped, within one
+    /// timeout for the whole response.
+    fn request(&mut self, method: &str, params: Value) -> Result<Value> {
+        let id = self.next_request_id;
+        self.next_request_id += 1;
+
+        let message = json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params });
+        self.process.send(&message.to_string())?;
+
+        let deadline = self.process.deadline();
+        loop {
+            let line = self.process.recv_by(deadline)?;
+            let Ok(response) = serde_json::from_str::<Value>(&line) else {
+                continue;
+            };
+            if response.get("id").and_then(Value::as_u64) != Some(id) {
+                continue;
+            }
+
+            if let Some(error) = response.get("error") {
+                let message = error
+                    .get("message")
+                    .and_then(Value::as_str)
+                    .unwrap_or("unknown error");
+                return Err(GroveError::backend(format!(
+                    "MCP {} failed: {}",
+                    method, message
+                )));
+            }
+
+            return Ok(response.get("result").cloned().unwrap_or(Value::Null));
+        }
+    }
+
+    /// Send a notification (no response expected).
+    fn notify(&mut self, method: &str) -> Result<()> {
+        let message = json!({ "jsonrpc": "2.0", "method": method });
+        self.process.send(&message.to_string())
+    }
+
+    /// Call a server tool, turning tool-level errors into backend errors.
+    fn call_tool(&mut self, name: &str, arguments: Value) -> Result<Value> {
+        let result = self.request(
+            "tools/call",
+            json!({ "name": name, "arguments": arguments }),
+        )?;
+
+        if result.get("isError").and_then(Value::as_bool) == Some(true) {
+            return Err(GroveError::backend(format!(
+                "MCP tool {} failed: {}",
+                name,
+                tool_text(&result)
+            )));
+        }
+
+        Ok(result)
+    }
+
+    /// Fetch the entity named `name`, if the server has it.
+    fn open_entity(&mut self, name: &str) -> Result<Option<Value>> {
+        let result = self.call_tool("open_nodes", json!({ "names": [name] }))?;
+        Ok(entities_from_result(&result)
+            .into_iter()
+            .find(|e| e.get("name").and_then(Value::as_str) == Some(name)))
+    }
+
+    /// Swap all observations of an existing entity for those of `learning`.
+    fn replace_entity(&mut self, entity: &Value, learning: &CompoundLearning) -> Result<()> {
+        self.call_tool(
+            "delete_observations",
+            json!({ "deletions": [{ "entityName": learning.id, "observations": observations(entity) }] }),
+        )?;
+        self.call_tool(
+            "add_observations",
+            json!({
+                "observations": [{
+                    "entityName": learning.id,
+                    "contents": learning_to_entity(learning)?["observations"],
+                }]
+            }),
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Concatenate the text content blocks of a tool result.
+fn tool_text(result: &Value) -> String {
+    result
+        .get("content")
+        .and_then(Value::as_array)
+        .map(|blocks| {
+            blocks
+                .iter()
+                .filter_map(|b| b.get("text").and_then(Value::as_str))
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .unwrap_or_default()
+}
+
+/// Extract graph entities from a `search_nodes`/`open_nodes` tool result.
+///
+/// Prefers `structuredContent` and falls back to parsing the text content as JSON.
+fn entities_from_result(result: &Value) -> Vec<Value> {
+    let graph = match result.get("structuredContent") {
+        Some(structured) => structured.clone(),
+        None => serde_json::from_str(&tool_text(result)).unwrap_or(Value::Null),
+    };
+
+    graph
+        .get("entities")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Get the observations of an entity as strings.
+fn observations(entity: &Value) -> Vec<String> {
+    entity
+        .get("observations")
+        .and_then(Value::as_array)
+        .map(|obs| {
+            obs.iter()
+                .filter_map(|o| o.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Build the status observation for a learning.
+fn status_observation(learning: &CompoundLearning) -> String {
+    format!("{}{:?}", STATUS_PREFIX, learning.status).to_lowercase()
+}
+
+/// Build the data observation holding the serialized learning.
+fn data_observation(learning: &CompoundLearning) -> Result<String> {
+    let data = serde_json::to_string(learning)
+        .map_err(|e| GroveError::backend(format!("Failed to serialize learning: {}", e)))?;
+    Ok(format!("{}{}", DATA_PREFIX, data))
+}
+
+/// Map a learning to an MCP knowledge-graph entity.
+pub fn learning_to_entity(learning: &CompoundLearning) -> Result<Value> {
+    let mut obs = vec![
+        format!("summary: {}", learning.summary),
+        format!("detail: {}", learning.detail),
+        format!("category: {}", learning.category.display_name()),
+        format!("scope: {:?}", learning.scope).to_lowercase(),
+        status_observation(learning),
+    ];
+    if !learning.tags.is_empty() {
+        obs.push(format!("tags: {}", learning.tags.join(", ")));
+    }
+    if let Some(ref ticket_id) = learning.ticket_id {
+        obs.push(format!("ticket: {}", ticket_id));
+    }
+    if let Some(ref files) = learning.context_files {
+        if !files.is_empty() {
+            obs.push(format!("files: {}", files.join(", ")));
+        }
+    }
+    obs.push(data_observation(learning)?);
+
+    Ok(json!({
+        "name": learning.id,
+        "entityType": ENTITY_TYPE,
+        "observations": obs,
+    }))
+}
+
+/// Map an MCP entity back to a learning.
+///
+/// Returns `None` for entities that were not written by Grove.
+pub fn entity_to_learning(entity: &Value) -> Option<CompoundLearning> {
+    if entity.get("entityType").and_then(Value::as_str) != Some(ENTITY_TYPE) {
+        return None;
+    }
+
+    observations(entity)
+        .iter()
+        .find_map(|o| o.strip_prefix(DATA_PREFIX))
+        .and_then(|data| serde_json::from_str(data).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::{FallbackBackend, MarkdownBackend};
+    use crate::core::{Confidence, LearningCategory, WriteGateCriterion};
+    use std::collections::HashMap;
+    use std::io::{BufRead, Write};
+    use tempfile::TempDir;
+
+    /// Environment variable that turns the ignored stub test into a server.
+    const STUB_ENV: &str = "GROVE_MCP_STUB_SERVER";
+
+    fn sample_learning() -> CompoundLearning {
+        CompoundLearning::new(
+            LearningCategory::Pitfall,
+            "Avoid N+1 queries in UserDashboard",
+            "The dashboard was loading users then iterating to load posts separately. Use eager loading instead.",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::BehaviorChanging],
+            vec!["performance".to_string(), "database".to_string()],
+            "test-session-123",
+        )
+        .with_ticket_id("T001")
+        .with_context_files(vec!["src/dashboard.rs".to_string()])
+    }
+
+    /// Config that re-runs this test binary as the stub MCP server.
+    ///
+    /// The stub keeps its graph in `store`, so each session sees earlier writes.
+    fn stub_config(store: &Path) -> McpBackendConfig {
+        let exe = std::env::current_exe().unwrap();
+        let mut env = HashMap::new();
+        env.insert(STUB_ENV.to_string(), store.display().to_string());
+        McpBackendConfig {
+            command: exe.display().to_string(),
+            args: vec![
+                "backends::mcp::tests::stub_mcp_server".to_string(),
+                "--exact".to_string(),
+                "--ignored".to_string(),
+                "--nocapture".to_string(),
+                "--test-threads=1".to_string(),
+                "-q".to_string(),
+            ],
+            env,
+            timeout_seconds: 10,
+        }
+    }
+
+    fn setup() -> (TempDir, McpBackend) {
+        let temp = TempDir::new().unwrap();
+        let config = stub_config(&temp.path().join("graph.json"));
+        let backend = McpBackend::new(&config, temp.path());
+        (temp, backend)
+    }
+
+    /// Minimal MCP memory server used by the tests below.
+    ///
+    /// Implements the subset of `@modelcontextprotocol/server-memory` that
+    /// Grove uses, persisting the graph to the file named by `STUB_ENV`.
+    #[test]
+    #[ignore = "helper process for McpBackend tests"]
+    fn stub_mcp_server() {
+        let Some(store) = std::env::var_os(STUB_ENV) else {
+            return;
+        };
+        let store = PathBuf::from(store);
+
+        // Record each launch so tests can check session reuse
+        let mut launches = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(store.with_extension("launches"))
+            .unwrap();
+        writeln!(launches, "launch").unwrap();
+
+        let mut entities: Vec<Value> = std::fs::read_to_string(&store)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+
+        let stdin = std::io::stdin();
+        let mut stdout = std::io::stdout();
+        for line in stdin.lock().lines() {
+            let Ok(line) = line else { break };
+            let Ok(request) = serde_json::from_str::<Value>(&line) else {
+                continue;
+            };
+            let Some(id) = request.get("id").cloned() else {
+                continue; // notification
+            };
+            let params = request.get("params").cloned().unwrap_or(Value::Null);
+            let args = params.get("arguments").cloned().unwrap_or(Value::Null);
+
+            let graph_result = |entities: Vec<Value>| {
+                let text = json!({ "entities": entities, "relations": [] }).to_string();
+                json!({ "content": [{ "type": "text", "text": text }] })
+            };
+
+            let result = match request["method"].as_str().unwrap_or_default() {
+                "initialize" => json!({
+                    "protocolVersion": PROTOCOL_VERSION,
+                    "capabilities": { "tools": {} },
+                    "serverInfo": { "name": "stub-memory", "version": "0.0.0" },
+                }),
+                "ping" => json!({}),
+                "tools/call" => match params["name"].as_str().unwrap_or_default() {
+                    "create_entities" => {
+                        for entity in args["entities"].as_array().cloned().unwrap_or_default() {
+                            if !entities.iter().any(|e| e["name"] == entity["name"]) {
+                                entities.push(entity);
+                            }
+                        }
+                        json!({ "content": [{ "type": "text", "text": "ok" }] })
+                    }
+                    "search_nodes" => {
+                        let q = args["query"].as_str().unwrap_or_default().to_lowercase();
+                        let found = entities
+                            .iter()
+                            .filter(|e| e.to_string().to_lowercase().contains(&q))
+                            .cloned()
+                            .collect();
+                        graph_result(found)
+                    }
+                    "open_nodes" => {
+                        let names = args["names"].as_array().cloned().unwrap_or_default();
+                        let found = entities
+                            .iter()
+                            .filter(|e| names.contains(&e["name"]))
+                            .cloned()
+                            .collect();
+                        graph_result(found)
+                    }
+                    "delete_observations" => {
+                        for d in args["deletions"].as_array().cloned().unwrap_or_default() {
+                            if let Some(e) =
+                                entities.iter_mut().find(|e| e["name"] == d["entityName"])
+                            {
+                                let remove =
+                                    d["observations"].as_array().cloned().unwrap_or_default();
+                                if let Some(obs) = e["observations"].as_array_mut() {
+                                    obs.retain(|o| !remove.contains(o));
+                                }
+                            }
+                        }
+                        json!({ "content": [{ "type": "text", "text": "ok" }] })
+                    }
+                    "add_observations" => {
+                        for a in args["observations"].as_array().cloned().unwrap_or_default() {
+                            if let Some(e) =
+                                entities.iter_mut().find(|e| e["name"] == a["entityName"])
+                            {
+                                if let Some(obs) = e["observations"].as_array_mut() {
+                                    obs.extend(
+                                        a["contents"].as_array().cloned().unwrap_or_default(),
+                                    );
+                                }
+                            }
+                        }
+                        json!({ "content": [{ "type": "text", "text": "ok" }] })
+                    }
+                    other => json!({
+                        "content": [{ "type": "text", "text": format!("unknown tool {}", other) }],
+                        "isError": true,
+                    }),
+                },
+                other => {
+                    let response = json!({
+                        "jsonrpc": "2.0",
+                        "id": id,
+                        "error": { "code": -32601, "message": format!("unknown method {}", other) },
+                    });
+                    writeln!(stdout, "{}", response).unwrap();
+                    stdout.flush().unwrap();
+                    continue;
+                }
+            };
+
+            std::fs::write(&store, serde_json::to_string(&entities).unwrap()).unwrap();
+            let response = json!({ "jsonrpc": "2.0", "id": id, "result": result });
+            writeln!(stdout, "{}", response).unwrap();
+            stdout.flush().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_entity_roundtrip() {
+        let learning = sample_learning();
+        let entity = learning_to_entity(&learning).unwrap();
+
+        assert_eq!(entity["name"], learning.id.as_str());
+        assert_eq!(entity["entityType"], ENTITY_TYPE);
+        let obs = observations(&entity);
+        assert!(obs.contains(&"summary: Avoid N+1 queries in UserDashboard".to_string()));
+        assert!(obs.contains(&"status: active".to_string()));
+        assert!(obs.contains(&"tags: performance, database".to_string()));
+
+        assert_eq!(entity_to_learning(&entity), Some(learning));
+    }
+
+    #[test]
+    fn test_entity_to_learning_ignores_foreign_entities() {
+        let entity = json!({
+            "name": "Alice",
+            "entityType": "person",
+            "observations": ["likes tea"],
+        });
+        assert!(entity_to_learning(&entity).is_none());
+    }
+
+    #[test]
+    fn test_entities_from_text_and_structured_content() {
+        let text = json!({
+            "content": [{ "type": "text", "text": "{\"entities\":[{\"name\":\"a\"}],\"relations\":[]}" }]
+        });
+        assert_eq!(entities_from_result(&text).len(), 1);
+
+        let structured = json!({
+            "content": [],
+            "structuredContent": { "entities": [{ "name": "a" }, { "name": "b" }] }
+        });
+        assert_eq!(entities_from_result(&structured).len(), 2);
+    }
+
+    #[test]
+    fn test_write_and_search_via_stub_server() {
+        let (_temp, backend) = setup();
+        let learning = sample_learning();
+
+        let result = backend.write(&learning).unwrap();
+        assert!(result.success, "write failed: {:?}", result.message);
+        assert!(result.location.starts_with("mcp:"));
+
+        let results = backend
+            .search(
+                &SearchQuery::with_tags(vec!["performance".to_string()]),
+                &SearchFilters::default(),
+            )
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].learning, learning);
+        assert_eq!(results[0].relevance, 1.0);
+
+        let none = backend
+            .search(
+                &SearchQuery::with_tags(vec!["security".to_string()]),
+                &SearchFilters::default(),
+            )
+            .unwrap();
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn test_archive_and_restore_via_stub_server() {
+        let (_temp, backend) = setup();
+        let learning = sample_learning();
+        backend.write(&learning).unwrap();
+
+        backend.archive(&learning.id).unwrap();
+        let active = backend
+            .search(&SearchQuery::new(), &SearchFilters::active_only())
+            .unwrap();
+        assert!(active.is_empty());
+
+        let all = backend.list_all().unwrap();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].status, LearningStatus::Archived);
+
+        backend.restore(&learning.id).unwrap();
+        let active = backend
+            .search(&SearchQuery::new(), &SearchFilters::active_only())
+            .unwrap();
+        assert_eq!(active.len(), 1);
+
+        assert!(backend.archive("cl_20990101_001").is_err());
+    }
+
+    #[test]
+    fn test_update_via_stub_server() {
+        let (_temp, backend) = setup();
+        let learning = sample_learning();
+        backend.write(&learning).unwrap();
+
+        let mut edited = learning.clone();
+        edited.summary = "Edited summary via MCP".to_string();
+        edited.tags = vec!["edited".to_string()];
+        backend.update(&edited).unwrap();
+
+        assert_eq!(backend.list_all().unwrap(), vec![edited]);
+
+        let mut missing = learning;
+        missing.id = "cl_20990101_001".to_string();
+        assert!(backend.update(&missing).is_err());
+    }
+
+    #[test]
+    fn test_next_id_and_ping_via_stub_server() {
+        let (_temp, backend) = setup();
+        let today = chrono::Utc::now().format("%Y%m%d").to_string();
+        assert!(backend.ping());
+        assert_eq!(backend.next_id(), format!("cl_{}_000", today));
+
+        let mut learning = sample_learning();
+        learning.id = format!("cl_{}_005", today);
+        backend.write(&learning).unwrap();
+
+        assert_eq!(backend.next_id(), format!("cl_{}_006", today));
+    }
+
+    #[test]
+    fn test_write_existing_id_overwrites_via_stub_server() {
+        let (_temp, backend) = setup();
+        let learning = sample_learning();
+        backend.write(&learning).unwrap();
+
+        let mut replacement = learning.clone();
+        replacement.summary = "Replacement written under the same ID".to_string();
+        let result = backend.write(&replacement).unwrap();
+        assert!(result.success, "write failed: {:?}", result.message);
+
+        assert_eq!(backend.list_all().unwrap(), vec![replacement]);
+    }
+
+    #[test]
+    fn test_write_and_update_sanitize_like_markdown_backend() {
+        let (_temp, backend) = setup();
+        let mut learning = sample_learning();
+        learning.summary = "Line one\n## injected | header".to_string();
+        learning.tags = vec!["Needs Cleanup!".to_string()];
+
+        let result = backend.write(&learning).unwrap();
+        assert_eq!(result.message.as_deref(), Some("Content was sanitized"));
+
+        let stored = backend.list_all().unwrap().remove(0);
+        assert_eq!(stored, sanitize_learning(&learning));
+        assert_ne!(stored.summary, learning.summary);
+
+        let mut edited = stored.clone();
+        edited.detail = "  ```rust\nunclosed fence".to_string();
+        backend.update(&edited).unwrap();
+        assert_eq!(
+            backend.list_all().unwrap()[0].detail,
+            "```rust\nunclosed fence\n```"
+        );
+    }
+
+    #[test]
+    fn test_operations_reuse_one_server_process() {
+        let (temp, backend) = setup();
+        let learning = sample_learning();
+
+        backend.next_id();
+        backend.write(&learning).unwrap();
+        backend
+            .search(&SearchQuery::new(), &SearchFilters::default())
+            .unwrap();
+        backend.archive(&learning.id).unwrap();
+
+        let launches = std::fs::read_to_string(temp.path().join("graph.launches")).unwrap();
+        assert_eq!(launches.lines().count(), 1);
+    }
+
+    #[test]
+    fn test_ephemeral_scope_is_discarded() {
+        let (temp, backend) = setup();
+        let mut learning = sample_learning();
+        learning.scope = LearningScope::Ephemeral;
+
+        let result = backend.write(&learning).unwrap();
+        assert!(result.success);
+        assert_eq!(result.location, "ephemeral");
+        assert!(!temp.path().join("graph.json").exists());
+    }
+
+    #[test]
+    fn test_unreachable_server_fails_open() {
+        let temp = TempDir::new().unwrap();
+        let config = McpBackendConfig {
+            command: "/nonexistent/grove-mcp-server".to_string(),
+            ..McpBackendConfig::default()
+        };
+        let backend = McpBackend::new(&config, temp.path());
+
+        assert!(!backend.ping());
+        let result = backend.write(&sample_learning()).unwrap();
+        assert!(!result.success);
+        assert!(backend
+            .search(&SearchQuery::new(), &SearchFilters::default())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_chatty_server_times_out_overall() {
+        let temp = TempDir::new().unwrap();
+        let config = McpBackendConfig {
+            command: "sh".to_string(),
+            args: vec![
+                "-c".to_string(),
+                "while true; do echo '{\"jsonrpc\":\"2.0\",\"method\":\"log\"}'; sleep 0.1; done"
+                    .to_string(),
+            ],
+            timeout_seconds: 1,
+            ..McpBackendConfig::default()
+        };
+        let backend = McpBackend::new(&config, temp.path());
+
+        let started = std::time::Instant::now();
+        assert!(!backend.ping());
+        assert!(started.elapsed() < std::time::Duration::from_secs(3));
+    }
+
+    #[test]
+    fn test_unreachable_server_falls_back_to_markdown() {
+        let temp = TempDir::new().unwrap();
+        let config = McpBackendConfig {
+            command: "/nonexistent/grove-mcp-server".to_string(),
+            ..McpBackendConfig::default()
+        };
+        let md_path = temp.path().join(".grove/learnings.md");
+        let backend = FallbackBackend::new(
+            Box::new(McpBackend::new(&config, temp.path())),
+            Box::new(MarkdownBackend::with_paths(
+                &md_path,
+                temp.path().join("personal.md"),
+            )),
+        );
+
+        let result = backend.write(&sample_learning()).unwrap();
+        assert!(result.success);
+        assert!(result.location.contains("(fallback)"));
+        assert_eq!(
+            backend
+                .search(&SearchQuery::new(), &SearchFilters::default())
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+}
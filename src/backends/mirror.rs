@@ -0,0 +1,445 @@
+//! Mirror backend wrapper for Grove.
+//!
+//! This module provides a backend wrapper that writes every learning to
+//! several backends at once, so the same learnings are visible everywhere
+//! (for example Total Recall plus a committed markdown file for teammates
+//! who don't use Total Recall).
+
+use std::collections::{HashMap, HashSet};
+
+use crate::backends::fallback::max_learning_id;
+use crate::backends::traits::{
+    MemoryBackend, SearchFilters, SearchQuery, SearchResult, WriteResult,
+};
+use crate::core::{CompoundLearning, LearningRevision};
+use crate::error::{GroveError, Result};
+use tracing::warn;
+
+/// A backend wrapper that mirrors writes and status changes to N backends.
+///
+/// - Writes go to every backend; the result is successful if at least one
+///   backend accepted the learning, with the others listed in
+///   [`WriteResult::failed_backends`].
+/// - Searches query every backend and merge results by learning ID, keeping
+///   the highest relevance.
+/// - Archive/restore/update are applied everywhere and succeed if any backend had
+///   the learning.
+pub struct MirrorBackend {
+    /// Mirrored backends, in priority order.
+    backends: Vec<Box<dyn MemoryBackend>>,
+}
+
+impl MirrorBackend {
+    /// Create a new mirror over the given backends.
+    ///
+    /// The first backend is treated as primary for naming purposes.
+    pub fn new(backends: Vec<Box<dyn MemoryBackend>>) -> Self {
+        Self { backends }
+    }
+
+    /// Names of the mirrored backends, in order.
+    pub fn backend_names(&self) -> Vec<&'static str> {
+        self.backends.iter().map(|b| b.name()).collect()
+    }
+
+    /// Merge result lists by learning ID, keeping the highest relevance.
+    fn merge_results(result_sets: Vec<Vec<SearchResult>>) -> Vec<SearchResult> {
+        let mut combined: Vec<SearchResult> = Vec::new();
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        for result in result_sets.into_iter().flatten() {
+            match positions.get(&result.learning.id) {
+                Some(&i) => {
+                    let existing = &mut combined[i];
+                    if result.relevance > existing.relevance {
+                        existing.relevance = result.relevance;
+                    }
+                }
+                None => {
+                    positions.insert(result.learning.id.clone(), combined.len());
+                    combined.push(result);
+                }
+            }
+        }
+        combined
+    }
+
+    /// Apply a status change to every backend.
+    ///
+    /// Succeeds if at least one backend applied it; otherwise returns the
+    /// collected errors.
+    fn apply_everywhere(
+        &self,
+        learning_id: &str,
+        op: impl Fn(&dyn MemoryBackend) -> Result<()>,
+    ) -> Result<()> {
+        let mut applied = false;
+        let mut errors = Vec::new();
+
+        for backend in &self.backends {
+            match op(backend.as_ref()) {
+                Ok(()) => applied = true,
+                Err(e) => errors.push(format!("{}: {}", backend.name(), e)),
+            }
+        }
+
+        if applied {
+            Ok(())
+        } else {
+            Err(GroveError::backend(format!(
+                "Learning {} not updated in any mirrored backend ({})",
+                learning_id,
+                errors.join("; ")
+            )))
+        }
+    }
+}
+
+impl MemoryBackend for MirrorBackend {
+    fn write(&self, learning: &CompoundLearning) -> Result<WriteResult> {
+        let mut locations = Vec::new();
+        let mut messages = Vec::new();
+        let mut failed = Vec::new();
+
+        for backend in &self.backends {
+            match backend.write(learning) {
+                Ok(result) if result.success => {
+                    locations.push(result.location);
+                    if let Some(message) = result.message {
+                        messages.push(format!("{}: {}", backend.name(), message));
+                    }
+                    // Nested wrappers may report their own partial failures
+                    failed.extend(result.failed_backends);
+                }
+                Ok(result) => {
+                    let reason = result.message.unwrap_or_else(|| "write failed".to_string());
+                    messages.push(format!("{} failed: {}", backend.name(), reason));
+                    failed.push(backend.name().to_string());
+                }
+                Err(e) => {
+                    messages.push(format!("{} failed: {}", backend.name(), e));
+                    failed.push(backend.name().to_string());
+                }
+            }
+        }
+
+        if locations.is_empty() {
+            warn!("All mirrored backends failed for learning {}", learning.id);
+            let mut result = WriteResult::failure(&learning.id, messages.join("; "));
+            result.failed_backends = failed;
+            return Ok(result);
+        }
+
+        if !failed.is_empty() {
+            warn!(
+                "Mirrored write of learning {} failed on: {}",
+                learning.id,
+                failed.join(", ")
+            );
+        }
+
+        Ok(WriteResult {
+            success: true,
+            learning_id: learning.id.clone(),
+            location: locations.join(", "),
+            message: if messages.is_empty() {
+                None
+            } else {
+                Some(messages.join("; "))
+            },
+            failed_backends: failed,
+        })
+    }
+
+    fn search(&self, query: &SearchQuery, filters: &SearchFilters) -> Result<Vec<SearchResult>> {
+        let mut result_sets = Vec::with_capacity(self.backends.len());
+        for backend in &self.backends {
+            match backend.search(query, filters) {
+                Ok(results) => result_sets.push(results),
+                Err(e) => warn!("Mirrored search failed on '{}': {}", backend.name(), e),
+            }
+        }
+
+        let mut combined = Self::merge_results(result_sets);
+
+        // Sort by relevance (highest first)
+        combined.sort_by(|a, b| {
+            b.relevance
+                .partial_cmp(&a.relevance)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        // Apply max_results limit
+        if let Some(limit) = filters.max_results {
+            combined.truncate(limit);
+        }
+
+        Ok(combined)
+    }
+
+    fn ping(&self) -> bool {
+        // Available if any mirrored backend is available
+        self.backends.iter().any(|b| b.ping())
+    }
+
+    fn name(&self) -> &'static str {
+        "mirror"
+    }
+
+    fn archive(&self, learning_id: &str) -> Result<()> {
+        self.apply_everywhere(learning_id, |b| b.archive(learning_id))
+    }
+
+    fn restore(&self, learning_id: &str) -> Result<()> {
+        self.apply_everywhere(learning_id, |b| b.restore(learning_id))
+    }
+
+    fn update(&self, learning: &CompoundLearning) -> Result<()> {
+        self.apply_everywhere(&learning.id, |b| b.update(learning))
+    }
+
+    fn record_revision(&self, revision: &LearningRevision) -> Result<u32> {
+        // Record in every backend that keeps history; report the first one's number
+        let mut rev = None;
+        for backend in &self.backends {
+            if let Ok(n) = backend.record_revision(revision) {
+                rev.get_or_insert(n);
+            }
+        }
+        rev.ok_or_else(|| {
+            GroveError::backend(format!(
+                "No mirrored backend recorded history for {}",
+                revision.learning_id
+            ))
+        })
+    }
+
+    fn history(&self, learning_id: &str) -> Result<Vec<LearningRevision>> {
+        self.backends
+            .iter()
+            .find_map(|b| b.history(learning_id).ok())
+            .ok_or_else(|| {
+                GroveError::backend("Revision history not supported by any mirrored backend")
+            })
+    }
+
+    fn list_all(&self) -> Result<Vec<CompoundLearning>> {
+        let mut all: Vec<CompoundLearning> = Vec::new();
+        let mut seen: HashSet<String> = HashSet::new();
+        for backend in &self.backends {
+            match backend.list_all() {
+                Ok(learnings) => {
+                    for learning in learnings {
+                        if seen.insert(learning.id.clone()) {
+                            all.push(learning);
+                        }
+                    }
+                }
+                Err(e) => warn!("Mirrored list failed on '{}': {}", backend.name(), e),
+            }
+        }
+        Ok(all)
+    }
+
+    fn next_id(&self) -> String {
+        // Take the highest next ID so no mirrored backend sees a collision
+        self.backends
+            .iter()
+            .map(|b| b.next_id())
+            .reduce(|a, b| max_learning_id(&a, &b))
+            .unwrap_or_else(crate::core::generate_learning_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::MarkdownBackend;
+    use crate::core::{
+        Confidence, LearningCategory, LearningScope, LearningStatus, WriteGateCriterion,
+    };
+    use tempfile::TempDir;
+
+    /// Backend that rejects every operation.
+    struct BrokenBackend;
+
+    impl MemoryBackend for BrokenBackend {
+        fn write(&self, learning: &CompoundLearning) -> Result<WriteResult> {
+            Ok(WriteResult::failure(&learning.id, "Backend unavailable"))
+        }
+
+        fn search(&self, _: &SearchQuery, _: &SearchFilters) -> Result<Vec<SearchResult>> {
+            Err(GroveError::backend("search unavailable"))
+        }
+
+        fn ping(&self) -> bool {
+            false
+        }
+
+        fn name(&self) -> &'static str {
+            "broken"
+        }
+    }
+
+    fn sample_learning() -> CompoundLearning {
+        CompoundLearning::new(
+            LearningCategory::Pattern,
+            "Test learning summary",
+            "Test learning detail that is long enough to pass validation",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::BehaviorChanging],
+            vec!["test".to_string()],
+            "test-session",
+        )
+    }
+
+    fn markdown(temp: &TempDir, name: &str) -> Box<dyn MemoryBackend> {
+        Box::new(MarkdownBackend::with_paths(
+            temp.path().join(format!("{}.md", name)),
+            temp.path().join(format!("{}-personal.md", name)),
+        ))
+    }
+
+    #[test]
+    fn test_write_goes_to_every_backend() {
+        let temp = TempDir::new().unwrap();
+        let backend = MirrorBackend::new(vec![markdown(&temp, "a"), markdown(&temp, "b")]);
+        let learning = sample_learning();
+
+        let result = backend.write(&learning).unwrap();
+
+        assert!(result.success);
+        assert!(!result.is_partial());
+        assert!(result.location.contains("a.md"));
+        assert!(result.location.contains("b.md"));
+        for name in ["a", "b"] {
+            let md = MarkdownBackend::new(temp.path().join(format!("{}.md", name)));
+            assert_eq!(md.parse_learnings().unwrap().len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_write_reports_partial_failure() {
+        let temp = TempDir::new().unwrap();
+        let backend = MirrorBackend::new(vec![Box::new(BrokenBackend), markdown(&temp, "a")]);
+
+        let result = backend.write(&sample_learning()).unwrap();
+
+        assert!(result.success);
+        assert!(result.is_partial());
+        assert_eq!(result.failed_backends, vec!["broken".to_string()]);
+        assert!(result.message.unwrap().contains("broken failed"));
+    }
+
+    #[test]
+    fn test_write_fails_when_all_backends_fail() {
+        let backend = MirrorBackend::new(vec![Box::new(BrokenBackend), Box::new(BrokenBackend)]);
+
+        let result = backend.write(&sample_learning()).unwrap();
+
+        assert!(!result.success);
+        assert_eq!(result.failed_backends.len(), 2);
+    }
+
+    #[test]
+    fn test_search_merges_and_dedups_by_id() {
+        let temp = TempDir::new().unwrap();
+        let backend = MirrorBackend::new(vec![
+            markdown(&temp, "a"),
+            markdown(&temp, "b"),
+            Box::new(BrokenBackend),
+        ]);
+        let shared = sample_learning();
+        backend.write(&shared).unwrap();
+
+        // A learning only present in the second backend
+        let mut only_b = sample_learning();
+        only_b.id = "cl_20990101_001".to_string();
+        MarkdownBackend::new(temp.path().join("b.md"))
+            .write(&only_b)
+            .unwrap();
+
+        let results = backend
+            .search(&SearchQuery::new(), &SearchFilters::default())
+            .unwrap();
+        assert_eq!(results.len(), 2);
+
+        let limited = backend
+            .search(
+                &SearchQuery::new(),
+                &SearchFilters::default().max_results(1),
+            )
+            .unwrap();
+        assert_eq!(limited.len(), 1);
+
+        assert_eq!(backend.list_all().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_archive_and_restore_apply_everywhere() {
+        let temp = TempDir::new().unwrap();
+        let backend = MirrorBackend::new(vec![markdown(&temp, "a"), markdown(&temp, "b")]);
+        let learning = sample_learning();
+        backend.write(&learning).unwrap();
+
+        backend.archive(&learning.id).unwrap();
+        for name in ["a", "b"] {
+            let md = MarkdownBackend::new(temp.path().join(format!("{}.md", name)));
+            assert_eq!(
+                md.parse_learnings().unwrap()[0].status,
+                LearningStatus::Archived
+            );
+        }
+
+        backend.restore(&learning.id).unwrap();
+        for name in ["a", "b"] {
+            let md = MarkdownBackend::new(temp.path().join(format!("{}.md", name)));
+            assert_eq!(
+                md.parse_learnings().unwrap()[0].status,
+                LearningStatus::Active
+            );
+        }
+    }
+
+    #[test]
+    fn test_archive_succeeds_if_any_backend_has_learning() {
+        let temp = TempDir::new().unwrap();
+        let backend = MirrorBackend::new(vec![markdown(&temp, "a"), markdown(&temp, "b")]);
+        let learning = sample_learning();
+        MarkdownBackend::new(temp.path().join("a.md"))
+            .write(&learning)
+            .unwrap();
+
+        assert!(backend.archive(&learning.id).is_ok());
+        assert!(backend.archive("cl_20990101_999").is_err());
+    }
+
+    #[test]
+    fn test_next_id_uses_highest_counter() {
+        let temp = TempDir::new().unwrap();
+        let today = chrono::Utc::now().format("%Y%m%d").to_string();
+        let mut learning = sample_learning();
+        learning.id = format!("cl_{}_004", today);
+        MarkdownBackend::new(temp.path().join("b.md"))
+            .write(&learning)
+            .unwrap();
+
+        let backend = MirrorBackend::new(vec![markdown(&temp, "a"), markdown(&temp, "b")]);
+
+        assert_eq!(backend.next_id(), format!("cl_{}_005", today));
+        assert_eq!(
+            backend.next_ids(2),
+            vec![format!("cl_{}_005", today), format!("cl_{}_006", today)]
+        );
+    }
+
+    #[test]
+    fn test_ping_and_name() {
+        let temp = TempDir::new().unwrap();
+        let backend = MirrorBackend::new(vec![Box::new(BrokenBackend), markdown(&temp, "a")]);
+
+        assert!(backend.ping());
+        assert_eq!(backend.name(), "mirror");
+        assert_eq!(backend.backend_names(), vec!["broken", "markdown"]);
+    }
+}
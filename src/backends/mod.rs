@@ -5,17 +5,50 @@
 //!
 //! Available backends:
 //! - **Markdown**: Built-in append-only markdown file backend (default)
+//! - **SQLite**: Indexed SQLite database backend (requires `sqlite-backend` feature)
 //! - **Total Recall**: Adapter for Total Recall memory system
+//! - **MCP**: Adapter for MCP memory servers over stdio JSON-RPC
+//! - **Process**: External plugin executable speaking a JSON-lines protocol
 //! - **Fallback**: Wrapper that tries primary, falls back to secondary on failure
+//! - **Mirror**: Wrapper that writes to several backends and merges their results
+//! - **Org**: Read-only organization-wide learnings corpus
+//! - **Layered**: Wrapper that searches read-only layers under the primary backend
+//! - **Indexed**: Wrapper that keeps the on-disk search index current (requires `tantivy-search` feature)
+//! - **Invalidating**: Wrapper that deletes files derived from the corpus when it changes
 
 pub mod fallback;
+#[cfg(feature = "tantivy-search")]
+pub mod indexed;
+pub mod invalidating;
+pub mod layered;
 pub mod markdown;
+pub mod mcp;
+pub mod mirror;
+pub mod org;
+pub mod process;
+pub mod query;
+#[cfg(feature = "sqlite-backend")]
+pub mod sqlite;
+pub(crate) mod stdio;
 pub mod total_recall;
 pub mod total_recall_format;
 pub mod traits;
 
 pub use fallback::FallbackBackend;
+#[cfg(feature = "tantivy-search")]
+pub use indexed::IndexedBackend;
+pub use invalidating::InvalidatingBackend;
+pub use layered::LayeredBackend;
 pub use markdown::MarkdownBackend;
+pub use mcp::McpBackend;
+pub use mirror::MirrorBackend;
+pub use org::OrgBackend;
+pub use process::ProcessBackend;
+#[cfg(feature = "sqlite-backend")]
+pub use sqlite::SqliteBackend;
 pub use total_recall::TotalRecallBackend;
 pub use total_recall_format as tr_format;
-pub use traits::{MemoryBackend, SearchFilters, SearchQuery, SearchResult, WriteResult};
+pub use traits::{
+    FieldFilter, LearningSource, MemoryBackend, SearchFilters, SearchQuery, SearchResult,
+    WriteResult,
+};
@@ -0,0 +1,229 @@
+//! Read-only organization learnings backend for Grove.
+//!
+//! This module provides a backend over a shared, org-wide corpus of
+//! learnings curated outside the project (typically a git checkout of a
+//! team repository). The corpus uses Grove's markdown learnings format and
+//! is searched alongside the project's own learnings, but never written.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::backends::markdown::{compute_relevance, parse_learnings_from_markdown};
+use crate::backends::traits::{
+    LearningSource, MemoryBackend, SearchFilters, SearchQuery, SearchResult, WriteResult,
+};
+use crate::core::CompoundLearning;
+use crate::error::{GroveError, Result};
+
+/// A read-only backend over an organization-wide learnings corpus.
+///
+/// The root may be a single markdown file or a directory. For a directory,
+/// `.grove/learnings.md` is used when present (a checkout of a project that
+/// uses Grove); otherwise every top-level `*.md` file is read.
+///
+/// Every search result is tagged [`LearningSource::Org`].
+#[derive(Debug, Clone)]
+pub struct OrgBackend {
+    /// File or directory holding the organization's learnings.
+    root: PathBuf,
+}
+
+impl OrgBackend {
+    /// Create a new org backend rooted at the given file or directory.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// The configured root path.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Markdown files making up the corpus, in a stable order.
+    pub(crate) fn learning_files(&self) -> Result<Vec<PathBuf>> {
+        if self.root.is_file() {
+            return Ok(vec![self.root.clone()]);
+        }
+
+        let grove_file = self.root.join(".grove").join("learnings.md");
+        if grove_file.is_file() {
+            return Ok(vec![grove_file]);
+        }
+
+        let entries = fs::read_dir(&self.root).map_err(|e| {
+            GroveError::backend(format!(
+                "Failed to read org learnings at {}: {}",
+                self.root.display(),
+                e
+            ))
+        })?;
+
+        let mut files: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "md"))
+            .collect();
+        files.sort();
+        Ok(files)
+    }
+
+    /// Parse every learning in the corpus.
+    ///
+    /// Learnings whose IDs were already seen in an earlier file are skipped.
+    pub fn parse_learnings(&self) -> Result<Vec<CompoundLearning>> {
+        let mut learnings: Vec<CompoundLearning> = Vec::new();
+        for path in self.learning_files()? {
+            let content = fs::read_to_string(&path).map_err(|e| {
+                GroveError::backend(format!("Failed to read {}: {}", path.display(), e))
+            })?;
+            for learning in parse_learnings_from_markdown(&content)? {
+                if !learnings.iter().any(|l| l.id == learning.id) {
+                    learnings.push(learning);
+                }
+            }
+        }
+        Ok(learnings)
+    }
+}
+
+impl MemoryBackend for OrgBackend {
+    fn write(&self, _learning: &CompoundLearning) -> Result<WriteResult> {
+        Err(GroveError::backend("Org learnings are read-only"))
+    }
+
+    fn search(&self, query: &SearchQuery, filters: &SearchFilters) -> Result<Vec<SearchResult>> {
+        let mut results: Vec<SearchResult> = self
+            .parse_learnings()?
+            .into_iter()
+            .filter(|learning| filters.matches(learning))
+            .filter_map(|learning| {
+                let relevance = if query.is_empty() {
+                    1.0
+                } else {
+                    compute_relevance(&learning, query)
+                };
+
+                if relevance > 0.0 || query.is_empty() {
+                    Some(SearchResult::new(learning, relevance).with_source(LearningSource::Org))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        // Sort by relevance (highest first)
+        results.sort_by(|a, b| {
+            b.relevance
+                .partial_cmp(&a.relevance)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        // Apply max_results limit
+        if let Some(limit) = filters.max_results {
+            results.truncate(limit);
+        }
+
+        Ok(results)
+    }
+
+    fn ping(&self) -> bool {
+        self.root.exists()
+    }
+
+    fn name(&self) -> &'static str {
+        "org"
+    }
+
+    fn list_all(&self) -> Result<Vec<CompoundLearning>> {
+        self.parse_learnings()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::MarkdownBackend;
+    use crate::core::{Confidence, LearningCategory, LearningScope, WriteGateCriterion};
+    use tempfile::TempDir;
+
+    fn learning(id: &str, summary: &str, tag: &str) -> CompoundLearning {
+        CompoundLearning::new(
+            LearningCategory::Convention,
+            summary,
+            "Org-wide convention detail that is long enough to pass validation",
+            LearningScope::Team,
+            Confidence::High,
+            vec![WriteGateCriterion::BehaviorChanging],
+            vec![tag.to_string()],
+            "org-session",
+        )
+        .with_id(id)
+    }
+
+    fn write_corpus(path: &Path, learnings: &[CompoundLearning]) {
+        let backend = MarkdownBackend::with_paths(path, path.with_extension("personal.md"));
+        for l in learnings {
+            backend.write(l).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_search_tags_results_as_org() {
+        let temp = TempDir::new().unwrap();
+        write_corpus(
+            &temp.path().join("security.md"),
+            &[
+                learning("cl_20260101_000", "Rotate API keys quarterly", "security"),
+                learning("cl_20260101_001", "Use structured logging", "logging"),
+            ],
+        );
+        let backend = OrgBackend::new(temp.path());
+
+        let results = backend
+            .search(
+                &SearchQuery::new().tags(vec!["security".to_string()]),
+                &SearchFilters::default(),
+            )
+            .unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].learning.id, "cl_20260101_000");
+        assert_eq!(results[0].source, LearningSource::Org);
+    }
+
+    #[test]
+    fn test_prefers_grove_learnings_file_in_checkout() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join(".grove")).unwrap();
+        write_corpus(
+            &temp.path().join(".grove").join("learnings.md"),
+            &[learning("cl_20260101_000", "From the grove file", "a")],
+        );
+        write_corpus(
+            &temp.path().join("README.md"),
+            &[learning("cl_20260101_001", "From a loose file", "b")],
+        );
+
+        let learnings = OrgBackend::new(temp.path()).list_all().unwrap();
+        assert_eq!(learnings.len(), 1);
+        assert_eq!(learnings[0].summary, "From the grove file");
+    }
+
+    #[test]
+    fn test_write_is_rejected() {
+        let temp = TempDir::new().unwrap();
+        let backend = OrgBackend::new(temp.path());
+
+        assert!(backend
+            .write(&learning("cl_20260101_000", "Anything", "a"))
+            .is_err());
+        assert!(backend.archive("cl_20260101_000").is_err());
+        assert!(backend.list_all().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_ping_requires_existing_root() {
+        let temp = TempDir::new().unwrap();
+        assert!(OrgBackend::new(temp.path()).ping());
+        assert!(!OrgBackend::new(temp.path().join("missing")).ping());
+    }
+}
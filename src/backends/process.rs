@@ -0,0 +1,632 @@
+//! Process plugin backend for Grove.
+//!
+//! This module lets an external executable act as a memory backend without
+//! forking Grove. Grove launches the command configured under
+//! `[backends.process]` and exchanges JSON-lines messages over its
+//! stdin/stdout, one plugin process per operation.
+//!
+//! # Protocol
+//!
+//! Each request is one JSON object per line:
+//!
+//! ```json
+//! {"v":1,"id":1,"method":"search","params":{"query":{...},"filters":{...}}}
+//! ```
+//!
+//! and the plugin answers with one line carrying the same `id` and either a
+//! `result` or an `error` string:
+//!
+//! ```json
+//! {"id":1,"result":[{"learning":{...},"relevance":0.8}]}
+//! {"id":1,"error":"learning not found"}
+//! ```
+//!
+//! | Method | Params | Result |
+//! |--------|--------|--------|
+//! | `write` | `{"learning": CompoundLearning}` | `WriteResult` |
+//! | `search` | `{"query": SearchQuery, "filters": SearchFilters}` | `[SearchResult]` |
+//! | `ping` | none | `true` when healthy |
+//! | `archive` | `{"id": "cl_..."}` | `null` |
+//! | `restore` | `{"id": "cl_..."}` | `null` |
+//! | `update` | `{"learning": CompoundLearning}` | `null` |
+//! | `list_all` | none | `[CompoundLearning]` |
+//! | `next_ids` | `{"count": N}` | `["cl_...", ...]` |
+//!
+//! Types are serialized exactly as Grove's own serde representations. Lines
+//! that are not JSON or carry a different `id` are ignored, so plugins may
+//! log to stdout, although stderr is preferred. The timeout covers the whole
+//! response, however many lines come before it. Plugins should handle
+//! requests until stdin reaches EOF.
+//!
+//! Like Total Recall, a failing plugin never blocks: writes return a failure
+//! result (so [`FallbackBackend`](super::FallbackBackend) can take over) and
+//! searches return no results.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::warn;
+
+use crate::backends::stdio::StdioProcess;
+use crate::backends::traits::{
+    MemoryBackend, SearchFilters, SearchQuery, SearchResult, WriteResult,
+};
+use crate::config::ProcessBackendConfig;
+use crate::core::CompoundLearning;
+use crate::error::{GroveError, Result};
+
+/// Version of the JSON-lines plugin protocol.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// A backend operation sent to a plugin.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "method", content = "params", rename_all = "snake_case")]
+pub enum PluginCall {
+    /// Persist a learning.
+    Write {
+        /// The learning to write.
+        learning: CompoundLearning,
+    },
+    /// Search for learnings.
+    Search {
+        /// Query terms.
+        query: SearchQuery,
+        /// Result filters.
+        filters: SearchFilters,
+    },
+    /// Health check.
+    Ping,
+    /// Archive a learning by ID.
+    Archive {
+        /// Learning ID.
+        id: String,
+    },
+    /// Restore an archived learning by ID.
+    Restore {
+        /// Learning ID.
+        id: String,
+    },
+    /// Replace a stored learning with an edited version.
+    Update {
+        /// The edited learning (matched by ID).
+        learning: CompoundLearning,
+    },
+    /// List every learning regardless of status.
+    ListAll,
+    /// Allocate a batch of learning IDs.
+    NextIds {
+        /// Number of IDs to allocate.
+        count: usize,
+    },
+}
+
+/// A request line sent to a plugin.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginRequest {
+    /// Protocol version.
+    pub v: u32,
+    /// Request ID, echoed in the response.
+    pub id: u64,
+    /// The operation and its parameters.
+    #[serde(flatten)]
+    pub call: PluginCall,
+}
+
+/// A response line returned by a plugin.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginResponse {
+    /// ID of the request being answered.
+    pub id: u64,
+    /// Method result (absent or null for methods without one).
+    #[serde(default)]
+    pub result: Value,
+    /// Error message if the operation failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Process plugin backend.
+///
+/// Launches the configured executable for each operation and talks to it
+/// over the JSON-lines protocol described in the module docs.
+#[derive(Debug, Clone)]
+pub struct ProcessBackend {
+    /// Plugin executable.
+    command: String,
+    /// Plugin arguments.
+    args: Vec<String>,
+    /// Extra environment for the plugin process.
+    env: HashMap<String, String>,
+    /// Working directory for the plugin process.
+    cwd: PathBuf,
+    /// Per-response timeout.
+    timeout: Duration,
+}
+
+impl ProcessBackend {
+    /// Create a backend from `[backends.process]` config, launching the plugin in `cwd`.
+    pub fn new(config: &ProcessBackendConfig, cwd: impl AsRef<Path>) -> Self {
+        Self {
+            command: config.command.clone(),
+            args: config.args.clone(),
+            env: config.env.clone(),
+            cwd: cwd.as_ref().to_path_buf(),
+            timeout: Duration::from_secs(config.timeout_seconds.max(1)),
+        }
+    }
+
+    /// Launch the plugin, send one call, and decode its result.
+    fn call<T: DeserializeOwned>(&self, call: PluginCall) -> Result<T> {
+        let mut process = StdioProcess::spawn(
+            &self.command,
+            &self.args,
+            &self.env,
+            Some(&self.cwd),
+            self.timeout,
+        )?;
+
+        let request = PluginRequest {
+            v: PROTOCOL_VERSION,
+            id: 1,
+            call,
+        };
+        let line = serde_json::to_string(&request)
+            .map_err(|e| GroveError::backend(format!("Failed to encode request: {}", e)))?;
+        process.send(&line)?;
+
+        // One deadline for the whole exchange: log lines don't extend it
+        let deadline = process.deadline();
+        loop {
+            let line = process.recv_by(deadline)?;
+            let Ok(response) = serde_json::from_str::<PluginResponse>(&line) else {
+                continue;
+            };
+            if response.id != request.id {
+                continue;
+            }
+
+            if let Some(error) = response.error {
+                return Err(GroveError::backend(format!(
+                    "Plugin {} failed: {}",
+                    self.command, error
+                )));
+            }
+
+            return serde_json::from_value(response.result).map_err(|e| {
+                GroveError::backend(format!(
+                    "Invalid response from plugin {}: {}",
+                    self.command, e
+                ))
+            });
+        }
+    }
+}
+
+impl MemoryBackend for ProcessBackend {
+    fn write(&self, learning: &CompoundLearning) -> Result<WriteResult> {
+        let call = PluginCall::Write {
+            learning: learning.clone(),
+        };
+        match self.call::<WriteResult>(call) {
+            Ok(result) => Ok(result),
+            Err(err) => {
+                // Fail-open: log warning, return failure result but don't block
+                warn!("Plugin write failed: {}", err);
+                Ok(WriteResult::failure(&learning.id, "Backend unavailable"))
+            }
+        }
+    }
+
+    fn search(&self, query: &SearchQuery, filters: &SearchFilters) -> Result<Vec<SearchResult>> {
+        let call = PluginCall::Search {
+            query: query.clone(),
+            filters: filters.clone(),
+        };
+        match self.call::<Vec<SearchResult>>(call) {
+            Ok(mut results) => {
+                // Enforce the limit even if the plugin ignored it
+                if let Some(limit) = filters.max_results {
+                    results.truncate(limit);
+                }
+                Ok(results)
+            }
+            Err(err) => {
+                warn!("Plugin search failed: {}", err);
+                Ok(Vec::new())
+            }
+        }
+    }
+
+    fn ping(&self) -> bool {
+        self.call::<bool>(PluginCall::Ping).unwrap_or(false)
+    }
+
+    fn name(&self) -> &'static str {
+        "process"
+    }
+
+    fn archive(&self, learning_id: &str) -> Result<()> {
+        self.call::<Value>(PluginCall::Archive {
+            id: learning_id.to_string(),
+        })
+        .map(|_| ())
+    }
+
+    fn restore(&self, learning_id: &str) -> Result<()> {
+        self.call::<Value>(PluginCall::Restore {
+            id: learning_id.to_string(),
+        })
+        .map(|_| ())
+    }
+
+    fn update(&self, learning: &CompoundLearning) -> Result<()> {
+        self.call::<Value>(PluginCall::Update {
+            learning: learning.clone(),
+        })
+        .map(|_| ())
+    }
+
+    fn list_all(&self) -> Result<Vec<CompoundLearning>> {
+        self.call(PluginCall::ListAll)
+    }
+
+    fn next_id(&self) -> String {
+        self.next_ids(1)
+            .into_iter()
+            .next()
+            .unwrap_or_else(crate::core::generate_learning_id)
+    }
+
+    fn next_ids(&self, count: usize) -> Vec<String> {
+        if count == 0 {
+            return Vec::new();
+        }
+
+        match self.call::<Vec<String>>(PluginCall::NextIds { count }) {
+            Ok(ids) if ids.len() == count => ids,
+            Ok(ids) => {
+                warn!(
+                    "Plugin returned {} IDs, expected {}; using local IDs",
+                    ids.len(),
+                    count
+                );
+                fallback_ids(count)
+            }
+            Err(err) => {
+                warn!("Plugin next_ids failed: {}", err);
+                fallback_ids(count)
+            }
+        }
+    }
+}
+
+/// IDs starting at today's `000` counter.
+///
+/// Used when the plugin can't allocate IDs, so a fallback backend's higher
+/// counter wins in [`FallbackBackend::next_ids`](super::FallbackBackend).
+fn fallback_ids(count: usize) -> Vec<String> {
+    let today = chrono::Utc::now().format("%Y%m%d").to_string();
+    (0..count)
+        .map(|i| format!("cl_{}_{:03}", today, i))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::{FallbackBackend, MarkdownBackend};
+    use crate::core::WriteGateCriterion;
+    use crate::core::{Confidence, LearningCategory, LearningScope, LearningStatus};
+    use std::io::{BufRead, Write};
+    use tempfile::TempDir;
+
+    /// Environment variable that turns the ignored stub test into a plugin.
+    const STUB_ENV: &str = "GROVE_PROCESS_STUB_PLUGIN";
+
+    fn sample_learning() -> CompoundLearning {
+        CompoundLearning::new(
+            LearningCategory::Pitfall,
+            "Avoid N+1 queries in UserDashboard",
+            "The dashboard was loading users then iterating to load posts separately. Use eager loading instead.",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::BehaviorChanging],
+            vec!["performance".to_string(), "database".to_string()],
+            "test-session-123",
+        )
+    }
+
+    /// Config that re-runs this test binary as the stub plugin.
+    fn stub_config(store: &Path) -> ProcessBackendConfig {
+        let exe = std::env::current_exe().unwrap();
+        let mut env = HashMap::new();
+        env.insert(STUB_ENV.to_string(), store.display().to_string());
+        ProcessBackendConfig {
+            command: exe.display().to_string(),
+            args: vec![
+                "backends::process::tests::stub_plugin".to_string(),
+                "--exact".to_string(),
+                "--ignored".to_string(),
+                "--nocapture".to_string(),
+                "--test-threads=1".to_string(),
+                "-q".to_string(),
+            ],
+            env,
+            timeout_seconds: 10,
+        }
+    }
+
+    fn setup() -> (TempDir, ProcessBackend) {
+        let temp = TempDir::new().unwrap();
+        let config = stub_config(&temp.path().join("store.json"));
+        let backend = ProcessBackend::new(&config, temp.path());
+        (temp, backend)
+    }
+
+    /// Minimal plugin used by the tests below.
+    ///
+    /// Keeps learnings in the JSON file named by `STUB_ENV` and answers every
+    /// protocol method.
+    #[test]
+    #[ignore = "helper process for ProcessBackend tests"]
+    fn stub_plugin() {
+        let Some(store) = std::env::var_os(STUB_ENV) else {
+            return;
+        };
+        let store = PathBuf::from(store);
+        let mut learnings: Vec<CompoundLearning> = std::fs::read_to_string(&store)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+
+        let mut stdout = std::io::stdout();
+        for line in std::io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            let request: PluginRequest = serde_json::from_str(&line).unwrap();
+
+            let set_status =
+                |learnings: &mut Vec<CompoundLearning>, id: &str, status| match learnings
+                    .iter_mut()
+                    .find(|l| l.id == id)
+                {
+                    Some(l) => {
+                        l.status = status;
+                        Ok(Value::Null)
+                    }
+                    None => Err(format!("learning {} not found", id)),
+                };
+
+            let outcome: std::result::Result<Value, String> = match request.call {
+                PluginCall::Write { learning } => {
+                    let id = learning.id.clone();
+                    learnings.push(learning);
+                    Ok(serde_json::to_value(WriteResult::success(id, "stub")).unwrap())
+                }
+                PluginCall::Search { filters, .. } => {
+                    let results: Vec<SearchResult> = learnings
+                        .iter()
+                        .filter(|l| filters.matches(l))
+                        .map(|l| SearchResult::new(l.clone(), 1.0))
+                        .collect();
+                    Ok(serde_json::to_value(results).unwrap())
+                }
+                PluginCall::Ping => Ok(Value::Bool(true)),
+                PluginCall::Archive { id } => {
+                    set_status(&mut learnings, &id, LearningStatus::Archived)
+                }
+                PluginCall::Restore { id } => {
+                    set_status(&mut learnings, &id, LearningStatus::Active)
+                }
+                PluginCall::Update { learning } => {
+                    match learnings.iter_mut().find(|l| l.id == learning.id) {
+                        Some(l) => {
+                            *l = learning;
+                            Ok(Value::Null)
+                        }
+                        None => Err(format!("learning {} not found", learning.id)),
+                    }
+                }
+                PluginCall::ListAll => Ok(serde_json::to_value(&learnings).unwrap()),
+                PluginCall::NextIds { count } => {
+                    let ids: Vec<String> = (0..count)
+                        .map(|i| format!("cl_20990101_{:03}", learnings.len() + i))
+                        .collect();
+                    Ok(serde_json::to_value(ids).unwrap())
+                }
+            };
+
+            std::fs::write(&store, serde_json::to_string(&learnings).unwrap()).unwrap();
+            let response = match outcome {
+                Ok(result) => PluginResponse {
+                    id: request.id,
+                    result,
+                    error: None,
+                },
+                Err(error) => PluginResponse {
+                    id: request.id,
+                    result: Value::Null,
+                    error: Some(error),
+                },
+            };
+            writeln!(stdout, "{}", serde_json::to_string(&response).unwrap()).unwrap();
+            stdout.flush().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_request_wire_format() {
+        let request = PluginRequest {
+            v: PROTOCOL_VERSION,
+            id: 7,
+            call: PluginCall::Archive {
+                id: "cl_20260101_001".to_string(),
+            },
+        };
+        let json: Value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "v": 1,
+                "id": 7,
+                "method": "archive",
+                "params": { "id": "cl_20260101_001" },
+            })
+        );
+
+        let ping: PluginRequest =
+            serde_json::from_str(r#"{"v":1,"id":2,"method":"ping"}"#).unwrap();
+        assert_eq!(ping.call, PluginCall::Ping);
+
+        let next: PluginRequest =
+            serde_json::from_str(r#"{"v":1,"id":3,"method":"next_ids","params":{"count":2}}"#)
+                .unwrap();
+        assert_eq!(next.call, PluginCall::NextIds { count: 2 });
+    }
+
+    #[test]
+    fn test_response_wire_format() {
+        let ok: PluginResponse = serde_json::from_str(r#"{"id":1,"result":true}"#).unwrap();
+        assert_eq!(ok.result, Value::Bool(true));
+        assert!(ok.error.is_none());
+
+        let err: PluginResponse = serde_json::from_str(r#"{"id":1,"error":"boom"}"#).unwrap();
+        assert_eq!(err.result, Value::Null);
+        assert_eq!(err.error.as_deref(), Some("boom"));
+    }
+
+    #[test]
+    fn test_write_search_and_list_via_stub_plugin() {
+        let (_temp, backend) = setup();
+        let learning = sample_learning();
+
+        let result = backend.write(&learning).unwrap();
+        assert!(result.success);
+        assert_eq!(result.location, "stub");
+
+        let results = backend
+            .search(&SearchQuery::new(), &SearchFilters::default())
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].learning, learning);
+
+        assert_eq!(backend.list_all().unwrap(), vec![learning]);
+    }
+
+    #[test]
+    fn test_archive_and_restore_via_stub_plugin() {
+        let (_temp, backend) = setup();
+        let learning = sample_learning();
+        backend.write(&learning).unwrap();
+
+        backend.archive(&learning.id).unwrap();
+        assert!(backend
+            .search(&SearchQuery::new(), &SearchFilters::active_only())
+            .unwrap()
+            .is_empty());
+
+        backend.restore(&learning.id).unwrap();
+        assert_eq!(
+            backend
+                .search(&SearchQuery::new(), &SearchFilters::active_only())
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let err = backend.archive("cl_20990101_999").unwrap_err();
+        assert!(err.to_string().contains("not found"));
+    }
+
+    #[test]
+    fn test_update_via_stub_plugin() {
+        let (_temp, backend) = setup();
+        let learning = sample_learning();
+        backend.write(&learning).unwrap();
+
+        let mut edited = learning.clone();
+        edited.summary = "Edited summary via plugin".to_string();
+        backend.update(&edited).unwrap();
+        assert_eq!(backend.list_all().unwrap(), vec![edited]);
+
+        let mut missing = learning;
+        missing.id = "cl_20990101_999".to_string();
+        assert!(backend.update(&missing).is_err());
+    }
+
+    #[test]
+    fn test_ping_and_next_ids_via_stub_plugin() {
+        let (_temp, backend) = setup();
+        assert!(backend.ping());
+        assert_eq!(backend.name(), "process");
+        assert_eq!(
+            backend.next_ids(2),
+            vec!["cl_20990101_000".to_string(), "cl_20990101_001".to_string()]
+        );
+        assert_eq!(backend.next_id(), "cl_20990101_000");
+        assert!(backend.next_ids(0).is_empty());
+    }
+
+    #[test]
+    fn test_missing_plugin_fails_open() {
+        let temp = TempDir::new().unwrap();
+        let config = ProcessBackendConfig {
+            command: "/nonexistent/grove-plugin".to_string(),
+            ..ProcessBackendConfig::default()
+        };
+        let backend = ProcessBackend::new(&config, temp.path());
+
+        assert!(!backend.ping());
+        assert!(!backend.write(&sample_learning()).unwrap().success);
+        assert!(backend
+            .search(&SearchQuery::new(), &SearchFilters::default())
+            .unwrap()
+            .is_empty());
+        assert!(backend.list_all().is_err());
+
+        let today = chrono::Utc::now().format("%Y%m%d").to_string();
+        assert_eq!(backend.next_id(), format!("cl_{}_000", today));
+    }
+
+    #[test]
+    fn test_chatty_plugin_times_out_overall() {
+        let temp = TempDir::new().unwrap();
+        let config = ProcessBackendConfig {
+            command: "sh".to_string(),
+            args: vec![
+                "-c".to_string(),
+                "while true; do echo still working; sleep 0.1; done".to_string(),
+            ],
+            timeout_seconds: 1,
+            ..ProcessBackendConfig::default()
+        };
+        let backend = ProcessBackend::new(&config, temp.path());
+
+        let started = std::time::Instant::now();
+        assert!(backend.list_all().is_err());
+        assert!(started.elapsed() < Duration::from_secs(3));
+    }
+
+    #[test]
+    fn test_missing_plugin_falls_back_to_markdown() {
+        let temp = TempDir::new().unwrap();
+        let config = ProcessBackendConfig {
+            command: "/nonexistent/grove-plugin".to_string(),
+            ..ProcessBackendConfig::default()
+        };
+        let backend = FallbackBackend::new(
+            Box::new(ProcessBackend::new(&config, temp.path())),
+            Box::new(MarkdownBackend::with_paths(
+                temp.path().join(".grove/learnings.md"),
+                temp.path().join("personal.md"),
+            )),
+        );
+
+        let result = backend.write(&sample_learning()).unwrap();
+        assert!(result.success);
+        assert!(result.location.contains("(fallback)"));
+    }
+}
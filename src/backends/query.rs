@@ -0,0 +1,375 @@
+//! Search query syntax for Grove.
+//!
+//! Parses `grove search` input into a [`SearchQuery`] (the free text that
+//! drives relevance scoring) and [`SearchFilters`] (field conditions that
+//! every backend enforces through [`SearchFilters::matches`]).
+//!
+//! | Syntax | Meaning |
+//! |--------|---------|
+//! | `tag:rust` | has the tag `rust` |
+//! | `category:pitfall` | is a pitfall |
+//! | `scope:personal` | has personal scope |
+//! | `file:src/hooks/*` | has a context file matching the glob |
+//! | `status:archived` | has the status (`status:any` drops the status filter) |
+//! | `created:>2026-01-01` | created after the date (also `>=`, `<`, `<=`, or a bare date) |
+//! | `ticket:PROJ-12` | is linked to the ticket |
+//! | `"exact phrase"` | contains the phrase |
+//! | `-term`, `-tag:x`, `-"phrase"` | negation: excludes matches |
+//!
+//! Field values may be quoted (`tag:"error handling"`). Words with an
+//! unknown `field:` prefix are treated as plain text.
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::backends::traits::{FieldFilter, SearchFilters, SearchQuery};
+use crate::core::{parse_learning_date, LearningCategory, LearningScope, LearningStatus};
+use crate::error::{GroveError, Result};
+
+/// Field names understood by the query syntax.
+pub const QUERY_FIELDS: &[&str] = &[
+    "tag", "category", "scope", "file", "status", "created", "ticket",
+];
+
+/// One piece of query input.
+#[derive(Debug, PartialEq)]
+enum Token {
+    /// A bare word.
+    Word(String),
+    /// A quoted phrase.
+    Phrase(String),
+    /// A `field:value` pair.
+    Field(String, String),
+}
+
+/// Parse query input, starting from `filters`.
+///
+/// Free text becomes keywords; quoted phrases are both required and used
+/// as keywords. Field conditions are added to `filters`, so callers can
+/// pass [`SearchFilters::all`] to include archived learnings unless the
+/// query says otherwise.
+pub fn parse_query(input: &str, filters: SearchFilters) -> Result<(SearchQuery, SearchFilters)> {
+    let mut keywords: Vec<String> = Vec::new();
+    let mut filters = filters;
+
+    for (negated, token) in tokenize(input) {
+        match token {
+            Token::Word(word) if negated => {
+                filters = filters.exclude(FieldFilter::Term(word));
+            }
+            Token::Word(word) => keywords.push(word),
+            Token::Phrase(phrase) if negated => {
+                filters = filters.exclude(FieldFilter::Phrase(phrase));
+            }
+            Token::Phrase(phrase) => {
+                filters = filters.require(FieldFilter::Phrase(phrase.clone()));
+                keywords.push(phrase);
+            }
+            Token::Field(field, value) => {
+                filters = apply_field(filters, &field, &value, negated)?;
+            }
+        }
+    }
+
+    Ok((SearchQuery::with_keywords(keywords), filters))
+}
+
+/// Add one `field:value` condition to the filters.
+fn apply_field(
+    mut filters: SearchFilters,
+    field: &str,
+    value: &str,
+    negated: bool,
+) -> Result<SearchFilters> {
+    if value.is_empty() {
+        return Err(GroveError::config(format!("{}: needs a value", field)));
+    }
+
+    let filter = match field {
+        "tag" => FieldFilter::Tag(value.to_string()),
+        "file" => FieldFilter::File(value.to_string()),
+        "ticket" => FieldFilter::Ticket(value.to_string()),
+        "category" => FieldFilter::Category(parse_category(value)?),
+        "scope" => {
+            let scope = parse_scope(value)?;
+            if !negated {
+                return Ok(filters.scope(scope));
+            }
+            FieldFilter::Scope(scope)
+        }
+        "status" => {
+            if value.eq_ignore_ascii_case("any") || value.eq_ignore_ascii_case("all") {
+                if negated {
+                    return Err(GroveError::config(format!(
+                        "status:{} cannot be negated",
+                        value
+                    )));
+                }
+                filters.status = None;
+                return Ok(filters);
+            }
+            let status = parse_status(value)?;
+            if !negated {
+                return Ok(filters.status(status));
+            }
+            FieldFilter::Status(status)
+        }
+        "created" => {
+            if negated {
+                return Err(GroveError::config(
+                    "created: cannot be negated; use created:< or created:> instead",
+                ));
+            }
+            return apply_created(filters, value);
+        }
+        _ => unreachable!("tokenize only yields known fields"),
+    };
+
+    Ok(if negated {
+        filters.exclude(filter)
+    } else {
+        filters.require(filter)
+    })
+}
+
+/// Apply a `created:` comparison.
+///
+/// Plain dates cover the whole day: `>2026-01-01` starts on January 2nd and
+/// `2026-01-01` alone matches only that day.
+fn apply_created(filters: SearchFilters, value: &str) -> Result<SearchFilters> {
+    let (op, date) = [">=", "<=", ">", "<", "="]
+        .iter()
+        .find_map(|op| value.strip_prefix(op).map(|rest| (*op, rest)))
+        .unwrap_or(("=", value));
+
+    let start: DateTime<Utc> = parse_learning_date(date).ok_or_else(|| {
+        GroveError::config(format!(
+            "Invalid date in created:{} (expected YYYY-MM-DD or RFC 3339)",
+            value
+        ))
+    })?;
+    let is_day = chrono::NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").is_ok();
+    let end = if is_day {
+        start + Duration::days(1)
+    } else {
+        start
+    };
+
+    Ok(match op {
+        ">" => filters.created_after(end),
+        ">=" => filters.created_after(start),
+        "<" => filters.created_before(start),
+        "<=" => filters.created_before(end),
+        _ if is_day => filters.created_after(start).created_before(end),
+        _ => filters
+            .created_after(start)
+            .created_before(start + Duration::seconds(1)),
+    })
+}
+
+fn parse_category(value: &str) -> Result<LearningCategory> {
+    LearningCategory::all()
+        .iter()
+        .copied()
+        .find(|c| c.display_name().eq_ignore_ascii_case(value))
+        .ok_or_else(|| {
+            let valid: Vec<String> = LearningCategory::all()
+                .iter()
+                .map(|c| c.display_name().to_lowercase())
+                .collect();
+            GroveError::config(format!(
+                "Unknown category '{}' (expected one of: {})",
+                value,
+                valid.join(", ")
+            ))
+        })
+}
+
+fn parse_scope(value: &str) -> Result<LearningScope> {
+    match value.to_lowercase().as_str() {
+        "project" => Ok(LearningScope::Project),
+        "team" => Ok(LearningScope::Team),
+        "personal" => Ok(LearningScope::Personal),
+        "ephemeral" => Ok(LearningScope::Ephemeral),
+        _ => Err(GroveError::config(format!(
+            "Unknown scope '{}' (expected one of: project, team, personal, ephemeral)",
+            value
+        ))),
+    }
+}
+
+fn parse_status(value: &str) -> Result<LearningStatus> {
+    match value.to_lowercase().as_str() {
+        "active" => Ok(LearningStatus::Active),
+        "archived" => Ok(LearningStatus::Archived),
+        "superseded" => Ok(LearningStatus::Superseded),
+        _ => Err(GroveError::config(format!(
+            "Unknown status '{}' (expected one of: active, archived, superseded, any)",
+            value
+        ))),
+    }
+}
+
+/// Split input into tokens, each with a leading-`-` negation flag.
+fn tokenize(input: &str) -> Vec<(bool, Token)> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let mut negated = false;
+        if c == '-' {
+            chars.next();
+            match chars.peek() {
+                Some(next) if !next.is_whitespace() => negated = true,
+                // A lone dash is not a term
+                _ => continue,
+            }
+        }
+
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            let phrase = read_until_quote(&mut chars);
+            if !phrase.trim().is_empty() {
+                tokens.push((negated, Token::Phrase(phrase.trim().to_string())));
+            }
+            continue;
+        }
+
+        let mut word = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() {
+                break;
+            }
+            chars.next();
+            word.push(c);
+            if c == ':' && chars.peek() == Some(&'"') {
+                chars.next();
+                word.push_str(&read_until_quote(&mut chars));
+                break;
+            }
+        }
+
+        let token = match word.split_once(':') {
+            Some((field, value)) if QUERY_FIELDS.contains(&field.to_lowercase().as_str()) => {
+                Token::Field(field.to_lowercase(), value.trim().to_string())
+            }
+            _ => Token::Word(word),
+        };
+        tokens.push((negated, token));
+    }
+
+    tokens
+}
+
+/// Read up to the closing quote (or the end of input), consuming it.
+fn read_until_quote(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> String {
+    let mut text = String::new();
+    for c in chars.by_ref() {
+        if c == '"' {
+            break;
+        }
+        text.push(c);
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> (SearchQuery, SearchFilters) {
+        parse_query(input, SearchFilters::active_only()).unwrap()
+    }
+
+    #[test]
+    fn test_tokenize_fields_phrases_and_negation() {
+        assert_eq!(
+            tokenize(r#"mutex tag:"error handling" -"old api" -wip note:x - "#),
+            vec![
+                (false, Token::Word("mutex".to_string())),
+                (
+                    false,
+                    Token::Field("tag".to_string(), "error handling".to_string())
+                ),
+                (true, Token::Phrase("old api".to_string())),
+                (true, Token::Word("wip".to_string())),
+                (false, Token::Word("note:x".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_builds_query_and_filters() {
+        let (query, filters) = parse(
+            r#"deadlock "await point" tag:rust category:pitfall scope:personal file:src/hooks/* ticket:PROJ-12 -tag:legacy"#,
+        );
+
+        assert_eq!(query.keywords, vec!["deadlock", "await point"]);
+        assert_eq!(filters.status, Some(LearningStatus::Active));
+        assert_eq!(filters.scope, Some(LearningScope::Personal));
+        assert_eq!(
+            filters.require,
+            vec![
+                FieldFilter::Phrase("await point".to_string()),
+                FieldFilter::Tag("rust".to_string()),
+                FieldFilter::Category(LearningCategory::Pitfall),
+                FieldFilter::File("src/hooks/*".to_string()),
+                FieldFilter::Ticket("PROJ-12".to_string()),
+            ]
+        );
+        assert_eq!(
+            filters.exclude,
+            vec![FieldFilter::Tag("legacy".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_parse_status_overrides_base_filters() {
+        let (query, filters) = parse("status:archived");
+        assert!(query.is_empty());
+        assert_eq!(filters.status, Some(LearningStatus::Archived));
+
+        let (_, filters) = parse("status:any -status:superseded");
+        assert_eq!(filters.status, None);
+        assert_eq!(
+            filters.exclude,
+            vec![FieldFilter::Status(LearningStatus::Superseded)]
+        );
+    }
+
+    #[test]
+    fn test_parse_created_ranges() {
+        let day = |s: &str| parse_learning_date(s).unwrap();
+
+        let (_, filters) = parse("created:>2026-01-01");
+        assert_eq!(filters.created_after, Some(day("2026-01-02")));
+        assert_eq!(filters.created_before, None);
+
+        let (_, filters) = parse("created:>=2026-01-01 created:<2026-02-01");
+        assert_eq!(filters.created_after, Some(day("2026-01-01")));
+        assert_eq!(filters.created_before, Some(day("2026-02-01")));
+
+        let (_, filters) = parse("created:2026-01-01");
+        assert_eq!(filters.created_after, Some(day("2026-01-01")));
+        assert_eq!(filters.created_before, Some(day("2026-01-02")));
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_values() {
+        let err = |input: &str| {
+            parse_query(input, SearchFilters::active_only())
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(err("category:gotcha").contains("pitfall"));
+        assert!(err("scope:global").contains("personal"));
+        assert!(err("status:deleted").contains("archived"));
+        assert!(err("created:>yesterday").contains("YYYY-MM-DD"));
+        assert!(err("-created:>2026-01-01").contains("cannot be negated"));
+        assert!(err("tag:").contains("needs a value"));
+    }
+}
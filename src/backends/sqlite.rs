@@ -0,0 +1,940 @@
+//! SQLite-based memory backend for Grove.
+//!
+//! This module provides a backend that stores learnings in an SQLite database
+//! at `.grove/learnings.db` (or `~/.grove/personal-learnings.db` for personal
+//! scope). It avoids the linear parse-and-scan cost of the markdown backend on
+//! large learning sets by indexing the fields used for filtering and matching.
+//!
+//! Each learning is stored as its full JSON representation alongside indexed
+//! columns for status, scope, category, and timestamp. Tags and context files
+//! live in junction tables so search can narrow candidates in SQL before the
+//! shared relevance scoring runs.
+//!
+//! Requires the `sqlite-backend` feature.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chrono::SecondsFormat;
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension};
+use serde::Serialize;
+
+use crate::backends::markdown::{compute_relevance, learning_was_sanitized, sanitize_learning};
+use crate::backends::traits::{
+    MemoryBackend, SearchFilters, SearchQuery, SearchResult, WriteResult,
+};
+use crate::core::{CompoundLearning, LearningScope, LearningStatus};
+use crate::error::{GroveError, Result};
+
+/// How long to wait on a locked database before giving up.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Database schema, applied idempotently on every open.
+const SCHEMA: &str = "
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE IF NOT EXISTS learnings (
+    id TEXT PRIMARY KEY,
+    status TEXT NOT NULL,
+    scope TEXT NOT NULL,
+    category TEXT NOT NULL,
+    ticket_id TEXT,
+    created_at TEXT NOT NULL,
+    search_text TEXT NOT NULL,
+    data TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_learnings_status ON learnings(status);
+CREATE INDEX IF NOT EXISTS idx_learnings_scope ON learnings(scope);
+CREATE INDEX IF NOT EXISTS idx_learnings_category ON learnings(category);
+CREATE INDEX IF NOT EXISTS idx_learnings_created_at ON learnings(created_at);
+CREATE INDEX IF NOT EXISTS idx_learnings_ticket_id ON learnings(ticket_id);
+
+CREATE TABLE IF NOT EXISTS learning_tags (
+    learning_id TEXT NOT NULL REFERENCES learnings(id) ON DELETE CASCADE,
+    tag TEXT NOT NULL,
+    PRIMARY KEY (learning_id, tag)
+);
+CREATE INDEX IF NOT EXISTS idx_learning_tags_tag ON learning_tags(tag);
+
+CREATE TABLE IF NOT EXISTS learning_files (
+    learning_id TEXT NOT NULL REFERENCES learnings(id) ON DELETE CASCADE,
+    path TEXT NOT NULL,
+    file_name TEXT NOT NULL,
+    PRIMARY KEY (learning_id, path)
+);
+CREATE INDEX IF NOT EXISTS idx_learning_files_file_name ON learning_files(file_name);
+";
+
+/// SQLite-based memory backend.
+///
+/// Stores learnings in indexed SQLite tables. Supports:
+/// - Scope routing (project/team → project database, personal → user database)
+/// - Indexed filtering by status, scope, category, and creation time
+/// - Candidate narrowing by tags, context files, keywords, and ticket
+/// - Transactional status updates for archiving
+#[derive(Debug, Clone)]
+pub struct SqliteBackend {
+    /// Path to the project database (.grove/learnings.db).
+    project_path: PathBuf,
+    /// Path to the personal database (~/.grove/personal-learnings.db).
+    personal_path: PathBuf,
+}
+
+impl SqliteBackend {
+    /// Create a new SQLite backend with the given project database path.
+    ///
+    /// The personal database path is automatically set to `~/.grove/personal-learnings.db`.
+    pub fn new(project_path: impl AsRef<Path>) -> Self {
+        let personal_path = dirs::home_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(".grove")
+            .join("personal-learnings.db");
+
+        Self {
+            project_path: project_path.as_ref().to_path_buf(),
+            personal_path,
+        }
+    }
+
+    /// Create a backend with explicit paths for both project and personal databases.
+    ///
+    /// Useful for testing.
+    pub fn with_paths(project_path: impl AsRef<Path>, personal_path: impl AsRef<Path>) -> Self {
+        Self {
+            project_path: project_path.as_ref().to_path_buf(),
+            personal_path: personal_path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Get the database path for a learning based on its scope.
+    fn path_for_scope(&self, scope: &LearningScope) -> Option<&Path> {
+        match scope {
+            LearningScope::Project | LearningScope::Team => Some(&self.project_path),
+            LearningScope::Personal => Some(&self.personal_path),
+            LearningScope::Ephemeral => None, // Ephemeral learnings are discarded
+        }
+    }
+
+    /// Open a database for writing, creating it and its parent directory if needed.
+    fn open_for_write(path: &Path) -> Result<Connection> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| {
+                GroveError::backend(format!(
+                    "Failed to create directory {}: {}",
+                    parent.display(),
+                    e
+                ))
+            })?;
+        }
+        Self::open(path)
+    }
+
+    /// Open an existing database for reading.
+    ///
+    /// Returns `None` if the database does not exist yet, so reads never
+    /// create empty database files as a side effect.
+    fn open_for_read(path: &Path) -> Result<Option<Connection>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        Self::open(path).map(Some)
+    }
+
+    /// Open a connection and ensure the schema exists.
+    fn open(path: &Path) -> Result<Connection> {
+        let conn = Connection::open(path).map_err(|e| {
+            GroveError::backend(format!("Failed to open {}: {}", path.display(), e))
+        })?;
+        conn.busy_timeout(BUSY_TIMEOUT)
+            .map_err(|e| sql_error(path, e))?;
+        conn.execute_batch(SCHEMA).map_err(|e| sql_error(path, e))?;
+        Ok(conn)
+    }
+
+    /// Insert or replace a learning and its tag/file rows in one transaction.
+    fn upsert(path: &Path, conn: &mut Connection, learning: &CompoundLearning) -> Result<()> {
+        let data = serde_json::to_string(learning)
+            .map_err(|e| GroveError::backend(format!("Failed to serialize learning: {}", e)))?;
+
+        let tx = conn.transaction().map_err(|e| sql_error(path, e))?;
+        tx.execute(
+            "INSERT INTO learnings (id, status, scope, category, ticket_id, created_at, search_text, data)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+             ON CONFLICT(id) DO UPDATE SET
+                status = excluded.status,
+                scope = excluded.scope,
+                category = excluded.category,
+                ticket_id = excluded.ticket_id,
+                created_at = excluded.created_at,
+                search_text = excluded.search_text,
+                data = excluded.data",
+            params![
+                learning.id,
+                enum_str(&learning.status),
+                enum_str(&learning.scope),
+                enum_str(&learning.category),
+                learning.ticket_id,
+                timestamp_str(learning),
+                search_text(learning),
+                data,
+            ],
+        )
+        .map_err(|e| sql_error(path, e))?;
+
+        tx.execute(
+            "DELETE FROM learning_tags WHERE learning_id = ?1",
+            params![learning.id],
+        )
+        .map_err(|e| sql_error(path, e))?;
+        tx.execute(
+            "DELETE FROM learning_files WHERE learning_id = ?1",
+            params![learning.id],
+        )
+        .map_err(|e| sql_error(path, e))?;
+
+        for tag in &learning.tags {
+            tx.execute(
+                "INSERT OR IGNORE INTO learning_tags (learning_id, tag) VALUES (?1, ?2)",
+                params![learning.id, tag.to_lowercase()],
+            )
+            .map_err(|e| sql_error(path, e))?;
+        }
+
+        for file in learning.context_files.iter().flatten() {
+            tx.execute(
+                "INSERT OR IGNORE INTO learning_files (learning_id, path, file_name) VALUES (?1, ?2, ?3)",
+                params![learning.id, file, file_name(file)],
+            )
+            .map_err(|e| sql_error(path, e))?;
+        }
+
+        tx.commit().map_err(|e| sql_error(path, e))
+    }
+
+    /// Load candidate learnings from one database.
+    ///
+    /// Status, scope, and creation time are filtered in SQL. For non-empty
+    /// queries, only rows that could possibly score above zero are returned;
+    /// exact scoring happens afterwards in [`compute_relevance`].
+    fn load_candidates(
+        path: &Path,
+        query: &SearchQuery,
+        filters: &SearchFilters,
+    ) -> Result<Vec<CompoundLearning>> {
+        let Some(conn) = Self::open_for_read(path)? else {
+            return Ok(Vec::new());
+        };
+
+        let mut sql = String::from("SELECT l.data FROM learnings l WHERE 1 = 1");
+        let mut args: Vec<String> = Vec::new();
+
+        if let Some(ref status) = filters.status {
+            sql.push_str(" AND l.status = ?");
+            args.push(enum_str(status));
+        }
+        if let Some(ref scope) = filters.scope {
+            sql.push_str(" AND l.scope = ?");
+            args.push(enum_str(scope));
+        }
+
+        if !query.is_empty() {
+            let mut clauses: Vec<String> = Vec::new();
+
+            for tag in &query.tags {
+                clauses.push(
+                    "EXISTS (SELECT 1 FROM learning_tags t WHERE t.learning_id = l.id \
+                     AND (instr(t.tag, ?) > 0 OR instr(?, t.tag) > 0))"
+                        .to_string(),
+                );
+                let tag = tag.to_lowercase();
+                args.push(tag.clone());
+                args.push(tag);
+            }
+
+            for file in &query.files {
+                clauses.push(
+                    "EXISTS (SELECT 1 FROM learning_files f WHERE f.learning_id = l.id \
+                     AND f.file_name = ?)"
+                        .to_string(),
+                );
+                args.push(file_name(file).to_string());
+            }
+
+            for keyword in &query.keywords {
+                let parts: Vec<String> = keyword
+                    .to_lowercase()
+                    .split_whitespace()
+                    .map(|p| p.to_string())
+                    .collect();
+                if parts.is_empty() {
+                    continue;
+                }
+                let conditions = vec!["instr(l.search_text, ?) > 0"; parts.len()].join(" AND ");
+                clauses.push(format!("({})", conditions));
+                args.extend(parts);
+            }
+
+            if let Some(ref ticket_id) = query.ticket_id {
+                clauses.push("l.ticket_id = ?".to_string());
+                args.push(ticket_id.clone());
+            }
+
+            if clauses.is_empty() {
+                return Ok(Vec::new());
+            }
+            sql.push_str(&format!(" AND ({})", clauses.join(" OR ")));
+        }
+
+        let mut stmt = conn.prepare(&sql).map_err(|e| sql_error(path, e))?;
+        let rows = stmt
+            .query_map(params_from_iter(args.iter()), |row| row.get::<_, String>(0))
+            .map_err(|e| sql_error(path, e))?;
+
+        let mut learnings = Vec::new();
+        for row in rows {
+            let data = row.map_err(|e| sql_error(path, e))?;
+            match serde_json::from_str::<CompoundLearning>(&data) {
+                Ok(learning) => learnings.push(learning),
+                Err(e) => {
+                    // Skip unreadable rows rather than failing the whole search
+                    tracing::warn!("Skipping malformed learning in {}: {}", path.display(), e);
+                }
+            }
+        }
+
+        Ok(learnings)
+    }
+
+    /// Load all learnings from both databases.
+    pub fn load_all_learnings(&self) -> Result<Vec<CompoundLearning>> {
+        let query = SearchQuery::new();
+        let filters = SearchFilters::all();
+        let mut learnings = Self::load_candidates(&self.project_path, &query, &filters)?;
+        learnings.extend(Self::load_candidates(
+            &self.personal_path,
+            &query,
+            &filters,
+        )?);
+        Ok(learnings)
+    }
+
+    /// Update the status of a learning in-place.
+    fn update_status(&self, learning_id: &str, new_status: LearningStatus) -> Result<()> {
+        // Try project database first, then personal
+        if Self::update_status_in_db(&self.project_path, learning_id, new_status)? {
+            return Ok(());
+        }
+        if Self::update_status_in_db(&self.personal_path, learning_id, new_status)? {
+            return Ok(());
+        }
+
+        Err(GroveError::backend(format!(
+            "Learning {} not found",
+            learning_id
+        )))
+    }
+
+    /// Update the status of a learning in a specific database.
+    ///
+    /// Returns true if the learning was found and updated.
+    fn update_status_in_db(
+        path: &Path,
+        learning_id: &str,
+        new_status: LearningStatus,
+    ) -> Result<bool> {
+        let Some(mut conn) = Self::open_for_read(path)? else {
+            return Ok(false);
+        };
+
+        let tx = conn.transaction().map_err(|e| sql_error(path, e))?;
+        let data: Option<String> = tx
+            .query_row(
+                "SELECT data FROM learnings WHERE id = ?1",
+                params![learning_id],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| sql_error(path, e))?;
+
+        let Some(data) = data else {
+            return Ok(false);
+        };
+
+        let mut learning: CompoundLearning = serde_json::from_str(&data).map_err(|e| {
+            GroveError::backend(format!("Failed to parse learning {}: {}", learning_id, e))
+        })?;
+        learning.status = new_status;
+        let data = serde_json::to_string(&learning)
+            .map_err(|e| GroveError::backend(format!("Failed to serialize learning: {}", e)))?;
+
+        tx.execute(
+            "UPDATE learnings SET status = ?1, data = ?2 WHERE id = ?3",
+            params![enum_str(&learning.status), data, learning_id],
+        )
+        .map_err(|e| sql_error(path, e))?;
+        tx.commit().map_err(|e| sql_error(path, e))?;
+
+        Ok(true)
+    }
+
+    /// Check whether a database holds a learning with the given ID.
+    fn contains(path: &Path, learning_id: &str) -> Result<bool> {
+        let Some(conn) = Self::open_for_read(path)? else {
+            return Ok(false);
+        };
+        conn.query_row(
+            "SELECT 1 FROM learnings WHERE id = ?1",
+            params![learning_id],
+            |_| Ok(()),
+        )
+        .optional()
+        .map(|row| row.is_some())
+        .map_err(|e| sql_error(path, e))
+    }
+
+    /// Find the highest counter used today in one database.
+    fn max_counter_for_prefix(path: &Path, today_prefix: &str) -> Option<u32> {
+        let conn = Self::open_for_read(path).ok()??;
+        let mut stmt = conn
+            .prepare("SELECT id FROM learnings WHERE id LIKE ?1 ESCAPE '\\'")
+            .ok()?;
+        let pattern = format!("{}%", today_prefix.replace('_', "\\_"));
+        let ids = stmt
+            .query_map(params![pattern], |row| row.get::<_, String>(0))
+            .ok()?;
+
+        ids.filter_map(|id| id.ok())
+            .filter_map(|id| {
+                id.strip_prefix(today_prefix)
+                    .and_then(|counter| counter.parse::<u32>().ok())
+            })
+            .max()
+    }
+}
+
+impl MemoryBackend for SqliteBackend {
+    fn write(&self, learning: &CompoundLearning) -> Result<WriteResult> {
+        let path = match self.path_for_scope(&learning.scope) {
+            Some(p) => p,
+            None => {
+                // Ephemeral scope - discard
+                return Ok(WriteResult::success_with_message(
+                    &learning.id,
+                    "ephemeral",
+                    "Ephemeral learning discarded (not persisted)",
+                ));
+            }
+        };
+
+        // Same sanitization as the markdown backend, so stored content is
+        // interchangeable between the two
+        let sanitized = sanitize_learning(learning);
+        let mut conn = Self::open_for_write(path)?;
+        Self::upsert(path, &mut conn, &sanitized)?;
+
+        if learning_was_sanitized(learning, &sanitized) {
+            Ok(WriteResult::success_with_message(
+                &learning.id,
+                path.display().to_string(),
+                "Content was sanitized",
+            ))
+        } else {
+            Ok(WriteResult::success(
+                &learning.id,
+                path.display().to_string(),
+            ))
+        }
+    }
+
+    fn search(&self, query: &SearchQuery, filters: &SearchFilters) -> Result<Vec<SearchResult>> {
+        let mut candidates = Self::load_candidates(&self.project_path, query, filters)?;
+        candidates.extend(Self::load_candidates(&self.personal_path, query, filters)?);
+
+        let mut results: Vec<SearchResult> = candidates
+            .into_iter()
+            .filter(|learning| filters.matches(learning))
+            .filter_map(|learning| {
+                let relevance = if query.is_empty() {
+                    // If no query, all matching learnings are equally relevant
+                    1.0
+                } else {
+                    compute_relevance(&learning, query)
+                };
+
+                if relevance > 0.0 || query.is_empty() {
+                    Some(SearchResult::new(learning, relevance))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        // Sort by relevance (highest first)
+        results.sort_by(|a, b| {
+            b.relevance
+                .partial_cmp(&a.relevance)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        // Apply max_results limit
+        if let Some(limit) = filters.max_results {
+            results.truncate(limit);
+        }
+
+        Ok(results)
+    }
+
+    fn ping(&self) -> bool {
+        // The project database must be openable (creating it if needed)
+        Self::open_for_write(&self.project_path).is_ok()
+    }
+
+    fn name(&self) -> &'static str {
+        "sqlite"
+    }
+
+    fn archive(&self, learning_id: &str) -> Result<()> {
+        self.update_status(learning_id, LearningStatus::Archived)
+    }
+
+    fn restore(&self, learning_id: &str) -> Result<()> {
+        self.update_status(learning_id, LearningStatus::Active)
+    }
+
+    fn update(&self, learning: &CompoundLearning) -> Result<()> {
+        // Rewrite the row in whichever database holds the learning
+        for path in [&self.project_path, &self.personal_path] {
+            if Self::contains(path, &learning.id)? {
+                let sanitized = sanitize_learning(learning);
+                let mut conn = Self::open_for_write(path)?;
+                return Self::upsert(path, &mut conn, &sanitized);
+            }
+        }
+
+        Err(GroveError::backend(format!(
+            "Learning {} not found",
+            learning.id
+        )))
+    }
+
+    fn list_all(&self) -> Result<Vec<CompoundLearning>> {
+        self.load_all_learnings()
+    }
+
+    fn next_id(&self) -> String {
+        // Scan existing IDs to find the highest counter for today
+        let today = chrono::Utc::now().format("%Y%m%d").to_string();
+        let today_prefix = format!("cl_{}_", today);
+
+        let next_counter = [&self.project_path, &self.personal_path]
+            .into_iter()
+            .filter_map(|path| Self::max_counter_for_prefix(path, &today_prefix))
+            .max()
+            .map(|max| max + 1)
+            .unwrap_or(0);
+
+        format!("cl_{}_{:03}", today, next_counter)
+    }
+}
+
+/// Map an SQLite error to a backend error with the database path.
+fn sql_error(path: &Path, e: rusqlite::Error) -> GroveError {
+    GroveError::backend(format!("SQLite error on {}: {}", path.display(), e))
+}
+
+/// Serialize a snake_case enum to its string form for an indexed column.
+fn enum_str<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .unwrap_or_default()
+}
+
+/// Format the learning timestamp so lexical order matches chronological order.
+fn timestamp_str(learning: &CompoundLearning) -> String {
+    learning
+        .timestamp
+        .to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+/// Lowercased summary and detail used for keyword candidate matching.
+fn search_text(learning: &CompoundLearning) -> String {
+    format!("{}\n{}", learning.summary, learning.detail).to_lowercase()
+}
+
+/// Last path component, used to narrow file-overlap candidates.
+fn file_name(path: &str) -> &str {
+    path.rsplit(['/', '\\']).next().unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Confidence, LearningCategory, WriteGateCriterion};
+    use tempfile::TempDir;
+
+    fn sample_learning() -> CompoundLearning {
+        CompoundLearning::new(
+            LearningCategory::Pitfall,
+            "Avoid N+1 queries in UserDashboard",
+            "The dashboard was loading users then iterating to load posts separately. Use eager loading instead.",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::BehaviorChanging],
+            vec!["performance".to_string(), "database".to_string()],
+            "test-session-123",
+        )
+        .with_ticket_id("T001")
+        .with_context_files(vec!["src/dashboard.rs".to_string()])
+    }
+
+    fn setup() -> (TempDir, SqliteBackend) {
+        let temp = TempDir::new().unwrap();
+        let backend = SqliteBackend::with_paths(
+            temp.path().join(".grove/learnings.db"),
+            temp.path().join("personal/personal-learnings.db"),
+        );
+        (temp, backend)
+    }
+
+    #[test]
+    fn test_write_and_search_roundtrip() {
+        let (_temp, backend) = setup();
+        let learning = sample_learning();
+
+        let result = backend.write(&learning).unwrap();
+        assert!(result.success);
+        assert_eq!(result.learning_id, learning.id);
+        assert!(result.location.ends_with("learnings.db"));
+
+        let results = backend
+            .search(&SearchQuery::new(), &SearchFilters::default())
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].learning, learning);
+    }
+
+    #[test]
+    fn test_search_by_tag() {
+        let (_temp, backend) = setup();
+        backend.write(&sample_learning()).unwrap();
+
+        let exact = backend
+            .search(
+                &SearchQuery::with_tags(vec!["Performance".to_string()]),
+                &SearchFilters::default(),
+            )
+            .unwrap();
+        assert_eq!(exact.len(), 1);
+        assert_eq!(exact[0].relevance, 1.0);
+
+        let partial = backend
+            .search(
+                &SearchQuery::with_tags(vec!["data".to_string()]),
+                &SearchFilters::default(),
+            )
+            .unwrap();
+        assert_eq!(partial.len(), 1);
+        assert_eq!(partial[0].relevance, 0.5);
+
+        let none = backend
+            .search(
+                &SearchQuery::with_tags(vec!["security".to_string()]),
+                &SearchFilters::default(),
+            )
+            .unwrap();
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn test_search_by_file_requires_path_overlap() {
+        let (_temp, backend) = setup();
+        backend.write(&sample_learning()).unwrap();
+
+        let matching = backend
+            .search(
+                &SearchQuery::with_files(vec!["/repo/src/dashboard.rs".to_string()]),
+                &SearchFilters::default(),
+            )
+            .unwrap();
+        assert_eq!(matching.len(), 1);
+        assert_eq!(matching[0].relevance, 0.8);
+
+        // Same file name in a different directory is not an overlap
+        let other_dir = backend
+            .search(
+                &SearchQuery::with_files(vec!["lib/dashboard.rs".to_string()]),
+                &SearchFilters::default(),
+            )
+            .unwrap();
+        assert!(other_dir.is_empty());
+    }
+
+    #[test]
+    fn test_search_by_keyword_matches_whole_words() {
+        let (_temp, backend) = setup();
+        backend.write(&sample_learning()).unwrap();
+
+        let results = backend
+            .search(
+                &SearchQuery::with_keywords(vec!["eager loading".to_string()]),
+                &SearchFilters::default(),
+            )
+            .unwrap();
+        assert_eq!(results.len(), 1);
+
+        // Substring of a word is a SQL candidate but scores zero
+        let results = backend
+            .search(
+                &SearchQuery::with_keywords(vec!["eag".to_string()]),
+                &SearchFilters::default(),
+            )
+            .unwrap();
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_search_by_ticket() {
+        let (_temp, backend) = setup();
+        backend.write(&sample_learning()).unwrap();
+
+        let results = backend
+            .search(
+                &SearchQuery::new().ticket_id("T001"),
+                &SearchFilters::default(),
+            )
+            .unwrap();
+        assert_eq!(results.len(), 1);
+
+        let results = backend
+            .search(
+                &SearchQuery::new().ticket_id("T002"),
+                &SearchFilters::default(),
+            )
+            .unwrap();
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_search_applies_filters_and_limit() {
+        let (_temp, backend) = setup();
+        for _ in 0..3 {
+            let mut learning = sample_learning();
+            learning.id = backend.next_id();
+            backend.write(&learning).unwrap();
+        }
+
+        let limited = backend
+            .search(
+                &SearchQuery::new(),
+                &SearchFilters::default().max_results(2),
+            )
+            .unwrap();
+        assert_eq!(limited.len(), 2);
+
+        let team_only = backend
+            .search(
+                &SearchQuery::new(),
+                &SearchFilters::default().scope(LearningScope::Team),
+            )
+            .unwrap();
+        assert!(team_only.is_empty());
+
+        let future = chrono::Utc::now() + chrono::Duration::days(1);
+        let recent = backend
+            .search(
+                &SearchQuery::new(),
+                &SearchFilters::default().created_after(future),
+            )
+            .unwrap();
+        assert!(recent.is_empty());
+    }
+
+    #[test]
+    fn test_personal_scope_routes_to_personal_db() {
+        let (temp, backend) = setup();
+        let mut learning = sample_learning();
+        learning.scope = LearningScope::Personal;
+
+        let result = backend.write(&learning).unwrap();
+        assert!(result.location.ends_with("personal-learnings.db"));
+        assert!(temp.path().join("personal/personal-learnings.db").exists());
+        assert!(!temp.path().join(".grove/learnings.db").exists());
+
+        let results = backend
+            .search(&SearchQuery::new(), &SearchFilters::default())
+            .unwrap();
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn test_ephemeral_scope_is_discarded() {
+        let (temp, backend) = setup();
+        let mut learning = sample_learning();
+        learning.scope = LearningScope::Ephemeral;
+
+        let result = backend.write(&learning).unwrap();
+        assert!(result.success);
+        assert_eq!(result.location, "ephemeral");
+        assert!(!temp.path().join(".grove/learnings.db").exists());
+    }
+
+    #[test]
+    fn test_write_same_id_replaces_row() {
+        let (_temp, backend) = setup();
+        let mut learning = sample_learning();
+        backend.write(&learning).unwrap();
+
+        learning.tags = vec!["caching".to_string()];
+        backend.write(&learning).unwrap();
+
+        let all = backend.list_all().unwrap();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].tags, vec!["caching".to_string()]);
+
+        let stale = backend
+            .search(
+                &SearchQuery::with_tags(vec!["performance".to_string()]),
+                &SearchFilters::default(),
+            )
+            .unwrap();
+        assert!(stale.is_empty());
+    }
+
+    #[test]
+    fn test_archive_and_restore() {
+        let (_temp, backend) = setup();
+        let learning = sample_learning();
+        backend.write(&learning).unwrap();
+
+        backend.archive(&learning.id).unwrap();
+        let active = backend
+            .search(&SearchQuery::new(), &SearchFilters::active_only())
+            .unwrap();
+        assert!(active.is_empty());
+
+        let archived = backend
+            .search(
+                &SearchQuery::new(),
+                &SearchFilters::all().status(LearningStatus::Archived),
+            )
+            .unwrap();
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].learning.status, LearningStatus::Archived);
+
+        backend.restore(&learning.id).unwrap();
+        let active = backend
+            .search(&SearchQuery::new(), &SearchFilters::active_only())
+            .unwrap();
+        assert_eq!(active.len(), 1);
+    }
+
+    #[test]
+    fn test_write_sanitizes_like_markdown_backend() {
+        let (_temp, backend) = setup();
+        let mut learning = sample_learning();
+        learning.summary = "Line one\n## injected | header".to_string();
+        learning.tags = vec!["Needs Cleanup!".to_string()];
+
+        let result = backend.write(&learning).unwrap();
+        assert_eq!(result.message.as_deref(), Some("Content was sanitized"));
+
+        let stored = backend.list_all().unwrap().remove(0);
+        assert_eq!(stored, sanitize_learning(&learning));
+        assert_ne!(stored.summary, learning.summary);
+
+        let mut edited = stored.clone();
+        edited.detail = "  ```rust\nunclosed fence".to_string();
+        backend.update(&edited).unwrap();
+        assert_eq!(
+            backend.list_all().unwrap()[0].detail,
+            "```rust\nunclosed fence\n```"
+        );
+    }
+
+    #[test]
+    fn test_update_replaces_learning_and_tags() {
+        let (_temp, backend) = setup();
+        let learning = sample_learning();
+        backend.write(&learning).unwrap();
+
+        let mut edited = learning.clone();
+        edited.summary = "Edited summary in sqlite".to_string();
+        edited.tags = vec!["edited".to_string()];
+        backend.update(&edited).unwrap();
+
+        assert_eq!(backend.list_all().unwrap(), vec![edited]);
+        let by_old_tag = backend
+            .search(
+                &SearchQuery::new().tags(learning.tags.clone()),
+                &SearchFilters::default(),
+            )
+            .unwrap();
+        assert!(by_old_tag.is_empty());
+
+        let mut missing = learning;
+        missing.id = "cl_20990101_001".to_string();
+        assert!(backend.update(&missing).is_err());
+    }
+
+    #[test]
+    fn test_archive_missing_learning_errors() {
+        let (_temp, backend) = setup();
+        backend.write(&sample_learning()).unwrap();
+
+        assert!(backend.archive("cl_20990101_001").is_err());
+    }
+
+    #[test]
+    fn test_list_all_includes_archived() {
+        let (_temp, backend) = setup();
+        let learning = sample_learning();
+        backend.write(&learning).unwrap();
+        backend.archive(&learning.id).unwrap();
+
+        let all = backend.list_all().unwrap();
+        assert_eq!(all.len(), 1);
+    }
+
+    #[test]
+    fn test_search_without_database_returns_empty() {
+        let (temp, backend) = setup();
+
+        let results = backend
+            .search(&SearchQuery::new(), &SearchFilters::default())
+            .unwrap();
+        assert!(results.is_empty());
+        // Reads must not create the database
+        assert!(!temp.path().join(".grove/learnings.db").exists());
+    }
+
+    #[test]
+    fn test_next_id_scans_both_databases() {
+        let (_temp, backend) = setup();
+        let today = chrono::Utc::now().format("%Y%m%d").to_string();
+        assert_eq!(backend.next_id(), format!("cl_{}_000", today));
+
+        let mut project = sample_learning();
+        project.id = format!("cl_{}_004", today);
+        backend.write(&project).unwrap();
+
+        let mut personal = sample_learning();
+        personal.id = format!("cl_{}_007", today);
+        personal.scope = LearningScope::Personal;
+        backend.write(&personal).unwrap();
+
+        assert_eq!(backend.next_id(), format!("cl_{}_008", today));
+        assert_eq!(
+            backend.next_ids(2),
+            vec![format!("cl_{}_008", today), format!("cl_{}_009", today)]
+        );
+    }
+
+    #[test]
+    fn test_ping_and_name() {
+        let (_temp, backend) = setup();
+        assert!(backend.ping());
+        assert_eq!(backend.name(), "sqlite");
+    }
+}
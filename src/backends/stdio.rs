@@ -0,0 +1,206 @@
+//! Line-oriented stdio transport for subprocess-backed memory backends.
+//!
+//! Spawns a child process with piped stdin/stdout and exchanges one message
+//! per line. Reads happen on a background thread so every receive can be
+//! bounded by a timeout; a hung server can never stall a hook.
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+use crate::error::{GroveError, Result};
+
+/// A running child process that speaks newline-delimited messages.
+///
+/// The process is killed and reaped when the handle is dropped.
+#[derive(Debug)]
+pub(crate) struct StdioProcess {
+    /// The child process.
+    child: Child,
+    /// Write end of the child's stdin (taken on drop to signal EOF).
+    stdin: Option<ChildStdin>,
+    /// Lines read from the child's stdout.
+    lines: Receiver<String>,
+    /// Maximum time to wait for each response.
+    timeout: Duration,
+    /// Command name used in error messages.
+    label: String,
+}
+
+impl StdioProcess {
+    /// Spawn `command` with `args` and `env`, optionally in `cwd`.
+    ///
+    /// Stderr is discarded so server logging never leaks into hook output.
+    pub(crate) fn spawn(
+        command: &str,
+        args: &[String],
+        env: &HashMap<String, String>,
+        cwd: Option<&Path>,
+        timeout: Duration,
+    ) -> Result<Self> {
+        let mut cmd = Command::new(command);
+        cmd.args(args)
+            .envs(env)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null());
+        if let Some(dir) = cwd {
+            cmd.current_dir(dir);
+        }
+
+        let mut child = cmd
+            .spawn()
+            .map_err(|e| GroveError::backend(format!("Failed to start {}: {}", command, e)))?;
+
+        let stdin = child.stdin.take();
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| GroveError::backend(format!("No stdout from {}", command)))?;
+
+        let (tx, lines) = mpsc::channel();
+        std::thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                let Ok(line) = line else { break };
+                if tx.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(Self {
+            child,
+            stdin,
+            lines,
+            timeout,
+            label: command.to_string(),
+        })
+    }
+
+    /// Write one line to the child's stdin.
+    pub(crate) fn send(&mut self, line: &str) -> Result<()> {
+        let stdin = self
+            .stdin
+            .as_mut()
+            .ok_or_else(|| GroveError::backend(format!("{} stdin is closed", self.label)))?;
+        writeln!(stdin, "{}", line)
+            .and_then(|_| stdin.flush())
+            .map_err(|e| GroveError::backend(format!("Failed to write to {}: {}", self.label, e)))
+    }
+
+    /// The instant one timeout from now, for bounding a whole exchange.
+    pub(crate) fn deadline(&self) -> Instant {
+        Instant::now() + self.timeout
+    }
+
+    /// Read the next line from the child's stdout, waiting until `deadline`
+    /// at most.
+    ///
+    /// Callers that skip unrelated lines pass one deadline for the whole
+    /// exchange, so a chatty child can't extend the wait line by line.
+    pub(crate) fn recv_by(&mut self, deadline: Instant) -> Result<String> {
+        let wait = deadline.saturating_duration_since(Instant::now());
+        match self.lines.recv_timeout(wait) {
+            Ok(line) => Ok(line),
+            Err(RecvTimeoutError::Timeout) => Err(GroveError::backend(format!(
+                "Timed out after {}s waiting for {}",
+                self.timeout.as_secs(),
+                self.label
+            ))),
+            Err(RecvTimeoutError::Disconnected) => Err(GroveError::backend(format!(
+                "{} exited before responding",
+                self.label
+            ))),
+        }
+    }
+}
+
+impl Drop for StdioProcess {
+    fn drop(&mut self) {
+        // Close stdin first so well-behaved servers see EOF, then make sure
+        // the process is gone and reaped.
+        drop(self.stdin.take());
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spawn_missing_command_errors() {
+        let result = StdioProcess::spawn(
+            "/nonexistent/grove-stdio-test",
+            &[],
+            &HashMap::new(),
+            None,
+            Duration::from_secs(1),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_send_and_recv_roundtrip() {
+        let mut process =
+            StdioProcess::spawn("cat", &[], &HashMap::new(), None, Duration::from_secs(5)).unwrap();
+
+        process.send("{\"hello\":1}").unwrap();
+        assert_eq!(
+            process.recv_by(process.deadline()).unwrap(),
+            "{\"hello\":1}"
+        );
+    }
+
+    #[test]
+    fn test_recv_times_out() {
+        let mut process = StdioProcess::spawn(
+            "sleep",
+            &["5".to_string()],
+            &HashMap::new(),
+            None,
+            Duration::from_millis(100),
+        )
+        .unwrap();
+
+        let err = process.recv_by(process.deadline()).unwrap_err();
+        assert!(err.to_string().contains("Timed out"));
+    }
+
+    #[test]
+    fn test_recv_by_shares_one_deadline() {
+        let script = "while true; do echo log line; sleep 0.05; done";
+        let mut process = StdioProcess::spawn(
+            "sh",
+            &["-c".to_string(), script.to_string()],
+            &HashMap::new(),
+            None,
+            Duration::from_millis(300),
+        )
+        .unwrap();
+
+        let started = Instant::now();
+        let deadline = process.deadline();
+        let err = loop {
+            match process.recv_by(deadline) {
+                Ok(_) => continue,
+                Err(e) => break e,
+            }
+        };
+        assert!(err.to_string().contains("Timed out"));
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_recv_after_exit_errors() {
+        let mut process =
+            StdioProcess::spawn("true", &[], &HashMap::new(), None, Duration::from_secs(5))
+                .unwrap();
+
+        assert!(process.recv_by(process.deadline()).is_err());
+    }
+}
@@ -24,7 +24,10 @@ use tracing::warn;
 
 use crate::util::sync_parent_dir;
 
-use crate::backends::markdown::validate_learning_id;
+use crate::backends::markdown::{
+    fence_marker, format_examples, format_relations, parse_relations, split_examples,
+    validate_learning_id, EXAMPLE_PREFIX,
+};
 use crate::backends::total_recall_format::{
     self as fmt, BLOCKQUOTE_PREFIX, DAILY_LOG_SEARCH_LIMIT, DATE_FORMAT, ENTRY_SEPARATOR,
     GROVE_ID_PREFIX, LABEL_CATEGORY, LABEL_CONFIDENCE, LABEL_CREATED, LABEL_FILES, LABEL_SUMMARY,
@@ -69,6 +72,40 @@ fn is_grove_entry_line(line: &str) -> bool {
         || line.starts_with(LABEL_FILES)
 }
 
+/// Select the lines of a log file that belong to grove entries.
+///
+/// Example headers and their fenced code are kept verbatim. The flag marks
+/// code lines, which must not be read as entry boundaries or metadata.
+fn grove_entry_lines(content: &str) -> Vec<(&str, bool)> {
+    let mut lines = Vec::new();
+    let mut open_fence: Option<String> = None;
+    let mut after_example_header = false;
+
+    for line in content.lines() {
+        if let Some(ref fence) = open_fence {
+            if line.trim() == fence {
+                open_fence = None;
+            }
+            lines.push((line, true));
+            continue;
+        }
+        if after_example_header {
+            if let Some(fence) = fence_marker(line) {
+                open_fence = Some(fence);
+                after_example_header = false;
+                lines.push((line, true));
+                continue;
+            }
+        }
+        after_example_header = line.starts_with(EXAMPLE_PREFIX);
+        if after_example_header || is_grove_entry_line(line) {
+            lines.push((line, false));
+        }
+    }
+
+    lines
+}
+
 /// Check if an entry matches all search terms (AND logic).
 ///
 /// Each term must be present in the entry (case-insensitive).
@@ -95,8 +132,8 @@ fn extract_matching_entries(content: &str, query: &str) -> String {
     let mut current_entry = String::new();
     let mut in_entry = false;
 
-    for line in content.lines() {
-        if line.contains(GROVE_ID_PREFIX) {
+    for (line, is_code) in grove_entry_lines(content) {
+        if !is_code && line.contains(GROVE_ID_PREFIX) {
             // Start of a new entry - flush previous if it matches
             if in_entry
                 && !current_entry.is_empty()
@@ -109,14 +146,14 @@ fn extract_matching_entries(content: &str, query: &str) -> String {
             in_entry = true;
         }
 
-        if in_entry && is_grove_entry_line(line) {
+        if in_entry {
             if !current_entry.is_empty() {
                 current_entry.push('\n');
             }
             current_entry.push_str(line);
 
             // Tags: line marks end of an entry
-            if line.starts_with(LABEL_TAGS) {
+            if !is_code && line.starts_with(LABEL_TAGS) {
                 if entry_matches_all_terms(&current_entry, &terms) {
                     results.push_str(&current_entry);
                     results.push_str(&format!("\n{}\n\n", ENTRY_SEPARATOR));
@@ -204,6 +241,13 @@ impl TotalRecallBackend {
             note.push('\n');
         }
 
+        // Examples as fenced blocks
+        if !learning.examples.is_empty() {
+            note.push('\n');
+            note.push_str(&format_examples(&learning.examples));
+            note.push('\n');
+        }
+
         // Metadata line
         let mut meta_parts = Vec::new();
 
@@ -232,6 +276,24 @@ impl TotalRecallBackend {
             }
         }
 
+        // Relations
+        if !learning.relations.is_empty() {
+            meta_parts.push(format!(
+                "{} {}",
+                fmt::LABEL_RELATIONS,
+                format_relations(&learning.relations)
+            ));
+        }
+
+        // Status (only non-active learnings carry one)
+        if learning.status != LearningStatus::Active {
+            meta_parts.push(format!(
+                "{} {}",
+                fmt::LABEL_STATUS,
+                status_display(&learning.status)
+            ));
+        }
+
         if !meta_parts.is_empty() {
             note.push('\n');
             note.push_str(&meta_parts.join(METADATA_SEPARATOR));
@@ -332,6 +394,16 @@ impl TotalRecallBackend {
             LABEL_CREATED,
             learning.timestamp.format(TIMESTAMP_FORMAT)
         ));
+        if learning.status != LearningStatus::Active {
+            // Status joins the Confidence/Created line, as rewrite_metadata_line does
+            md.pop();
+            md.push_str(&format!(
+                "{}{} {}\n",
+                METADATA_SEPARATOR,
+                fmt::LABEL_STATUS,
+                status_display(&learning.status)
+            ));
+        }
 
         // Tags
         if !learning.tags.is_empty() {
@@ -339,9 +411,23 @@ impl TotalRecallBackend {
             md.push_str(&format!("**Tags:** {}\n", tags.join(" ")));
         }
 
+        // Relations
+        if !learning.relations.is_empty() {
+            md.push_str(&format!(
+                "**{}** {}\n",
+                fmt::LABEL_RELATIONS,
+                format_relations(&learning.relations)
+            ));
+        }
+
         // Detail
         md.push_str(&format!("\n{}\n", learning.detail));
 
+        // Examples
+        if !learning.examples.is_empty() {
+            md.push_str(&format!("\n{}\n", format_examples(&learning.examples)));
+        }
+
         // Separator
         md.push_str(&format!("\n{}\n\n", ENTRY_SEPARATOR));
 
@@ -467,14 +553,12 @@ impl TotalRecallBackend {
                         results.push_str(&format!("[{}]\n", entry.path().display()));
                         // Extract grove entries - add separator after each Tags: line
                         // to properly delimit multiple entries within the same file
-                        for line in content.lines() {
-                            if is_grove_entry_line(line) {
-                                results.push_str(line);
-                                results.push('\n');
-                                // Tags: line marks end of an entry, add separator
-                                if line.starts_with(LABEL_TAGS) {
-                                    results.push_str(&format!("\n{}\n\n", ENTRY_SEPARATOR));
-                                }
+                        for (line, is_code) in grove_entry_lines(&content) {
+                            results.push_str(line);
+                            results.push('\n');
+                            // Tags: line marks end of an entry, add separator
+                            if !is_code && line.starts_with(LABEL_TAGS) {
+                                results.push_str(&format!("\n{}\n\n", ENTRY_SEPARATOR));
                             }
                         }
                     }
@@ -492,14 +576,12 @@ impl TotalRecallBackend {
                         if let Ok(content) = read_to_string_limited(&entry.path()) {
                             if content.contains(GROVE_ID_PREFIX) {
                                 results.push_str(&format!("[{}]\n", entry.path().display()));
-                                for line in content.lines() {
-                                    if is_grove_entry_line(line) {
-                                        results.push_str(line);
-                                        results.push('\n');
-                                        // Tags: line marks end of an entry, add separator
-                                        if line.starts_with(LABEL_TAGS) {
-                                            results.push_str(&format!("\n{}\n\n", ENTRY_SEPARATOR));
-                                        }
+                                for (line, is_code) in grove_entry_lines(&content) {
+                                    results.push_str(line);
+                                    results.push('\n');
+                                    // Tags: line marks end of an entry, add separator
+                                    if !is_code && line.starts_with(LABEL_TAGS) {
+                                        results.push_str(&format!("\n{}\n\n", ENTRY_SEPARATOR));
                                     }
                                 }
                             }
@@ -635,9 +717,16 @@ impl TotalRecallBackend {
         // We must NOT split on empty lines because details can be multi-paragraph.
         let mut entries = Vec::new();
         let mut current = String::new();
+        let mut open_fence: Option<String> = None;
 
         for line in output.lines() {
-            if line.trim() == ENTRY_SEPARATOR {
+            // A separator inside an example's code fence is code, not a boundary
+            match open_fence {
+                Some(ref fence) if line.trim() == fence => open_fence = None,
+                Some(_) => {}
+                None => open_fence = fence_marker(line),
+            }
+            if open_fence.is_none() && line.trim() == ENTRY_SEPARATOR {
                 if !current.trim().is_empty() && current.contains(GROVE_ID_PREFIX) {
                     entries.push(current.trim().to_string());
                 }
@@ -660,6 +749,10 @@ impl TotalRecallBackend {
 
     /// Parse a single grove entry from Total Recall output.
     fn parse_grove_entry(&self, entry: &str) -> Option<CompoundLearning> {
+        // Example code could contain anything, so parse the rest without it
+        let (entry, examples) = split_examples(entry);
+        let entry = entry.as_str();
+
         // Look for the grove: prefix to extract the ID
         let id_start = entry.find(GROVE_ID_PREFIX)?;
         let after_prefix = id_start + GROVE_ID_PREFIX.len();
@@ -785,6 +878,20 @@ impl TotalRecallBackend {
             })
             .unwrap_or(crate::core::LearningStatus::Active);
 
+        // Extract relations from the metadata line ("Relations: supersedes cl_x, ...")
+        // or the personal "**Relations:**" line
+        let relations = entry
+            .lines()
+            .filter(|line| !line.starts_with(BLOCKQUOTE_PREFIX))
+            .flat_map(|line| line.split('|'))
+            .find_map(|part| {
+                part.trim()
+                    .trim_start_matches("**")
+                    .strip_prefix(fmt::LABEL_RELATIONS)
+            })
+            .map(|rest| parse_relations(rest.trim_start_matches("**")))
+            .unwrap_or_default();
+
         // Build a partial learning
         Some(CompoundLearning {
             id: grove_id,
@@ -802,6 +909,13 @@ impl TotalRecallBackend {
             context_files: None,
             relevance_context: None,
             status,
+            relations,
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples,
+            provenance: None,
+            guard: None,
         })
     }
 
@@ -1037,6 +1151,248 @@ impl TotalRecallBackend {
     }
 }
 
+impl TotalRecallBackend {
+    /// Rewrite the editable content of a grove entry in the personal file.
+    ///
+    /// Replaces the summary, tags, and detail; the category and
+    /// Confidence/Created/Status line are kept as written.
+    fn update_personal_entry(&self, learning: &CompoundLearning) -> Result<bool> {
+        if !self.personal_path.exists() {
+            return Ok(false);
+        }
+
+        let grove_marker = format!("{}{}", GROVE_ID_PREFIX, learning.id);
+        let content = read_to_string_limited(&self.personal_path)?;
+        if !content.contains(&grove_marker) {
+            return Ok(false);
+        }
+
+        let mut new_lines: Vec<String> = Vec::new();
+        let mut entry_lines: Option<Vec<&str>> = None;
+        let mut open_fence: Option<String> = None;
+        let mut found = false;
+
+        for line in content.lines() {
+            if let Some(ref mut entry) = entry_lines {
+                match open_fence {
+                    Some(ref fence) if line.trim() == fence => open_fence = None,
+                    Some(_) => {}
+                    None => open_fence = fence_marker(line),
+                }
+                if open_fence.is_some() || line.trim() != ENTRY_SEPARATOR {
+                    entry.push(line);
+                    continue;
+                }
+
+                // End of the target entry: emit the rebuilt entry
+                let keep = |label: &str| entry.iter().find(|l| l.starts_with(label)).copied();
+                if let Some(category) = keep(LABEL_CATEGORY) {
+                    new_lines.push(category.to_string());
+                }
+                new_lines.push(format!("{} {}", LABEL_SUMMARY, learning.summary));
+                if let Some(confidence) = keep(LABEL_CONFIDENCE) {
+                    new_lines.push(confidence.to_string());
+                }
+                if !learning.tags.is_empty() {
+                    let tags: Vec<String> =
+                        learning.tags.iter().map(|t| format!("#{}", t)).collect();
+                    new_lines.push(format!("**Tags:** {}", tags.join(" ")));
+                }
+                if !learning.relations.is_empty() {
+                    new_lines.push(format!(
+                        "**{}** {}",
+                        fmt::LABEL_RELATIONS,
+                        format_relations(&learning.relations)
+                    ));
+                }
+                new_lines.push(String::new());
+                new_lines.extend(learning.detail.lines().map(String::from));
+                if !learning.examples.is_empty() {
+                    new_lines.push(String::new());
+                    new_lines.extend(
+                        format_examples(&learning.examples)
+                            .lines()
+                            .map(String::from),
+                    );
+                }
+                new_lines.push(String::new());
+                new_lines.push(line.to_string());
+                entry_lines = None;
+                found = true;
+                continue;
+            }
+
+            new_lines.push(line.to_string());
+            if !found && line.trim_start_matches("## ").trim() == grove_marker {
+                new_lines.push(String::new());
+                entry_lines = Some(Vec::new());
+            }
+        }
+
+        if !found {
+            return Ok(false);
+        }
+
+        // Atomic write
+        let temp_path = self.personal_path.with_extension("md.tmp");
+        fs::write(&temp_path, new_lines.join("\n") + "\n")?;
+        fs::rename(&temp_path, &self.personal_path)?;
+
+        Ok(true)
+    }
+
+    /// Rewrite a grove entry in Total Recall memory files.
+    ///
+    /// Searches daily logs and registers for an entry with the learning's ID.
+    /// Returns true if the entry was found and rewritten.
+    fn update_entry_in_memory_files(&self, learning: &CompoundLearning) -> Result<bool> {
+        let grove_marker = format!("{}{}", GROVE_ID_PREFIX, learning.id);
+
+        for dir_name in &["daily", "registers"] {
+            let dir = self.memory_dir.join(dir_name);
+            if !dir.is_dir() {
+                continue;
+            }
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.filter_map(|e| e.ok()) {
+                let path = entry.path();
+                if path.extension().is_some_and(|ext| ext == "md")
+                    && self.update_entry_in_file(&path, &grove_marker, learning)?
+                {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Rewrite a grove entry within a single file.
+    ///
+    /// Replaces the summary on the header line, the blockquoted detail, and
+    /// the `Tags:`/`Files:` metadata segments. The time, category, and the
+    /// remaining metadata (confidence, ticket, status) are kept as written.
+    fn update_entry_in_file(
+        &self,
+        path: &Path,
+        grove_marker: &str,
+        learning: &CompoundLearning,
+    ) -> Result<bool> {
+        if !path.exists() {
+            return Ok(false);
+        }
+
+        let content = read_to_string_limited(path)?;
+        if !content.contains(grove_marker) {
+            return Ok(false);
+        }
+
+        let mut new_lines: Vec<String> = Vec::new();
+        let mut in_target_entry = false;
+        let mut open_fence: Option<String> = None;
+        let mut found = false;
+
+        for line in content.lines() {
+            if !found && !in_target_entry && line.contains(grove_marker) {
+                // Header: keep the "[HH:MM] **Category** (grove:ID):" prefix
+                let header = match line.rfind("):") {
+                    Some(i) => format!("{} {}", &line[..i + 2], learning.summary),
+                    None => line.to_string(),
+                };
+                new_lines.push(header);
+                for detail_line in learning.detail.lines() {
+                    new_lines.push(format!("{}{}", BLOCKQUOTE_PREFIX, detail_line));
+                }
+                if !learning.examples.is_empty() {
+                    new_lines.push(String::new());
+                    new_lines.extend(
+                        format_examples(&learning.examples)
+                            .lines()
+                            .map(String::from),
+                    );
+                }
+                in_target_entry = true;
+                continue;
+            }
+
+            if in_target_entry {
+                // Skip the old detail and examples until the metadata line ends the entry
+                match open_fence {
+                    Some(ref fence) if line.trim() == fence => open_fence = None,
+                    Some(_) => {}
+                    None => open_fence = fence_marker(line),
+                }
+                if open_fence.is_none()
+                    && (line.starts_with(LABEL_TAGS) || line.starts_with(LABEL_CONFIDENCE))
+                {
+                    new_lines.push(String::new());
+                    new_lines.push(rewrite_metadata_line(line, learning));
+                    in_target_entry = false;
+                    found = true;
+                }
+                continue;
+            }
+
+            new_lines.push(line.to_string());
+        }
+
+        if !found {
+            return Ok(false);
+        }
+
+        // Atomic write: temp file + rename
+        let temp_path = path.with_extension("md.tmp");
+        fs::write(&temp_path, new_lines.join("\n"))?;
+        fs::rename(&temp_path, path)?;
+
+        Ok(true)
+    }
+}
+
+/// Rebuild a metadata line with a learning's tags, files, and relations.
+///
+/// Other segments (confidence, ticket, status) are carried over unchanged,
+/// with `Files:` and `Relations:` placed before any `Status:` segment.
+fn rewrite_metadata_line(line: &str, learning: &CompoundLearning) -> String {
+    let mut parts = Vec::new();
+    if !learning.tags.is_empty() {
+        let tags: Vec<String> = learning.tags.iter().map(|t| format!("#{}", t)).collect();
+        parts.push(format!("{} {}", LABEL_TAGS, tags.join(" ")));
+    }
+
+    let kept: Vec<&str> = line
+        .split('|')
+        .map(str::trim)
+        .filter(|part| {
+            !part.starts_with(LABEL_TAGS)
+                && !part.starts_with(LABEL_FILES)
+                && !part.starts_with(fmt::LABEL_RELATIONS)
+        })
+        .collect();
+    let (status, other): (Vec<&str>, Vec<&str>) = kept
+        .into_iter()
+        .partition(|part| part.starts_with(fmt::LABEL_STATUS));
+    parts.extend(other.into_iter().map(String::from));
+
+    if let Some(ref files) = learning.context_files {
+        if !files.is_empty() {
+            parts.push(format!("{} {}", LABEL_FILES, files.join(", ")));
+        }
+    }
+    if !learning.relations.is_empty() {
+        parts.push(format!(
+            "{} {}",
+            fmt::LABEL_RELATIONS,
+            format_relations(&learning.relations)
+        ));
+    }
+    parts.extend(status.into_iter().map(String::from));
+
+    parts.join(METADATA_SEPARATOR)
+}
+
 impl MemoryBackend for TotalRecallBackend {
     fn write(&self, learning: &CompoundLearning) -> Result<WriteResult> {
         // Personal scope bypasses Total Recall entirely
@@ -1124,6 +1480,21 @@ impl MemoryBackend for TotalRecallBackend {
         )))
     }
 
+    fn update(&self, learning: &CompoundLearning) -> Result<()> {
+        // Try personal file first, then Total Recall memory files
+        if self.update_personal_entry(learning)? {
+            return Ok(());
+        }
+        if self.update_entry_in_memory_files(learning)? {
+            return Ok(());
+        }
+
+        Err(crate::error::GroveError::backend(format!(
+            "Learning {} not found in Total Recall",
+            learning.id
+        )))
+    }
+
     fn ping(&self) -> bool {
         // Check if memory directory exists
         self.memory_dir.is_dir()
@@ -1191,10 +1562,21 @@ fn confidence_display(confidence: &Confidence) -> &'static str {
     }
 }
 
+fn status_display(status: &LearningStatus) -> &'static str {
+    match status {
+        LearningStatus::Active => "Active",
+        LearningStatus::Archived => "Archived",
+        LearningStatus::Superseded => "Superseded",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::{LearningCategory, WriteGateCriterion};
+    use crate::core::{
+        ExampleKind, LearningCategory, LearningExample, LearningRelation, RelationKind,
+        WriteGateCriterion,
+    };
     use tempfile::TempDir;
 
     fn sample_learning() -> CompoundLearning {
@@ -2405,6 +2787,146 @@ Tags: #database #performance | Confidence: High"#;
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_update_in_daily_log_preserves_status() {
+        let (_temp, backend, id) = setup_tr_with_learning();
+        backend.archive(&id).unwrap();
+
+        let mut edited = sample_learning();
+        edited.id = id.clone();
+        edited.summary = "Use eager loading in UserDashboard".to_string();
+        edited.detail = "Load users with their posts in one query.".to_string();
+        edited.tags = vec!["orm".to_string()];
+        backend.update(&edited).unwrap();
+
+        let all = backend.list_all().unwrap();
+        let learning = all.iter().find(|l| l.id == id).unwrap();
+        assert_eq!(learning.summary, "Use eager loading in UserDashboard");
+        assert_eq!(learning.detail, "Load users with their posts in one query.");
+        assert_eq!(learning.tags, vec!["orm"]);
+        assert_eq!(learning.status, LearningStatus::Archived);
+    }
+
+    #[test]
+    fn test_relations_roundtrip_daily_and_personal() {
+        let temp = TempDir::new().unwrap();
+        let memory_dir = temp.path().join("memory");
+        fs::create_dir_all(memory_dir.join("daily")).unwrap();
+        let personal_path = temp.path().join("personal-learnings.md");
+        let backend = TotalRecallBackend::with_paths(&memory_dir, &personal_path, &memory_dir);
+
+        let relations = vec![
+            LearningRelation::new(RelationKind::Supersedes, "cl_20260301_001"),
+            LearningRelation::new(RelationKind::DependsOn, "cl_20260301_002"),
+        ];
+        let mut project = sample_learning().with_relations(relations.clone());
+        project.id = "cl_20260328_070".to_string();
+        let mut personal = project.clone();
+        personal.id = "cl_20260328_071".to_string();
+        personal.scope = LearningScope::Personal;
+        backend.write(&project).unwrap();
+        backend.write(&personal).unwrap();
+
+        let all = backend.list_all().unwrap();
+        let learning = all.iter().find(|l| l.id == project.id).unwrap();
+        assert_eq!(learning.relations, relations);
+
+        // Personal learnings bypass Total Recall, so parse the personal file directly
+        let content = fs::read_to_string(&personal_path).unwrap();
+        assert!(content.contains("**Relations:** supersedes cl_20260301_001"));
+        let parsed = backend.parse_grove_entry(&content).unwrap();
+        assert_eq!(parsed.relations, relations);
+
+        // Updating rewrites the relations in place
+        let mut edited = project.clone();
+        edited.relations = vec![LearningRelation::new(
+            RelationKind::Refines,
+            "cl_20260301_003",
+        )];
+        backend.update(&edited).unwrap();
+        let all = backend.list_all().unwrap();
+        let learning = all.iter().find(|l| l.id == project.id).unwrap();
+        assert_eq!(learning.relations, edited.relations);
+    }
+
+    #[test]
+    fn test_examples_roundtrip_daily_and_personal() {
+        let temp = TempDir::new().unwrap();
+        let memory_dir = temp.path().join("memory");
+        fs::create_dir_all(memory_dir.join("daily")).unwrap();
+        let personal_path = temp.path().join("personal-learnings.md");
+        let backend = TotalRecallBackend::with_paths(&memory_dir, &personal_path, &memory_dir);
+
+        let examples = vec![LearningExample::new(ExampleKind::Bad, "> out.txt\n---")
+            .with_language("sh")
+            .with_caption("Truncates the file")];
+        let mut project = sample_learning().with_examples(examples.clone());
+        project.id = "cl_20260328_080".to_string();
+        let mut personal = project.clone();
+        personal.id = "cl_20260328_081".to_string();
+        personal.scope = LearningScope::Personal;
+        backend.write(&project).unwrap();
+        backend.write(&personal).unwrap();
+
+        let all = backend.list_all().unwrap();
+        let learning = all.iter().find(|l| l.id == project.id).unwrap();
+        assert_eq!(learning.examples, examples);
+        assert_eq!(learning.detail, project.detail);
+
+        let content = fs::read_to_string(&personal_path).unwrap();
+        let parsed = backend.parse_grove_entry(&content).unwrap();
+        assert_eq!(parsed.examples, examples);
+
+        // Updating replaces the examples in place
+        let edited = project.clone().with_examples(vec![LearningExample::new(
+            ExampleKind::Command,
+            "grove maintain list",
+        )]);
+        backend.update(&edited).unwrap();
+        let all = backend.list_all().unwrap();
+        let learning = all.iter().find(|l| l.id == project.id).unwrap();
+        assert_eq!(learning.examples, edited.examples);
+        assert_eq!(learning.tags, project.tags);
+    }
+
+    #[test]
+    fn test_update_personal_learning() {
+        let temp = TempDir::new().unwrap();
+        let memory_dir = temp.path().join("memory");
+        fs::create_dir_all(memory_dir.join("daily")).unwrap();
+        let personal_path = temp.path().join("personal-learnings.md");
+
+        let backend = TotalRecallBackend::with_paths(&memory_dir, &personal_path, &memory_dir);
+
+        let mut first = sample_learning();
+        first.id = "cl_20260328_060".to_string();
+        first.scope = LearningScope::Personal;
+        let mut second = first.clone();
+        second.id = "cl_20260328_061".to_string();
+        backend.write(&first).unwrap();
+        backend.write(&second).unwrap();
+
+        let mut edited = first.clone();
+        edited.summary = "Edited personal summary".to_string();
+        edited.detail = "Edited personal detail text".to_string();
+        backend.update(&edited).unwrap();
+
+        let content = fs::read_to_string(&personal_path).unwrap();
+        assert!(content.contains("**Summary:** Edited personal summary"));
+        assert!(content.contains("Edited personal detail text"));
+        assert_eq!(content.matches("Avoid N+1 queries").count(), 1);
+        assert!(content.contains("grove:cl_20260328_061"));
+    }
+
+    #[test]
+    fn test_update_not_found() {
+        let (_temp, backend, _id) = setup_tr_with_learning();
+
+        let mut learning = sample_learning();
+        learning.id = "cl_nonexistent_000".to_string();
+        assert!(backend.update(&learning).is_err());
+    }
+
     #[test]
     fn test_parse_grove_entry_with_status() {
         let temp = TempDir::new().unwrap();
@@ -82,6 +82,9 @@ pub const LABEL_TICKET: &str = "Ticket:";
 /// Label for files metadata.
 pub const LABEL_FILES: &str = "Files:";
 
+/// Label for relations metadata.
+pub const LABEL_RELATIONS: &str = "Relations:";
+
 /// Label for status metadata (used for archive/restore).
 pub const LABEL_STATUS: &str = "Status:";
 
@@ -7,7 +7,9 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::core::{CompoundLearning, LearningScope, LearningStatus};
+use crate::core::{
+    CompoundLearning, LearningCategory, LearningRevision, LearningScope, LearningStatus,
+};
 use crate::error::Result;
 
 /// Trait for memory backends that store and retrieve learnings.
@@ -58,6 +60,36 @@ pub trait MemoryBackend: Send + Sync {
         ))
     }
 
+    /// Replace a stored learning with an edited version.
+    ///
+    /// The learning is matched by ID and rewritten in place, keeping its
+    /// position and identity. Default implementation returns an error
+    /// indicating updates are not supported.
+    fn update(&self, _learning: &CompoundLearning) -> Result<()> {
+        Err(crate::error::GroveError::backend(
+            "Update not supported by this backend",
+        ))
+    }
+
+    /// Append a revision to a learning's history.
+    ///
+    /// The backend assigns the next revision number and returns it. Default
+    /// implementation returns an error indicating history is not supported.
+    fn record_revision(&self, _revision: &LearningRevision) -> Result<u32> {
+        Err(crate::error::GroveError::backend(
+            "Revision history not supported by this backend",
+        ))
+    }
+
+    /// Get a learning's revision history, oldest first.
+    ///
+    /// Default implementation returns an error indicating history is not supported.
+    fn history(&self, _learning_id: &str) -> Result<Vec<LearningRevision>> {
+        Err(crate::error::GroveError::backend(
+            "Revision history not supported by this backend",
+        ))
+    }
+
     /// List all learnings (for backends that support it).
     ///
     /// Default implementation uses search with an empty query.
@@ -148,6 +180,18 @@ impl MemoryBackend for Box<dyn MemoryBackend> {
         (**self).restore(learning_id)
     }
 
+    fn update(&self, learning: &CompoundLearning) -> Result<()> {
+        (**self).update(learning)
+    }
+
+    fn record_revision(&self, revision: &LearningRevision) -> Result<u32> {
+        (**self).record_revision(revision)
+    }
+
+    fn history(&self, learning_id: &str) -> Result<Vec<LearningRevision>> {
+        (**self).history(learning_id)
+    }
+
     fn list_all(&self) -> Result<Vec<crate::core::CompoundLearning>> {
         (**self).list_all()
     }
@@ -172,6 +216,9 @@ pub struct WriteResult {
     pub location: String,
     /// Optional message (e.g., warning about sanitization).
     pub message: Option<String>,
+    /// Backends that failed when others succeeded (e.g., mirrored writes).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failed_backends: Vec<String>,
 }
 
 impl WriteResult {
@@ -182,6 +229,7 @@ impl WriteResult {
             learning_id: learning_id.into(),
             location: location.into(),
             message: None,
+            failed_backends: Vec::new(),
         }
     }
 
@@ -196,6 +244,7 @@ impl WriteResult {
             learning_id: learning_id.into(),
             location: location.into(),
             message: Some(message.into()),
+            failed_backends: Vec::new(),
         }
     }
 
@@ -206,8 +255,14 @@ impl WriteResult {
             learning_id: learning_id.into(),
             location: String::new(),
             message: Some(message.into()),
+            failed_backends: Vec::new(),
         }
     }
+
+    /// Whether the write succeeded on some backends but failed on others.
+    pub fn is_partial(&self) -> bool {
+        self.success && !self.failed_backends.is_empty()
+    }
 }
 
 /// Query parameters for searching learnings.
@@ -298,8 +353,17 @@ pub struct SearchFilters {
     pub scope: Option<LearningScope>,
     /// Only include learnings created after this time.
     pub created_after: Option<DateTime<Utc>>,
+    /// Only include learnings created before this time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_before: Option<DateTime<Utc>>,
     /// Maximum number of results to return.
     pub max_results: Option<usize>,
+    /// Conditions every learning must meet.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub require: Vec<FieldFilter>,
+    /// Conditions that exclude a learning when any one is met.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<FieldFilter>,
 }
 
 impl Default for SearchFilters {
@@ -309,7 +373,10 @@ impl Default for SearchFilters {
             status: Some(LearningStatus::Active),
             scope: None,
             created_after: None,
+            created_before: None,
             max_results: None,
+            require: Vec::new(),
+            exclude: Vec::new(),
         }
     }
 }
@@ -321,7 +388,10 @@ impl SearchFilters {
             status: None,
             scope: None,
             created_after: None,
+            created_before: None,
             max_results: None,
+            require: Vec::new(),
+            exclude: Vec::new(),
         }
     }
 
@@ -348,12 +418,30 @@ impl SearchFilters {
         self
     }
 
+    /// Set the created_before filter.
+    pub fn created_before(mut self, time: DateTime<Utc>) -> Self {
+        self.created_before = Some(time);
+        self
+    }
+
     /// Set the max_results limit.
     pub fn max_results(mut self, limit: usize) -> Self {
         self.max_results = Some(limit);
         self
     }
 
+    /// Add a condition every learning must meet.
+    pub fn require(mut self, filter: FieldFilter) -> Self {
+        self.require.push(filter);
+        self
+    }
+
+    /// Add a condition that excludes a learning.
+    pub fn exclude(mut self, filter: FieldFilter) -> Self {
+        self.exclude.push(filter);
+        self
+    }
+
     /// Check if a learning matches these filters.
     pub fn matches(&self, learning: &CompoundLearning) -> bool {
         // Check status filter
@@ -377,7 +465,83 @@ impl SearchFilters {
             }
         }
 
-        true
+        // Check created_before filter
+        if let Some(ref created_before) = self.created_before {
+            if &learning.timestamp >= created_before {
+                return false;
+            }
+        }
+
+        self.require.iter().all(|f| f.matches(learning))
+            && !self.exclude.iter().any(|f| f.matches(learning))
+    }
+}
+
+/// A condition on one field of a learning.
+///
+/// Built by the search query syntax (see [`crate::backends::query`]) and
+/// checked in [`SearchFilters::matches`], so every backend honors it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "field", content = "value", rename_all = "snake_case")]
+pub enum FieldFilter {
+    /// Has this tag (case-insensitive, leading `#` ignored).
+    Tag(String),
+    /// Is in this category.
+    Category(LearningCategory),
+    /// Has this scope.
+    Scope(LearningScope),
+    /// Has this status.
+    Status(LearningStatus),
+    /// Has a context file matching this glob.
+    File(String),
+    /// Is linked to this ticket (case-insensitive).
+    Ticket(String),
+    /// Contains this phrase in the summary or detail (case-insensitive).
+    Phrase(String),
+    /// Contains this word in the summary, detail, or tags (case-insensitive).
+    Term(String),
+}
+
+impl FieldFilter {
+    /// Check whether a learning meets this condition.
+    pub fn matches(&self, learning: &CompoundLearning) -> bool {
+        match self {
+            FieldFilter::Tag(tag) => {
+                let tag = tag.trim_start_matches('#');
+                learning
+                    .tags
+                    .iter()
+                    .any(|t| t.trim_start_matches('#').eq_ignore_ascii_case(tag))
+            }
+            FieldFilter::Category(category) => learning.category == *category,
+            FieldFilter::Scope(scope) => learning.scope == *scope,
+            FieldFilter::Status(status) => learning.status == *status,
+            FieldFilter::File(pattern) => learning
+                .context_files
+                .iter()
+                .flatten()
+                .any(|f| crate::stats::scoring::path_matches(pattern, f)),
+            FieldFilter::Ticket(ticket) => learning
+                .ticket_id
+                .as_deref()
+                .is_some_and(|t| t.eq_ignore_ascii_case(ticket)),
+            FieldFilter::Phrase(phrase) => {
+                let phrase = phrase.to_lowercase();
+                learning.summary.to_lowercase().contains(&phrase)
+                    || learning.detail.to_lowercase().contains(&phrase)
+            }
+            FieldFilter::Term(term) => {
+                let term = term.to_lowercase();
+                let has_word = |text: &str| {
+                    text.to_lowercase()
+                        .split(|c: char| !c.is_alphanumeric() && c != '-' && c != '_')
+                        .any(|w| w == term)
+                };
+                has_word(&learning.summary)
+                    || has_word(&learning.detail)
+                    || learning.tags.iter().any(|t| has_word(t))
+            }
+        }
     }
 }
 
@@ -388,14 +552,45 @@ pub struct SearchResult {
     pub learning: CompoundLearning,
     /// Relevance score (0.0 to 1.0, higher is more relevant).
     pub relevance: f64,
+    /// Where the learning came from.
+    #[serde(default)]
+    pub source: LearningSource,
 }
 
 impl SearchResult {
-    /// Create a new search result.
+    /// Create a new search result for a project learning.
     pub fn new(learning: CompoundLearning, relevance: f64) -> Self {
         Self {
             learning,
             relevance,
+            source: LearningSource::Project,
+        }
+    }
+
+    /// Set where the learning came from.
+    pub fn with_source(mut self, source: LearningSource) -> Self {
+        self.source = source;
+        self
+    }
+}
+
+/// Where a search result came from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LearningSource {
+    /// The project's own learnings.
+    #[default]
+    Project,
+    /// The read-only organization-wide corpus.
+    Org,
+}
+
+impl LearningSource {
+    /// Get the source name as a string.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Project => "project",
+            Self::Org => "org",
         }
     }
 }
@@ -598,6 +793,44 @@ mod tests {
         let json = serde_json::to_string(&filters).unwrap();
         let deserialized: SearchFilters = serde_json::from_str(&json).unwrap();
         assert_eq!(filters, deserialized);
+
+        // Field filters round-trip and are omitted when empty
+        assert!(!json.contains("require"));
+        let filters = filters
+            .require(FieldFilter::Category(LearningCategory::Pitfall))
+            .exclude(FieldFilter::Tag("legacy".to_string()));
+        let json = serde_json::to_string(&filters).unwrap();
+        assert!(json.contains(r#"{"field":"category","value":"pitfall"}"#));
+        let deserialized: SearchFilters = serde_json::from_str(&json).unwrap();
+        assert_eq!(filters, deserialized);
+    }
+
+    #[test]
+    fn test_search_filters_require_and_exclude() {
+        let mut learning = sample_learning();
+        learning.tags = vec!["#Rust".to_string(), "async".to_string()];
+        learning.context_files = Some(vec!["src/hooks/runner.rs".to_string()]);
+        learning.ticket_id = Some("PROJ-12".to_string());
+
+        let matches = |filter: FieldFilter| SearchFilters::all().require(filter).matches(&learning);
+        assert!(matches(FieldFilter::Tag("rust".to_string())));
+        assert!(!matches(FieldFilter::Tag("rus".to_string())));
+        assert!(matches(FieldFilter::File("src/hooks/*".to_string())));
+        assert!(!matches(FieldFilter::File("src/cli/*".to_string())));
+        assert!(matches(FieldFilter::Ticket("proj-12".to_string())));
+        assert!(matches(FieldFilter::Phrase(
+            learning.summary[..8].to_uppercase()
+        )));
+        assert!(matches(FieldFilter::Term("async".to_string())));
+
+        let excluded = SearchFilters::all().exclude(FieldFilter::Term("async".to_string()));
+        assert!(!excluded.matches(&learning));
+
+        let created_before = SearchFilters::all().created_before(learning.timestamp);
+        assert!(
+            !created_before.matches(&learning),
+            "upper bound is exclusive"
+        );
     }
 
     // SearchResult tests
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use std::path::Path;
 
 use crate::config::Config;
-use crate::discovery::{detect_backends, BackendInfo};
+use crate::discovery::{detect_backends, ping_backend, BackendInfo};
 
 /// Options for the backends command.
 #[derive(Debug, Clone, Default)]
@@ -113,8 +113,17 @@ impl BackendsCommand {
         // Detect backends
         let backends = detect_backends(cwd, Some(&self.config));
 
-        // Convert to detail format
-        let details: Vec<BackendDetail> = backends.iter().map(BackendDetail::from).collect();
+        // Convert to detail format, pinging external backends for live health
+        let details: Vec<BackendDetail> = backends
+            .iter()
+            .map(|info| {
+                let mut detail = BackendDetail::from(info);
+                if let Some(healthy) = ping_backend(cwd, info, &self.config.backends) {
+                    detail.health = Some(if healthy { "ok" } else { "unreachable" }.to_string());
+                }
+                detail
+            })
+            .collect();
 
         // Find the active (first available/primary) backend
         let active = backends
@@ -156,16 +165,23 @@ impl BackendsCommand {
         lines.push("Discovered backends:\n".to_string());
 
         for backend in &output.backends {
-            let status = if backend.available { "+" } else { "-" };
+            let healthy = backend.health.as_deref() != Some("unreachable");
+            let status = if backend.available && healthy {
+                "+"
+            } else {
+                "-"
+            };
             let path_info = backend
                 .path
                 .as_ref()
                 .map(|p| format!(" ({})", p))
                 .unwrap_or_default();
 
+            let health_info = if healthy { "" } else { " - unreachable" };
+
             lines.push(format!(
-                "  [{}] {}: {}{}",
-                status, backend.name, backend.backend_type, path_info
+                "  [{}] {}: {}{}{}",
+                status, backend.name, backend.backend_type, path_info, health_info
             ));
         }
 
@@ -246,6 +262,29 @@ mod tests {
             .any(|b| b.backend_type.contains("Markdown")));
     }
 
+    #[test]
+    fn test_backends_reports_unreachable_plugin() {
+        let (temp, mut config) = setup_with_learnings();
+        config.backends.discovery = vec!["process".to_string(), "markdown".to_string()];
+        config.backends.process.command = "/nonexistent/grove-plugin".to_string();
+        let cmd = BackendsCommand::new(temp.path().to_string_lossy().to_string(), config);
+        let options = BackendsOptions::default();
+
+        let output = cmd.run(&options);
+
+        let plugin = output
+            .backends
+            .iter()
+            .find(|b| b.name == "process")
+            .unwrap();
+        assert_eq!(plugin.health.as_deref(), Some("unreachable"));
+        assert_eq!(output.active, Some("process".to_string()));
+
+        let text = cmd.format_output(&output, &options);
+        assert!(text.contains("[-] process"));
+        assert!(text.contains("unreachable"));
+    }
+
     #[test]
     fn test_backends_without_learnings() {
         let temp = TempDir::new().unwrap();
@@ -9,13 +9,15 @@ use std::path::Path;
 use serde::{Deserialize, Serialize};
 
 use crate::backends::MemoryBackend;
+use crate::cli::history::record_revision;
 use crate::cli::maintain::FailedUpdate;
-use crate::config::{Config, JudgeConfig};
+use crate::config::{git_author, project_stats_log_path, Config, JudgeConfig};
 use crate::core::{
-    CompoundLearning, Confidence, LearningCategory, LearningScope, LearningStatus,
-    WriteGateCriterion,
+    CompoundLearning, Confidence, LearningCategory, LearningRevision, LearningScope,
+    LearningStatus, Provenance, ProvenanceOrigin, RevisionSource, WriteGateCriterion,
 };
 use crate::llm;
+use crate::stats::StatsLogger;
 
 // ---------------------------------------------------------------------------
 // Thresholds
@@ -293,7 +295,7 @@ pub fn group_related_learnings(learnings: &[CompoundLearning]) -> Vec<LearningGr
         .collect();
 
     // Sort by group size descending
-    groups.sort_by(|a, b| b.learning_ids.len().cmp(&a.learning_ids.len()));
+    groups.sort_by_key(|g| std::cmp::Reverse(g.learning_ids.len()));
     groups
 }
 
@@ -458,7 +460,7 @@ fn merge_group(
 /// Run the consolidate command.
 pub fn run_consolidate<B: MemoryBackend>(
     backend: &B,
-    _config: &Config,
+    config: &Config,
     options: &ConsolidateOptions,
     project_root: &Path,
     merge_fn: &MergeFn,
@@ -529,10 +531,15 @@ pub fn run_consolidate<B: MemoryBackend>(
         return ConsolidateOutput::success(groups, stale_references, merge_proposals);
     }
 
-    // 7. Apply: write merged learnings, archive sources
+    // 7. Apply: write merged learnings, archive sources, and record the
+    // revisions linking each source to the learning that replaced it
+    let stats_logger = StatsLogger::new(project_stats_log_path(project_root));
     let mut archived = Vec::new();
     let mut written = Vec::new();
     let mut failed = Vec::new();
+    let provenance = Provenance::new(ProvenanceOrigin::Consolidate)
+        .with_author(git_author(project_root))
+        .with_model(Some(config.judge.model.clone()));
 
     for proposal in &merge_proposals {
         // Parse category from the proposal
@@ -548,13 +555,17 @@ pub fn run_consolidate<B: MemoryBackend>(
             vec![WriteGateCriterion::BehaviorChanging],
             proposal.merged_tags.clone(),
             "consolidate",
-        );
+        )
+        .with_provenance(provenance.clone());
         merged.id = backend.next_id();
 
         // Write merged learning
         match backend.write(&merged) {
             Ok(result) if result.success => {
                 written.push(result.learning_id);
+                let revision = LearningRevision::created(&merged, RevisionSource::Consolidate)
+                    .with_merged_from(proposal.group.learning_ids.clone());
+                record_revision(backend, &stats_logger, &revision);
             }
             Ok(result) => {
                 failed.push(FailedUpdate {
@@ -575,7 +586,17 @@ pub fn run_consolidate<B: MemoryBackend>(
         // Archive source learnings
         for source_id in &proposal.group.learning_ids {
             match backend.archive(source_id) {
-                Ok(()) => archived.push(source_id.clone()),
+                Ok(()) => {
+                    archived.push(source_id.clone());
+                    if let Some(source) = all_learnings.iter().find(|l| &l.id == source_id) {
+                        let mut after = source.clone();
+                        after.status = LearningStatus::Archived;
+                        let revision =
+                            LearningRevision::changed(source, &after, RevisionSource::Consolidate)
+                                .with_superseded_by(&merged.id);
+                        record_revision(backend, &stats_logger, &revision);
+                    }
+                }
                 Err(e) => failed.push(FailedUpdate {
                     id: source_id.clone(),
                     error: e.to_string(),
@@ -1177,6 +1198,59 @@ mod tests {
         assert_eq!(active.len(), 1);
         assert_eq!(archived.len(), 2);
         assert!(active[0].summary.contains("Merged"));
+
+        let provenance = active[0].provenance.as_ref().unwrap();
+        assert_eq!(provenance.origin, ProvenanceOrigin::Consolidate);
+        assert_eq!(
+            provenance.model.as_deref(),
+            Some(config.judge.model.as_str())
+        );
+    }
+
+    #[test]
+    fn test_consolidate_apply_links_revisions() {
+        let learnings = vec![
+            make_learning(
+                "cl_001",
+                LearningCategory::Pattern,
+                "Error handling pattern",
+                vec!["rust", "error-handling", "anyhow"],
+            ),
+            make_learning(
+                "cl_002",
+                LearningCategory::Pattern,
+                "Context on errors",
+                vec!["rust", "error-handling", "context"],
+            ),
+        ];
+        let (temp, backend) = setup_backend_with_learnings(&learnings);
+        let options = ConsolidateOptions {
+            apply: true,
+            ..Default::default()
+        };
+        let merge_fn = mock_merge_fn(&valid_merge_response());
+
+        let output = run_consolidate(
+            &backend,
+            &Config::default(),
+            &options,
+            temp.path(),
+            &merge_fn,
+        );
+        let merged_id = &output.written[0];
+
+        let merged_history = backend.history(merged_id).unwrap();
+        assert_eq!(merged_history.len(), 1);
+        assert_eq!(merged_history[0].source, RevisionSource::Consolidate);
+        assert_eq!(merged_history[0].merged_from, vec!["cl_001", "cl_002"]);
+
+        let source_history = backend.history("cl_001").unwrap();
+        assert_eq!(source_history.len(), 1);
+        assert_eq!(
+            source_history[0].superseded_by.as_deref(),
+            Some(merged_id.as_str())
+        );
+        assert_eq!(source_history[0].fields(), vec!["status"]);
     }
 
     #[test]
@@ -0,0 +1,542 @@
+//! Edit command for Grove.
+//!
+//! Edits a stored learning in place. The editable fields are opened in
+//! `$EDITOR` as JSON (or read as JSON from stdin), re-validated against the
+//! schema and write gate, and written back through the backend's `update`.
+//! Editing keeps the learning's ID, so its stats history is preserved, and
+//! each edit is recorded as a revision (see `grove history`).
+
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
+
+use crate::backends::markdown::validate_learning_id;
+use crate::backends::MemoryBackend;
+use crate::cli::history::record_revision;
+use crate::config::{project_stats_log_path, Config};
+use crate::core::reflect::{
+    validate_full_with_quality, CandidateExample, CandidateLearning, CandidateRelation,
+    WriteGateMode,
+};
+use crate::core::{
+    AppliesTo, CommandGuard, CompoundLearning, LearningRevision, QualityCheckMode, RevisionSource,
+};
+use crate::error::{FailOpen, GroveError, Result};
+use crate::stats::StatsLogger;
+
+/// Options for the edit command.
+#[derive(Debug, Clone, Default)]
+pub struct EditOptions {
+    /// Output as JSON.
+    pub json: bool,
+    /// Suppress output.
+    pub quiet: bool,
+}
+
+/// Editable fields of a learning (JSON from stdin or the editor).
+///
+/// Omitted fields keep their current values, so stdin input can be partial.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EditInput {
+    /// Brief summary.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// Detailed explanation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// Tags for categorization and search.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// Files that provide context for this learning.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_files: Option<Vec<String>>,
+    /// When/where this learning should be surfaced during retrieval.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relevance_context: Option<String>,
+    /// Write gate criteria (needed when the backend doesn't store them).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub criteria_met: Option<Vec<String>>,
+    /// Typed links to other learnings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relations: Option<Vec<CandidateRelation>>,
+    /// Conditions under which the learning applies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub applies_to: Option<AppliesTo>,
+    /// Date after which the learning no longer holds (empty clears it).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    /// Date by which the learning should be re-checked (empty clears it).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review_by: Option<String>,
+    /// Code snippets and commands that illustrate the learning.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub examples: Option<Vec<CandidateExample>>,
+    /// Bash commands that surface the learning before they run (empty clears it).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guard: Option<CommandGuard>,
+}
+
+impl EditInput {
+    /// Build the full editable document for a learning.
+    pub fn from_learning(learning: &CompoundLearning) -> Self {
+        let candidate = CandidateLearning::from(learning);
+        Self {
+            summary: Some(candidate.summary),
+            detail: Some(candidate.detail),
+            tags: Some(candidate.tags),
+            context_files: Some(candidate.context_files.unwrap_or_default()),
+            relevance_context: Some(candidate.relevance_context.unwrap_or_default()),
+            criteria_met: Some(candidate.criteria_met),
+            relations: Some(candidate.relations),
+            applies_to: Some(candidate.applies_to.unwrap_or_default()),
+            expires_at: Some(candidate.expires_at.unwrap_or_default()),
+            review_by: Some(candidate.review_by.unwrap_or_default()),
+            examples: Some(candidate.examples),
+            guard: Some(candidate.guard.unwrap_or_default()),
+        }
+    }
+
+    /// Apply the edits to a candidate built from the stored learning.
+    fn apply(&self, candidate: &mut CandidateLearning) {
+        if let Some(ref summary) = self.summary {
+            candidate.summary = summary.clone();
+        }
+        if let Some(ref detail) = self.detail {
+            candidate.detail = detail.clone();
+        }
+        if let Some(ref tags) = self.tags {
+            candidate.tags = tags.clone();
+        }
+        if let Some(ref files) = self.context_files {
+            candidate.context_files = Some(files.clone()).filter(|f| !f.is_empty());
+        }
+        if let Some(ref ctx) = self.relevance_context {
+            candidate.relevance_context = Some(ctx.clone()).filter(|c| !c.trim().is_empty());
+        }
+        if let Some(ref criteria) = self.criteria_met {
+            candidate.criteria_met = criteria.clone();
+        }
+        if let Some(ref relations) = self.relations {
+            candidate.relations = relations.clone();
+        }
+        if let Some(ref applies_to) = self.applies_to {
+            candidate.applies_to = Some(applies_to.clone()).filter(|a| !a.is_empty());
+        }
+        if let Some(ref expires_at) = self.expires_at {
+            candidate.expires_at = Some(expires_at.clone()).filter(|d| !d.trim().is_empty());
+        }
+        if let Some(ref review_by) = self.review_by {
+            candidate.review_by = Some(review_by.clone()).filter(|d| !d.trim().is_empty());
+        }
+        if let Some(ref examples) = self.examples {
+            candidate.examples = examples.clone();
+        }
+        if let Some(ref guard) = self.guard {
+            candidate.guard = Some(guard.clone()).filter(|g| !g.is_empty());
+        }
+    }
+}
+
+/// Output format for the edit command.
+#[derive(Debug, Clone, Serialize)]
+pub struct EditOutput {
+    /// Whether the edit was applied (or there was nothing to change).
+    pub success: bool,
+    /// The learning that was edited.
+    pub learning_id: String,
+    /// Names of the fields that changed.
+    pub changed_fields: Vec<String>,
+    /// Error message if the edit failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl EditOutput {
+    /// Create a successful output.
+    pub fn success(learning_id: impl Into<String>, changed_fields: Vec<String>) -> Self {
+        Self {
+            success: true,
+            learning_id: learning_id.into(),
+            changed_fields,
+            error: None,
+        }
+    }
+
+    /// Create a failed output.
+    pub fn failure(learning_id: impl Into<String>, error: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            learning_id: learning_id.into(),
+            changed_fields: Vec::new(),
+            error: Some(error.into()),
+        }
+    }
+}
+
+/// The edit command implementation.
+pub struct EditCommand<B: MemoryBackend> {
+    backend: B,
+    config: Config,
+}
+
+impl<B: MemoryBackend> EditCommand<B> {
+    /// Create a new edit command.
+    pub fn new(backend: B, config: Config) -> Self {
+        Self { backend, config }
+    }
+
+    /// Run the edit command.
+    ///
+    /// Two modes:
+    /// - With stdin: apply the JSON edits from stdin
+    /// - Without stdin: open the learning in `$EDITOR` and apply the result
+    pub fn run(&self, learning_id: &str, _options: &EditOptions, cwd: &Path) -> EditOutput {
+        let input = match self.read_stdin() {
+            Some(input) => input,
+            None => self
+                .find_learning(learning_id)
+                .and_then(|learning| self.edit_in_editor(&learning)),
+        };
+
+        match input {
+            Ok(input) => self.run_with_input(learning_id, &input, cwd),
+            Err(e) => EditOutput::failure(learning_id, e.to_string()),
+        }
+    }
+
+    /// Apply edits to a learning.
+    ///
+    /// The edited learning must pass schema validation and the write gate
+    /// before it replaces the stored one.
+    pub fn run_with_input(&self, learning_id: &str, input: &EditInput, cwd: &Path) -> EditOutput {
+        let original = match self.find_learning(learning_id) {
+            Ok(learning) => learning,
+            Err(e) => return EditOutput::failure(learning_id, e.to_string()),
+        };
+
+        let mut candidate = CandidateLearning::from(&original);
+        input.apply(&mut candidate);
+
+        // Re-run schema validation and the write gate on the edited content
+        let write_gate_mode = WriteGateMode::from_config(&self.config.gate.write_gate.mode);
+        let quality_mode =
+            QualityCheckMode::from_config(&self.config.gate.write_gate.quality_check);
+        let (valid, rejected) = validate_full_with_quality(
+            vec![candidate],
+            &original.session_id,
+            write_gate_mode,
+            quality_mode,
+            self.config.gate.write_gate.min_specificity_score,
+            None,
+            (0.0, 0.0, 0.0),
+        );
+
+        let Some(validated) = valid.into_iter().next() else {
+            let reason = rejected
+                .first()
+                .map(|r| format!("{} ({})", r.rejection_reason, r.stage))
+                .unwrap_or_else(|| "validation failed".to_string());
+            return EditOutput::failure(learning_id, format!("Edit rejected: {}", reason));
+        };
+
+        // Only the editable fields change; identity, status, and provenance stay
+        let mut edited = original.clone();
+        edited.summary = validated.summary;
+        edited.detail = validated.detail;
+        edited.tags = validated.tags;
+        edited.context_files = validated.context_files;
+        edited.relevance_context = validated.relevance_context;
+        edited.criteria_met = validated.criteria_met;
+        edited.relations = validated.relations;
+        edited.applies_to = validated.applies_to;
+        edited.expires_at = validated.expires_at;
+        edited.review_by = validated.review_by;
+        edited.examples = validated.examples;
+        edited.guard = validated.guard;
+
+        let revision = LearningRevision::changed(&original, &edited, RevisionSource::Edit);
+        let changed_fields = revision.fields();
+        if changed_fields.is_empty() {
+            return EditOutput::success(learning_id, changed_fields);
+        }
+
+        if let Err(e) = self.backend.update(&edited) {
+            return EditOutput::failure(learning_id, e.to_string());
+        }
+
+        let stats_logger = StatsLogger::new(project_stats_log_path(cwd));
+        stats_logger
+            .append_edited(learning_id, changed_fields.clone())
+            .fail_open_default("logging edited event");
+        record_revision(&self.backend, &stats_logger, &revision);
+
+        EditOutput::success(learning_id, changed_fields)
+    }
+
+    /// Look up a learning by ID in the backend.
+    fn find_learning(&self, learning_id: &str) -> Result<CompoundLearning> {
+        self.backend
+            .list_all()?
+            .into_iter()
+            .find(|l| l.id == learning_id)
+            .ok_or_else(|| GroveError::backend(format!("Learning {} not found", learning_id)))
+    }
+
+    /// Open the learning's editable fields in `$EDITOR` and read them back.
+    fn edit_in_editor(&self, learning: &CompoundLearning) -> Result<EditInput> {
+        let id = validate_learning_id(&learning.id)
+            .ok_or_else(|| GroveError::backend(format!("Invalid learning ID {}", learning.id)))?;
+        let path =
+            std::env::temp_dir().join(format!("grove-edit-{}-{}.json", id, std::process::id()));
+
+        let document = serde_json::to_string_pretty(&EditInput::from_learning(learning))
+            .map_err(|e| GroveError::serde(e.to_string()))?;
+        std::fs::write(&path, document).map_err(|e| GroveError::storage(&path, e))?;
+
+        let result = run_editor(&path).and_then(|()| {
+            let content =
+                std::fs::read_to_string(&path).map_err(|e| GroveError::storage(&path, e))?;
+            serde_json::from_str(&content)
+                .map_err(|e| GroveError::serde(format!("Invalid edit JSON: {}", e)))
+        });
+        let _ = std::fs::remove_file(&path);
+
+        result
+    }
+
+    /// Read edits from stdin (returns None if stdin is a terminal or empty).
+    fn read_stdin(&self) -> Option<Result<EditInput>> {
+        use std::io::{IsTerminal, Read};
+
+        if io::stdin().is_terminal() {
+            return None;
+        }
+
+        let mut input = String::new();
+        if io::stdin().lock().read_to_string(&mut input).is_err() || input.trim().is_empty() {
+            return None;
+        }
+
+        Some(
+            serde_json::from_str(&input)
+                .map_err(|e| GroveError::serde(format!("Invalid edit JSON: {}", e))),
+        )
+    }
+
+    /// Format output based on options.
+    pub fn format_output(&self, output: &EditOutput, options: &EditOptions) -> String {
+        if options.quiet {
+            return String::new();
+        }
+
+        if options.json {
+            serde_json::to_string_pretty(output).unwrap_or_else(|_| "{}".to_string())
+        } else {
+            self.format_human_readable(output)
+        }
+    }
+
+    /// Format output as human-readable text.
+    fn format_human_readable(&self, output: &EditOutput) -> String {
+        if !output.success {
+            return format!(
+                "Edit failed: {}\n",
+                output.error.as_deref().unwrap_or("unknown error")
+            );
+        }
+
+        if output.changed_fields.is_empty() {
+            format!("No changes to {}\n", output.learning_id)
+        } else {
+            format!(
+                "Edited {}: {}\n",
+                output.learning_id,
+                output.changed_fields.join(", ")
+            )
+        }
+    }
+}
+
+/// Launch `$EDITOR` (default `vi`) on a file and wait for it to exit.
+///
+/// The editor value is run through the shell so settings like
+/// `code --wait` work.
+fn run_editor(path: &Path) -> Result<()> {
+    let editor = std::env::var("EDITOR")
+        .ok()
+        .filter(|e| !e.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string());
+
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(format!("{} \"$1\"", editor))
+        .arg("grove-edit")
+        .arg(path)
+        .status()
+        .map_err(|e| GroveError::backend(format!("Failed to launch editor {}: {}", editor, e)))?;
+
+    if !status.success() {
+        return Err(GroveError::backend(format!(
+            "Editor {} exited with {}",
+            editor, status
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::MarkdownBackend;
+    use crate::config::project_learnings_path;
+    use crate::core::{Confidence, LearningCategory, LearningScope, WriteGateCriterion};
+    use crate::stats::StatsEventType;
+    use tempfile::TempDir;
+
+    const ID: &str = "cl_20260101_000";
+
+    fn setup() -> (TempDir, EditCommand<MarkdownBackend>) {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join(".grove")).unwrap();
+
+        let backend = MarkdownBackend::new(project_learnings_path(temp.path()));
+        let learning = CompoundLearning::new(
+            LearningCategory::Pitfall,
+            "Avoid N+1 queries in UserDashboard",
+            "The dashboard loaded users then posts one by one. Use eager loading with includes(:posts) instead.",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::BehaviorChanging],
+            vec!["performance".to_string(), "database".to_string()],
+            "session-1",
+        )
+        .with_id(ID);
+        backend.write(&learning).unwrap();
+
+        (temp, EditCommand::new(backend, Config::default()))
+    }
+
+    #[test]
+    fn test_edit_applies_partial_input() {
+        let (temp, cmd) = setup();
+        let input = EditInput {
+            summary: Some("Avoid N+1 queries in UserDashboard index view".to_string()),
+            tags: Some(vec!["performance".to_string(), "activerecord".to_string()]),
+            ..Default::default()
+        };
+
+        let output = cmd.run_with_input(ID, &input, temp.path());
+        assert!(output.success, "{:?}", output.error);
+        assert_eq!(output.changed_fields, vec!["summary", "tags"]);
+
+        let stored = cmd.find_learning(ID).unwrap();
+        assert_eq!(
+            stored.summary,
+            "Avoid N+1 queries in UserDashboard index view"
+        );
+        assert_eq!(stored.tags, vec!["performance", "activerecord"]);
+        assert_eq!(stored.session_id, "session-1");
+
+        let events = StatsLogger::new(project_stats_log_path(temp.path()))
+            .read_all()
+            .unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            events[0].data,
+            StatsEventType::edited(ID, vec!["summary".to_string(), "tags".to_string()])
+        );
+        assert_eq!(
+            events[1].data,
+            StatsEventType::revised(
+                ID,
+                Some(1),
+                RevisionSource::Edit,
+                vec!["summary".to_string(), "tags".to_string()]
+            )
+        );
+
+        let history = cmd.backend.history(ID).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(
+            history[0].changes[0].before.as_deref(),
+            Some("Avoid N+1 queries in UserDashboard")
+        );
+    }
+
+    #[test]
+    fn test_edit_rejected_by_schema_leaves_learning_unchanged() {
+        let (temp, cmd) = setup();
+        let input = EditInput {
+            summary: Some("short".to_string()),
+            ..Default::default()
+        };
+
+        let output = cmd.run_with_input(ID, &input, temp.path());
+        assert!(!output.success);
+        assert!(output.error.unwrap().contains("Edit rejected"));
+
+        let stored = cmd.find_learning(ID).unwrap();
+        assert_eq!(stored.summary, "Avoid N+1 queries in UserDashboard");
+    }
+
+    #[test]
+    fn test_edit_rejected_by_write_gate() {
+        let (temp, cmd) = setup();
+        let input = EditInput {
+            criteria_met: Some(Vec::new()),
+            ..Default::default()
+        };
+
+        let output = cmd.run_with_input(ID, &input, temp.path());
+        assert!(!output.success);
+    }
+
+    #[test]
+    fn test_edit_without_changes_logs_nothing() {
+        let (temp, cmd) = setup();
+        let original = cmd.find_learning(ID).unwrap();
+
+        let output = cmd.run_with_input(ID, &EditInput::from_learning(&original), temp.path());
+        assert!(output.success);
+        assert!(output.changed_fields.is_empty());
+        assert!(!project_stats_log_path(temp.path()).exists());
+    }
+
+    #[test]
+    fn test_edit_unknown_learning_fails() {
+        let (temp, cmd) = setup();
+
+        let output = cmd.run_with_input("cl_20990101_999", &EditInput::default(), temp.path());
+        assert!(!output.success);
+        assert!(output.error.unwrap().contains("not found"));
+    }
+
+    #[test]
+    fn test_format_output() {
+        let (_temp, cmd) = setup();
+        let options = EditOptions::default();
+
+        let edited = EditOutput::success(ID, vec!["detail".to_string()]);
+        assert_eq!(
+            cmd.format_output(&edited, &options),
+            format!("Edited {}: detail\n", ID)
+        );
+
+        let unchanged = EditOutput::success(ID, Vec::new());
+        assert!(cmd
+            .format_output(&unchanged, &options)
+            .contains("No changes"));
+
+        let failed = EditOutput::failure(ID, "boom");
+        assert_eq!(cmd.format_output(&failed, &options), "Edit failed: boom\n");
+
+        let quiet = EditOptions {
+            quiet: true,
+            ..Default::default()
+        };
+        assert!(cmd.format_output(&edited, &quiet).is_empty());
+    }
+}
@@ -0,0 +1,800 @@
+//! Export and import commands for Grove.
+//!
+//! `grove export` writes the whole learning corpus to a versioned JSONL file:
+//! a header line, then one line per learning (every `CompoundLearning` field)
+//! and one per stats event about those learnings. `grove import` reads that
+//! file into the current backend, remapping or keeping colliding IDs and
+//! skipping near-duplicates of learnings already in the corpus. Relations
+//! that target a skipped near-duplicate are pointed at the learning it
+//! duplicates.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::backends::MemoryBackend;
+use crate::config::project_stats_log_path;
+use crate::core::reflect::{check_near_duplicate, CandidateLearning};
+use crate::core::{CompoundLearning, LearningScope};
+use crate::error::{GroveError, Result};
+use crate::stats::tracker::GROVE_VERSION;
+use crate::stats::{StatsEvent, StatsEventType, StatsLogger};
+
+/// Current version of the export format.
+pub const EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// Format identifier written in the export header.
+pub const EXPORT_FORMAT_NAME: &str = "grove-export";
+
+/// First line of an export file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportHeader {
+    /// Always `grove-export`.
+    pub format: String,
+    /// Export format version.
+    pub version: u32,
+    /// Grove version that wrote the file.
+    pub grove_version: String,
+    /// When the export was made.
+    pub exported_at: DateTime<Utc>,
+    /// Number of learning records.
+    pub learnings: usize,
+    /// Number of stats event records.
+    pub events: usize,
+}
+
+/// One line of an export file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExportRecord {
+    /// The file header (always first).
+    Header(ExportHeader),
+    /// A learning, with every field.
+    Learning(Box<CompoundLearning>),
+    /// A stats event about an exported learning.
+    Event(StatsEvent),
+}
+
+/// What to do when an imported learning's ID already exists in the corpus.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CollisionPolicy {
+    /// Import under a newly generated ID (relations and events follow).
+    #[default]
+    Remap,
+    /// Keep the existing learning and skip the imported one.
+    Keep,
+}
+
+/// Options for the export and import commands.
+#[derive(Debug, Clone, Default)]
+pub struct ExportOptions {
+    /// Output as JSON.
+    pub json: bool,
+    /// Suppress output.
+    pub quiet: bool,
+    /// Only export or import learnings with this scope.
+    pub scope: Option<LearningScope>,
+    /// Import: how to handle ID collisions.
+    pub on_collision: CollisionPolicy,
+}
+
+/// Output format for the export command.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportOutput {
+    /// Whether the export was written.
+    pub success: bool,
+    /// File written to (`None` when exporting to stdout).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Number of learnings exported.
+    pub learnings: usize,
+    /// Number of stats events exported.
+    pub events: usize,
+    /// The export itself, when writing to stdout.
+    #[serde(skip)]
+    pub content: String,
+    /// Error message if the export failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ExportOutput {
+    /// Create a failed output.
+    pub fn failure(error: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            path: None,
+            learnings: 0,
+            events: 0,
+            content: String::new(),
+            error: Some(error.into()),
+        }
+    }
+}
+
+/// An imported learning whose ID was changed on collision.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RemappedId {
+    /// ID in the export file.
+    pub from: String,
+    /// ID it was imported under.
+    pub to: String,
+}
+
+/// An imported learning skipped as a near-duplicate.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DuplicateSkip {
+    /// ID in the export file.
+    pub learning_id: String,
+    /// The learning it duplicates (relations to the skipped ID now target it).
+    pub duplicate_of: String,
+}
+
+/// Output format for the import command.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportOutput {
+    /// Whether the import completed without write failures.
+    pub success: bool,
+    /// IDs of the learnings imported (as stored).
+    pub imported: Vec<String>,
+    /// Learnings imported under a new ID.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub remapped: Vec<RemappedId>,
+    /// Learnings skipped because their ID exists (`--on-collision keep`).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub kept_existing: Vec<String>,
+    /// Learnings skipped as near-duplicates.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub duplicates: Vec<DuplicateSkip>,
+    /// Learnings left out by the scope filter.
+    pub filtered: usize,
+    /// Learnings whose write failed.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub failed: Vec<String>,
+    /// Stats events appended to the stats log.
+    pub events: usize,
+    /// Error message if the import could not run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ImportOutput {
+    /// Create a failed output.
+    pub fn failure(error: impl Into<String>) -> Self {
+        Self {
+            error: Some(error.into()),
+            ..Default::default()
+        }
+    }
+}
+
+/// The export and import command implementation.
+pub struct ExportCommand<B: MemoryBackend> {
+    backend: B,
+}
+
+impl<B: MemoryBackend> ExportCommand<B> {
+    /// Create a new export command.
+    pub fn new(backend: B) -> Self {
+        Self { backend }
+    }
+
+    /// Export the corpus and its stats events, to `path` or (if `None`) into
+    /// the output's `content`.
+    pub fn run_export(
+        &self,
+        cwd: &Path,
+        path: Option<&Path>,
+        options: &ExportOptions,
+    ) -> ExportOutput {
+        let records = match self.export_records(cwd, options.scope) {
+            Ok(records) => records,
+            Err(e) => return ExportOutput::failure(e.to_string()),
+        };
+        let (learnings, events) = match records.first() {
+            Some(ExportRecord::Header(header)) => (header.learnings, header.events),
+            _ => (0, 0),
+        };
+
+        let mut content = String::new();
+        for record in &records {
+            match serde_json::to_string(record) {
+                Ok(line) => {
+                    content.push_str(&line);
+                    content.push('\n');
+                }
+                Err(e) => return ExportOutput::failure(e.to_string()),
+            }
+        }
+
+        if let Some(path) = path {
+            if let Err(e) = fs::write(path, &content) {
+                return ExportOutput::failure(GroveError::storage(path, e).to_string());
+            }
+            content.clear();
+        }
+
+        ExportOutput {
+            success: true,
+            path: path.map(|p| p.display().to_string()),
+            learnings,
+            events,
+            content,
+            error: None,
+        }
+    }
+
+    /// Build the export records: header, learnings, then matching stats events.
+    pub fn export_records(
+        &self,
+        cwd: &Path,
+        scope: Option<LearningScope>,
+    ) -> Result<Vec<ExportRecord>> {
+        let learnings: Vec<CompoundLearning> = self
+            .backend
+            .list_all()?
+            .into_iter()
+            .filter(|l| scope.is_none_or(|s| l.scope == s))
+            .collect();
+
+        let ids: HashSet<&str> = learnings.iter().map(|l| l.id.as_str()).collect();
+        let events: Vec<StatsEvent> = StatsLogger::new(project_stats_log_path(cwd))
+            .read_all()?
+            .into_iter()
+            .filter(|e| e.data.learning_id().is_some_and(|id| ids.contains(id)))
+            .collect();
+
+        let mut records = vec![ExportRecord::Header(ExportHeader {
+            format: EXPORT_FORMAT_NAME.to_string(),
+            version: EXPORT_FORMAT_VERSION,
+            grove_version: GROVE_VERSION.to_string(),
+            exported_at: Utc::now(),
+            learnings: learnings.len(),
+            events: events.len(),
+        })];
+        records.extend(
+            learnings
+                .into_iter()
+                .map(|l| ExportRecord::Learning(Box::new(l))),
+        );
+        records.extend(events.into_iter().map(ExportRecord::Event));
+        Ok(records)
+    }
+
+    /// Import an export file into the backend and the project stats log.
+    pub fn run_import(&self, cwd: &Path, path: &Path, options: &ExportOptions) -> ImportOutput {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) => return ImportOutput::failure(GroveError::storage(path, e).to_string()),
+        };
+
+        let (learnings, events) = match parse_export(&content) {
+            Ok(parsed) => parsed,
+            Err(e) => return ImportOutput::failure(e.to_string()),
+        };
+
+        let existing = match self.backend.list_all() {
+            Ok(existing) => existing,
+            Err(e) => return ImportOutput::failure(e.to_string()),
+        };
+
+        let mut output = ImportOutput::default();
+        let plan = plan_import(learnings, &existing, options, &mut output);
+
+        // Allocate fresh IDs for collisions, avoiding every ID already in use
+        let remap_count = plan.iter().filter(|(_, remap)| *remap).count();
+        let mut taken: HashSet<String> = existing.iter().map(|l| l.id.clone()).collect();
+        taken.extend(plan.iter().map(|(l, _)| l.id.clone()));
+        let mut fresh = self
+            .backend
+            .next_ids(remap_count + taken.len())
+            .into_iter()
+            .filter(|id| !taken.contains(id));
+
+        let mut id_map: HashMap<String, String> = HashMap::new();
+        for (learning, remap) in &plan {
+            if *remap {
+                let Some(new_id) = fresh.next() else {
+                    return ImportOutput::failure("Could not allocate IDs for colliding learnings");
+                };
+                output.remapped.push(RemappedId {
+                    from: learning.id.clone(),
+                    to: new_id.clone(),
+                });
+                id_map.insert(learning.id.clone(), new_id);
+            } else {
+                id_map.insert(learning.id.clone(), learning.id.clone());
+            }
+        }
+
+        // Relations to a skipped near-duplicate follow it to the learning it
+        // duplicates, which may itself be an earlier import that was remapped
+        for duplicate in &mut output.duplicates {
+            if let Some(target) = id_map.get(&duplicate.duplicate_of) {
+                duplicate.duplicate_of = target.clone();
+            }
+            id_map
+                .entry(duplicate.learning_id.clone())
+                .or_insert_with(|| duplicate.duplicate_of.clone());
+        }
+
+        for (mut learning, _) in plan {
+            learning.id = id_map[&learning.id].clone();
+            for relation in &mut learning.relations {
+                if let Some(target) = id_map.get(&relation.target) {
+                    relation.target = target.clone();
+                }
+            }
+
+            match self.backend.write(&learning) {
+                Ok(result) if result.success => output.imported.push(learning.id),
+                _ => output.failed.push(learning.id),
+            }
+        }
+
+        // Carry over stats events for the learnings that were imported
+        let imported: HashSet<&str> = output.imported.iter().map(String::as_str).collect();
+        let logger = StatsLogger::new(project_stats_log_path(cwd));
+        for mut event in events {
+            let Some(id) = event.data.learning_id_mut() else {
+                continue;
+            };
+            let Some(new_id) = id_map.get(id.as_str()) else {
+                continue;
+            };
+            if !imported.contains(new_id.as_str()) {
+                continue;
+            }
+            *id = new_id.clone();
+
+            if let StatsEventType::Corrected {
+                superseded_by: Some(ref mut by),
+                ..
+            } = event.data
+            {
+                if let Some(new_by) = id_map.get(by.as_str()) {
+                    *by = new_by.clone();
+                }
+            }
+
+            if let Err(e) = logger.append(&event) {
+                output.error = Some(format!("Failed to append stats events: {}", e));
+                break;
+            }
+            output.events += 1;
+        }
+
+        output.success = output.failed.is_empty() && output.error.is_none();
+        output
+    }
+
+    /// Format export output based on options.
+    pub fn format_export(&self, output: &ExportOutput, options: &ExportOptions) -> String {
+        if !output.success {
+            if options.quiet {
+                return String::new();
+            }
+            if options.json {
+                return serde_json::to_string_pretty(output).unwrap_or_else(|_| "{}".to_string());
+            }
+            return format!(
+                "Export failed: {}\n",
+                output.error.as_deref().unwrap_or("unknown error")
+            );
+        }
+
+        // Exporting to stdout: the export itself is the output
+        let Some(ref path) = output.path else {
+            return output.content.clone();
+        };
+
+        if options.quiet {
+            return String::new();
+        }
+        if options.json {
+            return serde_json::to_string_pretty(output).unwrap_or_else(|_| "{}".to_string());
+        }
+
+        format!(
+            "Exported {} learning(s) and {} stats event(s) to {}\n",
+            output.learnings, output.events, path
+        )
+    }
+
+    /// Format import output based on options.
+    pub fn format_import(&self, output: &ImportOutput, options: &ExportOptions) -> String {
+        if options.quiet {
+            return String::new();
+        }
+
+        if options.json {
+            return serde_json::to_string_pretty(output).unwrap_or_else(|_| "{}".to_string());
+        }
+
+        if output.imported.is_empty() && output.failed.is_empty() {
+            if let Some(ref error) = output.error {
+                return format!("Import failed: {}\n", error);
+            }
+        }
+
+        let mut out = format!(
+            "Imported {} learning(s) and {} stats event(s)\n",
+            output.imported.len(),
+            output.events
+        );
+        for remapped in &output.remapped {
+            out.push_str(&format!(
+                "  remapped {} -> {}\n",
+                remapped.from, remapped.to
+            ));
+        }
+        for duplicate in &output.duplicates {
+            out.push_str(&format!(
+                "  skipped {} (near-duplicate of {})\n",
+                duplicate.learning_id, duplicate.duplicate_of
+            ));
+        }
+        for id in &output.kept_existing {
+            out.push_str(&format!("  skipped {} (ID exists; kept existing)\n", id));
+        }
+        if output.filtered > 0 {
+            out.push_str(&format!(
+                "  {} learning(s) outside the requested scope\n",
+                output.filtered
+            ));
+        }
+        for id in &output.failed {
+            out.push_str(&format!("  failed to write {}\n", id));
+        }
+        if let Some(ref error) = output.error {
+            out.push_str(&format!("Error: {}\n", error));
+        }
+        out
+    }
+}
+
+/// Parse an export file into its learnings and stats events.
+///
+/// # Errors
+///
+/// Returns an error if the header is missing, the format version is newer
+/// than this Grove understands, or a line is not a valid record.
+pub fn parse_export(content: &str) -> Result<(Vec<CompoundLearning>, Vec<StatsEvent>)> {
+    let mut lines = content
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty());
+
+    match lines.next().map(|(_, l)| serde_json::from_str(l)) {
+        Some(Ok(ExportRecord::Header(header))) if header.format == EXPORT_FORMAT_NAME => {
+            if header.version > EXPORT_FORMAT_VERSION {
+                return Err(GroveError::backend(format!(
+                    "Unsupported export format version {} (this Grove reads up to {})",
+                    header.version, EXPORT_FORMAT_VERSION
+                )));
+            }
+        }
+        _ => {
+            return Err(GroveError::backend(
+                "Not a Grove export: missing grove-export header",
+            ))
+        }
+    }
+
+    let mut learnings = Vec::new();
+    let mut events = Vec::new();
+    for (i, line) in lines {
+        let record: ExportRecord = serde_json::from_str(line)
+            .map_err(|e| GroveError::backend(format!("Invalid record on line {}: {}", i + 1, e)))?;
+        match record {
+            ExportRecord::Learning(learning) => learnings.push(*learning),
+            ExportRecord::Event(event) => events.push(event),
+            ExportRecord::Header(_) => {
+                return Err(GroveError::backend(format!(
+                    "Unexpected header on line {}",
+                    i + 1
+                )))
+            }
+        }
+    }
+    Ok((learnings, events))
+}
+
+/// Decide which learnings to import and whether each needs a new ID.
+///
+/// Applies the scope filter, near-duplicate detection (against the existing
+/// corpus and earlier learnings in the file), and the collision policy,
+/// recording skipped learnings in `output`.
+fn plan_import(
+    learnings: Vec<CompoundLearning>,
+    existing: &[CompoundLearning],
+    options: &ExportOptions,
+    output: &mut ImportOutput,
+) -> Vec<(CompoundLearning, bool)> {
+    let existing_ids: HashSet<&str> = existing.iter().map(|l| l.id.as_str()).collect();
+    let mut corpus: Vec<CompoundLearning> = existing.to_vec();
+    let mut plan = Vec::new();
+
+    for learning in learnings {
+        if options.scope.is_some_and(|s| learning.scope != s) {
+            output.filtered += 1;
+            continue;
+        }
+
+        let duplicate = check_near_duplicate(&CandidateLearning::from(&learning), &corpus);
+        if duplicate.is_duplicate {
+            output.duplicates.push(DuplicateSkip {
+                learning_id: learning.id,
+                duplicate_of: duplicate.duplicate_of.unwrap_or_default(),
+            });
+            continue;
+        }
+
+        let collides = existing_ids.contains(learning.id.as_str());
+        if collides && options.on_collision == CollisionPolicy::Keep {
+            output.kept_existing.push(learning.id);
+            continue;
+        }
+
+        corpus.push(learning.clone());
+        plan.push((learning, collides));
+    }
+
+    plan
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::MarkdownBackend;
+    use crate::core::{
+        Confidence, LearningCategory, LearningRelation, RelationKind, WriteGateCriterion,
+    };
+    use tempfile::TempDir;
+
+    fn learning(id: &str, summary: &str, scope: LearningScope) -> CompoundLearning {
+        CompoundLearning::new(
+            LearningCategory::Pitfall,
+            summary,
+            "Detail that explains the pitfall in enough words to be useful.",
+            scope,
+            Confidence::High,
+            vec![
+                WriteGateCriterion::StableFact,
+                WriteGateCriterion::BehaviorChanging,
+            ],
+            vec!["testing".to_string()],
+            "session-1",
+        )
+        .with_id(id)
+    }
+
+    fn project(temp: &TempDir, name: &str) -> (std::path::PathBuf, ExportCommand<MarkdownBackend>) {
+        let root = temp.path().join(name);
+        fs::create_dir_all(root.join(".grove")).unwrap();
+        let backend = MarkdownBackend::with_paths(
+            root.join(".grove").join("learnings.md"),
+            root.join("personal.md"),
+        );
+        (root, ExportCommand::new(backend))
+    }
+
+    fn setup_source(temp: &TempDir) -> (std::path::PathBuf, ExportCommand<MarkdownBackend>) {
+        let (root, cmd) = project(temp, "source");
+        let first = learning(
+            "cl_20260101_001",
+            "Ecto cast drops fields missing from the schema",
+            LearningScope::Project,
+        );
+        let second = learning(
+            "cl_20260101_002",
+            "Phoenix LiveView forms need phx-change for validation",
+            LearningScope::Team,
+        )
+        .with_relations(vec![LearningRelation::new(
+            RelationKind::DependsOn,
+            "cl_20260101_001",
+        )]);
+        cmd.backend.write(&first).unwrap();
+        cmd.backend.write(&second).unwrap();
+
+        let logger = StatsLogger::new(project_stats_log_path(&root));
+        logger
+            .append_referenced("cl_20260101_001", "session-2", None)
+            .unwrap();
+        logger
+            .append_corrected(
+                "cl_20260101_001",
+                "session-3",
+                Some("cl_20260101_002".into()),
+            )
+            .unwrap();
+        logger
+            .append_skip(
+                "session-4",
+                "trivial",
+                crate::core::SkipDecider::Agent,
+                2,
+                None,
+            )
+            .unwrap();
+        (root, cmd)
+    }
+
+    #[test]
+    fn test_export_roundtrip_is_lossless() {
+        let temp = TempDir::new().unwrap();
+        let (root, cmd) = setup_source(&temp);
+
+        let output = cmd.run_export(&root, None, &ExportOptions::default());
+        assert!(output.success);
+        assert_eq!(output.learnings, 2);
+        assert_eq!(output.events, 2);
+
+        let (learnings, events) = parse_export(&output.content).unwrap();
+        assert_eq!(learnings, cmd.backend.list_all().unwrap());
+        assert_eq!(
+            learnings[0].criteria_met,
+            vec![
+                WriteGateCriterion::StableFact,
+                WriteGateCriterion::BehaviorChanging
+            ]
+        );
+        assert_eq!(events.len(), 2);
+        assert!(output.content.starts_with("{\"type\":\"header\""));
+    }
+
+    #[test]
+    fn test_export_scope_filter() {
+        let temp = TempDir::new().unwrap();
+        let (root, cmd) = setup_source(&temp);
+        let options = ExportOptions {
+            scope: Some(LearningScope::Team),
+            ..Default::default()
+        };
+
+        let output = cmd.run_export(&root, None, &options);
+        assert_eq!(output.learnings, 1);
+        assert_eq!(output.events, 0);
+    }
+
+    #[test]
+    fn test_import_remaps_collisions_and_carries_events() {
+        let temp = TempDir::new().unwrap();
+        let (source_root, source) = setup_source(&temp);
+        let file = temp.path().join("export.jsonl");
+        source.run_export(&source_root, Some(&file), &ExportOptions::default());
+
+        // The target already has a different learning under the first ID
+        let (root, cmd) = project(&temp, "target");
+        cmd.backend
+            .write(&learning(
+                "cl_20260101_001",
+                "Cargo workspaces share one lockfile at the root",
+                LearningScope::Project,
+            ))
+            .unwrap();
+
+        let output = cmd.run_import(&root, &file, &ExportOptions::default());
+        assert!(output.success, "{:?}", output);
+        assert_eq!(output.imported.len(), 2);
+        assert_eq!(output.remapped.len(), 1);
+        let new_id = output.remapped[0].to.clone();
+        assert_ne!(new_id, "cl_20260101_001");
+
+        let stored = cmd.backend.list_all().unwrap();
+        let second = stored.iter().find(|l| l.id == "cl_20260101_002").unwrap();
+        assert_eq!(second.relations[0].target, new_id);
+
+        let events = StatsLogger::new(project_stats_log_path(&root))
+            .read_all()
+            .unwrap();
+        assert_eq!(output.events, 2);
+        assert_eq!(events[0].data.learning_id(), Some(new_id.as_str()));
+        assert!(matches!(
+            events[1].data,
+            StatsEventType::Corrected {
+                superseded_by: Some(ref by),
+                ..
+            } if by == "cl_20260101_002"
+        ));
+    }
+
+    #[test]
+    fn test_import_keep_and_near_duplicates() {
+        let temp = TempDir::new().unwrap();
+        let (source_root, source) = setup_source(&temp);
+        let file = temp.path().join("export.jsonl");
+        source.run_export(&source_root, Some(&file), &ExportOptions::default());
+
+        let (root, cmd) = project(&temp, "target");
+        cmd.backend
+            .write(&learning(
+                "cl_20260101_001",
+                "Cargo workspaces share one lockfile at the root",
+                LearningScope::Project,
+            ))
+            .unwrap();
+        cmd.backend
+            .write(&learning(
+                "cl_20260105_001",
+                "Phoenix LiveView forms need phx-change for validation",
+                LearningScope::Project,
+            ))
+            .unwrap();
+
+        let options = ExportOptions {
+            on_collision: CollisionPolicy::Keep,
+            ..Default::default()
+        };
+        let output = cmd.run_import(&root, &file, &options);
+        assert!(output.success);
+        assert!(output.imported.is_empty());
+        assert_eq!(output.kept_existing, vec!["cl_20260101_001"]);
+        assert_eq!(output.duplicates[0].duplicate_of, "cl_20260105_001");
+        assert_eq!(output.events, 0);
+    }
+
+    #[test]
+    fn test_import_redirects_relations_to_near_duplicate_match() {
+        let temp = TempDir::new().unwrap();
+        let (source_root, source) = setup_source(&temp);
+        let file = temp.path().join("export.jsonl");
+        source.run_export(&source_root, Some(&file), &ExportOptions::default());
+
+        // The target already holds the first learning under another ID
+        let (root, cmd) = project(&temp, "target");
+        cmd.backend
+            .write(&learning(
+                "cl_20260105_001",
+                "Ecto cast drops fields missing from the schema",
+                LearningScope::Project,
+            ))
+            .unwrap();
+
+        let output = cmd.run_import(&root, &file, &ExportOptions::default());
+        assert!(output.success, "{:?}", output);
+        assert_eq!(output.imported, vec!["cl_20260101_002"]);
+        assert_eq!(output.duplicates[0].learning_id, "cl_20260101_001");
+        assert_eq!(output.duplicates[0].duplicate_of, "cl_20260105_001");
+
+        let stored = cmd.backend.list_all().unwrap();
+        let second = stored.iter().find(|l| l.id == "cl_20260101_002").unwrap();
+        assert_eq!(second.relations[0].target, "cl_20260105_001");
+    }
+
+    #[test]
+    fn test_import_scope_filter() {
+        let temp = TempDir::new().unwrap();
+        let (source_root, source) = setup_source(&temp);
+        let file = temp.path().join("export.jsonl");
+        source.run_export(&source_root, Some(&file), &ExportOptions::default());
+
+        let (root, cmd) = project(&temp, "target");
+        let options = ExportOptions {
+            scope: Some(LearningScope::Project),
+            ..Default::default()
+        };
+        let output = cmd.run_import(&root, &file, &options);
+        assert_eq!(output.imported, vec!["cl_20260101_001"]);
+        assert_eq!(output.filtered, 1);
+    }
+
+    #[test]
+    fn test_parse_export_rejects_bad_input() {
+        assert!(parse_export("{\"type\":\"learning\"}\n").is_err());
+
+        let future = format!(
+            "{{\"type\":\"header\",\"format\":\"grove-export\",\"version\":{},\"grove_version\":\"9.9.9\",\"exported_at\":\"2026-01-01T00:00:00Z\",\"learnings\":0,\"events\":0}}\n",
+            EXPORT_FORMAT_VERSION + 1
+        );
+        let err = parse_export(&future).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Unsupported export format version"));
+    }
+}
@@ -0,0 +1,401 @@
+//! History and diff commands for Grove.
+//!
+//! `grove history <id>` lists every recorded revision of a learning: what
+//! changed it, when, and which fields. `grove diff <id> [--rev N]` shows the
+//! field-level diff of one revision (the latest by default).
+
+use serde::Serialize;
+
+use crate::backends::MemoryBackend;
+use crate::core::LearningRevision;
+use crate::error::FailOpen;
+use crate::stats::StatsLogger;
+
+/// Options for the history and diff commands.
+#[derive(Debug, Clone, Default)]
+pub struct HistoryOptions {
+    /// Output as JSON.
+    pub json: bool,
+    /// Suppress output.
+    pub quiet: bool,
+}
+
+/// Output format for the history command.
+#[derive(Debug, Clone, Serialize)]
+pub struct HistoryOutput {
+    /// Whether the history was read.
+    pub success: bool,
+    /// The learning whose history was requested.
+    pub learning_id: String,
+    /// Revisions, oldest first.
+    pub revisions: Vec<LearningRevision>,
+    /// Error message if the history could not be read.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl HistoryOutput {
+    /// Create a successful output.
+    pub fn success(learning_id: impl Into<String>, revisions: Vec<LearningRevision>) -> Self {
+        Self {
+            success: true,
+            learning_id: learning_id.into(),
+            revisions,
+            error: None,
+        }
+    }
+
+    /// Create a failed output.
+    pub fn failure(learning_id: impl Into<String>, error: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            learning_id: learning_id.into(),
+            revisions: Vec::new(),
+            error: Some(error.into()),
+        }
+    }
+}
+
+/// Output format for the diff command.
+#[derive(Debug, Clone, Serialize)]
+pub struct DiffOutput {
+    /// Whether the revision was found.
+    pub success: bool,
+    /// The learning whose revision was requested.
+    pub learning_id: String,
+    /// The revision being shown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<LearningRevision>,
+    /// Error message if the revision could not be found.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl DiffOutput {
+    /// Create a successful output.
+    pub fn success(revision: LearningRevision) -> Self {
+        Self {
+            success: true,
+            learning_id: revision.learning_id.clone(),
+            revision: Some(revision),
+            error: None,
+        }
+    }
+
+    /// Create a failed output.
+    pub fn failure(learning_id: impl Into<String>, error: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            learning_id: learning_id.into(),
+            revision: None,
+            error: Some(error.into()),
+        }
+    }
+}
+
+/// The history and diff command implementation.
+pub struct HistoryCommand<B: MemoryBackend> {
+    backend: B,
+}
+
+impl<B: MemoryBackend> HistoryCommand<B> {
+    /// Create a new history command.
+    pub fn new(backend: B) -> Self {
+        Self { backend }
+    }
+
+    /// List the revisions of a learning.
+    pub fn run_history(&self, learning_id: &str) -> HistoryOutput {
+        match self.backend.history(learning_id) {
+            Ok(revisions) if revisions.is_empty() => HistoryOutput::failure(
+                learning_id,
+                format!("No history recorded for {}", learning_id),
+            ),
+            Ok(revisions) => HistoryOutput::success(learning_id, revisions),
+            Err(e) => HistoryOutput::failure(learning_id, e.to_string()),
+        }
+    }
+
+    /// Show one revision of a learning (the latest when `rev` is `None`).
+    pub fn run_diff(&self, learning_id: &str, rev: Option<u32>) -> DiffOutput {
+        let history = self.run_history(learning_id);
+        if !history.success {
+            return DiffOutput::failure(learning_id, history.error.unwrap_or_default());
+        }
+
+        let revision = match rev {
+            Some(n) => history.revisions.into_iter().find(|r| r.rev == n),
+            None => history.revisions.into_iter().last(),
+        };
+
+        match revision {
+            Some(revision) => DiffOutput::success(revision),
+            None => DiffOutput::failure(
+                learning_id,
+                format!(
+                    "Revision {} not found for {}",
+                    rev.unwrap_or_default(),
+                    learning_id
+                ),
+            ),
+        }
+    }
+
+    /// Format history output based on options.
+    pub fn format_history(&self, output: &HistoryOutput, options: &HistoryOptions) -> String {
+        if options.quiet {
+            return String::new();
+        }
+
+        if options.json {
+            return serde_json::to_string_pretty(output).unwrap_or_else(|_| "{}".to_string());
+        }
+
+        if !output.success {
+            return format!(
+                "History failed: {}\n",
+                output.error.as_deref().unwrap_or("unknown error")
+            );
+        }
+
+        let mut out = format!(
+            "History for {} ({} revision(s))\n\n",
+            output.learning_id,
+            output.revisions.len()
+        );
+        for revision in &output.revisions {
+            out.push_str(&format!(
+                "  rev {:<3} {}  {:<11}  {}",
+                revision.rev,
+                revision.timestamp.format("%Y-%m-%d %H:%M"),
+                revision.source.display_name(),
+                revision.fields().join(", ")
+            ));
+            if !revision.merged_from.is_empty() {
+                out.push_str(&format!(
+                    " (merged from {})",
+                    revision.merged_from.join(", ")
+                ));
+            }
+            if let Some(ref id) = revision.superseded_by {
+                out.push_str(&format!(" (superseded by {})", id));
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Format diff output based on options.
+    pub fn format_diff(&self, output: &DiffOutput, options: &HistoryOptions) -> String {
+        if options.quiet {
+            return String::new();
+        }
+
+        if options.json {
+            return serde_json::to_string_pretty(output).unwrap_or_else(|_| "{}".to_string());
+        }
+
+        let Some(ref revision) = output.revision else {
+            return format!(
+                "Diff failed: {}\n",
+                output.error.as_deref().unwrap_or("unknown error")
+            );
+        };
+
+        let mut out = format!(
+            "{} rev {} ({}, {})\n",
+            revision.learning_id,
+            revision.rev,
+            revision.source.display_name(),
+            revision.timestamp.format("%Y-%m-%d %H:%M UTC")
+        );
+        if let Some(ref id) = revision.superseded_by {
+            out.push_str(&format!("Superseded by {}\n", id));
+        }
+        if !revision.merged_from.is_empty() {
+            out.push_str(&format!(
+                "Merged from {}\n",
+                revision.merged_from.join(", ")
+            ));
+        }
+
+        for change in &revision.changes {
+            out.push_str(&format!("\n{}\n", change.field));
+            for line in change.before.iter().flat_map(|v| v.lines()) {
+                out.push_str(&format!("- {}\n", line));
+            }
+            for line in change.after.iter().flat_map(|v| v.lines()) {
+                out.push_str(&format!("+ {}\n", line));
+            }
+        }
+        out
+    }
+}
+
+/// Record a revision in the backend's history and log it to the stats log.
+///
+/// Fail-open: if the backend keeps no history, the stats event is still
+/// logged, just without a revision number.
+pub(crate) fn record_revision<B: MemoryBackend + ?Sized>(
+    backend: &B,
+    stats_logger: &StatsLogger,
+    revision: &LearningRevision,
+) {
+    let rev = backend
+        .record_revision(revision)
+        .map(Some)
+        .fail_open_default("recording learning revision");
+
+    stats_logger
+        .append_revised(
+            &revision.learning_id,
+            rev,
+            revision.source,
+            revision.fields(),
+        )
+        .fail_open_default("logging revised event");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::MarkdownBackend;
+    use crate::core::{
+        CompoundLearning, Confidence, LearningCategory, LearningScope, RevisionSource,
+        WriteGateCriterion,
+    };
+    use crate::stats::StatsEventType;
+    use tempfile::TempDir;
+
+    const ID: &str = "cl_20260101_000";
+
+    fn setup() -> (TempDir, HistoryCommand<MarkdownBackend>, StatsLogger) {
+        let temp = TempDir::new().unwrap();
+        let backend = MarkdownBackend::with_paths(
+            temp.path().join(".grove").join("learnings.md"),
+            temp.path().join("personal.md"),
+        );
+        let logger = StatsLogger::new(temp.path().join(".grove").join("stats.log"));
+
+        let learning = CompoundLearning::new(
+            LearningCategory::Pitfall,
+            "Avoid N+1 queries in UserDashboard",
+            "The dashboard loaded users then posts one by one. Use includes(:posts).",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::BehaviorChanging],
+            vec!["performance".to_string()],
+            "session-1",
+        )
+        .with_id(ID);
+        backend.write(&learning).unwrap();
+        record_revision(
+            &backend,
+            &logger,
+            &LearningRevision::created(&learning, RevisionSource::Reflect),
+        );
+
+        let mut edited = learning.clone();
+        edited.detail =
+            "Load posts with includes(:posts)\nto avoid one query per user.".to_string();
+        backend.update(&edited).unwrap();
+        record_revision(
+            &backend,
+            &logger,
+            &LearningRevision::changed(&learning, &edited, RevisionSource::Edit),
+        );
+
+        (temp, HistoryCommand::new(backend), logger)
+    }
+
+    #[test]
+    fn test_history_lists_revisions_and_logs_events() {
+        let (_temp, cmd, logger) = setup();
+
+        let output = cmd.run_history(ID);
+        assert!(output.success);
+        assert_eq!(output.revisions.len(), 2);
+        assert_eq!(output.revisions[1].source, RevisionSource::Edit);
+
+        let events = logger.read_all().unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            events[1].data,
+            StatsEventType::revised(
+                ID,
+                Some(2),
+                RevisionSource::Edit,
+                vec!["detail".to_string()]
+            )
+        );
+
+        let text = cmd.format_history(&output, &HistoryOptions::default());
+        assert!(text.contains("History for cl_20260101_000 (2 revision(s))"));
+        assert!(text.contains("edit"));
+    }
+
+    #[test]
+    fn test_history_unknown_learning_fails() {
+        let (_temp, cmd, _logger) = setup();
+        let output = cmd.run_history("cl_20260101_999");
+        assert!(!output.success);
+        assert!(output.error.unwrap().contains("No history"));
+    }
+
+    #[test]
+    fn test_diff_defaults_to_latest_revision() {
+        let (_temp, cmd, _logger) = setup();
+
+        let output = cmd.run_diff(ID, None);
+        assert!(output.success);
+        assert_eq!(output.revision.as_ref().unwrap().rev, 2);
+
+        let text = cmd.format_diff(&output, &HistoryOptions::default());
+        assert!(text.starts_with("cl_20260101_000 rev 2 (edit,"));
+        assert!(text.contains("- The dashboard loaded users"));
+        assert!(text.contains("+ Load posts with includes(:posts)\n+ to avoid one query per user."));
+    }
+
+    #[test]
+    fn test_diff_specific_and_missing_revision() {
+        let (_temp, cmd, _logger) = setup();
+
+        let output = cmd.run_diff(ID, Some(1));
+        assert_eq!(output.revision.unwrap().source, RevisionSource::Reflect);
+
+        let output = cmd.run_diff(ID, Some(7));
+        assert!(!output.success);
+        assert!(output.error.unwrap().contains("Revision 7 not found"));
+    }
+
+    #[test]
+    fn test_record_revision_without_backend_history() {
+        let temp = TempDir::new().unwrap();
+        let logger = StatsLogger::new(temp.path().join("stats.log"));
+        let backend = crate::backends::MirrorBackend::new(Vec::new());
+        let learning = CompoundLearning::new(
+            LearningCategory::Pattern,
+            "Summary long enough",
+            "Detail long enough to be a learning",
+            LearningScope::Project,
+            Confidence::High,
+            vec![],
+            vec![],
+            "s",
+        )
+        .with_id(ID);
+
+        record_revision(
+            &backend,
+            &logger,
+            &LearningRevision::created(&learning, RevisionSource::Retroflect),
+        );
+
+        let events = logger.read_all().unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            events[0].data,
+            StatsEventType::Revised { rev: None, .. }
+        ));
+    }
+}
@@ -0,0 +1,302 @@
+//! Index command for Grove.
+//!
+//! `grove index status` reports whether the project's persistent search
+//! index (`.grove/index/`) matches the learnings, and `grove index rebuild`
+//! rebuilds it from scratch. Hooks rebuild a stale index on their own; these
+//! commands are for inspecting it and forcing a rebuild. Both require the
+//! `tantivy-search` feature.
+
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::backends::MemoryBackend;
+
+/// Options for the index command.
+#[derive(Debug, Clone, Default)]
+pub struct IndexOptions {
+    /// Output as JSON.
+    pub json: bool,
+    /// Suppress output.
+    pub quiet: bool,
+}
+
+/// Output format for the index command.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IndexOutput {
+    /// Whether the command was successful.
+    pub success: bool,
+    /// The action performed ("status" or "rebuild").
+    pub action: String,
+    /// The index directory.
+    pub path: String,
+    /// Whether the index has been built.
+    pub exists: bool,
+    /// Documents in the index.
+    pub documents: u64,
+    /// Active learnings in the backend.
+    pub learnings: usize,
+    /// Whether the index no longer matches the learnings.
+    pub stale: bool,
+    /// Active learnings missing from the index.
+    pub missing: usize,
+    /// Indexed learnings whose content has changed.
+    pub changed: usize,
+    /// Indexed learnings that are gone or no longer active.
+    pub removed: usize,
+    /// When the index was last rebuilt from scratch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub built_at: Option<DateTime<Utc>>,
+    /// When the index was last changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+    /// Error message if the command failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl IndexOutput {
+    /// Create a failed output.
+    pub fn failure(action: &str, path: String, error: impl Into<String>) -> Self {
+        Self {
+            action: action.to_string(),
+            path,
+            error: Some(error.into()),
+            ..Default::default()
+        }
+    }
+}
+
+/// The index command implementation.
+pub struct IndexCommand<B: MemoryBackend> {
+    #[cfg_attr(not(feature = "tantivy-search"), allow(dead_code))]
+    backend: B,
+    index_dir: PathBuf,
+}
+
+impl<B: MemoryBackend> IndexCommand<B> {
+    /// Create a new index command for the index in `index_dir`.
+    pub fn new(backend: B, index_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            backend,
+            index_dir: index_dir.into(),
+        }
+    }
+
+    /// Report how the index compares to the backend's learnings.
+    pub fn run_status(&self) -> IndexOutput {
+        self.run("status", false)
+    }
+
+    /// Rebuild the index from the backend's learnings.
+    pub fn run_rebuild(&self) -> IndexOutput {
+        self.run("rebuild", true)
+    }
+
+    #[cfg(feature = "tantivy-search")]
+    fn run(&self, action: &str, rebuild: bool) -> IndexOutput {
+        use crate::backends::{SearchFilters, SearchQuery};
+        use crate::search::ProjectIndex;
+
+        let path = self.index_dir.display().to_string();
+
+        // Same corpus the hooks index: every active learning, layers included
+        let learnings: Vec<_> = match self
+            .backend
+            .search(&SearchQuery::new(), &SearchFilters::active_only())
+        {
+            Ok(results) => results.into_iter().map(|r| r.learning).collect(),
+            Err(e) => return IndexOutput::failure(action, path, e.to_string()),
+        };
+
+        if !rebuild && !ProjectIndex::exists(&self.index_dir) {
+            return IndexOutput {
+                success: true,
+                action: action.to_string(),
+                path,
+                learnings: learnings.len(),
+                stale: !learnings.is_empty(),
+                missing: learnings.len(),
+                ..Default::default()
+            };
+        }
+
+        let mut index = match ProjectIndex::open(&self.index_dir) {
+            Ok(index) => index,
+            Err(e) => return IndexOutput::failure(action, path, e.to_string()),
+        };
+        if rebuild {
+            if let Err(e) = index.rebuild(&learnings) {
+                return IndexOutput::failure(action, path, e.to_string());
+            }
+        }
+
+        let drift = index.drift(&learnings);
+        let manifest = index.manifest();
+        IndexOutput {
+            success: true,
+            action: action.to_string(),
+            path,
+            exists: true,
+            documents: index.index().num_docs(),
+            learnings: learnings.len(),
+            stale: !index.is_current(&learnings),
+            missing: drift.missing,
+            changed: drift.changed,
+            removed: drift.removed,
+            built_at: manifest.built_at,
+            updated_at: manifest.updated_at,
+            error: None,
+        }
+    }
+
+    #[cfg(not(feature = "tantivy-search"))]
+    fn run(&self, action: &str, _rebuild: bool) -> IndexOutput {
+        IndexOutput::failure(
+            action,
+            self.index_dir.display().to_string(),
+            "The search index requires grove built with the tantivy-search feature",
+        )
+    }
+
+    /// Format output based on options.
+    pub fn format_output(&self, output: &IndexOutput, options: &IndexOptions) -> String {
+        if options.quiet {
+            return String::new();
+        }
+
+        if options.json {
+            return serde_json::to_string_pretty(output).unwrap_or_else(|_| "{}".to_string());
+        }
+
+        if !output.success {
+            return format!(
+                "Index {} failed: {}\n",
+                output.action,
+                output.error.as_deref().unwrap_or("unknown error")
+            );
+        }
+
+        let mut out = String::new();
+        if output.action == "rebuild" {
+            out.push_str(&format!(
+                "Rebuilt search index ({} learning(s))\n\n",
+                output.documents
+            ));
+        }
+
+        let state = if !output.exists {
+            "not built".to_string()
+        } else if output.stale {
+            format!(
+                "stale ({} missing, {} changed, {} removed)",
+                output.missing, output.changed, output.removed
+            )
+        } else {
+            "current".to_string()
+        };
+
+        out.push_str(&format!("Search index: {}\n", output.path));
+        out.push_str(&format!("  Status:    {}\n", state));
+        out.push_str(&format!(
+            "  Documents: {} ({} active learning(s))\n",
+            output.documents, output.learnings
+        ));
+        if let Some(built_at) = output.built_at {
+            out.push_str(&format!(
+                "  Built:     {}\n",
+                built_at.format("%Y-%m-%d %H:%M")
+            ));
+        }
+        if let Some(updated_at) = output.updated_at {
+            out.push_str(&format!(
+                "  Updated:   {}\n",
+                updated_at.format("%Y-%m-%d %H:%M")
+            ));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::MarkdownBackend;
+    use crate::core::{
+        CompoundLearning, Confidence, LearningCategory, LearningScope, WriteGateCriterion,
+    };
+    use tempfile::TempDir;
+
+    fn learning(id: &str, summary: &str) -> CompoundLearning {
+        CompoundLearning::new(
+            LearningCategory::Pattern,
+            summary,
+            "Detail that is long enough to pass validation",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::BehaviorChanging],
+            vec!["index".to_string()],
+            "test-session",
+        )
+        .with_id(id)
+    }
+
+    fn setup(temp: &TempDir) -> IndexCommand<MarkdownBackend> {
+        let backend = MarkdownBackend::with_paths(
+            temp.path().join("learnings.md"),
+            temp.path().join("personal.md"),
+        );
+        backend
+            .write(&learning("cl_20260101_000", "Use atomic writes"))
+            .unwrap();
+        backend
+            .write(&learning("cl_20260101_001", "Avoid blocking hooks"))
+            .unwrap();
+        IndexCommand::new(backend, temp.path().join("index"))
+    }
+
+    #[cfg(feature = "tantivy-search")]
+    #[test]
+    fn test_status_and_rebuild() {
+        let temp = TempDir::new().unwrap();
+        let cmd = setup(&temp);
+
+        let status = cmd.run_status();
+        assert!(status.success);
+        assert!(!status.exists);
+        assert!(status.stale);
+        assert_eq!(status.missing, 2);
+        assert!(!temp.path().join("index").exists(), "status doesn't build");
+
+        let rebuilt = cmd.run_rebuild();
+        assert!(rebuilt.success);
+        assert!(rebuilt.exists);
+        assert!(!rebuilt.stale);
+        assert_eq!(rebuilt.documents, 2);
+
+        // A learning written behind the index's back shows up as missing
+        cmd.backend
+            .write(&learning("cl_20260101_002", "Check hooks for latency"))
+            .unwrap();
+        let status = cmd.run_status();
+        assert!(status.stale);
+        assert_eq!(status.missing, 1);
+        let text = cmd.format_output(&status, &IndexOptions::default());
+        assert!(text.contains("stale (1 missing, 0 changed, 0 removed)"));
+        assert!(text.contains("Documents: 2 (3 active learning(s))"));
+    }
+
+    #[cfg(not(feature = "tantivy-search"))]
+    #[test]
+    fn test_requires_tantivy_feature() {
+        let temp = TempDir::new().unwrap();
+        let cmd = setup(&temp);
+
+        let output = cmd.run_rebuild();
+        assert!(!output.success);
+        let text = cmd.format_output(&output, &IndexOptions::default());
+        assert!(text.contains("Index rebuild failed"));
+        assert!(text.contains("tantivy-search"));
+    }
+}
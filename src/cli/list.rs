@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::backends::{MemoryBackend, SearchFilters, SearchQuery};
 use crate::config::{Config, DecayConfig};
-use crate::core::CompoundLearning;
+use crate::core::{CompoundLearning, Provenance, ProvenanceFilter};
 use crate::stats::{LearningStats, RejectedCandidateSummary, StatsCache};
 
 /// Sort field for list output.
@@ -57,6 +57,8 @@ pub struct ListOptions {
     pub sort_order: SortOrder,
     /// Show rejected candidates instead of accepted learnings.
     pub rejections: bool,
+    /// Show only learnings whose provenance matches.
+    pub provenance: ProvenanceFilter,
 }
 
 /// Output format for the list command.
@@ -140,6 +142,9 @@ pub struct LearningInfo {
     /// Last referenced date (YYYY-MM-DD).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_used: Option<String>,
+    /// Who and what produced the learning.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<Provenance>,
 }
 
 impl LearningInfo {
@@ -196,6 +201,7 @@ impl LearningInfo {
             referenced,
             hit_rate_pct,
             last_used,
+            provenance: learning.provenance.clone(),
         }
     }
 }
@@ -296,7 +302,11 @@ impl<B: MemoryBackend> ListCommand<B> {
         match self.backend.search(&SearchQuery::new(), &filters) {
             Ok(results) => {
                 // Extract learnings
-                let mut learnings: Vec<_> = results.into_iter().map(|r| r.learning).collect();
+                let mut learnings: Vec<_> = results
+                    .into_iter()
+                    .map(|r| r.learning)
+                    .filter(|l| options.provenance.matches(l))
+                    .collect();
 
                 // Sort based on options
                 self.sort_learnings(&mut learnings, options);
@@ -539,6 +549,19 @@ impl<B: MemoryBackend> ListCommand<B> {
                 "   Created: {} | ID: {}",
                 learning.created, learning.id
             ));
+            if let Some(ref provenance) = learning.provenance {
+                let mut parts = vec![format!("Origin: {}", provenance.origin.as_str())];
+                for (label, value) in [
+                    ("Author", &provenance.author),
+                    ("Agent", &provenance.agent),
+                    ("Model", &provenance.model),
+                ] {
+                    if let Some(value) = value {
+                        parts.push(format!("{}: {}", label, value));
+                    }
+                }
+                lines.push(format!("   {}", parts.join(" | ")));
+            }
 
             // Add stats line if available (only shown when surfaced > 0)
             if let (Some(surfaced), Some(referenced), Some(hit_pct)) = (
@@ -686,6 +709,7 @@ This pattern has been archived.
             referenced: None,
             hit_rate_pct: None,
             last_used: None,
+            provenance: None,
         }];
         let output = ListOutput::success(learnings);
 
@@ -737,6 +761,59 @@ This pattern has been archived.
         assert!(output.learnings.iter().any(|l| l.status == "archived"));
     }
 
+    #[test]
+    fn test_list_filters_by_provenance() {
+        use crate::core::{
+            Confidence, LearningCategory, LearningScope, ProvenanceOrigin, WriteGateCriterion,
+        };
+
+        let (_temp, backend) = setup_with_learnings();
+        let learning = CompoundLearning::new(
+            LearningCategory::Convention,
+            "Reflected convention",
+            "Written by reflect from the main agent.",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::StableFact],
+            vec!["rust".to_string()],
+            "test-session",
+        )
+        .with_id("cl_20260101_004")
+        .with_provenance(
+            Provenance::new(ProvenanceOrigin::Reflect)
+                .with_agent(Some("main".to_string()))
+                .with_model(Some("claude-sonnet-4-6".to_string())),
+        );
+        backend.write(&learning).unwrap();
+
+        let cmd = ListCommand::new(backend, Config::default());
+        let options = ListOptions {
+            provenance: ProvenanceFilter {
+                origin: Some(ProvenanceOrigin::Reflect),
+                agent: Some("Main".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let output = cmd.run(&options);
+
+        assert!(output.success);
+        assert_eq!(output.count, 1);
+        assert_eq!(output.learnings[0].id, "cl_20260101_004");
+        let formatted = cmd.format_output(&output, &options);
+        assert!(formatted.contains("   Origin: reflect | Agent: main | Model: claude-sonnet-4-6"));
+
+        // Learnings without provenance never match a filter
+        let options = ListOptions {
+            provenance: ProvenanceFilter {
+                origin: Some(ProvenanceOrigin::Retroflect),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(cmd.run(&options).count, 0);
+    }
+
     #[test]
     fn test_list_stale_only() {
         let (_temp, backend) = setup_with_learnings();
@@ -874,6 +951,7 @@ This pattern has been archived.
             referenced: None,
             hit_rate_pct: None,
             last_used: None,
+            provenance: None,
         }];
         let output = ListOutput::success(learnings);
         let options = ListOptions::default();
@@ -1020,6 +1098,7 @@ This pattern has been archived.
             referenced: Some(3),
             hit_rate_pct: Some(60),
             last_used: Some("2026-02-10".to_string()),
+            provenance: None,
         }];
         let output = ListOutput::success(learnings);
         let options = ListOptions::default();
@@ -1,8 +1,9 @@
 //! Maintain command for Grove.
 //!
 //! Interactive review of stale learnings, with archive and restore operations.
+//! Listing also reports learnings past their review-by date.
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::backends::MemoryBackend;
@@ -55,6 +56,9 @@ pub struct MaintainOutput {
     pub action: String,
     /// Learnings that matched the criteria.
     pub stale_learnings: Vec<StaleLearningInfo>,
+    /// Active learnings past their review-by date.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub review_due: Vec<ReviewDueInfo>,
     /// Learnings that were archived.
     pub archived: Vec<String>,
     /// Learnings that were restored.
@@ -81,6 +85,21 @@ pub struct StaleLearningInfo {
     pub status: String,
 }
 
+/// Info about a learning past its review-by date.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewDueInfo {
+    /// Learning ID.
+    pub id: String,
+    /// Learning summary.
+    pub summary: String,
+    /// Category.
+    pub category: String,
+    /// When the learning was due for review.
+    pub review_by: DateTime<Utc>,
+    /// Days since the review date.
+    pub days_overdue: i64,
+}
+
 /// Info about a failed update.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FailedUpdate {
@@ -97,6 +116,7 @@ impl MaintainOutput {
             success: true,
             action: "list".to_string(),
             stale_learnings,
+            review_due: Vec::new(),
             archived: Vec::new(),
             restored: Vec::new(),
             failed: Vec::new(),
@@ -104,6 +124,12 @@ impl MaintainOutput {
         }
     }
 
+    /// Attach learnings past their review-by date.
+    pub fn with_review_due(mut self, review_due: Vec<ReviewDueInfo>) -> Self {
+        self.review_due = review_due;
+        self
+    }
+
     /// Create an archive output.
     pub fn archive(
         stale_learnings: Vec<StaleLearningInfo>,
@@ -114,6 +140,7 @@ impl MaintainOutput {
             success: failed.is_empty(),
             action: "archive".to_string(),
             stale_learnings,
+            review_due: Vec::new(),
             archived,
             restored: Vec::new(),
             failed,
@@ -127,6 +154,7 @@ impl MaintainOutput {
             success: failed.is_empty(),
             action: "restore".to_string(),
             stale_learnings: Vec::new(),
+            review_due: Vec::new(),
             archived: Vec::new(),
             restored,
             failed,
@@ -140,6 +168,7 @@ impl MaintainOutput {
             success: false,
             action: action.to_string(),
             stale_learnings: Vec::new(),
+            review_due: Vec::new(),
             archived: Vec::new(),
             restored: Vec::new(),
             failed: Vec::new(),
@@ -160,14 +189,15 @@ impl<B: MemoryBackend> MaintainCommand<B> {
         Self { backend, config }
     }
 
-    /// Run the maintain command to list stale learnings.
+    /// Run the maintain command to list stale and review-due learnings.
     pub fn list_stale(&self, options: &MaintainOptions) -> MaintainOutput {
         let stale_days = options.stale_days.unwrap_or(7);
 
-        match self.find_stale_learnings(stale_days) {
-            Ok(stale) => MaintainOutput::list(stale),
-            Err(e) => MaintainOutput::failure("list", e.to_string()),
-        }
+        let result = self.find_stale_learnings(stale_days).and_then(|stale| {
+            let review_due = self.find_review_due_learnings()?;
+            Ok(MaintainOutput::list(stale).with_review_due(review_due))
+        });
+        result.unwrap_or_else(|e| MaintainOutput::failure("list", e.to_string()))
     }
 
     /// Run archive on specified learning IDs.
@@ -186,6 +216,7 @@ impl<B: MemoryBackend> MaintainCommand<B> {
                 success: true,
                 action: "archive (dry run)".to_string(),
                 stale_learnings: stale,
+                review_due: Vec::new(),
                 archived: would_archive,
                 restored: Vec::new(),
                 failed: Vec::new(),
@@ -287,6 +318,33 @@ impl<B: MemoryBackend> MaintainCommand<B> {
         Ok(stale)
     }
 
+    /// Find active learnings whose review-by date has passed.
+    fn find_review_due_learnings(&self) -> Result<Vec<ReviewDueInfo>> {
+        let now = Utc::now();
+
+        let mut due: Vec<ReviewDueInfo> = self
+            .backend
+            .list_all()?
+            .into_iter()
+            .filter(|l| l.status == LearningStatus::Active && l.is_review_due(now))
+            .filter_map(|l| {
+                let review_by = l.review_by?;
+                Some(ReviewDueInfo {
+                    id: l.id,
+                    summary: l.summary,
+                    category: format!("{:?}", l.category).to_lowercase(),
+                    review_by,
+                    days_overdue: (now - review_by).num_days(),
+                })
+            })
+            .collect();
+
+        // Most overdue first
+        due.sort_by_key(|d| std::cmp::Reverse(d.days_overdue));
+
+        Ok(due)
+    }
+
     /// Format output based on options.
     pub fn format_output(&self, output: &MaintainOutput, options: &MaintainOptions) -> String {
         if options.quiet {
@@ -335,6 +393,29 @@ impl<B: MemoryBackend> MaintainCommand<B> {
                         "Run 'grove maintain --archive' to archive stale learnings.".to_string(),
                     );
                 }
+                if !output.review_due.is_empty() {
+                    lines.push(String::new());
+                    lines.push(format!(
+                        "{} learning(s) past their review date:\n",
+                        output.review_due.len()
+                    ));
+                    for (i, learning) in output.review_due.iter().enumerate() {
+                        lines.push(format!(
+                            "{}. [{}] {} (review due {}, {}d ago)",
+                            i + 1,
+                            learning.category,
+                            learning.summary,
+                            learning.review_by.format("%Y-%m-%d"),
+                            learning.days_overdue
+                        ));
+                        lines.push(format!("   ID: {}", learning.id));
+                        lines.push(String::new());
+                    }
+                    lines.push(
+                        "Run 'grove edit <id>' to confirm or update them, or archive them."
+                            .to_string(),
+                    );
+                }
             }
             action if action.starts_with("archive") => {
                 if !output.archived.is_empty() {
@@ -388,6 +469,9 @@ impl<B: MemoryBackend> MaintainCommand<B> {
 mod tests {
     use super::*;
     use crate::backends::MarkdownBackend;
+    use crate::core::{
+        CompoundLearning, Confidence, LearningCategory, LearningScope, WriteGateCriterion,
+    };
     use chrono::Duration;
     use std::fs;
     use tempfile::TempDir;
@@ -528,6 +612,43 @@ This pattern has been archived.
         assert!(output.success);
     }
 
+    #[test]
+    fn test_list_includes_review_due() {
+        let (_temp, backend) = setup_with_learnings();
+        let now = Utc::now();
+        let due = CompoundLearning::new(
+            LearningCategory::Convention,
+            "Staging deploys go through the release bot",
+            "Deploy staging by asking the release bot; manual deploys are blocked.",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::BehaviorChanging],
+            vec!["deploy".to_string()],
+            "test-session",
+        )
+        .with_id("cl_review")
+        .with_review_by(now - Duration::days(3));
+        let not_due = due
+            .clone()
+            .with_id("cl_later")
+            .with_review_by(now + Duration::days(30));
+        backend.write(&due).unwrap();
+        backend.write(&not_due).unwrap();
+
+        let cmd = MaintainCommand::new(backend, Config::default());
+        let options = MaintainOptions::default();
+        let output = cmd.list_stale(&options);
+
+        assert!(output.success);
+        assert_eq!(output.review_due.len(), 1);
+        assert_eq!(output.review_due[0].id, "cl_review");
+        assert_eq!(output.review_due[0].days_overdue, 3);
+
+        let formatted = cmd.format_output(&output, &options);
+        assert!(formatted.contains("1 learning(s) past their review date"));
+        assert!(formatted.contains("   ID: cl_review"));
+    }
+
     #[test]
     fn test_archive_dry_run() {
         let (_temp, backend) = setup_with_learnings();
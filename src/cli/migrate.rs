@@ -0,0 +1,512 @@
+//! Backend migration command for Grove.
+//!
+//! `grove backends migrate --from <backend> --to <backend>` copies the corpus
+//! from one backend to another. Learnings are read with `list_all` and written
+//! unchanged (IDs, statuses, and timestamps included), then the target is
+//! re-read to verify every source ID arrived.
+//!
+//! Each written ID is recorded in a checkpoint under `.grove/`, so a run that
+//! stops partway can be re-run without duplicating entries. The checkpoint is
+//! removed once the migration verifies.
+//!
+//! Personal-scope learnings live in the user's home directory and are shared
+//! across projects, so they are not migrated.
+
+use std::collections::HashSet;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::backends::MemoryBackend;
+use crate::config::project_grove_dir;
+use crate::core::LearningScope;
+use crate::error::{FailOpen, GroveError, Result};
+use crate::util::sync_parent_dir;
+
+/// Options for the migrate command.
+#[derive(Debug, Clone, Default)]
+pub struct MigrateOptions {
+    /// Output as JSON.
+    pub json: bool,
+    /// Suppress output.
+    pub quiet: bool,
+    /// Report what would be migrated without writing.
+    pub dry_run: bool,
+}
+
+/// A learning that could not be written to the target backend.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MigrateFailure {
+    /// The learning that failed.
+    pub learning_id: String,
+    /// Why the write failed.
+    pub error: String,
+}
+
+/// Output format for the migrate command.
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrateOutput {
+    /// Whether the migration completed and verified (or the dry run succeeded).
+    pub success: bool,
+    /// Source backend name.
+    pub from: String,
+    /// Target backend name.
+    pub to: String,
+    /// Whether this was a dry run.
+    pub dry_run: bool,
+    /// Number of project learnings in the source backend.
+    pub source_count: usize,
+    /// Number of personal-scope learnings left in place.
+    pub skipped_personal: usize,
+    /// Learnings already in the target or recorded in the checkpoint.
+    pub already_present: Vec<String>,
+    /// Learnings written in this run (or that would be, for a dry run).
+    pub migrated: Vec<String>,
+    /// Learnings whose write failed.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub failed: Vec<MigrateFailure>,
+    /// Source learnings not found in the target after migration.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub missing: Vec<String>,
+    /// Number of learnings in the target after migration.
+    pub target_count: usize,
+    /// Error message if the migration could not run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl MigrateOutput {
+    fn new(from: &str, to: &str, dry_run: bool) -> Self {
+        Self {
+            success: false,
+            from: from.to_string(),
+            to: to.to_string(),
+            dry_run,
+            source_count: 0,
+            skipped_personal: 0,
+            already_present: Vec::new(),
+            migrated: Vec::new(),
+            failed: Vec::new(),
+            missing: Vec::new(),
+            target_count: 0,
+            error: None,
+        }
+    }
+}
+
+/// IDs written so far by an interrupted migration.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MigrateCheckpoint {
+    /// Learning IDs already written to the target.
+    pub migrated: Vec<String>,
+}
+
+impl MigrateCheckpoint {
+    /// Load a checkpoint, treating a missing or unreadable file as empty.
+    pub fn load(path: &Path) -> Self {
+        if !path.exists() {
+            return Self::default();
+        }
+
+        fs::read_to_string(path)
+            .map_err(GroveError::from)
+            .and_then(|content| serde_json::from_str(&content).map_err(GroveError::from))
+            .fail_open_default("loading migration checkpoint")
+    }
+
+    /// Save the checkpoint atomically (temp file + rename).
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| GroveError::storage(parent, e))?;
+        }
+
+        let temp_path = path.with_extension("json.tmp");
+        {
+            let mut file =
+                fs::File::create(&temp_path).map_err(|e| GroveError::storage(&temp_path, e))?;
+            file.write_all(serde_json::to_string(self)?.as_bytes())
+                .map_err(|e| GroveError::storage(&temp_path, e))?;
+            file.sync_all()
+                .map_err(|e| GroveError::storage(&temp_path, e))?;
+        }
+        fs::rename(&temp_path, path).map_err(|e| GroveError::storage(path, e))?;
+
+        // Sync parent directory for durability (fail-open: write succeeded)
+        let _ = sync_parent_dir(path);
+
+        Ok(())
+    }
+}
+
+/// Path of the checkpoint for a migration between two backends.
+pub fn checkpoint_path(cwd: &Path, from: &str, to: &str) -> PathBuf {
+    project_grove_dir(cwd).join(format!("migrate-{}-to-{}.json", from, to))
+}
+
+/// The migrate command implementation.
+pub struct MigrateCommand<S: MemoryBackend, T: MemoryBackend> {
+    source: S,
+    target: T,
+    from: String,
+    to: String,
+}
+
+impl<S: MemoryBackend, T: MemoryBackend> MigrateCommand<S, T> {
+    /// Create a new migrate command between two named backends.
+    pub fn new(source: S, target: T, from: impl Into<String>, to: impl Into<String>) -> Self {
+        Self {
+            source,
+            target,
+            from: from.into(),
+            to: to.into(),
+        }
+    }
+
+    /// Run the migration.
+    pub fn run(&self, cwd: &Path, options: &MigrateOptions) -> MigrateOutput {
+        let mut output = MigrateOutput::new(&self.from, &self.to, options.dry_run);
+
+        if self.from == self.to {
+            output.error = Some("Source and target are the same backend".to_string());
+            return output;
+        }
+
+        if let Err(e) = self.migrate(cwd, options.dry_run, &mut output) {
+            output.error = Some(e.to_string());
+            return output;
+        }
+
+        output.success = output.failed.is_empty() && output.missing.is_empty();
+        if output.success && !options.dry_run {
+            let path = checkpoint_path(cwd, &self.from, &self.to);
+            if path.exists() {
+                fs::remove_file(&path)
+                    .map_err(|e| GroveError::storage(&path, e))
+                    .fail_open_default("removing migration checkpoint");
+            }
+        }
+        output
+    }
+
+    fn migrate(&self, cwd: &Path, dry_run: bool, output: &mut MigrateOutput) -> Result<()> {
+        let (personal, learnings): (Vec<_>, Vec<_>) = self
+            .source
+            .list_all()
+            .map_err(|e| GroveError::backend(format!("Failed to read {}: {}", self.from, e)))?
+            .into_iter()
+            .partition(|l| l.scope == LearningScope::Personal);
+        output.skipped_personal = personal.len();
+        output.source_count = learnings.len();
+
+        let path = checkpoint_path(cwd, &self.from, &self.to);
+        let mut checkpoint = MigrateCheckpoint::load(&path);
+        let mut done: HashSet<String> = self.target_ids()?;
+        done.extend(checkpoint.migrated.iter().cloned());
+
+        for learning in &learnings {
+            if done.contains(&learning.id) {
+                output.already_present.push(learning.id.clone());
+                continue;
+            }
+
+            if dry_run {
+                output.migrated.push(learning.id.clone());
+                continue;
+            }
+
+            let error = match self.target.write(learning) {
+                Ok(result) if result.success => None,
+                Ok(result) => Some(result.message.unwrap_or_else(|| "write failed".to_string())),
+                Err(e) => Some(e.to_string()),
+            };
+
+            match error {
+                None => {
+                    checkpoint.migrated.push(learning.id.clone());
+                    checkpoint.save(&path)?;
+                    done.insert(learning.id.clone());
+                    output.migrated.push(learning.id.clone());
+                }
+                Some(error) => output.failed.push(MigrateFailure {
+                    learning_id: learning.id.clone(),
+                    error,
+                }),
+            }
+        }
+
+        if dry_run {
+            return Ok(());
+        }
+
+        // Verify: every source ID must now be readable from the target
+        let target_ids = self.target_ids()?;
+        output.target_count = target_ids.len();
+        output.missing = learnings
+            .iter()
+            .filter(|l| !target_ids.contains(&l.id))
+            .map(|l| l.id.clone())
+            .collect();
+
+        // Forget missing IDs so a re-run writes them again
+        if !output.missing.is_empty() && !checkpoint.migrated.is_empty() {
+            checkpoint
+                .migrated
+                .retain(|id| !output.missing.contains(id));
+            checkpoint.save(&path)?;
+        }
+
+        Ok(())
+    }
+
+    fn target_ids(&self) -> Result<HashSet<String>> {
+        let learnings = self
+            .target
+            .list_all()
+            .map_err(|e| GroveError::backend(format!("Failed to read {}: {}", self.to, e)))?;
+        Ok(learnings.into_iter().map(|l| l.id).collect())
+    }
+
+    /// Format output based on options.
+    pub fn format_output(&self, output: &MigrateOutput, options: &MigrateOptions) -> String {
+        if options.quiet {
+            return String::new();
+        }
+
+        if options.json {
+            return serde_json::to_string_pretty(output).unwrap_or_else(|_| "{}".to_string());
+        }
+
+        if let Some(ref error) = output.error {
+            return format!("Migration failed: {}\n", error);
+        }
+
+        let mut out = format!(
+            "{} {} -> {}{}\n\n",
+            if output.dry_run {
+                "Migration plan"
+            } else {
+                "Migrated"
+            },
+            output.from,
+            output.to,
+            if output.dry_run { " (dry run)" } else { "" }
+        );
+        out.push_str(&format!(
+            "  Source learnings:  {} ({} personal skipped)\n",
+            output.source_count, output.skipped_personal
+        ));
+        out.push_str(&format!(
+            "  Already in target: {}\n",
+            output.already_present.len()
+        ));
+        out.push_str(&format!(
+            "  {}{}\n",
+            if output.dry_run {
+                "Would migrate:     "
+            } else {
+                "Migrated:          "
+            },
+            output.migrated.len()
+        ));
+
+        if output.dry_run {
+            return out;
+        }
+
+        out.push_str(&format!("  Failed:            {}\n", output.failed.len()));
+        for failure in &output.failed {
+            out.push_str(&format!("    {}: {}\n", failure.learning_id, failure.error));
+        }
+
+        if output.missing.is_empty() {
+            out.push_str(&format!(
+                "\nVerified: all {} source learnings present in {} ({} total)\n",
+                output.source_count, output.to, output.target_count
+            ));
+        } else {
+            out.push_str(&format!(
+                "\nVerification failed: {} of {} source learnings missing from {}: {}\n",
+                output.missing.len(),
+                output.source_count,
+                output.to,
+                output.missing.join(", ")
+            ));
+            out.push_str("Re-run the command to resume; already migrated learnings are skipped.\n");
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::{MarkdownBackend, TotalRecallBackend};
+    use crate::core::{
+        CompoundLearning, Confidence, LearningCategory, LearningStatus, WriteGateCriterion,
+    };
+    use chrono::{TimeZone, Utc};
+    use tempfile::TempDir;
+
+    fn learning(id: &str, scope: LearningScope) -> CompoundLearning {
+        let mut learning = CompoundLearning::new(
+            LearningCategory::Pitfall,
+            format!("Avoid N+1 queries in dashboard {}", id),
+            "Use includes(:posts) when loading users for the dashboard.",
+            scope,
+            Confidence::High,
+            vec![WriteGateCriterion::BehaviorChanging],
+            vec!["performance".to_string()],
+            "session-1",
+        )
+        .with_id(id);
+        learning.timestamp = Utc.with_ymd_and_hms(2026, 1, 15, 10, 30, 0).unwrap();
+        learning
+    }
+
+    fn setup() -> (TempDir, MigrateCommand<MarkdownBackend, TotalRecallBackend>) {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join(".grove")).unwrap();
+        fs::create_dir_all(temp.path().join("memory")).unwrap();
+
+        let source = MarkdownBackend::with_paths(
+            temp.path().join(".grove").join("learnings.md"),
+            temp.path().join("personal.md"),
+        );
+        source
+            .write(&learning("cl_20260115_001", LearningScope::Project))
+            .unwrap();
+        let mut archived = learning("cl_20260115_002", LearningScope::Project);
+        archived.status = LearningStatus::Archived;
+        source.write(&archived).unwrap();
+        source
+            .write(&learning("cl_20260115_003", LearningScope::Personal))
+            .unwrap();
+
+        let target = TotalRecallBackend::with_paths(
+            temp.path().join("memory"),
+            temp.path().join("tr-personal.md"),
+            temp.path(),
+        );
+
+        (
+            temp,
+            MigrateCommand::new(source, target, "markdown", "total-recall"),
+        )
+    }
+
+    #[test]
+    fn test_migrate_preserves_ids_statuses_and_timestamps() {
+        let (temp, cmd) = setup();
+
+        let output = cmd.run(temp.path(), &MigrateOptions::default());
+        assert!(output.success, "{:?}", output);
+        assert_eq!(output.source_count, 2);
+        assert_eq!(output.skipped_personal, 1);
+        assert_eq!(output.migrated, vec!["cl_20260115_001", "cl_20260115_002"]);
+        assert!(output.missing.is_empty());
+
+        let mut migrated = cmd.target.list_all().unwrap();
+        migrated.sort_by(|a, b| a.id.cmp(&b.id));
+        assert_eq!(migrated.len(), 2);
+        assert_eq!(migrated[0].status, LearningStatus::Active);
+        assert_eq!(migrated[1].status, LearningStatus::Archived);
+        assert_eq!(
+            migrated[0].timestamp.format("%Y-%m-%d %H:%M").to_string(),
+            "2026-01-15 10:30"
+        );
+
+        assert!(!checkpoint_path(temp.path(), "markdown", "total-recall").exists());
+
+        let text = cmd.format_output(&output, &MigrateOptions::default());
+        assert!(text.contains("Migrated markdown -> total-recall"));
+        assert!(text.contains("Verified: all 2 source learnings present in total-recall"));
+    }
+
+    #[test]
+    fn test_migrate_dry_run_writes_nothing() {
+        let (temp, cmd) = setup();
+        let options = MigrateOptions {
+            dry_run: true,
+            ..Default::default()
+        };
+
+        let output = cmd.run(temp.path(), &options);
+        assert!(output.success);
+        assert_eq!(output.migrated.len(), 2);
+        assert!(cmd.target.list_all().unwrap().is_empty());
+
+        let text = cmd.format_output(&output, &options);
+        assert!(text.contains("(dry run)"));
+        assert!(text.contains("Would migrate:     2"));
+    }
+
+    #[test]
+    fn test_migrate_resumes_from_checkpoint_without_duplicates() {
+        let (temp, cmd) = setup();
+
+        // Simulate a run that wrote the first learning, then stopped
+        let first = cmd.source.list_all().unwrap().remove(0);
+        cmd.target.write(&first).unwrap();
+        MigrateCheckpoint {
+            migrated: vec![first.id.clone()],
+        }
+        .save(&checkpoint_path(temp.path(), "markdown", "total-recall"))
+        .unwrap();
+
+        let output = cmd.run(temp.path(), &MigrateOptions::default());
+        assert!(output.success, "{:?}", output);
+        assert_eq!(output.already_present, vec![first.id.clone()]);
+        assert_eq!(output.migrated, vec!["cl_20260115_002"]);
+
+        let ids: Vec<String> = cmd
+            .target
+            .list_all()
+            .unwrap()
+            .into_iter()
+            .map(|l| l.id)
+            .collect();
+        assert_eq!(ids.iter().filter(|id| **id == first.id).count(), 1);
+        assert_eq!(output.target_count, 2);
+    }
+
+    #[test]
+    fn test_migrate_reports_missing_checkpointed_learning() {
+        let (temp, cmd) = setup();
+
+        // A checkpoint claims a write the target never received
+        MigrateCheckpoint {
+            migrated: vec!["cl_20260115_001".to_string()],
+        }
+        .save(&checkpoint_path(temp.path(), "markdown", "total-recall"))
+        .unwrap();
+
+        let output = cmd.run(temp.path(), &MigrateOptions::default());
+        assert!(!output.success);
+        assert_eq!(output.missing, vec!["cl_20260115_001"]);
+
+        let text = cmd.format_output(&output, &MigrateOptions::default());
+        assert!(text.contains("Verification failed: 1 of 2"));
+
+        // Re-running retries the missing learning
+        let output = cmd.run(temp.path(), &MigrateOptions::default());
+        assert!(output.success, "{:?}", output);
+        assert_eq!(output.migrated, vec!["cl_20260115_001"]);
+    }
+
+    #[test]
+    fn test_migrate_same_backend_fails() {
+        let temp = TempDir::new().unwrap();
+        let cmd = MigrateCommand::new(
+            MarkdownBackend::new(temp.path().join("a.md")),
+            MarkdownBackend::new(temp.path().join("b.md")),
+            "markdown",
+            "markdown",
+        );
+
+        let output = cmd.run(temp.path(), &MigrateOptions::default());
+        assert!(!output.success);
+        assert!(output.error.unwrap().contains("same backend"));
+    }
+}
@@ -2,8 +2,8 @@
 //!
 //! This module provides CLI commands for Grove, organized into:
 //! - **Core commands**: reflect, skip, observe (agent interaction)
-//! - **User commands**: search, list, stats, maintain (user-facing)
-//! - **Utility commands**: init, backends, tickets, debug, trace, clean
+//! - **User commands**: search, list, stats, maintain, edit, history, export (user-facing)
+//! - **Utility commands**: init, backends (including migrate), index, tickets, debug, trace, clean
 //! - **Hook command**: hook (Claude Code integration)
 
 // Core commands
@@ -14,8 +14,12 @@ pub mod skip;
 
 // User commands
 pub mod consolidate;
+pub mod edit;
+pub mod export;
+pub mod history;
 pub mod list;
 pub mod maintain;
+pub mod migrate;
 pub mod retroflect;
 pub mod review;
 pub mod search;
@@ -28,6 +32,7 @@ pub mod eval;
 pub mod backends_cmd;
 pub mod clean;
 pub mod debug;
+pub mod index;
 pub mod init;
 pub mod sessions;
 pub mod tickets_cmd;
@@ -36,9 +41,14 @@ pub mod trace;
 pub use backends_cmd::BackendsCommand;
 pub use clean::CleanCommand;
 pub use debug::DebugCommand;
+pub use edit::EditCommand;
+pub use export::ExportCommand;
+pub use history::HistoryCommand;
+pub use index::IndexCommand;
 pub use init::InitCommand;
 pub use list::ListCommand;
 pub use maintain::MaintainCommand;
+pub use migrate::MigrateCommand;
 pub use observe::ObserveCommand;
 pub use ref_cmd::RefCommand;
 pub use reflect::ReflectCommand;
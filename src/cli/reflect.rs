@@ -9,10 +9,12 @@ use std::path::Path;
 use serde::{Deserialize, Serialize};
 
 use crate::backends::{MemoryBackend, SearchFilters, SearchQuery};
-use crate::config::{project_stats_log_path, Config};
+use crate::cli::history::record_revision;
+use crate::config::{git_author, project_stats_log_path, Config};
 use crate::core::{
     validate_with_duplicates_and_quality_semantic, CandidateLearning, EventType, GateStatus,
-    QualityCheckMode, ReflectionResult, RejectedCandidate, SessionState, WriteGateMode,
+    LearningRevision, Provenance, ProvenanceOrigin, QualityCheckMode, ReflectionResult,
+    RejectedCandidate, RevisionSource, SessionState, WriteGateMode,
 };
 use crate::error::{FailOpen, Result};
 use crate::stats::StatsLogger;
@@ -39,7 +41,9 @@ pub const REFLECT_SCHEMA_EXAMPLE: &str = r#"{
   "learnings_used": [
     { "id": "cl_001", "how": "Applied retry pattern from this learning" }
   ],
-  "reflection_notes": "Applied cl_001 guidance for error handling."
+  "reflection_notes": "Applied cl_001 guidance for error handling.",
+  "agent": "main",
+  "model": "claude-sonnet-4-6"
 }"#;
 
 /// Schema field descriptions for help text.
@@ -57,6 +61,8 @@ pub const REFLECT_SCHEMA_HELP: &str = r#"STDIN JSON SCHEMA:
     relevance_context (optional) When/where to surface this learning during retrieval
   learnings_used  (optional) Array of { id, how } for learnings referenced during the session
   reflection_notes (optional) Free-form notes about applied learnings
+  agent           (optional) Agent writing the reflection ("main" or a subagent name)
+  model           (optional) Model writing the reflection (defaults to the session's model)
 
 EXAMPLE:
   grove reflect --json <<'EOF'
@@ -128,6 +134,12 @@ pub struct ReflectInput {
     /// Optional ratings for previously surfaced learnings.
     #[serde(default)]
     pub ratings: Option<Vec<LearningRating>>,
+    /// Agent writing the reflection (e.g. "main" or a subagent name).
+    #[serde(default)]
+    pub agent: Option<String>,
+    /// Model writing the reflection (defaults to the model recorded at session start).
+    #[serde(default)]
+    pub model: Option<String>,
 }
 
 /// Output format for the reflect command.
@@ -304,9 +316,16 @@ impl<S: SessionStore, B: MemoryBackend> ReflectCommand<S, B> {
         let learnings_accepted = valid_learnings.len();
 
         // Assign unique IDs from backend atomically (prevents race within batch)
+        // and record who produced each learning
+        let provenance = Provenance::new(ProvenanceOrigin::Reflect)
+            .with_author(git_author(Path::new(&session.cwd)))
+            .with_agent(input.agent.clone())
+            .with_model(input.model.clone().or_else(|| session.model.clone()))
+            .with_transcript_path(Some(session.transcript_path.clone()));
         let ids = self.backend.next_ids(valid_learnings.len());
         for (learning, id) in valid_learnings.iter_mut().zip(ids) {
             learning.id = id;
+            learning.provenance = Some(provenance.clone());
         }
 
         // Write valid learnings to backend
@@ -354,6 +373,15 @@ impl<S: SessionStore, B: MemoryBackend> ReflectCommand<S, B> {
             )
             .fail_open_default("logging reflection stats");
 
+        // Record the first revision of each written learning
+        for learning in valid_learnings
+            .iter()
+            .filter(|l| learning_ids.contains(&l.id))
+        {
+            let revision = LearningRevision::created(learning, RevisionSource::Reflect);
+            record_revision(&self.backend, &stats_logger, &revision);
+        }
+
         // Log individual rejected candidates for retrospective analysis
         for rejected_candidate in &rejected {
             stats_logger
@@ -655,6 +683,12 @@ mod tests {
             tags: vec!["async".to_string(), "io".to_string()],
             context_files: None,
         relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         }
     }
 
@@ -717,6 +751,8 @@ mod tests {
             learnings_used: None,
             reflection_notes: None,
             ratings: None,
+            agent: None,
+            model: None,
         };
 
         let options = ReflectOptions::default();
@@ -744,6 +780,8 @@ mod tests {
             learnings_used: None,
             reflection_notes: None,
             ratings: None,
+            agent: None,
+            model: None,
         };
 
         let options = ReflectOptions::default();
@@ -773,6 +811,8 @@ mod tests {
             learnings_used: None,
             reflection_notes: None,
             ratings: None,
+            agent: None,
+            model: None,
         };
 
         let options = ReflectOptions::default();
@@ -784,6 +824,49 @@ mod tests {
         assert_eq!(output.rejected.len(), 1);
     }
 
+    #[test]
+    fn test_reflect_records_provenance() {
+        let (temp, store, backend) = setup();
+        let config = Config::default();
+
+        let mut session = SessionState::new(
+            "test-session",
+            temp.path().to_string_lossy(),
+            "/tmp/transcript.jsonl",
+        );
+        session.model = Some("claude-sonnet-4-6".to_string());
+        store.put(&session).unwrap();
+
+        let learnings_path = temp.path().join(".grove").join("learnings.md");
+        let cmd = ReflectCommand::new(Arc::clone(&store), backend, config);
+        let input = ReflectInput {
+            session_id: "test-session".to_string(),
+            candidates: vec![valid_candidate()],
+            learnings_used: None,
+            reflection_notes: None,
+            ratings: None,
+            agent: Some("main".to_string()),
+            model: None,
+        };
+        let output = cmd.run_with_input(&input, &ReflectOptions::default());
+        assert!(output.success);
+
+        let learnings = MarkdownBackend::new(&learnings_path).list_all().unwrap();
+        let provenance = learnings[0].provenance.as_ref().unwrap();
+        assert_eq!(provenance.origin, ProvenanceOrigin::Reflect);
+        assert_eq!(provenance.agent.as_deref(), Some("main"));
+        // The model falls back to the one recorded at session start
+        assert_eq!(provenance.model.as_deref(), Some("claude-sonnet-4-6"));
+        assert_eq!(
+            provenance.transcript_path.as_deref(),
+            Some("/tmp/transcript.jsonl")
+        );
+        assert_eq!(
+            provenance.grove_version.as_deref(),
+            Some(env!("CARGO_PKG_VERSION"))
+        );
+    }
+
     #[test]
     fn test_reflect_updates_session_state() {
         let (_temp, store, backend) = setup();
@@ -801,6 +884,8 @@ mod tests {
             learnings_used: None,
             reflection_notes: None,
             ratings: None,
+            agent: None,
+            model: None,
         };
 
         let options = ReflectOptions::default();
@@ -833,6 +918,8 @@ mod tests {
             learnings_used: None,
             reflection_notes: None,
             ratings: None,
+            agent: None,
+            model: None,
         };
 
         let options = ReflectOptions::default();
@@ -852,6 +939,8 @@ mod tests {
             learnings_used: None,
             reflection_notes: None,
             ratings: None,
+            agent: None,
+            model: None,
         };
 
         let output2 = cmd2.run_with_input(&input2, &options);
@@ -875,6 +964,8 @@ mod tests {
             learnings_used: None,
             reflection_notes: None,
             ratings: None,
+            agent: None,
+            model: None,
         };
 
         let options = ReflectOptions::default();
@@ -1037,6 +1128,8 @@ mod tests {
             }]),
             reflection_notes: None,
             ratings: None,
+            agent: None,
+            model: None,
         };
 
         let injected = vec!["cl_001".to_string(), "cl_002".to_string()];
@@ -1054,6 +1147,8 @@ mod tests {
             learnings_used: None,
             reflection_notes: Some("I applied learning cl_001 for the API calls and used learning cl_002 for error handling.".to_string()),
             ratings: None,
+            agent: None,
+            model: None,
         };
 
         let injected = vec![
@@ -1077,6 +1172,8 @@ mod tests {
             learnings_used: None,
             reflection_notes: Some("The fix was based on cl_001 approach.".to_string()),
             ratings: None,
+            agent: None,
+            model: None,
         };
 
         let injected = vec!["cl_001".to_string()];
@@ -1097,6 +1194,8 @@ mod tests {
             learnings_used: None,
             reflection_notes: None,
             ratings: None,
+            agent: None,
+            model: None,
         };
 
         let injected = vec!["cl_001".to_string()];
@@ -1114,6 +1213,8 @@ mod tests {
             learnings_used: None,
             reflection_notes: Some("Applied Learning CL_001 for the fix.".to_string()),
             ratings: None,
+            agent: None,
+            model: None,
         };
 
         let injected = vec!["cl_001".to_string()];
@@ -1130,6 +1231,8 @@ mod tests {
             learnings_used: None,
             reflection_notes: Some("I did not use any previous learnings.".to_string()),
             ratings: None,
+            agent: None,
+            model: None,
         };
 
         let injected = vec!["cl_001".to_string(), "cl_002".to_string()];
@@ -1149,6 +1252,8 @@ mod tests {
             }]),
             reflection_notes: None,
             ratings: None,
+            agent: None,
+            model: None,
         };
 
         let injected = vec!["cl_001".to_string()];
@@ -1298,6 +1403,8 @@ mod tests {
                     useful: false,
                 },
             ]),
+            agent: None,
+            model: None,
         };
 
         let options = ReflectOptions::default();
@@ -9,12 +9,16 @@ use std::path::{Path, PathBuf};
 use serde::{Deserialize, Serialize};
 
 use crate::backends::{MarkdownBackend, MemoryBackend, SearchFilters, SearchQuery};
-use crate::config::{project_grove_dir, project_learnings_path, project_stats_log_path, Config};
-use crate::core::learning::CompoundLearning;
+use crate::cli::history::record_revision;
+use crate::config::{
+    git_author, project_grove_dir, project_learnings_path, project_stats_log_path, Config,
+};
+use crate::core::learning::{CompoundLearning, Provenance, ProvenanceOrigin};
 use crate::core::quality::QualityCheckMode;
 use crate::core::reflect::{
     validate_with_duplicates_and_quality_semantic, CandidateLearning, WriteGateMode,
 };
+use crate::core::revision::{LearningRevision, RevisionSource};
 use crate::eval::corpus::{parse_session_transcript, SessionSummary};
 use crate::llm;
 use crate::llm::batch::{self, BatchRequest, BatchResultType};
@@ -316,7 +320,7 @@ pub fn format_output(output: &RetroflectOutput, options: &RetroflectOptions) ->
         }
         if !reason_counts.is_empty() {
             let mut sorted: Vec<_> = reason_counts.into_iter().collect();
-            sorted.sort_by(|a, b| b.1.cmp(&a.1));
+            sorted.sort_by_key(|s| std::cmp::Reverse(s.1));
             let breakdown: Vec<String> = sorted
                 .iter()
                 .map(|(cat, count)| format!("{} {}", count, cat))
@@ -394,7 +398,7 @@ fn run_inner(
     }
 
     // Sort by timestamp (most recent first) and apply limit
-    all_sessions.sort_by(|a, b| b.1.timestamp.cmp(&a.1.timestamp));
+    all_sessions.sort_by_key(|s| std::cmp::Reverse(s.1.timestamp));
     all_sessions.truncate(options.limit);
     let total_session_count = all_sessions.len();
 
@@ -573,7 +577,7 @@ fn run_inner_batch(options: &RetroflectOptions, cwd: &Path) -> Result<Retroflect
         }
     }
 
-    all_sessions.sort_by(|a, b| b.1.timestamp.cmp(&a.1.timestamp));
+    all_sessions.sort_by_key(|s| std::cmp::Reverse(s.1.timestamp));
     all_sessions.truncate(options.limit);
 
     if all_sessions.is_empty() {
@@ -750,6 +754,7 @@ fn run_inner_batch(options: &RetroflectOptions, cwd: &Path) -> Result<Retroflect
                 response_text,
                 batch_accepted,
                 &config,
+                &options.model,
             ),
             BatchResultType::Failed(reason) => SessionResult {
                 session_id: session.session_id.clone(),
@@ -821,6 +826,7 @@ fn process_batch_result(
     response_text: &str,
     batch_accepted: &mut Vec<CompoundLearning>,
     config: &Config,
+    model: &str,
 ) -> SessionResult {
     if response_text.trim().is_empty() {
         return SessionResult {
@@ -901,10 +907,12 @@ fn process_batch_result(
         Some((&grove_dir, &config.gate.semantic_dedup)),
     );
 
-    // Assign IDs and write
+    // Assign IDs and record provenance
+    let provenance = retroflect_provenance(session, project_path, model);
     let ids = backend.next_ids(valid_learnings.len());
     for (learning, id) in valid_learnings.iter_mut().zip(ids) {
         learning.id = id;
+        learning.provenance = Some(provenance.clone());
     }
 
     // Ensure .grove/ exists
@@ -912,6 +920,9 @@ fn process_batch_result(
         let _ = init_grove_dir(&grove_dir);
     }
 
+    let stats_path = project_stats_log_path(project_path);
+    let stats_logger = StatsLogger::new(&stats_path);
+
     let mut accepted_count = 0;
     let mut accepted_summaries = Vec::new();
     for learning in &valid_learnings {
@@ -922,6 +933,8 @@ fn process_batch_result(
                     category: learning.category.display_name().to_string(),
                     summary: learning.summary.clone(),
                 });
+                let revision = LearningRevision::created(learning, RevisionSource::Retroflect);
+                record_revision(&backend, &stats_logger, &revision);
             }
             Ok(_) => {
                 eprintln!(
@@ -939,8 +952,6 @@ fn process_batch_result(
     batch_accepted.extend(valid_learnings);
 
     // Log stats event
-    let stats_path = project_stats_log_path(project_path);
-    let stats_logger = StatsLogger::new(&stats_path);
     if let Err(e) = stats_logger.append_retroflect(
         &session_id_for_validation,
         &session.session_id,
@@ -961,6 +972,14 @@ fn process_batch_result(
     }
 }
 
+/// Provenance for learnings mined from a past session.
+fn retroflect_provenance(session: &SessionSummary, project_path: &Path, model: &str) -> Provenance {
+    Provenance::new(ProvenanceOrigin::Retroflect)
+        .with_author(git_author(project_path))
+        .with_model(Some(model.to_string()))
+        .with_transcript_path(Some(session.transcript_path.display().to_string()))
+}
+
 /// Process a single session: LLM synthesis → validation → write.
 #[allow(clippy::too_many_arguments)]
 fn process_session(
@@ -1085,10 +1104,12 @@ fn process_session(
         Some((&grove_dir, &config.gate.semantic_dedup)),
     );
 
-    // Assign IDs and write
+    // Assign IDs, record provenance, and write
+    let provenance = retroflect_provenance(session, project_path, &options.model);
     let ids = backend.next_ids(valid_learnings.len());
     for (learning, id) in valid_learnings.iter_mut().zip(ids) {
         learning.id = id;
+        learning.provenance = Some(provenance.clone());
     }
 
     let mut accepted_count = 0;
@@ -1101,6 +1122,8 @@ fn process_session(
                     category: learning.category.display_name().to_string(),
                     summary: learning.summary.clone(),
                 });
+                let revision = LearningRevision::created(learning, RevisionSource::Retroflect);
+                record_revision(backend, stats_logger, &revision);
             }
             Ok(_) => {
                 eprintln!(
@@ -1619,6 +1642,12 @@ mod tests {
             tags: vec!["rust".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         // Simulate the tag injection logic
@@ -1641,6 +1670,12 @@ mod tests {
             tags: vec!["retroflect".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let mut tags = candidate.tags.clone();
@@ -2088,6 +2123,7 @@ mod tests {
     fn make_test_session(id: &str, project_cwd: &Path) -> SessionSummary {
         SessionSummary {
             session_id: id.to_string(),
+            transcript_path: project_cwd.join(format!("{}.jsonl", id)),
             project_cwd: project_cwd.to_path_buf(),
             timestamp: None,
             user_turns: 10,
@@ -2352,6 +2388,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn process_session_records_provenance() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let project_dir = setup_project_with_grove(dir.path());
+        let session = make_test_session("test-provenance", &project_dir);
+        let backend = make_backend(&project_dir);
+        let stats_logger = StatsLogger::new(project_dir.join(".grove/stats.log"));
+        let config = Config::default();
+        let options = RetroflectOptions::default();
+        let mut batch_accepted = Vec::new();
+
+        let response = mock_llm_json_response();
+        let caller =
+            move |_: &str, _: &str, _: &str, _: &str| -> Option<String> { Some(response.clone()) };
+
+        let result = process_session(
+            &session,
+            &project_dir,
+            &[],
+            &mut batch_accepted,
+            &backend,
+            &stats_logger,
+            &config,
+            &options,
+            &caller,
+        );
+
+        assert!(result.accepted > 0);
+        let written = backend.list_all().unwrap();
+        let provenance = written[0].provenance.as_ref().unwrap();
+        assert_eq!(provenance.origin, ProvenanceOrigin::Retroflect);
+        assert_eq!(provenance.model.as_deref(), Some(options.model.as_str()));
+        assert_eq!(
+            provenance.transcript_path,
+            Some(session.transcript_path.display().to_string())
+        );
+    }
+
     #[test]
     fn process_session_logs_stats_event() {
         let dir = tempfile::TempDir::new().unwrap();
@@ -2830,8 +2904,14 @@ mod tests {
         let config = Config::default();
         let mut batch_accepted = Vec::new();
 
-        let result =
-            process_batch_result(&session, &project_dir, "   ", &mut batch_accepted, &config);
+        let result = process_batch_result(
+            &session,
+            &project_dir,
+            "   ",
+            &mut batch_accepted,
+            &config,
+            "test-model",
+        );
         assert!(result.skip_reason.is_some());
         assert!(result.skip_reason.unwrap().contains("empty response"));
     }
@@ -2850,6 +2930,7 @@ mod tests {
             "not valid json",
             &mut batch_accepted,
             &config,
+            "test-model",
         );
         assert!(result.skip_reason.is_some());
         assert!(result.skip_reason.unwrap().contains("parse"));
@@ -2870,6 +2951,7 @@ mod tests {
             &response,
             &mut batch_accepted,
             &config,
+            "test-model",
         );
         assert!(result.skip_reason.is_none());
         assert_eq!(result.candidates, 2);
@@ -2892,6 +2974,7 @@ mod tests {
             &response,
             &mut batch_accepted,
             &config,
+            "test-model",
         );
         let first_accepted = result1.accepted;
         assert!(first_accepted > 0, "first session should accept learnings");
@@ -2904,6 +2987,7 @@ mod tests {
             &response,
             &mut batch_accepted,
             &config,
+            "test-model",
         );
         // Second session should accept fewer (or zero) because of dedup
         assert!(
@@ -2938,6 +3022,7 @@ mod tests {
             response,
             &mut batch_accepted,
             &config,
+            "test-model",
         );
         assert!(result.skip_reason.is_none());
         if result.accepted > 0 {
@@ -2958,8 +3043,14 @@ mod tests {
         let config = Config::default();
         let mut batch_accepted = Vec::new();
 
-        let result =
-            process_batch_result(&session, &project_dir, "[]", &mut batch_accepted, &config);
+        let result = process_batch_result(
+            &session,
+            &project_dir,
+            "[]",
+            &mut batch_accepted,
+            &config,
+            "test-model",
+        );
         assert!(result.skip_reason.is_none());
         assert_eq!(result.candidates, 0);
         assert_eq!(result.accepted, 0);
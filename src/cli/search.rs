@@ -1,10 +1,15 @@
 //! Search command for Grove.
 //!
-//! Searches for learnings across active backends.
+//! Searches for learnings across active backends. Queries use the field
+//! syntax in [`crate::backends::query`] (`tag:rust -"old api"`), and
+//! `--explain` shows how each result was scored.
 
 use serde::{Deserialize, Serialize};
 
+use crate::backends::query::parse_query;
 use crate::backends::{MemoryBackend, SearchFilters, SearchQuery, SearchResult};
+use crate::config::RetrievalConfig;
+use crate::stats::scoring::{score_breakdown, ScoreBreakdown};
 
 /// Options for the search command.
 #[derive(Debug, Clone, Default)]
@@ -17,6 +22,8 @@ pub struct SearchOptions {
     pub limit: Option<usize>,
     /// Include archived learnings.
     pub include_archived: bool,
+    /// Show per-field score contributions for each result.
+    pub explain: bool,
 }
 
 /// Output format for the search command.
@@ -30,6 +37,9 @@ pub struct SearchOutput {
     pub count: usize,
     /// The search results.
     pub results: Vec<SearchResultInfo>,
+    /// BM25 variant used for `--explain` ("standard" or "boosted").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bm25_profile: Option<String>,
     /// Error message if search failed.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
@@ -50,6 +60,22 @@ pub struct SearchResultInfo {
     pub tags: Vec<String>,
     /// Status.
     pub status: String,
+    /// Where the learning came from ("project" or "org").
+    pub source: String,
+    /// Score contributions (with `--explain`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explain: Option<ScoreExplanation>,
+}
+
+/// How a search result was scored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreExplanation {
+    /// Per-field contributions from the hook scoring function.
+    pub scoring: ScoreBreakdown,
+    /// Normalized BM25 score from Tantivy rescoring (requires the
+    /// `tantivy-search` feature).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bm25: Option<f64>,
 }
 
 impl From<&SearchResult> for SearchResultInfo {
@@ -61,6 +87,8 @@ impl From<&SearchResult> for SearchResultInfo {
             relevance: result.relevance,
             tags: result.learning.tags.clone(),
             status: format!("{:?}", result.learning.status).to_lowercase(),
+            source: result.source.as_str().to_string(),
+            explain: None,
         }
     }
 }
@@ -74,6 +102,7 @@ impl SearchOutput {
             query: query.into(),
             count,
             results,
+            bm25_profile: None,
             error: None,
         }
     }
@@ -85,6 +114,7 @@ impl SearchOutput {
             query: query.into(),
             count: 0,
             results: Vec::new(),
+            bm25_profile: None,
             error: Some(error.into()),
         }
     }
@@ -93,12 +123,23 @@ impl SearchOutput {
 /// The search command implementation.
 pub struct SearchCommand<B: MemoryBackend> {
     backend: B,
+    #[cfg_attr(not(feature = "tantivy-search"), allow(dead_code))]
+    retrieval: RetrievalConfig,
 }
 
 impl<B: MemoryBackend> SearchCommand<B> {
     /// Create a new search command.
     pub fn new(backend: B) -> Self {
-        Self { backend }
+        Self {
+            backend,
+            retrieval: RetrievalConfig::default(),
+        }
+    }
+
+    /// Use these retrieval settings when explaining BM25 rescoring.
+    pub fn with_retrieval(mut self, retrieval: RetrievalConfig) -> Self {
+        self.retrieval = retrieval;
+        self
     }
 
     /// Run the search command with the given query.
@@ -108,19 +149,16 @@ impl<B: MemoryBackend> SearchCommand<B> {
             return SearchOutput::failure("", "Search query cannot be empty");
         }
 
-        // Build search query from keywords
-        let keywords: Vec<String> = trimmed_query
-            .split_whitespace()
-            .map(|s| s.to_string())
-            .collect();
-        let search_query = SearchQuery::with_keywords(keywords);
-
-        // Build filters
-        let mut filters = if options.include_archived {
+        // Build filters, then let the query syntax narrow them
+        let base_filters = if options.include_archived {
             SearchFilters::all()
         } else {
             SearchFilters::active_only()
         };
+        let (search_query, mut filters) = match parse_query(trimmed_query, base_filters) {
+            Ok(parsed) => parsed,
+            Err(e) => return SearchOutput::failure(trimmed_query, e.to_string()),
+        };
         if let Some(limit) = options.limit {
             filters = filters.max_results(limit);
         }
@@ -142,12 +180,70 @@ impl<B: MemoryBackend> SearchCommand<B> {
 
                 let result_infos: Vec<SearchResultInfo> =
                     results.iter().map(SearchResultInfo::from).collect();
-                SearchOutput::success(trimmed_query, result_infos)
+                let mut output = SearchOutput::success(trimmed_query, result_infos);
+                if options.explain {
+                    self.explain(&mut output, &results, &search_query);
+                }
+                output
             }
             Err(e) => SearchOutput::failure(trimmed_query, e.to_string()),
         }
     }
 
+    /// Attach score explanations to each result.
+    fn explain(&self, output: &mut SearchOutput, results: &[SearchResult], query: &SearchQuery) {
+        #[cfg(feature = "tantivy-search")]
+        let bm25 = self.bm25_scores(output, results, query);
+
+        for (info, result) in output.results.iter_mut().zip(results) {
+            info.explain = Some(ScoreExplanation {
+                scoring: score_breakdown(query, &result.learning),
+                #[cfg(feature = "tantivy-search")]
+                bm25: bm25.get(&result.learning.id).copied(),
+                #[cfg(not(feature = "tantivy-search"))]
+                bm25: None,
+            });
+        }
+    }
+
+    /// Rescore the results with BM25 the way the hooks do.
+    ///
+    /// Indexes every active learning so term weights match the project
+    /// index, and picks the BM25 profile from the corpus size.
+    #[cfg(feature = "tantivy-search")]
+    fn bm25_scores(
+        &self,
+        output: &mut SearchOutput,
+        results: &[SearchResult],
+        query: &SearchQuery,
+    ) -> std::collections::HashMap<String, f64> {
+        use crate::config::RetrievalProfile;
+        use crate::hooks::runner::rescore_with_tantivy;
+        use crate::search::TantivySearchIndex;
+
+        let corpus: Vec<_> = self
+            .backend
+            .search(&SearchQuery::new(), &SearchFilters::active_only())
+            .map(|r| r.into_iter().map(|r| r.learning).collect())
+            .unwrap_or_default();
+        let profile = RetrievalProfile::select(corpus.len(), self.retrieval.corpus_size_threshold);
+        output.bm25_profile = Some(
+            match profile {
+                RetrievalProfile::Standard => "standard",
+                RetrievalProfile::SmallCorpus => "boosted",
+            }
+            .to_string(),
+        );
+
+        let index = TantivySearchIndex::in_memory()
+            .and_then(|index| index.index_learnings(&corpus).map(|_| index))
+            .ok();
+        rescore_with_tantivy(results.to_vec(), query, profile, index.as_ref())
+            .into_iter()
+            .map(|r| (r.learning.id, r.relevance))
+            .collect()
+    }
+
     /// Format output based on options.
     pub fn format_output(&self, output: &SearchOutput, options: &SearchOptions) -> String {
         if options.quiet {
@@ -191,7 +287,22 @@ impl<B: MemoryBackend> SearchCommand<B> {
             if !result.tags.is_empty() {
                 lines.push(format!("   Tags: {}", result.tags.join(", ")));
             }
-            lines.push(format!("   ID: {}", result.id));
+            lines.push(format!("   ID: {} | Source: {}", result.id, result.source));
+            if let Some(ref explain) = result.explain {
+                let s = &explain.scoring;
+                let mut line = format!(
+                    "   Score: tags {:.2} + files {:.2} + keywords {:.2}, x{:.2} domain = {:.2}",
+                    s.tags, s.files, s.keywords, s.domain_penalty, s.score
+                );
+                if let Some(bm25) = explain.bm25 {
+                    line.push_str(&format!(
+                        " | BM25 ({}): {:.2}",
+                        output.bm25_profile.as_deref().unwrap_or("standard"),
+                        bm25
+                    ));
+                }
+                lines.push(line);
+            }
             lines.push(String::new());
         }
 
@@ -271,6 +382,8 @@ This pattern has been archived.
             relevance: 0.9,
             tags: vec!["rust".to_string()],
             status: "active".to_string(),
+            source: "project".to_string(),
+            explain: None,
         }];
         let output = SearchOutput::success("test query", results);
 
@@ -305,6 +418,60 @@ This pattern has been archived.
         assert!(output.results.iter().any(|r| r.summary.contains("Error")));
     }
 
+    #[test]
+    fn test_search_shows_org_source() {
+        let (temp, backend) = setup_with_learnings();
+        let org_path = temp.path().join("org.md");
+        fs::write(
+            &org_path,
+            fs::read_to_string(temp.path().join(".grove").join("learnings.md"))
+                .unwrap()
+                .replace("cl_20260101_002", "cl_20260101_900"),
+        )
+        .unwrap();
+        let layered = crate::backends::LayeredBackend::new(
+            Box::new(backend),
+            vec![Box::new(crate::backends::OrgBackend::new(org_path))],
+        );
+
+        let cmd = SearchCommand::new(layered);
+        let options = SearchOptions::default();
+        let output = cmd.run("mutex", &options);
+
+        let sources: Vec<(&str, &str)> = output
+            .results
+            .iter()
+            .map(|r| (r.id.as_str(), r.source.as_str()))
+            .collect();
+        assert!(sources.contains(&("cl_20260101_002", "project")));
+        assert!(sources.contains(&("cl_20260101_900", "org")));
+        assert!(cmd
+            .format_output(&output, &options)
+            .contains("ID: cl_20260101_900 | Source: org"));
+    }
+
+    #[test]
+    fn test_search_shows_org_learning_sharing_project_id() {
+        let (temp, backend) = setup_with_learnings();
+        let org_path = temp.path().join("org.md");
+        fs::copy(temp.path().join(".grove").join("learnings.md"), &org_path).unwrap();
+        let layered = crate::backends::LayeredBackend::new(
+            Box::new(backend),
+            vec![Box::new(crate::backends::OrgBackend::new(org_path))],
+        );
+
+        let cmd = SearchCommand::new(layered);
+        let output = cmd.run("mutex", &SearchOptions::default());
+
+        let sources: Vec<(&str, &str)> = output
+            .results
+            .iter()
+            .map(|r| (r.id.as_str(), r.source.as_str()))
+            .collect();
+        assert!(sources.contains(&("cl_20260101_002", "project")));
+        assert!(sources.contains(&("cl_20260101_002", "org")));
+    }
+
     #[test]
     fn test_search_with_multiple_keywords() {
         let (_temp, backend) = setup_with_learnings();
@@ -394,6 +561,62 @@ This pattern has been archived.
         assert!(output.results.len() <= 1);
     }
 
+    #[test]
+    fn test_search_field_filters() {
+        let (_temp, backend) = setup_with_learnings();
+        let cmd = SearchCommand::new(backend);
+        let options = SearchOptions::default();
+        let ids = |query: &str| -> Vec<String> {
+            let output = cmd.run(query, &options);
+            assert!(output.success, "{:?}", output.error);
+            output.results.into_iter().map(|r| r.id).collect()
+        };
+
+        assert_eq!(ids("tag:mutex"), vec!["cl_20260101_002"]);
+        assert_eq!(ids("category:pattern"), vec!["cl_20260101_001"]);
+        assert_eq!(ids("tag:rust -mutex"), vec!["cl_20260101_001"]);
+        assert_eq!(ids("\"mutex across await\""), vec!["cl_20260101_002"]);
+        assert_eq!(ids("status:archived"), vec!["cl_20260101_003"]);
+        assert!(ids("created:>2026-01-01").is_empty());
+
+        let output = cmd.run("category:gotcha", &options);
+        assert!(!output.success);
+        assert!(output.error.unwrap().contains("Unknown category"));
+    }
+
+    #[test]
+    fn test_search_explain_shows_score_contributions() {
+        let (_temp, backend) = setup_with_learnings();
+        let cmd = SearchCommand::new(backend);
+        let options = SearchOptions {
+            explain: true,
+            ..Default::default()
+        };
+
+        let output = cmd.run("mutex", &options);
+        let explain = output.results[0].explain.as_ref().unwrap();
+        assert!(explain.scoring.keywords > 0.0);
+        assert_eq!(explain.scoring.tags, 0.0);
+        assert!(explain.scoring.score > 0.0);
+        #[cfg(feature = "tantivy-search")]
+        assert!(explain.bm25.is_some());
+
+        let text = cmd.format_output(&output, &options);
+        assert!(text.contains("Score: tags 0.00 + files 0.00 + keywords"));
+        assert_eq!(
+            text.contains("BM25"),
+            cfg!(feature = "tantivy-search"),
+            "{}",
+            text
+        );
+
+        // Without --explain nothing extra is computed or serialized
+        let output = cmd.run("mutex", &SearchOptions::default());
+        assert!(output.results[0].explain.is_none());
+        let json = serde_json::to_string(&output).unwrap();
+        assert!(!json.contains("explain"));
+    }
+
     #[test]
     fn test_search_no_results() {
         let (_temp, backend) = setup_with_learnings();
@@ -454,6 +677,8 @@ This pattern has been archived.
             relevance: 0.9,
             tags: vec!["rust".to_string()],
             status: "active".to_string(),
+            source: "project".to_string(),
+            explain: None,
         }];
         let output = SearchOutput::success("test", results);
         let options = SearchOptions::default();
@@ -2,7 +2,7 @@
 //!
 //! Displays quality dashboard with insights and configuration recommendations.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 
@@ -10,13 +10,43 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::config::{project_stats_log_path, stats_cache_path, Config};
+use crate::core::ProvenanceFilter;
 use crate::discovery::create_primary_backend;
 use crate::stats::{
     apply_safe_recommendations, generate_insights, generate_recommendations, AggregateStats,
-    ConfigRecommendation, Insight, InsightConfig, Recommendations, ReflectionStats, StatsCache,
-    StatsCacheManager, StatsLogger, WriteGateStats,
+    ConfigRecommendation, GuardStats, InjectionStats, Insight, InsightConfig, Recommendations,
+    ReflectionStats, StatsCache, StatsCacheManager, StatsEvent, StatsLogger, WriteGateStats,
 };
 
+/// Keep events matching a version filter.
+///
+/// `"0.9.0"` matches events with that exact version; `"pre:0.9.0"` matches
+/// events without a version or with an older one.
+fn filter_events_by_version(events: Vec<StatsEvent>, version_filter: &str) -> Vec<StatsEvent> {
+    if let Some(target) = version_filter.strip_prefix("pre:") {
+        let target_parts: Vec<u64> = target.split('.').filter_map(|s| s.parse().ok()).collect();
+        events
+            .into_iter()
+            .filter(|e| match &e.grove_version {
+                None => true,
+                Some(v) => {
+                    let v_parts: Vec<u64> = v.split('.').filter_map(|s| s.parse().ok()).collect();
+                    v_parts < target_parts
+                }
+            })
+            .collect()
+    } else {
+        events
+            .into_iter()
+            .filter(|e| {
+                e.grove_version
+                    .as_ref()
+                    .is_some_and(|v| v == version_filter)
+            })
+            .collect()
+    }
+}
+
 /// Options for the stats command.
 #[derive(Debug, Clone, Default)]
 pub struct StatsOptions {
@@ -32,6 +62,8 @@ pub struct StatsOptions {
     pub update_config: bool,
     /// Filter stats to a specific grove version (e.g., "0.9.0" or "pre:0.9.0").
     pub version: Option<String>,
+    /// Filter stats to learnings whose provenance matches.
+    pub provenance: ProvenanceFilter,
 }
 
 /// Output format for the stats command.
@@ -45,6 +77,12 @@ pub struct StatsOutput {
     pub reflections: ReflectionStatsInfo,
     /// Write gate statistics.
     pub write_gate: WriteGateStatsInfo,
+    /// Injection size statistics.
+    #[serde(default)]
+    pub injections: InjectionStatsInfo,
+    /// Command guard statistics.
+    #[serde(default)]
+    pub guards: GuardStatsInfo,
     /// Generated insights.
     pub insights: Vec<InsightInfo>,
     /// Configuration recommendations.
@@ -238,6 +276,58 @@ impl From<&WriteGateStats> for WriteGateStatsInfo {
     }
 }
 
+/// Simplified injection size stats for output.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InjectionStatsInfo {
+    /// Number of injections.
+    pub count: u32,
+    /// Number of sessions with injections.
+    pub sessions: usize,
+    /// Average approximate tokens of learning text per session.
+    pub avg_tokens_per_session: f64,
+    /// Average approximate tokens of learning text per injection.
+    pub avg_tokens_per_injection: f64,
+    /// Learnings injected with trimmed detail.
+    pub trimmed: u32,
+    /// Fraction of `retrieval.token_budget` used by budgeted injections.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget_used: Option<f64>,
+}
+
+impl From<&InjectionStats> for InjectionStatsInfo {
+    fn from(stats: &InjectionStats) -> Self {
+        Self {
+            count: stats.count,
+            sessions: stats.by_session.len(),
+            avg_tokens_per_session: sanitize_f64(stats.avg_tokens_per_session()),
+            avg_tokens_per_injection: sanitize_f64(stats.avg_tokens_per_injection()),
+            trimmed: stats.trimmed,
+            budget_used: stats.budget_used().map(sanitize_f64),
+        }
+    }
+}
+
+/// Simplified command guard stats for output.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GuardStatsInfo {
+    /// Guard matches that warned before the command ran.
+    pub warned: u32,
+    /// Guard matches that blocked the command.
+    pub blocked: u32,
+    /// Number of learnings whose guards fired.
+    pub learnings: usize,
+}
+
+impl From<&GuardStats> for GuardStatsInfo {
+    fn from(stats: &GuardStats) -> Self {
+        Self {
+            warned: stats.warned,
+            blocked: stats.blocked,
+            learnings: stats.by_learning.len(),
+        }
+    }
+}
+
 /// Simplified insight for output.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InsightInfo {
@@ -275,6 +365,8 @@ impl StatsOutput {
             aggregates: AggregateStatsInfo::from(&cache.aggregates),
             reflections: ReflectionStatsInfo::from(&cache.reflections),
             write_gate: WriteGateStatsInfo::from(&cache.write_gate),
+            injections: InjectionStatsInfo::from(&cache.injections),
+            guards: GuardStatsInfo::from(&cache.guards),
             insights: insights.iter().map(InsightInfo::from).collect(),
             recommendations: RecommendationsInfo::from(recommendations),
             warnings,
@@ -308,6 +400,8 @@ impl StatsOutput {
                 pass_rate: 0.0,
                 avg_specificity: None,
             },
+            injections: InjectionStatsInfo::default(),
+            guards: GuardStatsInfo::default(),
             insights: Vec::new(),
             recommendations: RecommendationsInfo::default(),
             warnings,
@@ -341,6 +435,8 @@ impl StatsOutput {
                 pass_rate: 0.0,
                 avg_specificity: None,
             },
+            injections: InjectionStatsInfo::default(),
+            guards: GuardStatsInfo::default(),
             insights: Vec::new(),
             recommendations: RecommendationsInfo::default(),
             warnings: Vec::new(),
@@ -396,10 +492,19 @@ impl StatsCommand {
         };
         let cache_manager = StatsCacheManager::new(&cache_path, &log_path);
 
-        let cache = if let Some(ref version_filter) = options.version {
-            // Version-filtered stats: replay log with event filtering (not cached)
+        // Learning metadata is needed up front to filter events by provenance
+        let backend = create_primary_backend(&self.project_path, Some(&self.config));
+        let learnings = backend.list_all().map(|learnings| {
+            learnings
+                .into_iter()
+                .filter(|l| options.provenance.matches(l))
+                .collect::<Vec<_>>()
+        });
+
+        let cache = if options.version.is_some() || !options.provenance.is_empty() {
+            // Filtered stats: replay log with event filtering (not cached)
             let logger = StatsLogger::new(&log_path);
-            let events = match logger.read_all() {
+            let mut filtered = match logger.read_all() {
                 Ok(e) => e,
                 Err(e) => {
                     warnings.push(format!("Failed to read stats log: {}", e));
@@ -407,38 +512,24 @@ impl StatsCommand {
                 }
             };
 
-            let filtered: Vec<_> = if let Some(target) = version_filter.strip_prefix("pre:") {
-                // "pre:0.9.0" matches events without grove_version or with version < target
-                let target_parts: Vec<u64> =
-                    target.split('.').filter_map(|s| s.parse().ok()).collect();
-                events
-                    .into_iter()
-                    .filter(|e| match &e.grove_version {
-                        None => true,
-                        Some(v) => {
-                            let v_parts: Vec<u64> =
-                                v.split('.').filter_map(|s| s.parse().ok()).collect();
-                            v_parts < target_parts
-                        }
-                    })
-                    .collect()
-            } else {
-                // "0.9.0" matches events with that exact version
-                events
-                    .into_iter()
-                    .filter(|e| {
-                        e.grove_version
-                            .as_ref()
-                            .is_some_and(|v| v == version_filter)
-                    })
-                    .collect()
-            };
+            let mut applied = Vec::new();
+            if let Some(ref version_filter) = options.version {
+                filtered = filter_events_by_version(filtered, version_filter);
+                applied.push(format!("version: {}", version_filter));
+            }
+            if !options.provenance.is_empty() {
+                // Only events about learnings with matching provenance remain
+                let ids: HashSet<&str> =
+                    learnings.iter().flatten().map(|l| l.id.as_str()).collect();
+                filtered.retain(|e| e.data.learning_id().is_some_and(|id| ids.contains(id)));
+                applied.push(format!("provenance: {}", options.provenance));
+            }
 
             warnings.push(format!(
-                "Filtered to {} of {} log entries (version: {})",
+                "Filtered to {} of {} log entries ({})",
                 filtered.len(),
                 logger.count().unwrap_or(0),
-                version_filter,
+                applied.join("; "),
             ));
 
             StatsCache::from_events(&filtered)
@@ -468,24 +559,28 @@ impl StatsCommand {
             .filter_map(|(id, stats)| stats.last_surfaced.map(|ts| (id.clone(), ts)))
             .collect();
 
-        // Load learning metadata from backend for full insights
-        let backend = create_primary_backend(&self.project_path, Some(&self.config));
-        let (learning_categories, learning_criteria, learning_context_files) =
-            match backend.list_all() {
+        // Use learning metadata from backend for full insights
+        let (learning_categories, learning_criteria, learning_context_files, learning_review_dates) =
+            match learnings {
                 Ok(learnings) => {
                     let mut categories = HashMap::new();
                     let mut criteria = HashMap::new();
                     let mut context_files = HashMap::new();
+                    let mut review_dates = HashMap::new();
 
                     for learning in learnings {
                         categories.insert(learning.id.clone(), learning.category);
                         criteria.insert(learning.id.clone(), learning.criteria_met.clone());
+                        if let Some(review_by) = learning.review_by.filter(|_| learning.is_active())
+                        {
+                            review_dates.insert(learning.id.clone(), review_by);
+                        }
                         if let Some(files) = learning.context_files {
                             context_files.insert(learning.id, files);
                         }
                     }
 
-                    (categories, criteria, context_files)
+                    (categories, criteria, context_files, review_dates)
                 }
                 Err(e) => {
                     // Fail-open: log warning but continue with empty maps
@@ -493,7 +588,12 @@ impl StatsCommand {
                         "Could not load learning metadata for insights: {}",
                         e
                     ));
-                    (HashMap::new(), HashMap::new(), HashMap::new())
+                    (
+                        HashMap::new(),
+                        HashMap::new(),
+                        HashMap::new(),
+                        HashMap::new(),
+                    )
                 }
             };
 
@@ -506,6 +606,7 @@ impl StatsCommand {
             &learning_categories,
             &learning_criteria,
             &learning_context_files,
+            &learning_review_dates,
             decay_config,
             &insight_config,
             now,
@@ -616,6 +717,37 @@ impl StatsCommand {
             output.reflections.skip_rate * 100.0
         ));
 
+        // Injections
+        if output.injections.count > 0 {
+            let injections = &output.injections;
+            lines.push("📥 Injections".to_string());
+            lines.push(format!(
+                "   Injections: {} | Sessions: {} | Trimmed learnings: {}",
+                injections.count, injections.sessions, injections.trimmed
+            ));
+            let budget = injections
+                .budget_used
+                .map(|used| format!(" | Budget used: {:.1}%", used * 100.0))
+                .unwrap_or_default();
+            lines.push(format!(
+                "   Average size: ~{:.0} tokens/session, ~{:.0} tokens/injection{}\n",
+                injections.avg_tokens_per_session, injections.avg_tokens_per_injection, budget
+            ));
+        }
+
+        // Command guards
+        let guards = &output.guards;
+        if guards.warned + guards.blocked > 0 {
+            lines.push("🛡️ Command Guards".to_string());
+            lines.push(format!(
+                "   Fired: {} | Warned: {} | Blocked: {} | Learnings: {}\n",
+                guards.warned + guards.blocked,
+                guards.warned,
+                guards.blocked,
+                guards.learnings
+            ));
+        }
+
         // Write gate
         if options.detailed {
             lines.push("🚪 Write Gate".to_string());
@@ -1195,6 +1327,56 @@ mod tests {
         assert_eq!(output.aggregates.total_learnings, 0);
     }
 
+    #[test]
+    fn test_stats_provenance_filter() {
+        use crate::core::ProvenanceOrigin;
+
+        let temp = setup_with_versioned_stats();
+        fs::write(
+            temp.path().join(".grove").join("learnings.md"),
+            "# Project Learnings\n\n\
+             ## cl_001\n\n\
+             **Category:** Pattern\n\
+             **Summary:** Reflected pattern\n\
+             **Provenance:** origin: reflect; agent: main\n\n\
+             Detail.\n\n---\n\n\
+             ## cl_002\n\n\
+             **Category:** Pattern\n\
+             **Summary:** Retroflected pattern\n\
+             **Provenance:** origin: retroflect\n\n\
+             Detail.\n\n---\n",
+        )
+        .unwrap();
+
+        let cmd = StatsCommand::new(Config::default(), temp.path());
+        let options = StatsOptions {
+            provenance: ProvenanceFilter {
+                origin: Some(ProvenanceOrigin::Retroflect),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let output = cmd.run(&options);
+        assert!(output.success);
+        assert!(output
+            .warnings
+            .iter()
+            .any(|w| w == "Filtered to 2 of 4 log entries (provenance: origin=retroflect)"));
+        assert_eq!(output.aggregates.total_learnings, 1);
+
+        // Combined with a version filter, both must hold
+        let options = StatsOptions {
+            version: Some("pre:0.9.0".to_string()),
+            ..options
+        };
+        let output = cmd.run(&options);
+        assert!(output
+            .warnings
+            .iter()
+            .any(|w| w.contains("Filtered to 0 of 4")
+                && w.contains("version: pre:0.9.0; provenance: origin=retroflect")));
+    }
+
     #[test]
     fn test_stats_no_version_filter_uses_cache() {
         let temp = setup_with_versioned_stats();
@@ -1236,6 +1418,63 @@ mod tests {
         assert!(formatted.contains("Implicit references: 5"));
     }
 
+    #[test]
+    fn test_format_output_shows_injection_sizes() {
+        let temp = setup();
+        let cmd = StatsCommand::new(Config::default(), temp.path());
+        let options = StatsOptions::default();
+
+        let mut output = StatsOutput::empty(vec![]);
+        assert!(!cmd.format_output(&output, &options).contains("Injections"));
+
+        let cache = StatsCache::from_events(&[
+            StatsEvent::new(crate::stats::StatsEventType::injected(
+                "s1", 600, 1500, 3, 1,
+            )),
+            StatsEvent::new(crate::stats::StatsEventType::injected(
+                "s2", 300, 1500, 2, 0,
+            )),
+        ]);
+        output.injections = InjectionStatsInfo::from(&cache.injections);
+
+        let formatted = cmd.format_output(&output, &options);
+        assert!(formatted.contains("Injections: 2 | Sessions: 2 | Trimmed learnings: 1"));
+        assert!(formatted.contains("~450 tokens/session"));
+        assert!(formatted.contains("Budget used: 30.0%"));
+    }
+
+    #[test]
+    fn test_format_output_shows_guard_fires() {
+        let temp = setup();
+        let cmd = StatsCommand::new(Config::default(), temp.path());
+        let options = StatsOptions::default();
+
+        let mut output = StatsOutput::empty(vec![]);
+        assert!(!cmd
+            .format_output(&output, &options)
+            .contains("Command Guards"));
+
+        let cache = StatsCache::from_events(&[
+            StatsEvent::new(crate::stats::StatsEventType::guard_fired(
+                "L001",
+                "s1",
+                "git push --force",
+                crate::core::GuardSeverity::Block,
+            )),
+            StatsEvent::new(crate::stats::StatsEventType::guard_fired(
+                "L002",
+                "s1",
+                "terraform apply",
+                crate::core::GuardSeverity::Warn,
+            )),
+        ]);
+        output.guards = GuardStatsInfo::from(&cache.guards);
+
+        let formatted = cmd.format_output(&output, &options);
+        assert!(formatted.contains("Command Guards"));
+        assert!(formatted.contains("Fired: 2 | Warned: 1 | Blocked: 1 | Learnings: 2"));
+    }
+
     #[test]
     fn test_stats_version_filter_pre_semver_ordering() {
         // Verify that "pre:0.10.0" correctly includes 0.9.0 events.
@@ -1,10 +1,11 @@
 //! Trace command for Grove.
 //!
-//! Shows trace events for a session.
+//! Shows trace events for a session, and with `--explain-injections`, why
+//! each injected learning was selected.
 
 use serde::{Deserialize, Serialize};
 
-use crate::core::{SessionState, TraceEvent};
+use crate::core::{InjectedLearning, InjectionExplanation, SessionState, TraceEvent};
 use crate::error::Result;
 use crate::storage::SessionStore;
 
@@ -19,6 +20,8 @@ pub struct TraceOptions {
     pub limit: Option<usize>,
     /// Filter by event type.
     pub event_type: Option<String>,
+    /// Show the score breakdown of each injected learning.
+    pub explain_injections: bool,
 }
 
 /// Output format for the trace command.
@@ -34,6 +37,9 @@ pub struct TraceOutput {
     pub total: usize,
     /// Trace events.
     pub events: Vec<TraceEventInfo>,
+    /// Injected learnings (with `--explain-injections`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub injections: Vec<InjectionInfo>,
     /// Error message if command failed.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
@@ -51,6 +57,31 @@ pub struct TraceEventInfo {
     pub details: Option<String>,
 }
 
+/// An injected learning and why it was selected.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InjectionInfo {
+    /// Learning ID.
+    pub learning_id: String,
+    /// Composite score when injected.
+    pub score: f64,
+    /// What happened to the learning.
+    pub outcome: String,
+    /// Score breakdown (absent for sessions recorded before explanations).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<InjectionExplanation>,
+}
+
+impl From<&InjectedLearning> for InjectionInfo {
+    fn from(injected: &InjectedLearning) -> Self {
+        Self {
+            learning_id: injected.learning_id.clone(),
+            score: injected.score,
+            outcome: format!("{:?}", injected.outcome).to_lowercase(),
+            explanation: injected.explanation.clone(),
+        }
+    }
+}
+
 impl From<&TraceEvent> for TraceEventInfo {
     fn from(event: &TraceEvent) -> Self {
         Self {
@@ -75,6 +106,7 @@ impl TraceOutput {
             count,
             total,
             events,
+            injections: Vec::new(),
             error: None,
         }
     }
@@ -87,6 +119,7 @@ impl TraceOutput {
             count: 0,
             total: 0,
             events: Vec::new(),
+            injections: Vec::new(),
             error: Some(error.into()),
         }
     }
@@ -139,7 +172,16 @@ impl<S: SessionStore> TraceCommand<S> {
             events.truncate(limit);
         }
 
-        TraceOutput::success(session_id, events, total)
+        let mut output = TraceOutput::success(session_id, events, total);
+        if options.explain_injections {
+            output.injections = session
+                .gate
+                .injected_learnings
+                .iter()
+                .map(InjectionInfo::from)
+                .collect();
+        }
+        output
     }
 
     /// Load a session from the store.
@@ -155,6 +197,8 @@ impl<S: SessionStore> TraceCommand<S> {
 
         if options.json {
             serde_json::to_string_pretty(output).unwrap_or_else(|_| "{}".to_string())
+        } else if options.explain_injections && output.success {
+            format_injections(output)
         } else {
             self.format_human_readable(output)
         }
@@ -196,6 +240,100 @@ impl<S: SessionStore> TraceCommand<S> {
     }
 }
 
+/// Format injected learnings with their score breakdowns.
+fn format_injections(output: &TraceOutput) -> String {
+    if output.injections.is_empty() {
+        return format!(
+            "No learnings were injected in session: {}\n",
+            output.session_id
+        );
+    }
+
+    let mut lines = vec![format!(
+        "Injected learnings for session: {} ({})\n",
+        output.session_id,
+        output.injections.len()
+    )];
+
+    for injection in &output.injections {
+        lines.push(format!(
+            "{} (score: {:.3}, outcome: {})",
+            injection.learning_id, injection.score, injection.outcome
+        ));
+        let Some(ref e) = injection.explanation else {
+            lines.push("  No explanation recorded".to_string());
+            lines.push(String::new());
+            continue;
+        };
+
+        let mut query = Vec::new();
+        if !e.keywords.is_empty() {
+            query.push(format!("keywords [{}]", e.keywords.join(", ")));
+        }
+        if !e.tags.is_empty() {
+            query.push(format!("tags [{}]", e.tags.join(", ")));
+        }
+        if !e.files.is_empty() {
+            query.push(format!("files [{}]", e.files.join(", ")));
+        }
+        if !query.is_empty() {
+            lines.push(format!("  Query:      {}", query.join("; ")));
+        }
+        lines.push(format!(
+            "  Scores:     relevance {:.3} ({}) x recency {:.3} x reference {:.3} = {:.3} ({})",
+            e.relevance, e.scoring_backend, e.recency, e.reference, e.score, e.strategy
+        ));
+
+        // File-triggered and guarded learnings skip relevance and selection thresholds
+        if let Some(ref file) = e.trigger_file {
+            lines.push(format!("  Trigger:    about to edit {}", file));
+            lines.push(String::new());
+            continue;
+        }
+        if let Some(ref command) = e.trigger_command {
+            lines.push(format!("  Trigger:    about to run `{}`", command));
+            lines.push(String::new());
+            continue;
+        }
+
+        let relevance_check = if e.recent_without_match {
+            format!(
+                "recent learning, no relevance match (min {:.2})",
+                e.min_relevance
+            )
+        } else {
+            format!("{:.3} >= {:.2}", e.relevance, e.min_relevance)
+        };
+        lines.push(format!("  Relevance:  {}", relevance_check));
+        lines.push(format!(
+            "  Adaptive:   top score >= {:.2}, gap >= {:.2}",
+            e.min_confidence, e.min_score_gap
+        ));
+        lines.push(format!(
+            "  Dynamic K:  {:.3} >= {:.3} (ratio {:.2}), rank {} of {}",
+            e.score, e.dynamic_k_cutoff, e.dynamic_k_ratio, e.rank, e.candidates
+        ));
+        if let Some(overlap) = e.intent_overlap {
+            lines.push(format!("  Intent:     {:.0}% overlap", overlap * 100.0));
+        }
+        if let Some(similarity) = e.diversity_similarity {
+            lines.push(format!(
+                "  Diversity:  {:.2} max similarity to earlier picks",
+                similarity
+            ));
+        }
+        if let Some(rerank) = e.rerank_score {
+            lines.push(format!("  Rerank:     {:.0}/5", rerank));
+        }
+        if let Some(ref parent) = e.dependency_of {
+            lines.push(format!("  Included as a dependency of {}", parent));
+        }
+        lines.push(String::new());
+    }
+
+    lines.join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -407,6 +545,60 @@ mod tests {
         assert!(formatted.contains("No trace events"));
     }
 
+    #[test]
+    fn test_trace_explain_injections() {
+        use crate::core::InjectedLearning;
+
+        let store = create_test_store();
+        let mut session = create_test_session("test-1");
+        session.gate.injected_learnings.push(
+            InjectedLearning::new("cl_001", 0.72).with_explanation(Some(InjectionExplanation {
+                scoring_backend: "bm25".to_string(),
+                strategy: "moderate".to_string(),
+                keywords: vec!["mutex".to_string()],
+                relevance: 0.9,
+                recency: 0.8,
+                reference: 1.0,
+                score: 0.72,
+                min_relevance: 0.3,
+                dynamic_k_ratio: 0.5,
+                dynamic_k_cutoff: 0.36,
+                rank: 1,
+                candidates: 4,
+                rerank_score: Some(5.0),
+                ..Default::default()
+            })),
+        );
+        session
+            .gate
+            .injected_learnings
+            .push(InjectedLearning::new("cl_002", 0.4));
+        store.put(&session).unwrap();
+
+        let cmd = TraceCommand::new(store);
+        let output = cmd.run("test-1", &TraceOptions::default());
+        assert!(
+            output.injections.is_empty(),
+            "only with --explain-injections"
+        );
+
+        let options = TraceOptions {
+            explain_injections: true,
+            ..Default::default()
+        };
+        let output = cmd.run("test-1", &options);
+        assert_eq!(output.injections.len(), 2);
+        assert_eq!(output.injections[0].outcome, "pending");
+
+        let text = cmd.format_output(&output, &options);
+        assert!(text.contains("cl_001 (score: 0.720, outcome: pending)"));
+        assert!(text.contains("Query:      keywords [mutex]"));
+        assert!(text.contains("relevance 0.900 (bm25) x recency 0.800"));
+        assert!(text.contains("Dynamic K:  0.720 >= 0.360 (ratio 0.50), rank 1 of 4"));
+        assert!(text.contains("Rerank:     5/5"));
+        assert!(text.contains("No explanation recorded"));
+    }
+
     #[test]
     fn test_trace_event_info_from_event() {
         let event = TraceEvent::new(EventType::SessionStart, Some("Test".to_string()));
@@ -74,6 +74,34 @@ pub struct BackendsConfig {
     pub discovery: Vec<String>,
     /// Per-backend enable/disable overrides.
     pub overrides: HashMap<String, bool>,
+    /// Backends to mirror every write to (empty = single primary backend).
+    ///
+    /// When set, learnings are written to all listed backends, searches merge
+    /// their results, and archive/restore apply everywhere.
+    pub mirror: Vec<String>,
+    /// MCP memory server settings (used when `mcp` is discovered).
+    pub mcp: McpBackendConfig,
+    /// Process plugin settings (used when `process` is discovered).
+    pub process: ProcessBackendConfig,
+    /// Read-only organization-wide learnings searched alongside the project's.
+    pub org: OrgBackendConfig,
+}
+
+/// Longest a subprocess backend may wait per response while a hook runs.
+///
+/// Claude Code allows hooks about five seconds; the cap leaves room for the
+/// rest of the hook's work.
+pub const HOOK_BACKEND_TIMEOUT_SECONDS: u64 = 3;
+
+impl BackendsConfig {
+    /// Cap MCP and process plugin timeouts at [`HOOK_BACKEND_TIMEOUT_SECONDS`].
+    pub fn cap_timeouts_for_hooks(&mut self) {
+        self.mcp.timeout_seconds = self.mcp.timeout_seconds.min(HOOK_BACKEND_TIMEOUT_SECONDS);
+        self.process.timeout_seconds = self
+            .process
+            .timeout_seconds
+            .min(HOOK_BACKEND_TIMEOUT_SECONDS);
+    }
 }
 
 impl Default for BackendsConfig {
@@ -81,10 +109,116 @@ impl Default for BackendsConfig {
         Self {
             discovery: vec!["total-recall".to_string(), "markdown".to_string()],
             overrides: HashMap::new(),
+            mirror: Vec::new(),
+            mcp: McpBackendConfig::default(),
+            process: ProcessBackendConfig::default(),
+            org: OrgBackendConfig::default(),
+        }
+    }
+}
+
+/// MCP memory server configuration.
+///
+/// Grove launches `command` with `args` and speaks MCP JSON-RPC over its
+/// stdin/stdout. The backend is only discovered when a command is set.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct McpBackendConfig {
+    /// Server executable to launch.
+    pub command: String,
+    /// Arguments passed to the server.
+    pub args: Vec<String>,
+    /// Extra environment variables for the server process.
+    pub env: HashMap<String, String>,
+    /// Seconds to wait for each server response.
+    pub timeout_seconds: u64,
+}
+
+impl Default for McpBackendConfig {
+    fn default() -> Self {
+        Self {
+            command: String::new(),
+            args: Vec::new(),
+            env: HashMap::new(),
+            timeout_seconds: 3,
         }
     }
 }
 
+impl McpBackendConfig {
+    /// Whether a server command has been configured.
+    pub fn is_configured(&self) -> bool {
+        !self.command.trim().is_empty()
+    }
+}
+
+/// Process plugin backend configuration.
+///
+/// Grove launches `command` with `args` and exchanges JSON-lines requests
+/// over its stdin/stdout (see `backends::process`). The backend is only
+/// discovered when a command is set.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ProcessBackendConfig {
+    /// Plugin executable to launch.
+    pub command: String,
+    /// Arguments passed to the plugin.
+    pub args: Vec<String>,
+    /// Extra environment variables for the plugin process.
+    pub env: HashMap<String, String>,
+    /// Seconds to wait for each plugin response.
+    pub timeout_seconds: u64,
+}
+
+impl Default for ProcessBackendConfig {
+    fn default() -> Self {
+        Self {
+            command: String::new(),
+            args: Vec::new(),
+            env: HashMap::new(),
+            timeout_seconds: 3,
+        }
+    }
+}
+
+impl ProcessBackendConfig {
+    /// Whether a plugin command has been configured.
+    pub fn is_configured(&self) -> bool {
+        !self.command.trim().is_empty()
+    }
+}
+
+/// Organization-wide learning source configuration.
+///
+/// `path` points at a shared corpus curated outside the project: a grove
+/// markdown learnings file, or a directory (such as a git checkout) holding
+/// one. Relative paths resolve against the project root; `~/` expands to the
+/// home directory. The source is only searched, never written.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct OrgBackendConfig {
+    /// File or directory holding the organization's learnings.
+    pub path: String,
+}
+
+impl OrgBackendConfig {
+    /// Whether an org learnings path has been configured.
+    pub fn is_configured(&self) -> bool {
+        !self.path.trim().is_empty()
+    }
+
+    /// Resolve the configured path against the project root.
+    pub fn resolve(&self, cwd: &Path) -> PathBuf {
+        let path = self.path.trim();
+        if let Some(rest) = path.strip_prefix("~/") {
+            if let Some(home) = dirs::home_dir() {
+                return home.join(rest);
+            }
+        }
+        find_project_root(cwd).join(path)
+    }
+}
+
 /// Gate behavior configuration.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(default)]
@@ -93,7 +227,7 @@ pub struct GateConfig {
     pub auto_skip: AutoSkipConfig,
     /// Write gate configuration for filtering learnings.
     pub write_gate: WriteGateConfig,
-    /// Semantic deduplication configuration (requires `semantic-dedup` feature).
+    /// Semantic deduplication and embedding provider configuration.
     #[serde(default)]
     pub semantic_dedup: SemanticDedupConfig,
     /// Whether to count skipped sessions as dismissals for unreferenced learnings.
@@ -197,29 +331,66 @@ impl Default for WriteGateConfig {
 
 /// Semantic deduplication configuration.
 ///
-/// When enabled (requires `semantic-dedup` feature), embeds learning summaries
-/// using an ONNX model and rejects candidates with cosine similarity >= threshold
-/// against existing learnings. Falls back to string-based dedup when disabled.
+/// When enabled, embeds learning summaries and rejects candidates with cosine
+/// similarity >= threshold against existing learnings. Falls back to
+/// string-based dedup when disabled. The provider settings also select the
+/// embeddings used by hybrid retrieval.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(default)]
 pub struct SemanticDedupConfig {
     /// Whether semantic deduplication is enabled.
-    /// Default: false. Requires the `semantic-dedup` feature to have effect.
+    /// Default: false.
     pub enabled: bool,
     /// Cosine similarity threshold above which a candidate is considered a duplicate.
     /// Default: 0.90. Range: 0.0..=1.0.
     pub similarity_threshold: f64,
+    /// Embedding provider: "fastembed" (local ONNX model, requires the
+    /// `semantic-dedup` feature), "openai" (OpenAI-compatible `/v1/embeddings`
+    /// endpoint), or "hashing" (deterministic, no model download).
+    /// Default: "fastembed".
+    pub provider: String,
+    /// Model name sent to the "openai" provider. Empty uses
+    /// "text-embedding-3-small".
+    pub model: String,
+    /// Embeddings endpoint for the "openai" provider.
+    /// Default: "https://api.openai.com/v1/embeddings".
+    pub api_url: String,
+    /// Environment variable holding the "openai" provider's API key. When the
+    /// variable is unset, requests are sent without authentication (local servers).
+    /// Default: "OPENAI_API_KEY".
+    pub api_key_env: String,
+    /// Request timeout in seconds for the "openai" provider. Default: 30.
+    /// Inside hooks it is capped at [`HOOK_EMBEDDING_TIMEOUT_SECONDS`].
+    pub timeout_seconds: u64,
+    /// Vector size for the "hashing" provider. Default: 256.
+    pub dimensions: usize,
 }
 
 fn default_similarity_threshold() -> f64 {
     0.90
 }
 
+/// Valid values for the embedding provider field.
+pub const VALID_EMBEDDING_PROVIDERS: &[&str] = &["fastembed", "openai", "hashing"];
+
+/// Longest an embedding request may take while a hook runs.
+pub const HOOK_EMBEDDING_TIMEOUT_SECONDS: u64 = 3;
+
 impl SemanticDedupConfig {
     /// Check if a similarity threshold value is valid.
     pub fn is_valid_similarity_threshold(value: f64) -> bool {
         (0.0..=1.0).contains(&value)
     }
+
+    /// Check if an embedding provider value is valid.
+    pub fn is_valid_provider(value: &str) -> bool {
+        VALID_EMBEDDING_PROVIDERS.contains(&value)
+    }
+
+    /// Cap the request timeout at [`HOOK_EMBEDDING_TIMEOUT_SECONDS`].
+    pub fn cap_timeout_for_hooks(&mut self) {
+        self.timeout_seconds = self.timeout_seconds.min(HOOK_EMBEDDING_TIMEOUT_SECONDS);
+    }
 }
 
 impl Default for SemanticDedupConfig {
@@ -227,6 +398,12 @@ impl Default for SemanticDedupConfig {
         Self {
             enabled: false,
             similarity_threshold: default_similarity_threshold(),
+            provider: "fastembed".to_string(),
+            model: String::new(),
+            api_url: "https://api.openai.com/v1/embeddings".to_string(),
+            api_key_env: "OPENAI_API_KEY".to_string(),
+            timeout_seconds: 30,
+            dimensions: 256,
         }
     }
 }
@@ -250,7 +427,7 @@ pub const VALID_DECIDERS: &[&str] = &["agent", "always", "never"];
 pub const VALID_STRATEGIES: &[&str] = &["conservative", "moderate", "aggressive"];
 
 /// Valid values for the scoring backend field.
-pub const VALID_SCORING_BACKENDS: &[&str] = &["keyword", "bm25"];
+pub const VALID_SCORING_BACKENDS: &[&str] = &["keyword", "bm25", "hybrid"];
 
 impl AutoSkipConfig {
     /// Check if a decider value is valid.
@@ -442,6 +619,9 @@ impl Default for IntentFilterConfig {
 /// git branch, recent files). This provides +15-25% relevance improvement.
 ///
 /// Disabled by default. Uses the same backend infrastructure as `JudgeConfig`.
+/// With `model = "local"`, candidates are instead rescored by embedding
+/// similarity with the local fastembed model (requires the `semantic-dedup`
+/// feature); `backend` and `timeout_seconds` are then unused.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(default)]
 pub struct RerankConfig {
@@ -450,6 +630,7 @@ pub struct RerankConfig {
     /// Timeout in seconds for the reranking LLM call.
     pub timeout_seconds: u32,
     /// Model to use for reranking. For CLI backend: short name (e.g., "haiku").
+    /// `"local"` selects the local embedding reranker.
     pub model: String,
     /// Backend to use: "cli" (claude CLI) or "api" (curl + ANTHROPIC_API_KEY).
     pub backend: String,
@@ -466,6 +647,67 @@ impl Default for RerankConfig {
     }
 }
 
+/// Hybrid retrieval configuration (`scoring_backend = "hybrid"`).
+///
+/// Fuses the BM25 ranking with a ranking by embedding similarity using
+/// reciprocal rank fusion: each learning scores `weight / (rrf_k + rank)` in
+/// each ranking it appears in. Requires the `tantivy-search` feature and uses
+/// the embedding provider from [`SemanticDedupConfig`]; when the provider is
+/// unavailable, hybrid falls back to BM25.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct HybridConfig {
+    /// RRF rank constant; larger values flatten the difference between ranks.
+    pub rrf_k: f64,
+    /// Weight of the BM25 ranking.
+    pub bm25_weight: f64,
+    /// Weight of the embedding-similarity ranking.
+    pub embedding_weight: f64,
+    /// Minimum cosine similarity for a learning to enter the embedding ranking.
+    pub min_similarity: f64,
+}
+
+impl Default for HybridConfig {
+    fn default() -> Self {
+        Self {
+            rrf_k: 60.0,
+            bm25_weight: 1.0,
+            embedding_weight: 1.0,
+            min_similarity: 0.3,
+        }
+    }
+}
+
+/// Diversity-aware selection of injected learnings.
+///
+/// When enabled, the learnings that pass dynamic K are picked by maximal
+/// marginal relevance (MMR): each pick maximizes
+/// `lambda * relevance - (1 - lambda) * similarity`, where similarity is the
+/// highest similarity to an already-picked learning. Similarity is the cosine
+/// of cached embeddings when available, otherwise the overlap of tags,
+/// category, and summary terms.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct DiversityConfig {
+    /// Enable MMR selection (default: false).
+    pub enabled: bool,
+    /// Trade-off between relevance (1.0) and diversity (0.0) (default: 0.7).
+    pub lambda: f64,
+    /// Use cached embeddings from `[gate.semantic_dedup]` for similarity
+    /// when available (default: true).
+    pub use_embeddings: bool,
+}
+
+impl Default for DiversityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            lambda: 0.7,
+            use_embeddings: true,
+        }
+    }
+}
+
 /// Learning retrieval configuration.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(default)]
@@ -481,7 +723,8 @@ pub struct RetrievalConfig {
     pub recency_half_life_days: u32,
     /// Per-category half-life overrides for recency decay.
     pub category_half_lives: CategoryHalfLifeConfig,
-    /// Scoring backend: "keyword" (default) or "bm25" (Tantivy BM25).
+    /// Scoring backend: "keyword", "bm25" (Tantivy BM25, default), or
+    /// "hybrid" (BM25 fused with embedding similarity).
     pub scoring_backend: String,
     /// Minimum top score to trigger injection (0.0 to 1.0, default: 0.1).
     /// If the highest-scoring learning is below this threshold, injection is suppressed entirely.
@@ -505,10 +748,21 @@ pub struct RetrievalConfig {
     /// Extracts domain terms from learning tags, summaries, and relevance context,
     /// then augments queries with matching terms to bridge the BM25 vocabulary gap.
     pub corpus_enrichment: bool,
+    /// Approximate token budget for learning examples in injected context (default: 300).
+    /// Examples are added in rank order while they fit; 0 leaves them out.
+    pub example_token_budget: usize,
+    /// Approximate token budget for learning text per injection (default: 0,
+    /// disabled). When set, learnings fill it in rank order, trimmed in stages
+    /// (full detail, then summary with truncated detail, then summary only).
+    pub token_budget: usize,
     /// Intent-based post-retrieval filter configuration.
     pub intent_filter: IntentFilterConfig,
     /// LLM reranking configuration for deferred injection.
     pub rerank: RerankConfig,
+    /// Rank fusion settings for the hybrid scoring backend.
+    pub hybrid: HybridConfig,
+    /// Diversity-aware (MMR) selection of injected learnings.
+    pub diversity: DiversityConfig,
 }
 
 impl RetrievalConfig {
@@ -552,8 +806,12 @@ impl Default for RetrievalConfig {
             adaptive_dk: false,
             corpus_size_threshold: 50,
             corpus_enrichment: true,
+            example_token_budget: 300,
+            token_budget: 0,
             intent_filter: IntentFilterConfig::default(),
             rerank: RerankConfig::default(),
+            hybrid: HybridConfig::default(),
+            diversity: DiversityConfig::default(),
         }
     }
 }
@@ -637,6 +895,14 @@ pub struct ContextConfig {
     /// When true, the first tool call extracts keywords from tool_input
     /// and re-runs retrieval with augmented signals.
     pub deferred_injection: bool,
+    /// Inject learnings about a file when the agent is about to edit it.
+    /// Matches `context_files` and `applies_to.paths` on Edit, MultiEdit,
+    /// Write, and NotebookEdit calls; each learning at most once per session.
+    pub file_triggers: bool,
+    /// Check Bash commands against learnings' command guards.
+    /// A matching guard adds the learning as context, or denies the
+    /// command when the guard's severity is `block`.
+    pub command_guards: bool,
 }
 
 impl Default for ContextConfig {
@@ -645,6 +911,8 @@ impl Default for ContextConfig {
             active_ticket_query: true,
             active_ticket_timeout_ms: 2000,
             deferred_injection: true,
+            file_triggers: true,
+            command_guards: true,
         }
     }
 }
@@ -1113,6 +1381,19 @@ impl Config {
             }
         }
 
+        // GROVE_EMBEDDING_PROVIDER
+        if let Ok(val) = env::var("GROVE_EMBEDDING_PROVIDER") {
+            if SemanticDedupConfig::is_valid_provider(&val) {
+                self.gate.semantic_dedup.provider = val;
+            } else {
+                eprintln!(
+                    "Warning: Invalid GROVE_EMBEDDING_PROVIDER value '{}'. \
+                    Valid values: {:?}. Using default '{}'.",
+                    val, VALID_EMBEDDING_PROVIDERS, self.gate.semantic_dedup.provider
+                );
+            }
+        }
+
         // GROVE_DECAY_DAYS
         if let Ok(val) = env::var("GROVE_DECAY_DAYS") {
             match val.parse::<u32>() {
@@ -1216,6 +1497,43 @@ impl Config {
             self.backends.overrides.insert(k, v);
         }
 
+        if !other.backends.mirror.is_empty() {
+            self.backends.mirror = other.backends.mirror;
+        }
+        if other.backends.org != OrgBackendConfig::default() {
+            self.backends.org = other.backends.org;
+        }
+
+        // Backends: merge MCP server settings field by field
+        let default_mcp = McpBackendConfig::default();
+        if other.backends.mcp.command != default_mcp.command {
+            self.backends.mcp.command = other.backends.mcp.command;
+        }
+        if other.backends.mcp.args != default_mcp.args {
+            self.backends.mcp.args = other.backends.mcp.args;
+        }
+        for (k, v) in other.backends.mcp.env {
+            self.backends.mcp.env.insert(k, v);
+        }
+        if other.backends.mcp.timeout_seconds != default_mcp.timeout_seconds {
+            self.backends.mcp.timeout_seconds = other.backends.mcp.timeout_seconds;
+        }
+
+        // Backends: merge process plugin settings field by field
+        let default_process = ProcessBackendConfig::default();
+        if other.backends.process.command != default_process.command {
+            self.backends.process.command = other.backends.process.command;
+        }
+        if other.backends.process.args != default_process.args {
+            self.backends.process.args = other.backends.process.args;
+        }
+        for (k, v) in other.backends.process.env {
+            self.backends.process.env.insert(k, v);
+        }
+        if other.backends.process.timeout_seconds != default_process.timeout_seconds {
+            self.backends.process.timeout_seconds = other.backends.process.timeout_seconds;
+        }
+
         // Gate: merge auto_skip settings field by field
         // Take each non-default value from other
         let default_auto_skip = AutoSkipConfig::default();
@@ -1279,6 +1597,24 @@ impl Config {
             self.gate.semantic_dedup.similarity_threshold =
                 other.gate.semantic_dedup.similarity_threshold;
         }
+        if other.gate.semantic_dedup.provider != default_semantic_dedup.provider {
+            self.gate.semantic_dedup.provider = other.gate.semantic_dedup.provider;
+        }
+        if other.gate.semantic_dedup.model != default_semantic_dedup.model {
+            self.gate.semantic_dedup.model = other.gate.semantic_dedup.model;
+        }
+        if other.gate.semantic_dedup.api_url != default_semantic_dedup.api_url {
+            self.gate.semantic_dedup.api_url = other.gate.semantic_dedup.api_url;
+        }
+        if other.gate.semantic_dedup.api_key_env != default_semantic_dedup.api_key_env {
+            self.gate.semantic_dedup.api_key_env = other.gate.semantic_dedup.api_key_env;
+        }
+        if other.gate.semantic_dedup.timeout_seconds != default_semantic_dedup.timeout_seconds {
+            self.gate.semantic_dedup.timeout_seconds = other.gate.semantic_dedup.timeout_seconds;
+        }
+        if other.gate.semantic_dedup.dimensions != default_semantic_dedup.dimensions {
+            self.gate.semantic_dedup.dimensions = other.gate.semantic_dedup.dimensions;
+        }
 
         // Decay: merge field by field
         let default_decay = DecayConfig::default();
@@ -1332,6 +1668,12 @@ impl Config {
         if other.retrieval.corpus_enrichment != default_retrieval.corpus_enrichment {
             self.retrieval.corpus_enrichment = other.retrieval.corpus_enrichment;
         }
+        if other.retrieval.example_token_budget != default_retrieval.example_token_budget {
+            self.retrieval.example_token_budget = other.retrieval.example_token_budget;
+        }
+        if other.retrieval.token_budget != default_retrieval.token_budget {
+            self.retrieval.token_budget = other.retrieval.token_budget;
+        }
         // Intent filter: merge field by field
         let default_intent = IntentFilterConfig::default();
         if other.retrieval.intent_filter.enabled != default_intent.enabled {
@@ -1357,6 +1699,35 @@ impl Config {
         if other.retrieval.rerank.backend != default_rerank.backend {
             self.retrieval.rerank.backend = other.retrieval.rerank.backend.clone();
         }
+        // Hybrid: merge field by field
+        let default_hybrid = HybridConfig::default();
+        if (other.retrieval.hybrid.rrf_k - default_hybrid.rrf_k).abs() > f64::EPSILON {
+            self.retrieval.hybrid.rrf_k = other.retrieval.hybrid.rrf_k;
+        }
+        if (other.retrieval.hybrid.bm25_weight - default_hybrid.bm25_weight).abs() > f64::EPSILON {
+            self.retrieval.hybrid.bm25_weight = other.retrieval.hybrid.bm25_weight;
+        }
+        if (other.retrieval.hybrid.embedding_weight - default_hybrid.embedding_weight).abs()
+            > f64::EPSILON
+        {
+            self.retrieval.hybrid.embedding_weight = other.retrieval.hybrid.embedding_weight;
+        }
+        if (other.retrieval.hybrid.min_similarity - default_hybrid.min_similarity).abs()
+            > f64::EPSILON
+        {
+            self.retrieval.hybrid.min_similarity = other.retrieval.hybrid.min_similarity;
+        }
+        // Diversity: merge field by field
+        let default_diversity = DiversityConfig::default();
+        if other.retrieval.diversity.enabled != default_diversity.enabled {
+            self.retrieval.diversity.enabled = other.retrieval.diversity.enabled;
+        }
+        if (other.retrieval.diversity.lambda - default_diversity.lambda).abs() > f64::EPSILON {
+            self.retrieval.diversity.lambda = other.retrieval.diversity.lambda;
+        }
+        if other.retrieval.diversity.use_embeddings != default_diversity.use_embeddings {
+            self.retrieval.diversity.use_embeddings = other.retrieval.diversity.use_embeddings;
+        }
 
         // Circuit breaker: merge field by field
         let default_cb = CircuitBreakerConfig::default();
@@ -1375,6 +1746,12 @@ impl Config {
         if other.context.active_ticket_timeout_ms != default_context.active_ticket_timeout_ms {
             self.context.active_ticket_timeout_ms = other.context.active_ticket_timeout_ms;
         }
+        if other.context.file_triggers != default_context.file_triggers {
+            self.context.file_triggers = other.context.file_triggers;
+        }
+        if other.context.command_guards != default_context.command_guards {
+            self.context.command_guards = other.context.command_guards;
+        }
 
         // Judge: merge field by field
         let default_judge = JudgeConfig::default();
@@ -1594,6 +1971,24 @@ impl Config {
             ));
         }
 
+        // Embedding provider
+        if self.gate.semantic_dedup.provider != other.gate.semantic_dedup.provider {
+            changes.push((
+                "gate.semantic_dedup.provider".to_string(),
+                self.gate.semantic_dedup.provider.clone(),
+                other.gate.semantic_dedup.provider.clone(),
+            ));
+        }
+
+        // Embedding model
+        if self.gate.semantic_dedup.model != other.gate.semantic_dedup.model {
+            changes.push((
+                "gate.semantic_dedup.model".to_string(),
+                self.gate.semantic_dedup.model.clone(),
+                other.gate.semantic_dedup.model.clone(),
+            ));
+        }
+
         // Decay days
         if self.decay.passive_duration_days != other.decay.passive_duration_days {
             changes.push((
@@ -1705,6 +2100,24 @@ impl Config {
             ));
         }
 
+        // Example token budget
+        if self.retrieval.example_token_budget != other.retrieval.example_token_budget {
+            changes.push((
+                "retrieval.example_token_budget".to_string(),
+                self.retrieval.example_token_budget.to_string(),
+                other.retrieval.example_token_budget.to_string(),
+            ));
+        }
+
+        // Injection token budget
+        if self.retrieval.token_budget != other.retrieval.token_budget {
+            changes.push((
+                "retrieval.token_budget".to_string(),
+                self.retrieval.token_budget.to_string(),
+                other.retrieval.token_budget.to_string(),
+            ));
+        }
+
         changes
     }
 }
@@ -1831,6 +2244,28 @@ pub fn find_project_root(cwd: &Path) -> PathBuf {
     cwd.to_path_buf()
 }
 
+/// Get the git author (`Name <email>`) from git config as seen from `cwd`.
+///
+/// Returns whichever of `user.name` and `user.email` is set, or `None` when
+/// neither is (or git is unavailable).
+pub fn git_author(cwd: &Path) -> Option<String> {
+    let read = |key: &str| {
+        let output = std::process::Command::new("git")
+            .args(["config", "--get", key])
+            .current_dir(cwd)
+            .stderr(std::process::Stdio::null())
+            .output()
+            .ok()?;
+        let value = String::from_utf8(output.stdout).ok()?.trim().to_string();
+        (output.status.success() && !value.is_empty()).then_some(value)
+    };
+
+    match (read("user.name"), read("user.email")) {
+        (Some(name), Some(email)) => Some(format!("{} <{}>", name, email)),
+        (name, email) => name.or(email),
+    }
+}
+
 /// Get the sessions directory.
 ///
 /// Returns `<grove_home>/sessions/`.
@@ -1870,6 +2305,13 @@ pub fn project_learnings_path(cwd: &Path) -> PathBuf {
     project_grove_dir(cwd).join("learnings.md")
 }
 
+/// Get the project SQLite learnings database path.
+///
+/// Returns `<cwd>/.grove/learnings.db`.
+pub fn project_learnings_db_path(cwd: &Path) -> PathBuf {
+    project_grove_dir(cwd).join("learnings.db")
+}
+
 /// Get the project stats log path.
 ///
 /// Returns `<cwd>/.grove/stats.log`.
@@ -1877,6 +2319,27 @@ pub fn project_stats_log_path(cwd: &Path) -> PathBuf {
     project_grove_dir(cwd).join("stats.log")
 }
 
+/// Get the project search index directory.
+///
+/// Returns `<cwd>/.grove/index/`.
+pub fn project_index_dir(cwd: &Path) -> PathBuf {
+    project_grove_dir(cwd).join("index")
+}
+
+/// Get the project trigger index path.
+///
+/// Returns `<cwd>/.grove/trigger-index.json`.
+pub fn project_trigger_index_path(cwd: &Path) -> PathBuf {
+    project_grove_dir(cwd).join("trigger-index.json")
+}
+
+/// Get the project hook template directory.
+///
+/// Returns `<cwd>/.grove/templates/`.
+pub fn project_templates_dir(cwd: &Path) -> PathBuf {
+    project_grove_dir(cwd).join("templates")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2145,6 +2608,119 @@ total-recall = false
         assert_eq!(config.backends.overrides.get("total-recall"), Some(&false));
     }
 
+    #[test]
+    fn test_backends_mirror_config() {
+        let dir = TempDir::new().unwrap();
+        let grove_dir = dir.path().join(".grove");
+        fs::create_dir_all(&grove_dir).unwrap();
+
+        let config_path = grove_dir.join("config.toml");
+        fs::write(
+            &config_path,
+            "[backends]\nmirror = [\"total-recall\", \"markdown\"]\n",
+        )
+        .unwrap();
+
+        let config = Config::load_from_cwd(dir.path());
+
+        assert_eq!(config.backends.mirror, vec!["total-recall", "markdown"]);
+        assert!(Config::default().backends.mirror.is_empty());
+    }
+
+    #[test]
+    fn test_backends_mcp_config() {
+        let dir = TempDir::new().unwrap();
+        let grove_dir = dir.path().join(".grove");
+        fs::create_dir_all(&grove_dir).unwrap();
+
+        let config_path = grove_dir.join("config.toml");
+        let toml_content = r#"
+[backends]
+discovery = ["mcp", "markdown"]
+
+[backends.mcp]
+command = "npx"
+args = ["-y", "@modelcontextprotocol/server-memory"]
+timeout_seconds = 30
+
+[backends.mcp.env]
+MEMORY_FILE_PATH = "/tmp/memory.json"
+"#;
+        fs::write(&config_path, toml_content).unwrap();
+
+        let config = Config::load_from_cwd(dir.path());
+
+        assert!(config.backends.mcp.is_configured());
+        assert_eq!(config.backends.mcp.command, "npx");
+        assert_eq!(
+            config.backends.mcp.args,
+            vec!["-y", "@modelcontextprotocol/server-memory"]
+        );
+        assert_eq!(config.backends.mcp.timeout_seconds, 30);
+        assert_eq!(
+            config.backends.mcp.env.get("MEMORY_FILE_PATH"),
+            Some(&"/tmp/memory.json".to_string())
+        );
+    }
+
+    #[test]
+    fn test_backends_process_config() {
+        let dir = TempDir::new().unwrap();
+        let grove_dir = dir.path().join(".grove");
+        fs::create_dir_all(&grove_dir).unwrap();
+
+        let config_path = grove_dir.join("config.toml");
+        let toml_content = r#"
+[backends]
+discovery = ["process", "markdown"]
+
+[backends.process]
+command = "/usr/local/bin/kb-grove-plugin"
+args = ["--store", "team"]
+timeout_seconds = 5
+"#;
+        fs::write(&config_path, toml_content).unwrap();
+
+        let config = Config::load_from_cwd(dir.path());
+
+        assert!(config.backends.process.is_configured());
+        assert_eq!(
+            config.backends.process.command,
+            "/usr/local/bin/kb-grove-plugin"
+        );
+        assert_eq!(config.backends.process.args, vec!["--store", "team"]);
+        assert_eq!(config.backends.process.timeout_seconds, 5);
+        assert!(!config.backends.mcp.is_configured());
+    }
+
+    #[test]
+    fn test_backends_mcp_default_unconfigured() {
+        let config = Config::default();
+        assert!(!config.backends.mcp.is_configured());
+        assert_eq!(config.backends.mcp.timeout_seconds, 3);
+    }
+
+    #[test]
+    fn test_cap_embedding_timeout_for_hooks() {
+        let mut config = SemanticDedupConfig::default();
+        assert_eq!(config.timeout_seconds, 30);
+
+        config.cap_timeout_for_hooks();
+        assert_eq!(config.timeout_seconds, HOOK_EMBEDDING_TIMEOUT_SECONDS);
+    }
+
+    #[test]
+    fn test_cap_timeouts_for_hooks() {
+        let mut backends = BackendsConfig::default();
+        backends.mcp.timeout_seconds = 30;
+        backends.process.timeout_seconds = 1;
+
+        backends.cap_timeouts_for_hooks();
+
+        assert_eq!(backends.mcp.timeout_seconds, HOOK_BACKEND_TIMEOUT_SECONDS);
+        assert_eq!(backends.process.timeout_seconds, 1);
+    }
+
     #[test]
     #[serial]
     fn test_grove_home_with_env() {
@@ -2218,6 +2794,10 @@ total-recall = false
             project_learnings_path(cwd),
             PathBuf::from("/some/project/.grove/learnings.md")
         );
+        assert_eq!(
+            project_learnings_db_path(cwd),
+            PathBuf::from("/some/project/.grove/learnings.db")
+        );
         assert_eq!(
             project_stats_log_path(cwd),
             PathBuf::from("/some/project/.grove/stats.log")
@@ -2246,6 +2826,7 @@ total-recall = false
             backends: BackendsConfig {
                 discovery: vec!["markdown".to_string()],
                 overrides: HashMap::new(),
+                ..Default::default()
             },
             gate: GateConfig {
                 auto_skip: AutoSkipConfig {
@@ -2277,8 +2858,12 @@ total-recall = false
                 adaptive_dk: false,
                 corpus_size_threshold: 50,
                 corpus_enrichment: true,
+                example_token_budget: 300,
+                token_budget: 0,
                 intent_filter: IntentFilterConfig::default(),
                 rerank: RerankConfig::default(),
+                hybrid: HybridConfig::default(),
+                diversity: DiversityConfig::default(),
             },
             circuit_breaker: CircuitBreakerConfig {
                 max_blocks: 5,
@@ -2887,6 +3472,35 @@ skip_counts_as_dismissal = false
         ));
     }
 
+    #[test]
+    fn test_semantic_dedup_provider_toml_and_merge() {
+        let toml_str = r#"
+[gate.semantic_dedup]
+provider = "openai"
+model = "nomic-embed-text"
+api_url = "http://localhost:11434/v1/embeddings"
+"#;
+        let project: Config = toml::from_str(toml_str).unwrap();
+        let dedup = &project.gate.semantic_dedup;
+        assert_eq!(dedup.provider, "openai");
+        assert_eq!(dedup.api_key_env, "OPENAI_API_KEY");
+        assert_eq!(dedup.dimensions, 256);
+
+        let mut base = Config::default();
+        base.gate.semantic_dedup.dimensions = 128;
+        let merged = base.merge(project);
+        assert_eq!(merged.gate.semantic_dedup.provider, "openai");
+        assert_eq!(merged.gate.semantic_dedup.model, "nomic-embed-text");
+        assert_eq!(
+            merged.gate.semantic_dedup.api_url,
+            "http://localhost:11434/v1/embeddings"
+        );
+        assert_eq!(merged.gate.semantic_dedup.dimensions, 128);
+
+        assert!(SemanticDedupConfig::is_valid_provider("hashing"));
+        assert!(!SemanticDedupConfig::is_valid_provider("word2vec"));
+    }
+
     // Category-aware decay threshold tests
 
     #[test]
@@ -3394,6 +4008,104 @@ max_injections = 10
         assert_eq!(config.retrieval.rerank, deserialized.retrieval.rerank);
     }
 
+    // =========================================================================
+    // Hybrid Config Tests
+    // =========================================================================
+
+    #[test]
+    fn test_hybrid_toml_deserialization_and_merge() {
+        let dir = TempDir::new().unwrap();
+        let config_path = dir.path().join("config.toml");
+
+        let toml_content = r#"
+[retrieval]
+scoring_backend = "hybrid"
+
+[retrieval.hybrid]
+rrf_k = 30.0
+embedding_weight = 0.5
+"#;
+
+        fs::write(&config_path, toml_content).unwrap();
+        let project = Config::load_from_file(&config_path).unwrap();
+
+        assert_eq!(project.retrieval.scoring_backend, "hybrid");
+        assert!((project.retrieval.hybrid.rrf_k - 30.0).abs() < f64::EPSILON);
+        assert!((project.retrieval.hybrid.embedding_weight - 0.5).abs() < f64::EPSILON);
+        assert!((project.retrieval.hybrid.bm25_weight - 1.0).abs() < f64::EPSILON);
+        assert!((project.retrieval.hybrid.min_similarity - 0.3).abs() < f64::EPSILON);
+
+        let merged = Config::default().merge(project.clone());
+        assert_eq!(merged.retrieval.hybrid, project.retrieval.hybrid);
+        assert!(RetrievalConfig::is_valid_scoring_backend("hybrid"));
+    }
+
+    #[test]
+    fn test_token_budget_toml_merge_and_diff() {
+        let dir = TempDir::new().unwrap();
+        let config_path = dir.path().join("config.toml");
+        fs::write(&config_path, "[retrieval]\ntoken_budget = 800\n").unwrap();
+        let project = Config::load_from_file(&config_path).unwrap();
+
+        assert_eq!(project.retrieval.token_budget, 800);
+        assert_eq!(Config::default().retrieval.token_budget, 0);
+
+        let merged = Config::default().merge(project);
+        assert_eq!(merged.retrieval.token_budget, 800);
+        let changes = Config::default().diff(&merged);
+        assert!(changes
+            .iter()
+            .any(|(key, old, new)| key == "retrieval.token_budget" && old == "0" && new == "800"));
+    }
+
+    #[test]
+    fn test_file_triggers_toml_and_merge() {
+        let dir = TempDir::new().unwrap();
+        let config_path = dir.path().join("config.toml");
+        fs::write(&config_path, "[context]\nfile_triggers = false\n").unwrap();
+        let project = Config::load_from_file(&config_path).unwrap();
+
+        assert!(!project.context.file_triggers);
+        assert!(Config::default().context.file_triggers);
+        assert!(!Config::default().merge(project).context.file_triggers);
+    }
+
+    #[test]
+    fn test_command_guards_toml_and_merge() {
+        let dir = TempDir::new().unwrap();
+        let config_path = dir.path().join("config.toml");
+        fs::write(&config_path, "[context]\ncommand_guards = false\n").unwrap();
+        let project = Config::load_from_file(&config_path).unwrap();
+
+        assert!(!project.context.command_guards);
+        assert!(project.context.file_triggers);
+        assert!(Config::default().context.command_guards);
+        assert!(!Config::default().merge(project).context.command_guards);
+    }
+
+    #[test]
+    fn test_diversity_toml_deserialization_and_merge() {
+        let dir = TempDir::new().unwrap();
+        let config_path = dir.path().join("config.toml");
+
+        let toml_content = r#"
+[retrieval.diversity]
+enabled = true
+lambda = 0.5
+"#;
+
+        fs::write(&config_path, toml_content).unwrap();
+        let project = Config::load_from_file(&config_path).unwrap();
+
+        assert!(project.retrieval.diversity.enabled);
+        assert!((project.retrieval.diversity.lambda - 0.5).abs() < f64::EPSILON);
+        assert!(project.retrieval.diversity.use_embeddings);
+        assert!(!Config::default().retrieval.diversity.enabled);
+
+        let merged = Config::default().merge(project.clone());
+        assert_eq!(merged.retrieval.diversity, project.retrieval.diversity);
+    }
+
     // =========================================================================
     // Category Half-Life Config Tests
     // =========================================================================
@@ -1,20 +1,31 @@
-//! Semantic deduplication via embedding similarity.
+//! Embedding providers and semantic similarity.
 //!
-//! Provides cosine similarity computation (always available) and ONNX-based
-//! embedding generation (behind the `semantic-dedup` feature flag) for
-//! detecting paraphrase-level duplicates that string matching misses.
+//! Provides cosine similarity, the [`EmbeddingProvider`] trait, and a sidecar
+//! cache of learning embeddings shared by semantic deduplication and hybrid
+//! retrieval. Three providers are available, selected by
+//! [`SemanticDedupConfig::provider`] through [`create_provider`]:
+//!
+//! - `fastembed`: local ONNX model (behind the `semantic-dedup` feature flag)
+//! - `openai`: any OpenAI-compatible `/v1/embeddings` HTTP endpoint, called via curl
+//! - `hashing`: deterministic feature hashing with no model download, for CI and tests
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
 
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "semantic-dedup")]
 use crate::config::SemanticDedupConfig;
-#[cfg(feature = "semantic-dedup")]
 use crate::core::reflect::DuplicateCheckResult;
-#[cfg(feature = "semantic-dedup")]
 use crate::core::CompoundLearning;
+use crate::error::{GroveError, Result};
+
+/// Model ID of the fastembed provider's AllMiniLML6V2 model.
+pub const FASTEMBED_MODEL_ID: &str = "fastembed:all-MiniLM-L6-v2";
+
+/// Default model for the `openai` provider.
+pub const DEFAULT_OPENAI_MODEL: &str = "text-embedding-3-small";
 
 /// Compute cosine similarity between two vectors.
 ///
@@ -46,10 +57,52 @@ pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
 }
 
 /// Trait for embedding text into vectors.
-#[cfg(feature = "semantic-dedup")]
 pub trait EmbeddingProvider: Send + Sync {
     /// Embed one or more texts into vectors.
-    fn embed(&self, texts: &[&str]) -> crate::error::Result<Vec<Vec<f32>>>;
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>>;
+
+    /// Identifies the provider and model (e.g. `"openai:text-embedding-3-small"`).
+    ///
+    /// Vectors from different model IDs are never compared; the embedding
+    /// cache keeps them apart.
+    fn model_id(&self) -> String;
+}
+
+/// Create the embedding provider selected in `config`.
+///
+/// Fails when the provider is unknown, or is `fastembed` and grove was built
+/// without the `semantic-dedup` feature.
+pub fn create_provider(config: &SemanticDedupConfig) -> Result<Box<dyn EmbeddingProvider>> {
+    match config.provider.as_str() {
+        "hashing" => Ok(Box::new(HashingEmbeddingProvider::new(config.dimensions))),
+        "openai" => Ok(Box::new(OpenAiEmbeddingProvider::from_config(config))),
+        #[cfg(feature = "semantic-dedup")]
+        "fastembed" => Ok(Box::new(FastEmbedProvider::new()?)),
+        #[cfg(not(feature = "semantic-dedup"))]
+        "fastembed" => Err(GroveError::config(
+            "The fastembed embedding provider requires grove built with the semantic-dedup \
+             feature; set gate.semantic_dedup.provider to \"openai\" or \"hashing\" instead",
+        )),
+        other => Err(GroveError::config(format!(
+            "Unknown embedding provider '{}'. Valid: {:?}",
+            other,
+            crate::config::VALID_EMBEDDING_PROVIDERS
+        ))),
+    }
+}
+
+/// Model ID of the provider selected in `config`, without creating it.
+///
+/// Lets callers read cached vectors without loading a model. Returns `None`
+/// for unknown providers.
+pub fn provider_model_id(config: &SemanticDedupConfig) -> Option<String> {
+    match config.provider.as_str() {
+        "hashing" => Some(format!("hashing:{}", config.dimensions.max(1))),
+        "openai" if config.model.is_empty() => Some(format!("openai:{}", DEFAULT_OPENAI_MODEL)),
+        "openai" => Some(format!("openai:{}", config.model)),
+        "fastembed" => Some(FASTEMBED_MODEL_ID.to_string()),
+        _ => None,
+    }
 }
 
 /// Provider backed by fastembed's AllMiniLML6V2 model.
@@ -64,13 +117,13 @@ pub struct FastEmbedProvider {
 #[cfg(feature = "semantic-dedup")]
 impl FastEmbedProvider {
     /// Create a new provider, downloading the model if needed.
-    pub fn new() -> crate::error::Result<Self> {
+    pub fn new() -> Result<Self> {
         use fastembed::{EmbeddingModel, InitOptions, TextEmbedding};
 
         let model = TextEmbedding::try_new(
             InitOptions::new(EmbeddingModel::AllMiniLML6V2).with_show_download_progress(false),
         )
-        .map_err(|e| crate::error::GroveError::Config {
+        .map_err(|e| GroveError::Config {
             message: format!("fastembed init failed: {e}"),
         })?;
 
@@ -78,51 +131,363 @@ impl FastEmbedProvider {
             model: std::sync::Mutex::new(model),
         })
     }
+
+    /// Create a new provider only if the model is already downloaded.
+    ///
+    /// Hooks use this so a cold cache never triggers a download mid-session.
+    pub fn new_if_cached() -> Result<Self> {
+        use fastembed::{EmbeddingModel, TextEmbedding};
+
+        let info = TextEmbedding::get_model_info(&EmbeddingModel::AllMiniLML6V2).map_err(|e| {
+            GroveError::Config {
+                message: format!("fastembed model info unavailable: {e}"),
+            }
+        })?;
+        let cache_dir = std::env::var("HF_HOME")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| PathBuf::from(fastembed::get_cache_dir()));
+        let mut files = vec![info.model_file.as_str()];
+        files.extend(info.additional_files.iter().map(String::as_str));
+        files.extend(FASTEMBED_TOKENIZER_FILES);
+        if !hf_model_cached(&cache_dir, &info.model_code, &files) {
+            return Err(GroveError::config(format!(
+                "fastembed model {} is not downloaded to {}",
+                info.model_code,
+                cache_dir.display()
+            )));
+        }
+        Self::new()
+    }
+}
+
+/// Tokenizer files fastembed loads alongside the model.
+#[cfg(feature = "semantic-dedup")]
+const FASTEMBED_TOKENIZER_FILES: [&str; 4] = [
+    "tokenizer.json",
+    "config.json",
+    "special_tokens_map.json",
+    "tokenizer_config.json",
+];
+
+/// Whether `files` of the Hugging Face model `model_code` are in the hub
+/// cache at `cache_dir` (`models--<org>--<name>/snapshots/<refs/main>/`).
+#[cfg_attr(not(feature = "semantic-dedup"), allow(dead_code))]
+fn hf_model_cached(cache_dir: &Path, model_code: &str, files: &[&str]) -> bool {
+    let repo = cache_dir.join(format!("models--{}", model_code.replace('/', "--")));
+    let Ok(commit) = std::fs::read_to_string(repo.join("refs").join("main")) else {
+        return false;
+    };
+    let snapshot = repo.join("snapshots").join(commit.trim());
+    files.iter().all(|file| snapshot.join(file).is_file())
 }
 
 #[cfg(feature = "semantic-dedup")]
 impl EmbeddingProvider for FastEmbedProvider {
-    fn embed(&self, texts: &[&str]) -> crate::error::Result<Vec<Vec<f32>>> {
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
         let owned: Vec<String> = texts.iter().map(|s| s.to_string()).collect();
         self.model
             .lock()
-            .map_err(|e| crate::error::GroveError::Config {
+            .map_err(|e| GroveError::Config {
                 message: format!("embedding lock poisoned: {e}"),
             })?
             .embed(owned, None)
-            .map_err(|e| crate::error::GroveError::Config {
+            .map_err(|e| GroveError::Config {
                 message: format!("embedding failed: {e}"),
             })
     }
+
+    fn model_id(&self) -> String {
+        FASTEMBED_MODEL_ID.to_string()
+    }
+}
+
+/// Provider for an OpenAI-compatible `/v1/embeddings` endpoint.
+///
+/// Works with OpenAI itself and with local servers that speak the same API
+/// (Ollama, llama.cpp, vLLM, LM Studio). Requests go through `curl`, like the
+/// rest of Grove's HTTP calls. The API key is optional so unauthenticated
+/// local servers work.
+pub struct OpenAiEmbeddingProvider {
+    api_url: String,
+    model: String,
+    api_key: Option<String>,
+    timeout_seconds: u64,
+}
+
+impl OpenAiEmbeddingProvider {
+    /// Create a provider for `model` at `api_url`.
+    pub fn new(
+        api_url: impl Into<String>,
+        model: impl Into<String>,
+        api_key: Option<String>,
+        timeout_seconds: u64,
+    ) -> Self {
+        Self {
+            api_url: api_url.into(),
+            model: model.into(),
+            api_key,
+            timeout_seconds,
+        }
+    }
+
+    /// Create a provider from config, reading the API key from `api_key_env`.
+    pub fn from_config(config: &SemanticDedupConfig) -> Self {
+        let model = if config.model.is_empty() {
+            DEFAULT_OPENAI_MODEL
+        } else {
+            config.model.as_str()
+        };
+        let api_key = std::env::var(&config.api_key_env)
+            .ok()
+            .filter(|key| !key.is_empty());
+        Self::new(&config.api_url, model, api_key, config.timeout_seconds)
+    }
+
+    /// Parse an embeddings response, ordering vectors by their `index`.
+    fn parse_response(body: &str, expected: usize) -> Result<Vec<Vec<f32>>> {
+        #[derive(Deserialize)]
+        struct Item {
+            #[serde(default)]
+            index: usize,
+            embedding: Vec<f32>,
+        }
+
+        let json: serde_json::Value = serde_json::from_str(body)
+            .map_err(|e| GroveError::serde(format!("Invalid embeddings response: {}", e)))?;
+
+        if let Some(err) = json.get("error") {
+            let message = err
+                .get("message")
+                .and_then(|m| m.as_str())
+                .or_else(|| err.as_str())
+                .unwrap_or("unknown error");
+            return Err(GroveError::backend(format!(
+                "Embeddings API error: {}",
+                message
+            )));
+        }
+
+        let data = json.get("data").cloned().unwrap_or_default();
+        let mut items: Vec<Item> = serde_json::from_value(data)
+            .map_err(|e| GroveError::serde(format!("Invalid embeddings response: {}", e)))?;
+        if items.len() != expected {
+            return Err(GroveError::backend(format!(
+                "Embeddings API returned {} vectors for {} inputs",
+                items.len(),
+                expected
+            )));
+        }
+        items.sort_by_key(|item| item.index);
+        Ok(items.into_iter().map(|item| item.embedding).collect())
+    }
+}
+
+impl EmbeddingProvider for OpenAiEmbeddingProvider {
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let body = serde_json::json!({ "model": self.model, "input": texts }).to_string();
+        let timeout = self.timeout_seconds.to_string();
+        let mut command = Command::new("curl");
+        command.args([
+            "-sS",
+            "--max-time",
+            &timeout,
+            "-X",
+            "POST",
+            &self.api_url,
+            "-H",
+            "content-type: application/json",
+            "--data-binary",
+            "@-",
+        ]);
+        if let Some(ref key) = self.api_key {
+            command.args(["-H", &format!("authorization: Bearer {}", key)]);
+        }
+
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| GroveError::backend(format!("Failed to invoke curl: {}", e)))?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(body.as_bytes()).map_err(|e| {
+                GroveError::backend(format!("Failed to send embeddings request: {}", e))
+            })?;
+        }
+        let output = child
+            .wait_with_output()
+            .map_err(|e| GroveError::backend(format!("Failed to run curl: {}", e)))?;
+
+        if !output.status.success() {
+            return Err(GroveError::backend(format!(
+                "Embeddings request to {} failed ({}): {}",
+                self.api_url,
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        Self::parse_response(&String::from_utf8_lossy(&output.stdout), texts.len())
+    }
+
+    fn model_id(&self) -> String {
+        format!("openai:{}", self.model)
+    }
 }
 
-/// Sidecar cache mapping learning IDs to their embedding vectors.
+/// Deterministic provider based on feature hashing.
 ///
-/// Stored as JSON at `.grove/embeddings.json`. Fail-open on all I/O errors.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+/// Each lowercased word and each character trigram of a word is hashed into
+/// one of `dimensions` buckets with a hash-derived sign, and the vector is
+/// L2-normalized. Texts sharing words or word fragments come out similar.
+/// No model, no network, and identical output on every machine, which makes
+/// it suitable for CI and tests. It captures lexical overlap only, not
+/// meaning.
+pub struct HashingEmbeddingProvider {
+    dimensions: usize,
+}
+
+impl HashingEmbeddingProvider {
+    /// Default vector size.
+    pub const DEFAULT_DIMENSIONS: usize = 256;
+
+    /// Create a provider producing vectors of `dimensions` (at least 1).
+    pub fn new(dimensions: usize) -> Self {
+        Self {
+            dimensions: dimensions.max(1),
+        }
+    }
+
+    fn embed_one(&self, text: &str) -> Vec<f32> {
+        let mut vector = vec![0.0_f32; self.dimensions];
+        let mut add = |feature: &str, weight: f32| {
+            let hash = fnv1a(feature);
+            let bucket = (hash % self.dimensions as u64) as usize;
+            let sign = if hash >> 63 == 0 { 1.0 } else { -1.0 };
+            vector[bucket] += sign * weight;
+        };
+
+        let lower = text.to_lowercase();
+        for word in lower.split(|c: char| !c.is_alphanumeric()) {
+            if word.is_empty() {
+                continue;
+            }
+            add(word, 1.0);
+            let padded: Vec<char> = format!("<{}>", word).chars().collect();
+            for gram in padded.windows(3) {
+                add(&gram.iter().collect::<String>(), 0.5);
+            }
+        }
+
+        let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            for v in &mut vector {
+                *v /= norm;
+            }
+        }
+        vector
+    }
+}
+
+impl Default for HashingEmbeddingProvider {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_DIMENSIONS)
+    }
+}
+
+impl EmbeddingProvider for HashingEmbeddingProvider {
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        Ok(texts.iter().map(|text| self.embed_one(text)).collect())
+    }
+
+    fn model_id(&self) -> String {
+        format!("hashing:{}", self.dimensions)
+    }
+}
+
+/// 64-bit FNV-1a, stable across Rust releases and platforms.
+fn fnv1a(text: &str) -> u64 {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    text.bytes().fold(OFFSET, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+/// Sidecar cache mapping learnings to the embedding vectors of their summaries.
+///
+/// Stored as JSON at `.grove/embeddings.json`, grouped by the model ID of the
+/// provider that produced them, so switching providers or models never mixes
+/// vectors. Entries are keyed by learning ID plus an FNV hash of the summary,
+/// so an edited summary (or another learning reusing the ID) misses the cache
+/// instead of reading a stale vector. A cache holds the vectors of one model;
+/// saving keeps the other models' vectors in the file. Fail-open on all I/O
+/// errors.
+#[derive(Debug, Clone, Default)]
 pub struct EmbeddingCache {
-    /// Map of learning_id → embedding vector.
+    /// Model ID the vectors belong to (see [`EmbeddingProvider::model_id`]).
+    pub model_id: String,
+
+    /// Map of `learning_id:summary_hash` → embedding vector.
     pub entries: HashMap<String, Vec<f32>>,
 
-    /// Path to the cache file (not serialized).
-    #[serde(skip)]
+    /// Path to the cache file.
     path: PathBuf,
 }
 
+/// On-disk layout of the embedding cache.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheFile {
+    /// Map of model ID → `learning_id:summary_hash` → embedding vector.
+    #[serde(default)]
+    models: BTreeMap<String, HashMap<String, Vec<f32>>>,
+}
+
+impl CacheFile {
+    /// Read the cache file. Returns an empty file on any error (fail-open).
+    ///
+    /// Vectors cached under a bare learning ID (before entries carried a
+    /// summary hash) can't be checked against the summary and are dropped.
+    fn read(path: &Path) -> Self {
+        let mut file: CacheFile = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        for entries in file.models.values_mut() {
+            entries.retain(|key, _| has_summary_hash(key));
+        }
+        file
+    }
+}
+
+/// Cache key for a learning: its ID plus the FNV hash of its summary.
+fn entry_key(learning: &CompoundLearning) -> String {
+    format!("{}:{:016x}", learning.id, fnv1a(&learning.summary))
+}
+
+/// Whether a cache key ends in a summary hash (see [`entry_key`]).
+fn has_summary_hash(key: &str) -> bool {
+    key.rsplit_once(':')
+        .is_some_and(|(_, hash)| hash.len() == 16 && hash.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
 impl EmbeddingCache {
-    /// Load cache from disk. Returns empty cache on any error (fail-open).
-    pub fn load(path: &Path) -> Self {
+    /// Load the vectors of `model_id` from the cache in `path`.
+    /// Returns an empty cache on any error (fail-open).
+    pub fn load(path: &Path, model_id: &str) -> Self {
         let cache_path = path.join("embeddings.json");
-        match std::fs::read_to_string(&cache_path) {
-            Ok(content) => {
-                let mut cache: EmbeddingCache = serde_json::from_str(&content).unwrap_or_default();
-                cache.path = cache_path;
-                cache
-            }
-            Err(_) => EmbeddingCache {
-                entries: HashMap::new(),
-                path: cache_path,
-            },
+        let entries = CacheFile::read(&cache_path)
+            .models
+            .remove(model_id)
+            .unwrap_or_default();
+        EmbeddingCache {
+            model_id: model_id.to_string(),
+            entries,
+            path: cache_path,
         }
     }
 
@@ -132,7 +497,10 @@ impl EmbeddingCache {
         if let Some(parent) = self.path.parent() {
             let _ = std::fs::create_dir_all(parent);
         }
-        match serde_json::to_string(self) {
+        let mut file = CacheFile::read(&self.path);
+        file.models
+            .insert(self.model_id.clone(), self.entries.clone());
+        match serde_json::to_string(&file) {
             Ok(json) => {
                 let tmp_path = self.path.with_extension("json.tmp");
                 if let Err(e) = std::fs::write(&tmp_path, json) {
@@ -150,14 +518,14 @@ impl EmbeddingCache {
         }
     }
 
-    /// Get a cached embedding by learning ID.
-    pub fn get(&self, id: &str) -> Option<&Vec<f32>> {
-        self.entries.get(id)
+    /// Get the cached embedding of a learning's current summary.
+    pub fn get(&self, learning: &CompoundLearning) -> Option<&Vec<f32>> {
+        self.entries.get(&entry_key(learning))
     }
 
-    /// Insert an embedding for a learning ID.
-    pub fn insert(&mut self, id: String, embedding: Vec<f32>) {
-        self.entries.insert(id, embedding);
+    /// Insert the embedding of a learning's current summary.
+    pub fn insert(&mut self, learning: &CompoundLearning, embedding: Vec<f32>) {
+        self.entries.insert(entry_key(learning), embedding);
     }
 }
 
@@ -170,7 +538,6 @@ impl EmbeddingCache {
 /// pre-filter, but this ensures archived/superseded learnings never ghost-block).
 ///
 /// Fail-open: any embedding error results in `no_duplicate()`.
-#[cfg(feature = "semantic-dedup")]
 pub fn check_semantic_duplicate(
     candidate_summary: &str,
     existing_learnings: &[CompoundLearning],
@@ -200,7 +567,7 @@ pub fn check_semantic_duplicate(
     let uncached: Vec<usize> = active
         .iter()
         .enumerate()
-        .filter(|(_, l)| cache.get(&l.id).is_none())
+        .filter(|(_, l)| cache.get(l).is_none())
         .map(|(i, _)| i)
         .collect();
 
@@ -213,7 +580,7 @@ pub fn check_semantic_duplicate(
         match provider.embed(&texts) {
             Ok(embeddings) => {
                 for (idx, embedding) in uncached.iter().zip(embeddings) {
-                    cache.insert(active[*idx].id.clone(), embedding);
+                    cache.insert(active[*idx], embedding);
                 }
             }
             Err(e) => {
@@ -227,7 +594,7 @@ pub fn check_semantic_duplicate(
 
     // Compare candidate against all active learnings
     for learning in &active {
-        if let Some(existing_embedding) = cache.get(&learning.id) {
+        if let Some(existing_embedding) = cache.get(learning) {
             let similarity = cosine_similarity(&candidate_embedding, existing_embedding);
             if similarity >= config.similarity_threshold {
                 return DuplicateCheckResult {
@@ -250,6 +617,28 @@ pub fn check_semantic_duplicate(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_hf_model_cached_requires_every_file_in_the_main_snapshot() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let code = "Qdrant/all-MiniLM-L6-v2-onnx";
+        let files = ["model.onnx", "tokenizer.json"];
+        assert!(!hf_model_cached(dir.path(), code, &files));
+
+        let repo = dir.path().join("models--Qdrant--all-MiniLM-L6-v2-onnx");
+        let snapshot = repo.join("snapshots").join("abc123");
+        std::fs::create_dir_all(&snapshot).unwrap();
+        std::fs::create_dir_all(repo.join("refs")).unwrap();
+        std::fs::write(repo.join("refs").join("main"), "abc123\n").unwrap();
+        std::fs::write(snapshot.join("model.onnx"), "onnx").unwrap();
+        assert!(
+            !hf_model_cached(dir.path(), code, &files),
+            "partial download"
+        );
+
+        std::fs::write(snapshot.join("tokenizer.json"), "{}").unwrap();
+        assert!(hf_model_cached(dir.path(), code, &files));
+    }
+
     #[test]
     fn test_cosine_identical_vectors() {
         let v = vec![1.0, 2.0, 3.0];
@@ -320,26 +709,175 @@ mod tests {
         assert!((sim - 0.0).abs() < 1e-6, "zero vector should return 0.0");
     }
 
+    fn cached_learning(id: &str, summary: &str) -> CompoundLearning {
+        use crate::core::{Confidence, LearningCategory, LearningScope};
+        CompoundLearning::new(
+            LearningCategory::Pattern,
+            summary,
+            "detail",
+            LearningScope::Project,
+            Confidence::High,
+            vec![],
+            vec![],
+            "session",
+        )
+        .with_id(id)
+    }
+
     #[test]
     fn test_embedding_cache_roundtrip() {
         let dir = tempfile::tempdir().unwrap();
         let grove_dir = dir.path();
+        let first = cached_learning("L001", "First summary");
+        let second = cached_learning("L002", "Second summary");
 
-        let mut cache = EmbeddingCache::load(grove_dir);
-        cache.insert("L001".to_string(), vec![1.0, 2.0, 3.0]);
-        cache.insert("L002".to_string(), vec![4.0, 5.0, 6.0]);
+        let mut cache = EmbeddingCache::load(grove_dir, "hashing:3");
+        cache.insert(&first, vec![1.0, 2.0, 3.0]);
+        cache.insert(&second, vec![4.0, 5.0, 6.0]);
         cache.save();
 
-        let loaded = EmbeddingCache::load(grove_dir);
-        assert_eq!(loaded.get("L001").unwrap(), &vec![1.0, 2.0, 3.0]);
-        assert_eq!(loaded.get("L002").unwrap(), &vec![4.0, 5.0, 6.0]);
-        assert!(loaded.get("L999").is_none());
+        let loaded = EmbeddingCache::load(grove_dir, "hashing:3");
+        assert_eq!(loaded.get(&first).unwrap(), &vec![1.0, 2.0, 3.0]);
+        assert_eq!(loaded.get(&second).unwrap(), &vec![4.0, 5.0, 6.0]);
+        assert!(loaded
+            .get(&cached_learning("L999", "First summary"))
+            .is_none());
+    }
+
+    #[test]
+    fn test_embedding_cache_misses_after_summary_change() {
+        let original = cached_learning("L001", "Retry network calls");
+        let mut cache = EmbeddingCache::default();
+        cache.insert(&original, vec![1.0, 0.0]);
+
+        // An edit, or another source reusing the ID, must not see the old vector
+        let mut edited = original.clone();
+        edited.summary = "Retry network calls with jitter".to_string();
+        assert!(cache.get(&edited).is_none());
+
+        cache.insert(&edited, vec![0.0, 1.0]);
+        assert_eq!(cache.get(&original).unwrap(), &vec![1.0, 0.0]);
+        assert_eq!(cache.get(&edited).unwrap(), &vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_embedding_cache_keeps_models_apart() {
+        let dir = tempfile::tempdir().unwrap();
+        let learning = cached_learning("L001", "Shared summary");
+
+        let mut hashing = EmbeddingCache::load(dir.path(), "hashing:3");
+        hashing.insert(&learning, vec![1.0, 0.0, 0.0]);
+        hashing.save();
+
+        let mut openai = EmbeddingCache::load(dir.path(), "openai:text-embedding-3-small");
+        assert!(
+            openai.get(&learning).is_none(),
+            "other model's vectors hidden"
+        );
+        openai.insert(&learning, vec![0.5; 4]);
+        openai.save();
+
+        // Saving one model keeps the other's vectors
+        let hashing = EmbeddingCache::load(dir.path(), "hashing:3");
+        assert_eq!(hashing.get(&learning).unwrap(), &vec![1.0, 0.0, 0.0]);
+        let openai = EmbeddingCache::load(dir.path(), "openai:text-embedding-3-small");
+        assert_eq!(openai.get(&learning).unwrap(), &vec![0.5; 4]);
+    }
+
+    #[test]
+    fn test_embedding_cache_drops_vectors_keyed_by_id_only() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("embeddings.json"),
+            r#"{"entries": {"L001": [1.0, 2.0]}, "models": {"hashing:2": {"L001": [1.0, 2.0]}}}"#,
+        )
+        .unwrap();
+
+        assert!(EmbeddingCache::load(dir.path(), FASTEMBED_MODEL_ID)
+            .entries
+            .is_empty());
+        assert!(EmbeddingCache::load(dir.path(), "hashing:2")
+            .entries
+            .is_empty());
+    }
+
+    #[test]
+    fn test_hashing_provider_is_deterministic_and_lexical() {
+        let provider = HashingEmbeddingProvider::default();
+        let vectors = provider
+            .embed(&[
+                "Retry network requests with backoff",
+                "Retry failed network requests",
+                "Prefer small focused commits",
+                "Retry network requests with backoff",
+            ])
+            .unwrap();
+
+        assert_eq!(
+            vectors[0].len(),
+            HashingEmbeddingProvider::DEFAULT_DIMENSIONS
+        );
+        assert_eq!(vectors[0], vectors[3], "same text, same vector");
+        let related = cosine_similarity(&vectors[0], &vectors[1]);
+        let unrelated = cosine_similarity(&vectors[0], &vectors[2]);
+        assert!(related > 0.5, "shared words should be similar: {related}");
+        assert!(related > unrelated + 0.3, "{related} vs {unrelated}");
+        assert_eq!(provider.model_id(), "hashing:256");
+    }
+
+    #[test]
+    fn test_openai_parse_response_orders_by_index() {
+        let body = r#"{"object": "list", "data": [
+            {"object": "embedding", "index": 1, "embedding": [0.0, 1.0]},
+            {"object": "embedding", "index": 0, "embedding": [1.0, 0.0]}
+        ], "model": "nomic-embed-text"}"#;
+        let vectors = OpenAiEmbeddingProvider::parse_response(body, 2).unwrap();
+        assert_eq!(vectors, vec![vec![1.0, 0.0], vec![0.0, 1.0]]);
+
+        assert!(OpenAiEmbeddingProvider::parse_response(body, 3).is_err());
+        let error = r#"{"error": {"message": "model not found", "type": "invalid_request_error"}}"#;
+        let err = OpenAiEmbeddingProvider::parse_response(error, 1).unwrap_err();
+        assert!(err.to_string().contains("model not found"));
+    }
+
+    #[test]
+    fn test_create_provider_from_config() {
+        let mut config = SemanticDedupConfig {
+            provider: "hashing".to_string(),
+            dimensions: 64,
+            ..Default::default()
+        };
+        assert_eq!(create_provider(&config).unwrap().model_id(), "hashing:64");
+        assert_eq!(provider_model_id(&config).as_deref(), Some("hashing:64"));
+
+        config.provider = "openai".to_string();
+        assert_eq!(
+            create_provider(&config).unwrap().model_id(),
+            "openai:text-embedding-3-small"
+        );
+        assert_eq!(
+            provider_model_id(&config).as_deref(),
+            Some("openai:text-embedding-3-small")
+        );
+        config.model = "nomic-embed-text".to_string();
+        assert_eq!(
+            create_provider(&config).unwrap().model_id(),
+            "openai:nomic-embed-text"
+        );
+        assert_eq!(
+            provider_model_id(&config).as_deref(),
+            Some("openai:nomic-embed-text")
+        );
+
+        config.provider = "word2vec".to_string();
+        assert!(create_provider(&config).is_err());
+        assert_eq!(provider_model_id(&config), None);
     }
 
     #[test]
     fn test_embedding_cache_missing_file_empty() {
         let dir = tempfile::tempdir().unwrap();
-        let cache = EmbeddingCache::load(dir.path());
+        let cache = EmbeddingCache::load(dir.path(), "hashing:256");
         assert!(cache.entries.is_empty());
     }
 
@@ -347,12 +885,12 @@ mod tests {
     fn test_embedding_cache_corrupt_file_empty() {
         let dir = tempfile::tempdir().unwrap();
         std::fs::write(dir.path().join("embeddings.json"), "not valid json").unwrap();
-        let cache = EmbeddingCache::load(dir.path());
+        let cache = EmbeddingCache::load(dir.path(), "hashing:256");
         assert!(cache.entries.is_empty());
     }
 }
 
-#[cfg(all(test, feature = "semantic-dedup"))]
+#[cfg(test)]
 mod semantic_tests {
     use super::*;
     use crate::core::learning::{
@@ -382,6 +920,10 @@ mod semantic_tests {
             let result: Vec<Vec<f32>> = queue.drain(..n).collect();
             Ok(result)
         }
+
+        fn model_id(&self) -> String {
+            "mock".to_string()
+        }
     }
 
     /// Mock provider that always fails.
@@ -393,6 +935,10 @@ mod semantic_tests {
                 message: "mock embedding failure".into(),
             })
         }
+
+        fn model_id(&self) -> String {
+            "failing".to_string()
+        }
     }
 
     fn make_learning(id: &str, summary: &str) -> CompoundLearning {
@@ -412,6 +958,13 @@ mod semantic_tests {
             context_files: None,
             relevance_context: None,
             status: LearningStatus::Active,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            provenance: None,
+            guard: None,
         }
     }
 
@@ -427,6 +980,7 @@ mod semantic_tests {
         let config = SemanticDedupConfig {
             enabled: true,
             similarity_threshold: 0.90,
+            ..Default::default()
         };
 
         let mut cache = EmbeddingCache::default();
@@ -458,6 +1012,7 @@ mod semantic_tests {
         let config = SemanticDedupConfig {
             enabled: true,
             similarity_threshold: 0.90,
+            ..Default::default()
         };
 
         let mut cache = EmbeddingCache::default();
@@ -484,6 +1039,7 @@ mod semantic_tests {
         let config = SemanticDedupConfig {
             enabled: true,
             similarity_threshold: 0.90,
+            ..Default::default()
         };
 
         let mut cache = EmbeddingCache::default();
@@ -515,11 +1071,12 @@ mod semantic_tests {
         let config = SemanticDedupConfig {
             enabled: true,
             similarity_threshold: 0.90,
+            ..Default::default()
         };
 
         let mut cache = EmbeddingCache::default();
         // Pre-cache the existing learning
-        cache.insert("L001".to_string(), existing_emb);
+        cache.insert(&existing[0], existing_emb);
 
         let result = check_semantic_duplicate(
             "Something different",
@@ -535,6 +1092,7 @@ mod semantic_tests {
         );
     }
 
+    #[cfg(feature = "semantic-dedup")]
     #[test]
     #[ignore] // Requires model download (~22MB)
     fn test_fastembed_provider_live() {
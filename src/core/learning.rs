@@ -62,6 +62,27 @@ pub struct CompoundLearning {
     pub relevance_context: Option<String>,
     /// Current status of the learning.
     pub status: LearningStatus,
+    /// Typed links to other learnings.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub relations: Vec<LearningRelation>,
+    /// Conditions that must hold for the learning to be surfaced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub applies_to: Option<AppliesTo>,
+    /// When the learning stops being true and should no longer be surfaced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
+    /// When the learning should be re-checked by a human.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review_by: Option<DateTime<Utc>>,
+    /// Code snippets and commands that illustrate the learning.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<LearningExample>,
+    /// Who and what produced the learning.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<Provenance>,
+    /// Bash commands that surface the learning right before they run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guard: Option<CommandGuard>,
 }
 
 impl CompoundLearning {
@@ -97,6 +118,13 @@ impl CompoundLearning {
             context_files: None,
             relevance_context: None,
             status: LearningStatus::Active,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            provenance: None,
+            guard: None,
         }
     }
 
@@ -127,6 +155,66 @@ impl CompoundLearning {
         self
     }
 
+    /// Set the relations to other learnings.
+    pub fn with_relations(mut self, relations: Vec<LearningRelation>) -> Self {
+        self.relations = relations;
+        self
+    }
+
+    /// Set the applicability predicates (an empty block clears them).
+    pub fn with_applies_to(mut self, applies_to: AppliesTo) -> Self {
+        self.applies_to = (!applies_to.is_empty()).then_some(applies_to);
+        self
+    }
+
+    /// Set the structured examples.
+    pub fn with_examples(mut self, examples: Vec<LearningExample>) -> Self {
+        self.examples = examples;
+        self
+    }
+
+    /// Set the expiry date.
+    pub fn with_expires_at(mut self, expires_at: DateTime<Utc>) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    /// Set the review-by date.
+    pub fn with_review_by(mut self, review_by: DateTime<Utc>) -> Self {
+        self.review_by = Some(review_by);
+        self
+    }
+
+    /// Set the provenance.
+    pub fn with_provenance(mut self, provenance: Provenance) -> Self {
+        self.provenance = Some(provenance);
+        self
+    }
+
+    /// Set the command guard (a guard without commands clears it).
+    pub fn with_guard(mut self, guard: CommandGuard) -> Self {
+        self.guard = (!guard.is_empty()).then_some(guard);
+        self
+    }
+
+    /// Whether the learning has expired as of `now`.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|at| at <= now)
+    }
+
+    /// Whether the learning is past its review date as of `now`.
+    pub fn is_review_due(&self, now: DateTime<Utc>) -> bool {
+        self.review_by.is_some_and(|at| at <= now)
+    }
+
+    /// IDs of the learnings this one is related to by the given kind.
+    pub fn relation_targets(&self, kind: RelationKind) -> impl Iterator<Item = &str> {
+        self.relations
+            .iter()
+            .filter(move |r| r.kind == kind)
+            .map(|r| r.target.as_str())
+    }
+
     /// Archive this learning.
     pub fn archive(&mut self) {
         self.status = LearningStatus::Archived;
@@ -163,6 +251,22 @@ pub fn generate_learning_id() -> String {
     format!("cl_{}_{:03}", now.format("%Y%m%d"), counter % 1000)
 }
 
+/// Parse an expiry or review date.
+///
+/// Accepts RFC 3339 timestamps or plain `YYYY-MM-DD` dates (midnight UTC).
+pub fn parse_learning_date(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+                .map(|ndt| ndt.and_utc())
+        })
+}
+
 /// Reset the learning ID counter.
 ///
 /// Primarily for testing purposes.
@@ -278,6 +382,564 @@ pub enum Confidence {
     Low,
 }
 
+/// Kind of a typed relation from one learning to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RelationKind {
+    /// Replaces the target; the target is not surfaced alongside this learning.
+    Supersedes,
+    /// Adds detail to or narrows the target.
+    Refines,
+    /// Disagrees with the target.
+    Contradicts,
+    /// Loosely related to the target.
+    Related,
+    /// Only makes sense together with the target, which is surfaced with it.
+    DependsOn,
+}
+
+impl RelationKind {
+    /// Get all relation kinds.
+    pub fn all() -> &'static [RelationKind] {
+        &[
+            RelationKind::Supersedes,
+            RelationKind::Refines,
+            RelationKind::Contradicts,
+            RelationKind::Related,
+            RelationKind::DependsOn,
+        ]
+    }
+
+    /// Get the serialized name for this kind (e.g. `depends_on`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RelationKind::Supersedes => "supersedes",
+            RelationKind::Refines => "refines",
+            RelationKind::Contradicts => "contradicts",
+            RelationKind::Related => "related",
+            RelationKind::DependsOn => "depends_on",
+        }
+    }
+
+    /// Parse a relation kind, accepting `depends-on` as well as `depends_on`.
+    pub fn parse(value: &str) -> Option<Self> {
+        let normalized = value.trim().to_lowercase().replace('-', "_");
+        Self::all()
+            .iter()
+            .copied()
+            .find(|k| k.as_str() == normalized)
+    }
+}
+
+/// A typed edge from a learning to another learning.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LearningRelation {
+    /// Kind of relation.
+    pub kind: RelationKind,
+    /// ID of the related learning.
+    pub target: String,
+}
+
+impl LearningRelation {
+    /// Create a new relation.
+    pub fn new(kind: RelationKind, target: impl Into<String>) -> Self {
+        Self {
+            kind,
+            target: target.into(),
+        }
+    }
+}
+
+/// Kind of a structured example attached to a learning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExampleKind {
+    /// Code to imitate.
+    Good,
+    /// Code to avoid.
+    Bad,
+    /// An exact command to run.
+    Command,
+}
+
+impl ExampleKind {
+    /// Get all example kinds.
+    pub fn all() -> &'static [ExampleKind] {
+        &[ExampleKind::Good, ExampleKind::Bad, ExampleKind::Command]
+    }
+
+    /// Get the serialized name for this kind.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExampleKind::Good => "good",
+            ExampleKind::Bad => "bad",
+            ExampleKind::Command => "command",
+        }
+    }
+
+    /// Parse an example kind (case-insensitive).
+    pub fn parse(value: &str) -> Option<Self> {
+        let normalized = value.trim().to_lowercase();
+        Self::all()
+            .iter()
+            .copied()
+            .find(|k| k.as_str() == normalized)
+    }
+}
+
+/// A code snippet or command that illustrates a learning.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LearningExample {
+    /// Whether this is code to imitate, code to avoid, or a command.
+    pub kind: ExampleKind,
+    /// Language of the snippet (used as the code fence info string).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// The snippet or command itself.
+    pub code: String,
+    /// One-line description of what the example shows.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+}
+
+impl LearningExample {
+    /// Create a new example.
+    pub fn new(kind: ExampleKind, code: impl Into<String>) -> Self {
+        Self {
+            kind,
+            language: None,
+            code: code.into(),
+            caption: None,
+        }
+    }
+
+    /// Set the language.
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Set the caption.
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+}
+
+/// Conditions under which a learning applies.
+///
+/// Each non-empty list is a predicate; a learning is surfaced only when every
+/// predicate has at least one match. Path and branch entries are globs
+/// (`*`, `**`, `?`); languages and tools are compared case-insensitively.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppliesTo {
+    /// Path globs relative to the project root (e.g. `migrations/**`, `*.tsx`).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<String>,
+    /// Language identifiers (e.g. `rust`, `typescript`).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub languages: Vec<String>,
+    /// Branch name globs (e.g. `release/*`).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub branches: Vec<String>,
+    /// Tools that must be in use (e.g. `Bash`, `terraform`).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tools: Vec<String>,
+}
+
+impl AppliesTo {
+    /// Whether no predicates are set.
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+            && self.languages.is_empty()
+            && self.branches.is_empty()
+            && self.tools.is_empty()
+    }
+
+    /// Trim entries and drop empty ones; languages are lowercased.
+    pub fn normalized(&self) -> Self {
+        let clean = |values: &[String], lower: bool| -> Vec<String> {
+            values
+                .iter()
+                .map(|v| v.trim())
+                .filter(|v| !v.is_empty())
+                .map(|v| {
+                    if lower {
+                        v.to_lowercase()
+                    } else {
+                        v.to_string()
+                    }
+                })
+                .collect()
+        };
+        Self {
+            paths: clean(&self.paths, false),
+            languages: clean(&self.languages, true),
+            branches: clean(&self.branches, false),
+            tools: clean(&self.tools, false),
+        }
+    }
+}
+
+impl std::fmt::Display for AppliesTo {
+    /// Formats as `key: a, b` groups separated by `; ` (e.g.
+    /// `paths: migrations/**; languages: sql`).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let groups: Vec<String> = [
+            ("paths", &self.paths),
+            ("languages", &self.languages),
+            ("branches", &self.branches),
+            ("tools", &self.tools),
+        ]
+        .iter()
+        .filter(|(_, values)| !values.is_empty())
+        .map(|(key, values)| format!("{}: {}", key, values.join(", ")))
+        .collect();
+        write!(f, "{}", groups.join("; "))
+    }
+}
+
+/// What a command guard does when a Bash command matches.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuardSeverity {
+    /// Inject the learning before the command runs.
+    #[default]
+    Warn,
+    /// Deny the command, with the learning as the reason.
+    Block,
+}
+
+impl GuardSeverity {
+    /// Get the serialized name for this severity.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GuardSeverity::Warn => "warn",
+            GuardSeverity::Block => "block",
+        }
+    }
+
+    /// Parse a severity (case-insensitive).
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "warn" => Some(GuardSeverity::Warn),
+            "block" => Some(GuardSeverity::Block),
+            _ => None,
+        }
+    }
+}
+
+/// Bash commands that surface a learning right before they run.
+///
+/// Patterns wrapped in slashes (`/git push .*--force/`) are regexes matched
+/// anywhere in the command. Other patterns are command prefixes matched word
+/// by word against each command in a `&&`, `||`, `;`, or `|` chain, after
+/// environment assignments and `sudo` (`cargo publish` matches
+/// `cd crate && sudo cargo publish --allow-dirty`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CommandGuard {
+    /// Command prefixes or `/regex/` patterns.
+    pub commands: Vec<String>,
+    /// What happens when a command matches.
+    pub severity: GuardSeverity,
+}
+
+impl CommandGuard {
+    /// Whether no command patterns are set.
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Trim patterns (collapsing whitespace in prefixes) and drop empty ones.
+    pub fn normalized(&self) -> Self {
+        let commands = self
+            .commands
+            .iter()
+            .map(|c| c.trim())
+            .filter(|c| !c.is_empty())
+            .map(|c| {
+                if guard_regex(c).is_some() {
+                    c.to_string()
+                } else {
+                    c.split_whitespace().collect::<Vec<_>>().join(" ")
+                }
+            })
+            .collect();
+        Self {
+            commands,
+            severity: self.severity,
+        }
+    }
+
+    /// Patterns that are not valid regexes, with the parse error.
+    pub fn invalid_patterns(&self) -> Vec<(String, String)> {
+        self.commands
+            .iter()
+            .filter_map(|c| {
+                let error = regex::Regex::new(guard_regex(c)?).err()?.to_string();
+                // Syntax errors end with a one-line description after a caret diagram
+                let reason = error.lines().last().unwrap_or_default();
+                Some((c.clone(), reason.trim_start_matches("error: ").to_string()))
+            })
+            .collect()
+    }
+
+    /// The first pattern matching `command`, if any.
+    ///
+    /// Invalid regexes never match.
+    pub fn matching_pattern(&self, command: &str) -> Option<&str> {
+        self.commands
+            .iter()
+            .find(|pattern| match guard_regex(pattern) {
+                Some(source) => regex::Regex::new(source).is_ok_and(|re| re.is_match(command)),
+                None => command_has_prefix(command, pattern),
+            })
+            .map(String::as_str)
+    }
+}
+
+impl std::fmt::Display for CommandGuard {
+    /// Formats as `severity: block; commands: ["cargo publish"]` (commands
+    /// as a JSON array, since regexes may contain any separator).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "severity: {}; commands: {}",
+            self.severity.as_str(),
+            serde_json::to_string(&self.commands).unwrap_or_default()
+        )
+    }
+}
+
+/// The regex source of a `/regex/` guard pattern.
+fn guard_regex(pattern: &str) -> Option<&str> {
+    pattern
+        .strip_prefix('/')
+        .and_then(|p| p.strip_suffix('/'))
+        .filter(|p| !p.is_empty())
+}
+
+/// Whether any command in a shell chain starts with the words of `prefix`.
+fn command_has_prefix(command: &str, prefix: &str) -> bool {
+    let prefix: Vec<&str> = prefix.split_whitespace().collect();
+    if prefix.is_empty() {
+        return false;
+    }
+    command
+        .split(['&', '|', ';', '\n'])
+        .map(|segment| {
+            segment
+                .split_whitespace()
+                .skip_while(|word| word.contains('=') || *word == "sudo")
+                .enumerate()
+                // Compare the program by name so `/usr/bin/cargo` matches `cargo`
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.rsplit('/').next().unwrap_or(word)
+                    } else {
+                        word
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .any(|words| words.starts_with(&prefix))
+}
+
+/// Command that produced a learning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProvenanceOrigin {
+    /// Captured by `grove reflect` at the end of a session.
+    Reflect,
+    /// Mined from a past session by `grove retroflect`.
+    Retroflect,
+    /// Merged from related learnings by `grove maintain consolidate`.
+    Consolidate,
+}
+
+impl ProvenanceOrigin {
+    /// Get all origins.
+    pub fn all() -> &'static [ProvenanceOrigin] {
+        &[
+            ProvenanceOrigin::Reflect,
+            ProvenanceOrigin::Retroflect,
+            ProvenanceOrigin::Consolidate,
+        ]
+    }
+
+    /// Get the serialized name for this origin.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProvenanceOrigin::Reflect => "reflect",
+            ProvenanceOrigin::Retroflect => "retroflect",
+            ProvenanceOrigin::Consolidate => "consolidate",
+        }
+    }
+
+    /// Parse an origin (case-insensitive).
+    pub fn parse(value: &str) -> Option<Self> {
+        let normalized = value.trim().to_lowercase();
+        Self::all()
+            .iter()
+            .copied()
+            .find(|o| o.as_str() == normalized)
+    }
+}
+
+/// Who and what produced a learning.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Provenance {
+    /// Command that wrote the learning.
+    pub origin: ProvenanceOrigin,
+    /// Git author (`Name <email>`) from the repository's git config.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// Agent that produced the learning (e.g. `main` or a subagent name).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent: Option<String>,
+    /// Model that produced the learning.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Transcript of the session the learning came from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transcript_path: Option<String>,
+    /// Grove version that wrote the learning.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grove_version: Option<String>,
+}
+
+impl Provenance {
+    /// Create provenance for the given origin, stamped with this grove version.
+    pub fn new(origin: ProvenanceOrigin) -> Self {
+        Self {
+            origin,
+            author: None,
+            agent: None,
+            model: None,
+            transcript_path: None,
+            grove_version: Some(env!("CARGO_PKG_VERSION").to_string()),
+        }
+    }
+
+    /// Set the git author (blank values are ignored).
+    pub fn with_author(mut self, author: Option<String>) -> Self {
+        self.author = non_blank(author);
+        self
+    }
+
+    /// Set the agent (blank values are ignored).
+    pub fn with_agent(mut self, agent: Option<String>) -> Self {
+        self.agent = non_blank(agent);
+        self
+    }
+
+    /// Set the model (blank values are ignored).
+    pub fn with_model(mut self, model: Option<String>) -> Self {
+        self.model = non_blank(model);
+        self
+    }
+
+    /// Set the transcript path (blank values and `<unknown>` are ignored).
+    pub fn with_transcript_path(mut self, transcript_path: Option<String>) -> Self {
+        self.transcript_path = non_blank(transcript_path).filter(|p| p != "<unknown>");
+        self
+    }
+}
+
+impl std::fmt::Display for Provenance {
+    /// Formats as `key: value` pairs separated by `; ` (e.g.
+    /// `origin: reflect; agent: main; grove: 0.10.0`).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut pairs = vec![format!("origin: {}", self.origin.as_str())];
+        for (key, value) in [
+            ("author", &self.author),
+            ("agent", &self.agent),
+            ("model", &self.model),
+            ("transcript", &self.transcript_path),
+            ("grove", &self.grove_version),
+        ] {
+            if let Some(value) = value {
+                pairs.push(format!("{}: {}", key, value));
+            }
+        }
+        write!(f, "{}", pairs.join("; "))
+    }
+}
+
+/// Criteria for selecting learnings by provenance.
+///
+/// Text fields match case-insensitively as substrings; unset fields match
+/// everything. Learnings without provenance only match an empty filter.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProvenanceFilter {
+    /// Required origin.
+    pub origin: Option<ProvenanceOrigin>,
+    /// Text the git author must contain.
+    pub author: Option<String>,
+    /// Text the agent must contain.
+    pub agent: Option<String>,
+    /// Text the model must contain.
+    pub model: Option<String>,
+}
+
+impl ProvenanceFilter {
+    /// Whether no criteria are set.
+    pub fn is_empty(&self) -> bool {
+        self.origin.is_none()
+            && self.author.is_none()
+            && self.agent.is_none()
+            && self.model.is_none()
+    }
+
+    /// Whether the learning satisfies every criterion.
+    pub fn matches(&self, learning: &CompoundLearning) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+        let Some(provenance) = &learning.provenance else {
+            return false;
+        };
+        let contains = |needle: &Option<String>, haystack: &Option<String>| match needle {
+            None => true,
+            Some(needle) => haystack
+                .as_ref()
+                .is_some_and(|h| h.to_lowercase().contains(&needle.to_lowercase())),
+        };
+        self.origin.is_none_or(|o| o == provenance.origin)
+            && contains(&self.author, &provenance.author)
+            && contains(&self.agent, &provenance.agent)
+            && contains(&self.model, &provenance.model)
+    }
+}
+
+impl std::fmt::Display for ProvenanceFilter {
+    /// Formats the set criteria as `key=value` pairs (e.g. `origin=reflect, agent=main`).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut pairs = Vec::new();
+        if let Some(origin) = self.origin {
+            pairs.push(format!("origin={}", origin.as_str()));
+        }
+        for (key, value) in [
+            ("author", &self.author),
+            ("agent", &self.agent),
+            ("model", &self.model),
+        ] {
+            if let Some(value) = value {
+                pairs.push(format!("{}={}", key, value));
+            }
+        }
+        write!(f, "{}", pairs.join(", "))
+    }
+}
+
+fn non_blank(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
 /// Write gate criterion that a learning can meet.
 ///
 /// Each learning must claim at least one criterion to pass the write gate.
@@ -800,4 +1462,270 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_relation_kind_parse() {
+        assert_eq!(
+            RelationKind::parse("depends-on"),
+            Some(RelationKind::DependsOn)
+        );
+        assert_eq!(
+            RelationKind::parse("depends_on"),
+            Some(RelationKind::DependsOn)
+        );
+        assert_eq!(
+            RelationKind::parse(" Supersedes "),
+            Some(RelationKind::Supersedes)
+        );
+        assert_eq!(RelationKind::parse("blocks"), None);
+        for kind in RelationKind::all() {
+            assert_eq!(RelationKind::parse(kind.as_str()), Some(*kind));
+        }
+    }
+
+    #[test]
+    fn test_applies_to_serialization() {
+        let learning = CompoundLearning::new(
+            LearningCategory::Pitfall,
+            "Summary",
+            "Detail",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::StableFact],
+            vec![],
+            "s",
+        );
+        assert!(!serde_json::to_string(&learning)
+            .unwrap()
+            .contains("applies_to"));
+        assert!(learning
+            .clone()
+            .with_applies_to(AppliesTo::default())
+            .applies_to
+            .is_none());
+
+        let learning = learning.with_applies_to(AppliesTo {
+            paths: vec!["migrations/**".to_string()],
+            languages: vec!["sql".to_string()],
+            ..Default::default()
+        });
+        let json = serde_json::to_string(&learning).unwrap();
+        assert!(
+            json.contains("\"applies_to\":{\"paths\":[\"migrations/**\"],\"languages\":[\"sql\"]}")
+        );
+
+        let parsed: CompoundLearning = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, learning);
+    }
+
+    #[test]
+    fn test_examples_serialization() {
+        assert_eq!(ExampleKind::parse(" Command "), Some(ExampleKind::Command));
+        assert_eq!(ExampleKind::parse("ugly"), None);
+        for kind in ExampleKind::all() {
+            assert_eq!(ExampleKind::parse(kind.as_str()), Some(*kind));
+        }
+
+        let learning = CompoundLearning::new(
+            LearningCategory::Convention,
+            "Summary",
+            "Detail",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::StableFact],
+            vec![],
+            "s",
+        );
+        assert!(!serde_json::to_string(&learning)
+            .unwrap()
+            .contains("examples"));
+
+        let learning =
+            learning.with_examples(vec![
+                LearningExample::new(ExampleKind::Good, "fetch_all()").with_language("python")
+            ]);
+        let json = serde_json::to_string(&learning).unwrap();
+        assert!(json.contains(
+            "\"examples\":[{\"kind\":\"good\",\"language\":\"python\",\"code\":\"fetch_all()\"}]"
+        ));
+        let parsed: CompoundLearning = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, learning);
+    }
+
+    #[test]
+    fn test_provenance_display_and_filter() {
+        let provenance = Provenance::new(ProvenanceOrigin::Reflect)
+            .with_author(Some("Jane Doe <jane@example.com>".to_string()))
+            .with_agent(Some("  ".to_string()))
+            .with_model(Some("claude-sonnet-4-6".to_string()))
+            .with_transcript_path(Some("<unknown>".to_string()));
+        assert_eq!(provenance.agent, None);
+        assert_eq!(provenance.transcript_path, None);
+        assert_eq!(
+            provenance.to_string(),
+            format!(
+                "origin: reflect; author: Jane Doe <jane@example.com>; model: claude-sonnet-4-6; grove: {}",
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+
+        let learning = CompoundLearning::new(
+            LearningCategory::Pattern,
+            "Summary",
+            "Detail",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::StableFact],
+            vec![],
+            "s",
+        );
+        let with_provenance = learning.clone().with_provenance(provenance);
+
+        assert!(ProvenanceFilter::default().matches(&learning));
+        let filter = ProvenanceFilter {
+            origin: Some(ProvenanceOrigin::Reflect),
+            model: Some("SONNET".to_string()),
+            ..Default::default()
+        };
+        assert!(filter.matches(&with_provenance));
+        assert!(!filter.matches(&learning));
+        assert_eq!(filter.to_string(), "origin=reflect, model=SONNET");
+
+        let filter = ProvenanceFilter {
+            agent: Some("main".to_string()),
+            ..Default::default()
+        };
+        assert!(!filter.matches(&with_provenance));
+        let filter = ProvenanceFilter {
+            origin: Some(ProvenanceOrigin::Consolidate),
+            ..Default::default()
+        };
+        assert!(!filter.matches(&with_provenance));
+    }
+
+    #[test]
+    fn test_applies_to_normalized() {
+        let applies_to = AppliesTo {
+            paths: vec![" *.tsx ".to_string(), "".to_string()],
+            languages: vec!["TypeScript".to_string()],
+            branches: vec!["  ".to_string()],
+            tools: vec!["Bash".to_string()],
+        }
+        .normalized();
+        assert_eq!(applies_to.paths, vec!["*.tsx"]);
+        assert_eq!(applies_to.languages, vec!["typescript"]);
+        assert!(applies_to.branches.is_empty());
+        assert_eq!(applies_to.tools, vec!["Bash"]);
+        assert!(AppliesTo::default().normalized().is_empty());
+    }
+
+    #[test]
+    fn test_command_guard_matching() {
+        let guard = CommandGuard {
+            commands: vec![
+                "  cargo   publish ".to_string(),
+                "/git push .*(-f|--force)/".to_string(),
+                "".to_string(),
+            ],
+            severity: GuardSeverity::Block,
+        }
+        .normalized();
+        assert_eq!(
+            guard.commands,
+            vec!["cargo publish", "/git push .*(-f|--force)/"]
+        );
+
+        assert_eq!(
+            guard.matching_pattern("cargo publish"),
+            Some("cargo publish")
+        );
+        assert_eq!(
+            guard.matching_pattern("cd crate && FOO=1 sudo /usr/bin/cargo publish --dry-run"),
+            Some("cargo publish")
+        );
+        assert_eq!(
+            guard.matching_pattern("git push origin main --force"),
+            Some("/git push .*(-f|--force)/")
+        );
+        assert_eq!(guard.matching_pattern("cargo publisher"), None);
+        assert_eq!(guard.matching_pattern("echo cargo publish"), None);
+        assert_eq!(guard.matching_pattern("git push origin main"), None);
+
+        assert_eq!(
+            guard.to_string(),
+            r#"severity: block; commands: ["cargo publish","/git push .*(-f|--force)/"]"#
+        );
+        assert!(guard.invalid_patterns().is_empty());
+
+        let invalid = CommandGuard {
+            commands: vec!["/rm (-rf/".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(invalid.invalid_patterns().len(), 1);
+        assert_eq!(invalid.matching_pattern("rm (-rf /"), None);
+        assert_eq!(GuardSeverity::parse(" Block "), Some(GuardSeverity::Block));
+        assert_eq!(GuardSeverity::parse("deny"), None);
+    }
+
+    #[test]
+    fn test_expiry_and_review_dates() {
+        let now = Utc::now();
+        let learning = CompoundLearning::new(
+            LearningCategory::Pattern,
+            "Test",
+            "Detail",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::BehaviorChanging],
+            vec![],
+            "session",
+        );
+        assert!(!learning.is_expired(now));
+        assert!(!learning.is_review_due(now));
+
+        let learning = learning
+            .with_expires_at(now - chrono::Duration::days(1))
+            .with_review_by(now + chrono::Duration::days(1));
+        assert!(learning.is_expired(now));
+        assert!(!learning.is_review_due(now));
+
+        assert_eq!(
+            parse_learning_date("2026-03-01"),
+            parse_learning_date("2026-03-01T00:00:00Z")
+        );
+        assert!(parse_learning_date("next tuesday").is_none());
+    }
+
+    #[test]
+    fn test_relations_serialization() {
+        let learning = CompoundLearning::new(
+            LearningCategory::Pattern,
+            "Summary",
+            "Detail",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::StableFact],
+            vec![],
+            "s",
+        );
+        let json = serde_json::to_string(&learning).unwrap();
+        assert!(!json.contains("relations"));
+
+        let learning = learning.with_relations(vec![
+            LearningRelation::new(RelationKind::DependsOn, "cl_a"),
+            LearningRelation::new(RelationKind::Supersedes, "cl_b"),
+            LearningRelation::new(RelationKind::DependsOn, "cl_c"),
+        ]);
+        let json = serde_json::to_string(&learning).unwrap();
+        assert!(json.contains("{\"kind\":\"depends_on\",\"target\":\"cl_a\"}"));
+
+        let parsed: CompoundLearning = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, learning);
+        assert_eq!(
+            parsed
+                .relation_targets(RelationKind::DependsOn)
+                .collect::<Vec<_>>(),
+            vec!["cl_a", "cl_c"]
+        );
+    }
 }
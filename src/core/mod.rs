@@ -9,13 +9,16 @@ pub mod judge;
 pub mod learning;
 pub mod quality;
 pub mod reflect;
+pub mod revision;
 pub mod state;
 
 pub use embeddings::cosine_similarity;
 pub use gate::Gate;
 pub use learning::{
-    generate_learning_id, CompoundLearning, Confidence, LearningCategory, LearningScope,
-    LearningStatus, WriteGateCriterion, LEARNING_SCHEMA_VERSION, PENDING_LEARNING_ID,
+    generate_learning_id, parse_learning_date, AppliesTo, CommandGuard, CompoundLearning,
+    Confidence, ExampleKind, GuardSeverity, LearningCategory, LearningExample, LearningRelation,
+    LearningScope, LearningStatus, Provenance, ProvenanceFilter, ProvenanceOrigin, RelationKind,
+    WriteGateCriterion, LEARNING_SCHEMA_VERSION, PENDING_LEARNING_ID,
 };
 pub use quality::{assess_specificity, QualityCheckMode, SpecificityScore};
 pub use reflect::{
@@ -24,8 +27,9 @@ pub use reflect::{
     CandidateLearning, CriterionPlausibility, DuplicateCheckResult, RejectedCandidate,
     SchemaValidationError, ValidationStage, WriteGateConfidence, WriteGateMode, WriteGateResult,
 };
+pub use revision::{diff_learnings, FieldChange, LearningRevision, RevisionSource};
 pub use state::{
-    CircuitBreakerState, EventType, GateState, GateStatus, InjectedLearning, InjectionOutcome,
-    ReflectionResult, SessionState, SkipDecider, SkipDecision, SubagentObservation,
-    TicketCloseIntent, TicketContext, TraceEvent,
+    CircuitBreakerState, EventType, GateState, GateStatus, InjectedLearning, InjectionExplanation,
+    InjectionOutcome, ReflectionResult, SessionState, SkipDecider, SkipDecision,
+    SubagentObservation, TicketCloseIntent, TicketContext, TraceEvent,
 };
@@ -3,11 +3,13 @@
 //! This module implements Layer 1 (schema) and Layer 2 (write gate) validation
 //! for candidate learnings. Candidates must pass both layers to be written.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::core::learning::{
-    CompoundLearning, Confidence, LearningCategory, LearningScope, LearningStatus,
-    WriteGateCriterion,
+    parse_learning_date, AppliesTo, CommandGuard, CompoundLearning, Confidence, ExampleKind,
+    LearningCategory, LearningExample, LearningRelation, LearningScope, LearningStatus,
+    RelationKind, WriteGateCriterion,
 };
 
 // =============================================================================
@@ -36,6 +38,10 @@ pub const DETAIL_MAX_LENGTH: usize = 2000;
 pub const TAGS_MIN_COUNT: usize = 1;
 /// Maximum number of tags.
 pub const TAGS_MAX_COUNT: usize = 10;
+/// Maximum number of examples.
+pub const EXAMPLES_MAX_COUNT: usize = 5;
+/// Maximum length for an example's code.
+pub const EXAMPLE_CODE_MAX_LENGTH: usize = 2000;
 
 // =============================================================================
 // Types
@@ -145,6 +151,19 @@ pub enum SchemaValidationError {
     NoCriteriaClaimed,
     /// Invalid criterion value.
     InvalidCriterion(String),
+    /// Relation with an unknown kind or invalid target ID.
+    InvalidRelation(String),
+    /// Path predicate that is absolute or escapes the project root.
+    InvalidAppliesTo(String),
+    /// Expiry or review date that is not RFC 3339 or `YYYY-MM-DD`.
+    InvalidDate(String),
+    /// Too many examples.
+    TooManyExamples { count: usize, max: usize },
+    /// Example with an unknown kind, empty or oversized code, or a malformed
+    /// language or caption.
+    InvalidExample { index: usize, reason: String },
+    /// Command guard pattern that is not a valid regex.
+    InvalidGuard { pattern: String, reason: String },
 }
 
 impl std::fmt::Display for SchemaValidationError {
@@ -183,6 +202,24 @@ impl std::fmt::Display for SchemaValidationError {
             SchemaValidationError::InvalidCriterion(c) => {
                 write!(f, "invalid criterion: '{}'", c)
             }
+            SchemaValidationError::InvalidRelation(r) => {
+                write!(f, "invalid relation: '{}'", r)
+            }
+            SchemaValidationError::InvalidAppliesTo(p) => {
+                write!(f, "invalid applies_to path: '{}'", p)
+            }
+            SchemaValidationError::InvalidDate(d) => {
+                write!(f, "invalid date: '{}' (expected YYYY-MM-DD or RFC 3339)", d)
+            }
+            SchemaValidationError::TooManyExamples { count, max } => {
+                write!(f, "too many examples: {} (max: {})", count, max)
+            }
+            SchemaValidationError::InvalidExample { index, reason } => {
+                write!(f, "invalid example at index {}: {}", index, reason)
+            }
+            SchemaValidationError::InvalidGuard { pattern, reason } => {
+                write!(f, "invalid guard pattern '{}': {}", pattern, reason)
+            }
         }
     }
 }
@@ -223,6 +260,190 @@ pub struct CandidateLearning {
     /// When/where this learning should be surfaced during retrieval.
     #[serde(default)]
     pub relevance_context: Option<String>,
+    /// Typed links to existing learnings.
+    #[serde(default)]
+    pub relations: Vec<CandidateRelation>,
+    /// Conditions under which the learning applies (paths, languages, branches, tools).
+    #[serde(default)]
+    pub applies_to: Option<AppliesTo>,
+    /// Date after which the learning no longer holds (YYYY-MM-DD or RFC 3339).
+    #[serde(default)]
+    pub expires_at: Option<String>,
+    /// Date by which the learning should be re-checked (YYYY-MM-DD or RFC 3339).
+    #[serde(default)]
+    pub review_by: Option<String>,
+    /// Code snippets and commands that illustrate the learning.
+    #[serde(default)]
+    pub examples: Vec<CandidateExample>,
+    /// Bash commands (prefixes or `/regex/`) that surface the learning before they run.
+    #[serde(default)]
+    pub guard: Option<CommandGuard>,
+}
+
+/// Raw example input: a kind, the code, and optional language and caption.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CandidateExample {
+    /// Example kind (good, bad, command).
+    pub kind: String,
+    /// Language of the snippet (e.g. "rust", "sh").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// The snippet or command itself.
+    pub code: String,
+    /// One-line description of what the example shows.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+}
+
+/// Raw relation input: a relation kind and the ID of the related learning.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CandidateRelation {
+    /// Relation kind (supersedes, refines, contradicts, related, depends_on).
+    pub kind: String,
+    /// ID of the related learning.
+    pub target: String,
+}
+
+impl From<&CompoundLearning> for CandidateLearning {
+    /// Convert a stored learning back into candidate form so it can be
+    /// re-validated (e.g. after an edit).
+    fn from(learning: &CompoundLearning) -> Self {
+        Self {
+            category: serde_name(&learning.category),
+            summary: learning.summary.clone(),
+            detail: learning.detail.clone(),
+            scope: serde_name(&learning.scope),
+            confidence: serde_name(&learning.confidence),
+            criteria_met: learning.criteria_met.iter().map(serde_name).collect(),
+            tags: learning.tags.clone(),
+            context_files: learning.context_files.clone(),
+            relevance_context: learning.relevance_context.clone(),
+            relations: learning
+                .relations
+                .iter()
+                .map(|r| CandidateRelation {
+                    kind: r.kind.as_str().to_string(),
+                    target: r.target.clone(),
+                })
+                .collect(),
+            applies_to: learning.applies_to.clone(),
+            expires_at: learning.expires_at.map(format_date),
+            review_by: learning.review_by.map(format_date),
+            examples: learning
+                .examples
+                .iter()
+                .map(|e| CandidateExample {
+                    kind: e.kind.as_str().to_string(),
+                    language: e.language.clone(),
+                    code: e.code.clone(),
+                    caption: e.caption.clone(),
+                })
+                .collect(),
+            guard: learning.guard.clone(),
+        }
+    }
+}
+
+/// The serialized (snake_case) name of a unit enum value.
+fn serde_name<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(String::from))
+        .unwrap_or_default()
+}
+
+/// Validate raw examples, trimming languages and captions.
+///
+/// Languages become code fence info strings, so they must be a single token;
+/// captions must fit on one line.
+fn validate_examples(
+    candidates: &[CandidateExample],
+    errors: &mut Vec<SchemaValidationError>,
+) -> Vec<LearningExample> {
+    if candidates.len() > EXAMPLES_MAX_COUNT {
+        errors.push(SchemaValidationError::TooManyExamples {
+            count: candidates.len(),
+            max: EXAMPLES_MAX_COUNT,
+        });
+    }
+
+    let mut examples = Vec::new();
+    for (index, candidate) in candidates.iter().enumerate() {
+        let mut invalid = |reason: &str| {
+            errors.push(SchemaValidationError::InvalidExample {
+                index,
+                reason: reason.to_string(),
+            })
+        };
+
+        let Some(kind) = ExampleKind::parse(&candidate.kind) else {
+            invalid(&format!("unknown kind '{}'", candidate.kind));
+            continue;
+        };
+        let code = candidate.code.trim_end();
+        if code.trim().is_empty() {
+            invalid("code is empty");
+            continue;
+        }
+        if code.chars().count() > EXAMPLE_CODE_MAX_LENGTH {
+            invalid(&format!(
+                "code longer than {} chars",
+                EXAMPLE_CODE_MAX_LENGTH
+            ));
+            continue;
+        }
+        let language = candidate
+            .language
+            .as_deref()
+            .map(|l| l.trim().to_lowercase())
+            .filter(|l| !l.is_empty());
+        if language
+            .as_deref()
+            .is_some_and(|l| l.contains(|c: char| c.is_whitespace() || c == '`'))
+        {
+            invalid("language must be a single word");
+            continue;
+        }
+        let caption = candidate
+            .caption
+            .as_deref()
+            .map(str::trim)
+            .filter(|c| !c.is_empty());
+        if caption.is_some_and(|c| c.contains('\n') || c.chars().count() > SUMMARY_MAX_LENGTH) {
+            invalid(&format!(
+                "caption must be one line of at most {} chars",
+                SUMMARY_MAX_LENGTH
+            ));
+            continue;
+        }
+
+        examples.push(LearningExample {
+            kind,
+            language,
+            code: code.to_string(),
+            caption: caption.map(String::from),
+        });
+    }
+
+    examples
+}
+
+/// Render an expiry or review date in the form accepted by validation.
+fn format_date(date: DateTime<Utc>) -> String {
+    date.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+}
+
+/// Parse an optional date field; blank values mean unset.
+fn parse_date_field(
+    value: Option<&String>,
+    errors: &mut Vec<SchemaValidationError>,
+) -> Option<DateTime<Utc>> {
+    let value = value.map(|v| v.trim()).filter(|v| !v.is_empty())?;
+    let date = parse_learning_date(value);
+    if date.is_none() {
+        errors.push(SchemaValidationError::InvalidDate(value.to_string()));
+    }
+    date
 }
 
 fn default_scope() -> String {
@@ -338,6 +559,44 @@ pub fn validate_schema(
         errors.push(SchemaValidationError::NoCriteriaClaimed);
     }
 
+    // Validate relations
+    let mut relations = Vec::new();
+    for relation in &candidate.relations {
+        let target = relation.target.trim();
+        let valid_target = !target.is_empty()
+            && target
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        match RelationKind::parse(&relation.kind) {
+            Some(kind) if valid_target => relations.push(LearningRelation::new(kind, target)),
+            _ => errors.push(SchemaValidationError::InvalidRelation(format!(
+                "{} {}",
+                relation.kind, relation.target
+            ))),
+        }
+    }
+
+    // Validate applicability predicates (paths are relative to the project root)
+    let applies_to = candidate.applies_to.as_ref().map(AppliesTo::normalized);
+    for path in applies_to.iter().flat_map(|a| &a.paths) {
+        if path.starts_with('/') || path.split('/').any(|part| part == "..") {
+            errors.push(SchemaValidationError::InvalidAppliesTo(path.clone()));
+        }
+    }
+
+    // Validate examples
+    let examples = validate_examples(&candidate.examples, &mut errors);
+
+    // Validate command guard patterns
+    let guard = candidate.guard.as_ref().map(CommandGuard::normalized);
+    for (pattern, reason) in guard.iter().flat_map(CommandGuard::invalid_patterns) {
+        errors.push(SchemaValidationError::InvalidGuard { pattern, reason });
+    }
+
+    // Validate expiry and review dates
+    let expires_at = parse_date_field(candidate.expires_at.as_ref(), &mut errors);
+    let review_by = parse_date_field(candidate.review_by.as_ref(), &mut errors);
+
     // If there are any errors, return them
     if !errors.is_empty() {
         return Err(errors);
@@ -363,6 +622,22 @@ pub fn validate_schema(
         learning = learning.with_relevance_context(ctx.clone());
     }
 
+    if !relations.is_empty() {
+        learning = learning.with_relations(relations);
+    }
+
+    if let Some(applies_to) = applies_to {
+        learning = learning.with_applies_to(applies_to);
+    }
+
+    if let Some(guard) = guard {
+        learning = learning.with_guard(guard);
+    }
+
+    learning.expires_at = expires_at;
+    learning.review_by = review_by;
+    learning.examples = examples;
+
     Ok(learning)
 }
 
@@ -1124,20 +1399,21 @@ pub fn validate_with_duplicates_and_quality_semantic(
     );
 
     // Lazy-init semantic dedup provider + cache if enabled
-    #[cfg(feature = "semantic-dedup")]
     let mut semantic_state: Option<(
         Box<dyn crate::core::embeddings::EmbeddingProvider>,
         crate::core::embeddings::EmbeddingCache,
         crate::config::SemanticDedupConfig,
     )> = None;
 
-    #[cfg(feature = "semantic-dedup")]
     if let Some((grove_dir, config)) = semantic_dedup {
         if config.enabled {
-            match crate::core::embeddings::FastEmbedProvider::new() {
+            match crate::core::embeddings::create_provider(config) {
                 Ok(provider) => {
-                    let cache = crate::core::embeddings::EmbeddingCache::load(grove_dir);
-                    semantic_state = Some((Box::new(provider), cache, config.clone()));
+                    let cache = crate::core::embeddings::EmbeddingCache::load(
+                        grove_dir,
+                        &provider.model_id(),
+                    );
+                    semantic_state = Some((provider, cache, config.clone()));
                 }
                 Err(e) => {
                     tracing::warn!("Semantic dedup: provider init failed (fail-open): {e}");
@@ -1146,10 +1422,6 @@ pub fn validate_with_duplicates_and_quality_semantic(
         }
     }
 
-    // Suppress unused variable warnings when feature is disabled
-    #[cfg(not(feature = "semantic-dedup"))]
-    let _ = semantic_dedup;
-
     // Layer 3: Duplicate detection
     // Track validated learnings to detect duplicates within the same batch
     let mut final_valid: Vec<CompoundLearning> = Vec::new();
@@ -1183,8 +1455,7 @@ pub fn validate_with_duplicates_and_quality_semantic(
             continue;
         }
 
-        // Semantic duplicate check (feature-gated)
-        #[cfg(feature = "semantic-dedup")]
+        // Semantic duplicate check
         {
             if let Some((ref provider, ref mut cache, ref config)) = semantic_state {
                 // Check against existing learnings in storage
@@ -1233,7 +1504,6 @@ pub fn validate_with_duplicates_and_quality_semantic(
     }
 
     // Save embedding cache if used
-    #[cfg(feature = "semantic-dedup")]
     if let Some((_, cache, _)) = &semantic_state {
         cache.save();
     }
@@ -1306,6 +1576,12 @@ mod tests {
             tags: vec!["async".to_string(), "io".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         }
     }
 
@@ -1486,6 +1762,22 @@ mod tests {
         assert_eq!(learning.tags.len(), 2);
     }
 
+    #[test]
+    fn test_candidate_from_learning_roundtrips_through_schema() {
+        let learning = validate_schema(&valid_candidate(), "session-1").unwrap();
+
+        let candidate = CandidateLearning::from(&learning);
+        assert_eq!(candidate.category, "pattern");
+        assert_eq!(candidate.scope, "project");
+        assert_eq!(candidate.confidence, "high");
+        assert_eq!(candidate.criteria_met, vec!["behavior_changing"]);
+
+        let revalidated = validate_schema(&candidate, "session-1").unwrap();
+        assert_eq!(revalidated.category, learning.category);
+        assert_eq!(revalidated.criteria_met, learning.criteria_met);
+        assert_eq!(revalidated.tags, learning.tags);
+    }
+
     #[test]
     fn test_validate_candidate_with_relevance_context() {
         let mut candidate = valid_candidate();
@@ -1665,6 +1957,188 @@ mod tests {
             .any(|e| matches!(e, SchemaValidationError::InvalidCriterion(_))));
     }
 
+    #[test]
+    fn test_validate_relations() {
+        let mut candidate = valid_candidate();
+        candidate.relations = vec![
+            CandidateRelation {
+                kind: "depends-on".to_string(),
+                target: "cl_20260101_001".to_string(),
+            },
+            CandidateRelation {
+                kind: "supersedes".to_string(),
+                target: " cl_20260101_002 ".to_string(),
+            },
+        ];
+
+        let learning = validate_schema(&candidate, "session-1").unwrap();
+        assert_eq!(
+            learning.relations,
+            vec![
+                LearningRelation::new(RelationKind::DependsOn, "cl_20260101_001"),
+                LearningRelation::new(RelationKind::Supersedes, "cl_20260101_002"),
+            ]
+        );
+        assert_eq!(
+            CandidateLearning::from(&learning).relations[0].kind,
+            "depends_on"
+        );
+    }
+
+    #[test]
+    fn test_validate_invalid_relation() {
+        let mut candidate = valid_candidate();
+        candidate.relations = vec![
+            CandidateRelation {
+                kind: "blocks".to_string(),
+                target: "cl_20260101_001".to_string(),
+            },
+            CandidateRelation {
+                kind: "related".to_string(),
+                target: "../etc/passwd".to_string(),
+            },
+        ];
+
+        let errors = validate_schema(&candidate, "session-1").unwrap_err();
+        let invalid = errors
+            .iter()
+            .filter(|e| matches!(e, SchemaValidationError::InvalidRelation(_)))
+            .count();
+        assert_eq!(invalid, 2);
+    }
+
+    #[test]
+    fn test_validate_applies_to() {
+        let mut candidate = valid_candidate();
+        candidate.applies_to = Some(AppliesTo {
+            paths: vec![" migrations/** ".to_string()],
+            languages: vec!["SQL".to_string()],
+            ..Default::default()
+        });
+
+        let learning = validate_schema(&candidate, "session-1").unwrap();
+        let applies_to = learning.applies_to.clone().unwrap();
+        assert_eq!(applies_to.paths, vec!["migrations/**"]);
+        assert_eq!(applies_to.languages, vec!["sql"]);
+        assert_eq!(
+            CandidateLearning::from(&learning).applies_to,
+            Some(applies_to)
+        );
+
+        candidate.applies_to = Some(AppliesTo {
+            paths: vec!["/etc/*".to_string(), "../shared/**".to_string()],
+            ..Default::default()
+        });
+        let errors = validate_schema(&candidate, "session-1").unwrap_err();
+        assert_eq!(
+            errors
+                .iter()
+                .filter(|e| matches!(e, SchemaValidationError::InvalidAppliesTo(_)))
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_validate_expiry_and_review_dates() {
+        let mut candidate = valid_candidate();
+        candidate.expires_at = Some("2026-12-31".to_string());
+        candidate.review_by = Some("  ".to_string());
+
+        let learning = validate_schema(&candidate, "session-1").unwrap();
+        assert_eq!(
+            learning.expires_at,
+            parse_learning_date("2026-12-31T00:00:00Z")
+        );
+        assert!(learning.review_by.is_none());
+        assert_eq!(
+            CandidateLearning::from(&learning).expires_at.as_deref(),
+            Some("2026-12-31T00:00:00Z")
+        );
+
+        candidate.review_by = Some("end of Q3".to_string());
+        let errors = validate_schema(&candidate, "session-1").unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| matches!(e, SchemaValidationError::InvalidDate(d) if d == "end of Q3")));
+    }
+
+    #[test]
+    fn test_validate_guard() {
+        let mut candidate = valid_candidate();
+        candidate.guard = Some(CommandGuard {
+            commands: vec![" git  push --force ".to_string(), " ".to_string()],
+            severity: crate::core::GuardSeverity::Block,
+        });
+        let learning = validate_schema(&candidate, "session-1").unwrap();
+        let guard = learning.guard.as_ref().unwrap();
+        assert_eq!(guard.commands, vec!["git push --force"]);
+        assert_eq!(
+            CandidateLearning::from(&learning).guard.as_ref(),
+            Some(guard)
+        );
+
+        candidate.guard = Some(CommandGuard {
+            commands: vec!["/[unclosed/".to_string()],
+            ..Default::default()
+        });
+        let errors = validate_schema(&candidate, "session-1").unwrap_err();
+        assert!(errors.iter().any(
+            |e| matches!(e, SchemaValidationError::InvalidGuard { pattern, .. } if pattern == "/[unclosed/")
+        ));
+
+        // A guard without commands is dropped
+        candidate.guard = Some(CommandGuard::default());
+        assert!(validate_schema(&candidate, "session-1")
+            .unwrap()
+            .guard
+            .is_none());
+    }
+
+    #[test]
+    fn test_validate_examples() {
+        let example = |kind: &str, language: Option<&str>, code: &str| CandidateExample {
+            kind: kind.to_string(),
+            language: language.map(String::from),
+            code: code.to_string(),
+            caption: Some(" Run the suite ".to_string()),
+        };
+
+        let mut candidate = valid_candidate();
+        candidate.examples = vec![example("Command", Some(" SH "), "cargo test\n")];
+        let learning = validate_schema(&candidate, "session-1").unwrap();
+        assert_eq!(learning.examples.len(), 1);
+        assert_eq!(learning.examples[0].kind, ExampleKind::Command);
+        assert_eq!(learning.examples[0].language.as_deref(), Some("sh"));
+        assert_eq!(learning.examples[0].code, "cargo test");
+        assert_eq!(
+            learning.examples[0].caption.as_deref(),
+            Some("Run the suite")
+        );
+        assert_eq!(CandidateLearning::from(&learning).examples.len(), 1);
+
+        candidate.examples = vec![
+            example("ugly", None, "x"),
+            example("good", None, "  "),
+            example("good", Some("shell script"), "ls"),
+        ];
+        let errors = validate_schema(&candidate, "session-1").unwrap_err();
+        let indexes: Vec<usize> = errors
+            .iter()
+            .filter_map(|e| match e {
+                SchemaValidationError::InvalidExample { index, .. } => Some(*index),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(indexes, vec![0, 1, 2]);
+
+        candidate.examples = vec![example("good", None, "ls"); EXAMPLES_MAX_COUNT + 1];
+        let errors = validate_schema(&candidate, "session-1").unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| matches!(e, SchemaValidationError::TooManyExamples { .. })));
+    }
+
     #[test]
     fn test_validate_all_invalid_criteria() {
         let mut candidate = valid_candidate();
@@ -2259,6 +2733,12 @@ mod tests {
             tags: vec!["test".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let (valid, rejected) =
@@ -2281,6 +2761,12 @@ mod tests {
             tags: vec!["test".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let (valid, rejected) =
@@ -2303,6 +2789,12 @@ mod tests {
             tags: vec!["test".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let (valid, rejected) =
@@ -2326,6 +2818,12 @@ mod tests {
             tags: vec!["test".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         // All modes reject at schema level
@@ -2359,6 +2857,12 @@ mod tests {
             tags: vec!["test".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let (valid, rejected) = validate_full(vec![candidate], "session-1");
@@ -2417,6 +2921,12 @@ mod tests {
             tags: vec!["test".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let result = check_near_duplicate(&candidate, &existing);
@@ -2442,6 +2952,12 @@ mod tests {
             tags: vec!["test".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let result = check_near_duplicate(&candidate, &existing);
@@ -2466,6 +2982,12 @@ mod tests {
             tags: vec!["test".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let result = check_near_duplicate(&candidate, &existing);
@@ -2490,6 +3012,12 @@ mod tests {
             tags: vec!["test".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let result = check_near_duplicate(&candidate, &existing);
@@ -2514,6 +3042,12 @@ mod tests {
             tags: vec!["test".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let result = check_near_duplicate(&candidate, &existing);
@@ -2538,6 +3072,12 @@ mod tests {
             tags: vec!["test".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let result = check_near_duplicate(&candidate, &existing);
@@ -2559,6 +3099,12 @@ mod tests {
             tags: vec!["test".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let result = check_near_duplicate(&candidate, &existing);
@@ -2590,6 +3136,12 @@ mod tests {
             tags: vec!["test".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let result = check_near_duplicate(&candidate, &existing);
@@ -2618,6 +3170,12 @@ mod tests {
             tags: vec!["test".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let result = check_near_duplicate(&candidate, &existing);
@@ -2646,6 +3204,12 @@ mod tests {
             tags: vec!["test".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let result = check_near_duplicate(&candidate, &existing);
@@ -2675,6 +3239,12 @@ mod tests {
             tags: vec!["test".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let result = check_near_duplicate(&candidate, &existing);
@@ -2700,6 +3270,12 @@ mod tests {
             tags: vec!["test".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let result = check_near_duplicate(&candidate, &existing);
@@ -2725,6 +3301,12 @@ mod tests {
             tags: vec!["test".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let result = check_near_duplicate(&candidate, &existing);
@@ -2750,6 +3332,12 @@ mod tests {
             tags: vec!["test".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let result = check_near_duplicate(&candidate, &existing);
@@ -3037,6 +3625,12 @@ mod tests {
             tags: vec!["testing".to_string(), "general".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let (valid, rejected) = validate_full_with_quality(
@@ -3068,6 +3662,12 @@ mod tests {
             tags: vec!["phoenix".to_string(), "LiveView".to_string(), "elixir".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let (valid, rejected) = validate_full_with_quality(
@@ -3105,6 +3705,12 @@ mod tests {
             tags: vec!["vector".to_string(), "vrl".to_string(), "deployment".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         }
     }
 
@@ -3216,6 +3822,12 @@ mod tests {
             tags: vec!["phoenix".to_string(), "LiveView".to_string(), "elixir".to_string()],
             context_files: None,
             relevance_context: None,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            guard: None,
         };
 
         let judge_fn = |_: &CompoundLearning| -> Option<f64> {
@@ -3258,7 +3870,7 @@ mod tests {
     }
 
     // =========================================================================
-    // Semantic dedup integration tests (feature-gated)
+    // Semantic dedup integration tests
     // =========================================================================
 
     #[test]
@@ -3306,6 +3918,7 @@ mod tests {
         let config = crate::config::SemanticDedupConfig {
             enabled: true,
             similarity_threshold: 0.90,
+            ..Default::default()
         };
 
         let candidate = CandidateLearning {
@@ -3337,6 +3950,48 @@ mod tests {
         assert_eq!(rejected.len(), 1);
     }
 
+    #[test]
+    fn test_semantic_dedup_hashing_provider_catches_rewording() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = crate::config::SemanticDedupConfig {
+            enabled: true,
+            similarity_threshold: 0.75,
+            provider: "hashing".to_string(),
+            ..Default::default()
+        };
+
+        let candidate = CandidateLearning {
+            summary: "Always retry failed network requests with exponential backoff".to_string(),
+            detail: "Transient network failures resolve on retry; back off exponentially."
+                .to_string(),
+            ..valid_candidate()
+        };
+        let existing = vec![semantic_test_learning(
+            "L001",
+            "Retry network requests with exponential backoff on failure",
+        )];
+
+        let (valid, rejected) = validate_with_duplicates_and_quality_semantic(
+            vec![candidate],
+            "test-session",
+            &existing,
+            WriteGateMode::Strict,
+            crate::core::quality::QualityCheckMode::Disabled,
+            0.0,
+            None,
+            (0.0, 0.0, 0.0),
+            Some((dir.path(), &config)),
+        );
+
+        assert!(valid.is_empty(), "rewording should be a semantic duplicate");
+        assert!(rejected[0].rejection_reason.contains("semantic duplicate"));
+        let cache = crate::core::embeddings::EmbeddingCache::load(dir.path(), "hashing:256");
+        assert!(
+            cache.get(&existing[0]).is_some(),
+            "cache keyed by provider model"
+        );
+    }
+
     /// Helper to create a CompoundLearning with a specific ID and summary for dedup tests.
     fn semantic_test_learning(id: &str, summary: &str) -> CompoundLearning {
         use crate::core::learning::{LearningStatus, WriteGateCriterion};
@@ -3357,6 +4012,13 @@ mod tests {
             context_files: None,
             relevance_context: None,
             status: LearningStatus::Active,
+            relations: Vec::new(),
+            applies_to: None,
+            expires_at: None,
+            review_by: None,
+            examples: Vec::new(),
+            provenance: None,
+            guard: None,
         }
     }
 }
@@ -0,0 +1,279 @@
+//! Revision history for learnings.
+//!
+//! Every change to a `CompoundLearning` is recorded as a `LearningRevision`:
+//! what changed it, when, and a field-level diff. Consolidation also links
+//! archived learnings to the merged learning that replaced them.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::CompoundLearning;
+
+/// What produced a revision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RevisionSource {
+    /// Written by `grove reflect` at the end of a session.
+    Reflect,
+    /// Merged or archived by `grove maintain consolidate --apply`.
+    Consolidate,
+    /// Changed by `grove edit`.
+    Edit,
+    /// Mined from a past session by `grove retroflect`.
+    Retroflect,
+}
+
+impl RevisionSource {
+    /// Get the display name for this source.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Reflect => "reflect",
+            Self::Consolidate => "consolidate",
+            Self::Edit => "edit",
+            Self::Retroflect => "retroflect",
+        }
+    }
+}
+
+/// A single field that changed between two versions of a learning.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldChange {
+    /// Name of the field (e.g. "summary", "tags").
+    pub field: String,
+    /// Value before the change (`None` when the field was unset or new).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    /// Value after the change (`None` when the field was cleared).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+}
+
+/// One version in a learning's history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LearningRevision {
+    /// The learning this revision belongs to.
+    pub learning_id: String,
+    /// Revision number, starting at 1. Assigned by the backend on record.
+    #[serde(default)]
+    pub rev: u32,
+    /// What produced this revision.
+    pub source: RevisionSource,
+    /// When the revision was made.
+    pub timestamp: DateTime<Utc>,
+    /// Field-level diff from the previous revision.
+    pub changes: Vec<FieldChange>,
+    /// The learning that replaced this one (set when consolidation archives it).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub superseded_by: Option<String>,
+    /// Learnings merged into this one (set on a consolidated learning's first revision).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub merged_from: Vec<String>,
+}
+
+impl LearningRevision {
+    /// Revision for a newly written learning (every set field is a change).
+    pub fn created(learning: &CompoundLearning, source: RevisionSource) -> Self {
+        Self::new(&learning.id, source, diff_learnings(None, learning))
+    }
+
+    /// Revision for a change from `before` to `after`.
+    pub fn changed(
+        before: &CompoundLearning,
+        after: &CompoundLearning,
+        source: RevisionSource,
+    ) -> Self {
+        Self::new(&after.id, source, diff_learnings(Some(before), after))
+    }
+
+    fn new(learning_id: &str, source: RevisionSource, changes: Vec<FieldChange>) -> Self {
+        Self {
+            learning_id: learning_id.to_string(),
+            rev: 0,
+            source,
+            timestamp: Utc::now(),
+            changes,
+            superseded_by: None,
+            merged_from: Vec::new(),
+        }
+    }
+
+    /// Link this revision to the learning that replaced it.
+    pub fn with_superseded_by(mut self, learning_id: impl Into<String>) -> Self {
+        self.superseded_by = Some(learning_id.into());
+        self
+    }
+
+    /// Record the learnings that were merged into this one.
+    pub fn with_merged_from(mut self, learning_ids: Vec<String>) -> Self {
+        self.merged_from = learning_ids;
+        self
+    }
+
+    /// Names of the fields changed in this revision.
+    pub fn fields(&self) -> Vec<String> {
+        self.changes.iter().map(|c| c.field.clone()).collect()
+    }
+}
+
+/// Compute the field-level diff between two versions of a learning.
+///
+/// With no `before`, every field that has a value is reported as added.
+/// Identity fields (ID, session, timestamp, schema version) are not diffed.
+pub fn diff_learnings(
+    before: Option<&CompoundLearning>,
+    after: &CompoundLearning,
+) -> Vec<FieldChange> {
+    let fields = |l: &CompoundLearning| -> Vec<(&'static str, Option<String>)> {
+        vec![
+            ("category", render(&l.category)),
+            ("summary", render(&l.summary)),
+            ("detail", render(&l.detail)),
+            ("scope", render(&l.scope)),
+            ("confidence", render(&l.confidence)),
+            ("criteria_met", render(&l.criteria_met)),
+            ("tags", render(&l.tags)),
+            ("ticket_id", render(&l.ticket_id)),
+            ("context_files", render(&l.context_files)),
+            ("relevance_context", render(&l.relevance_context)),
+            (
+                "relations",
+                render(
+                    &l.relations
+                        .iter()
+                        .map(|r| format!("{} {}", r.kind.as_str(), r.target))
+                        .collect::<Vec<_>>(),
+                ),
+            ),
+            (
+                "applies_to",
+                l.applies_to
+                    .as_ref()
+                    .map(ToString::to_string)
+                    .filter(|a| !a.is_empty()),
+            ),
+            ("examples", render(&l.examples)),
+            ("guard", l.guard.as_ref().map(ToString::to_string)),
+            ("expires_at", render(&l.expires_at)),
+            ("review_by", render(&l.review_by)),
+            ("status", render(&l.status)),
+        ]
+    };
+
+    let old = before.map(fields);
+    fields(after)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, (field, after))| {
+            let before = old.as_ref().and_then(|o| o[i].1.clone());
+            (before != after).then(|| FieldChange {
+                field: field.to_string(),
+                before,
+                after,
+            })
+        })
+        .collect()
+}
+
+/// Render a field value as display text (`None` for unset or empty values).
+fn render<T: Serialize>(value: &T) -> Option<String> {
+    let text = match serde_json::to_value(value).ok()? {
+        serde_json::Value::Null => return None,
+        serde_json::Value::String(s) => s,
+        serde_json::Value::Array(items) => items
+            .iter()
+            .map(|v| {
+                v.as_str()
+                    .map(String::from)
+                    .unwrap_or_else(|| v.to_string())
+            })
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => other.to_string(),
+    };
+    (!text.is_empty()).then_some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        Confidence, LearningCategory, LearningScope, LearningStatus, WriteGateCriterion,
+    };
+
+    fn sample() -> CompoundLearning {
+        CompoundLearning::new(
+            LearningCategory::Pitfall,
+            "Avoid N+1 queries in UserDashboard",
+            "Use includes(:posts) when loading users for the dashboard.",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::BehaviorChanging],
+            vec!["performance".to_string(), "database".to_string()],
+            "session-1",
+        )
+        .with_id("cl_20260101_000")
+    }
+
+    #[test]
+    fn test_diff_created_lists_set_fields() {
+        let changes = diff_learnings(None, &sample());
+        let fields: Vec<&str> = changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec![
+                "category",
+                "summary",
+                "detail",
+                "scope",
+                "confidence",
+                "criteria_met",
+                "tags",
+                "status"
+            ]
+        );
+        assert!(changes.iter().all(|c| c.before.is_none()));
+        assert_eq!(changes[6].after.as_deref(), Some("performance, database"));
+        assert_eq!(changes[7].after.as_deref(), Some("active"));
+    }
+
+    #[test]
+    fn test_diff_changed_fields_only() {
+        let before = sample();
+        let mut after = before.clone();
+        after.summary = "Avoid N+1 queries in the dashboard controller".to_string();
+        after.status = LearningStatus::Archived;
+        after.context_files = Some(vec!["app/controllers/dashboard.rb".to_string()]);
+
+        let revision = LearningRevision::changed(&before, &after, RevisionSource::Edit);
+        assert_eq!(
+            revision.fields(),
+            vec!["summary", "context_files", "status"]
+        );
+        assert_eq!(
+            revision.changes[0].before.as_deref(),
+            Some("Avoid N+1 queries in UserDashboard")
+        );
+        assert_eq!(revision.changes[1].before, None);
+        assert_eq!(revision.changes[2].after.as_deref(), Some("archived"));
+    }
+
+    #[test]
+    fn test_diff_identical_is_empty() {
+        let learning = sample();
+        assert!(diff_learnings(Some(&learning), &learning).is_empty());
+    }
+
+    #[test]
+    fn test_revision_serialization_roundtrip() {
+        let revision = LearningRevision::created(&sample(), RevisionSource::Consolidate)
+            .with_merged_from(vec!["cl_a".to_string(), "cl_b".to_string()]);
+
+        let json = serde_json::to_string(&revision).unwrap();
+        assert!(json.contains("\"source\":\"consolidate\""));
+        assert!(json.contains("\"merged_from\":[\"cl_a\",\"cl_b\"]"));
+        assert!(!json.contains("superseded_by"));
+
+        let parsed: LearningRevision = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, revision);
+    }
+}
@@ -18,6 +18,9 @@ pub struct SessionState {
     pub cwd: String,
     /// Path to the transcript file.
     pub transcript_path: String,
+    /// Model reported by Claude Code at session start.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
     /// When the session was created.
     pub created_at: DateTime<Utc>,
     /// When the session was last updated.
@@ -44,6 +47,7 @@ impl SessionState {
             id: id.into(),
             cwd: cwd.into(),
             transcript_path: transcript_path.into(),
+            model: None,
             created_at: now,
             updated_at: now,
             gate: GateState::default(),
@@ -100,6 +104,9 @@ pub struct GateState {
     pub subagent_observations: Vec<SubagentObservation>,
     /// Learnings injected at session start.
     pub injected_learnings: Vec<InjectedLearning>,
+    /// Approximate tokens of learning text injected into this session.
+    #[serde(default)]
+    pub injected_tokens: usize,
     /// Pending ticket close intent (pre-confirmation).
     pub ticket_close_intent: Option<TicketCloseIntent>,
     /// Cached diff size from git (lines changed).
@@ -123,6 +130,7 @@ impl Default for GateState {
             skip: None,
             subagent_observations: Vec::new(),
             injected_learnings: Vec::new(),
+            injected_tokens: 0,
             ticket_close_intent: None,
             cached_diff_size: None,
             ticket: None,
@@ -376,6 +384,10 @@ pub enum EventType {
     DeferredInjection,
     /// User prompt submit injection attempted.
     UserPromptInjection,
+    /// File-triggered injection attempted via PreToolUse.
+    FileInjection,
+    /// Command guard matched a Bash command.
+    CommandGuard,
 }
 
 /// Reflection result from a completed reflection.
@@ -481,6 +493,9 @@ pub struct InjectedLearning {
     pub score: f64,
     /// What happened to the learning.
     pub outcome: InjectionOutcome,
+    /// Why the learning was injected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<InjectionExplanation>,
 }
 
 impl InjectedLearning {
@@ -490,9 +505,16 @@ impl InjectedLearning {
             learning_id: learning_id.into(),
             score,
             outcome: InjectionOutcome::Pending,
+            explanation: None,
         }
     }
 
+    /// Attach the retrieval explanation.
+    pub fn with_explanation(mut self, explanation: Option<InjectionExplanation>) -> Self {
+        self.explanation = explanation;
+        self
+    }
+
     /// Mark as referenced.
     pub fn mark_referenced(&mut self) {
         self.outcome = InjectionOutcome::Referenced;
@@ -514,6 +536,69 @@ impl InjectedLearning {
     }
 }
 
+/// Why a learning was injected: the query it was retrieved for, each score
+/// component, and the cutoffs it cleared on the way.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct InjectionExplanation {
+    /// Scoring backend that produced the relevance ("keyword", "bm25", or "hybrid").
+    pub scoring_backend: String,
+    /// Retrieval strategy in effect (after any downgrade).
+    pub strategy: String,
+    /// Query keywords, including corpus enrichment terms.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+    /// Query tags.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Query files.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<String>,
+    /// Relevance from the scoring backend.
+    pub relevance: f64,
+    /// Recency weight.
+    pub recency: f64,
+    /// Reference boost.
+    pub reference: f64,
+    /// Composite score (relevance x recency x reference, after strategy multipliers).
+    pub score: f64,
+    /// Minimum relevance the strategy requires.
+    pub min_relevance: f64,
+    /// Qualified as a recent learning despite no relevance match.
+    #[serde(default)]
+    pub recent_without_match: bool,
+    /// Minimum top score for any injection (adaptive threshold).
+    pub min_confidence: f64,
+    /// Minimum gap between top and median score (adaptive threshold).
+    pub min_score_gap: f64,
+    /// Dynamic K ratio of the top score.
+    pub dynamic_k_ratio: f64,
+    /// Score cutoff from dynamic K (top score x ratio).
+    pub dynamic_k_cutoff: f64,
+    /// Rank among scored candidates (1-based).
+    pub rank: usize,
+    /// Number of candidates that qualified for scoring.
+    pub candidates: usize,
+    /// Fraction of the user's intent keywords found, when the intent filter ran.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub intent_overlap: Option<f64>,
+    /// Highest similarity to a learning picked before this one, when
+    /// diversity (MMR) selection ran.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diversity_similarity: Option<f64>,
+    /// LLM rerank score (1-5), when reranking ran.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rerank_score: Option<f64>,
+    /// ID of the learning that pulled this one in through `depends_on`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dependency_of: Option<String>,
+    /// File whose edit triggered the injection, for file-triggered learnings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trigger_file: Option<String>,
+    /// Bash command whose guard match surfaced the learning.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trigger_command: Option<String>,
+}
+
 /// Outcome of an injected learning.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
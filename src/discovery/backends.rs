@@ -6,6 +6,9 @@
 //! Supported backends:
 //! - **config**: Explicit backend declared in `.grove/config.toml`
 //! - **total-recall**: Total Recall memory system
+//! - **mcp**: MCP memory server configured under `[backends.mcp]`
+//! - **process**: External plugin configured under `[backends.process]`
+//! - **sqlite**: Indexed SQLite database (requires the `sqlite-backend` feature)
 //! - **markdown**: Built-in fallback (always available)
 
 use std::fs;
@@ -13,9 +16,14 @@ use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 
-use crate::backends::{FallbackBackend, MarkdownBackend, MemoryBackend, TotalRecallBackend};
-use crate::config::{project_grove_dir, project_learnings_path, BackendsConfig, Config};
-use crate::error::Result;
+use crate::backends::{
+    FallbackBackend, InvalidatingBackend, LayeredBackend, MarkdownBackend, McpBackend,
+    MemoryBackend, MirrorBackend, OrgBackend, ProcessBackend, TotalRecallBackend,
+};
+use crate::config::{
+    project_grove_dir, project_learnings_db_path, project_learnings_path, BackendsConfig, Config,
+};
+use crate::error::{GroveError, Result};
 
 /// Backend type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -25,6 +33,12 @@ pub enum BackendType {
     Config,
     /// Total Recall memory system.
     TotalRecall,
+    /// SQLite database backend (requires the `sqlite-backend` feature).
+    Sqlite,
+    /// MCP memory server.
+    Mcp,
+    /// External plugin process speaking the JSON-lines protocol.
+    Process,
     /// Built-in markdown backend (always available).
     Markdown,
 }
@@ -35,6 +49,9 @@ impl BackendType {
         match self {
             Self::Config => "config",
             Self::TotalRecall => "total-recall",
+            Self::Sqlite => "sqlite",
+            Self::Mcp => "mcp",
+            Self::Process => "process",
             Self::Markdown => "markdown",
         }
     }
@@ -44,6 +61,9 @@ impl BackendType {
         match s.to_lowercase().as_str() {
             "config" => Some(Self::Config),
             "total-recall" | "totalrecall" | "total_recall" => Some(Self::TotalRecall),
+            "sqlite" | "sqlite3" => Some(Self::Sqlite),
+            "mcp" => Some(Self::Mcp),
+            "process" | "plugin" => Some(Self::Process),
             "markdown" | "md" => Some(Self::Markdown),
             _ => None,
         }
@@ -113,7 +133,7 @@ fn detect_with_config(cwd: &Path, config: &BackendsConfig) -> Vec<BackendInfo> {
         };
 
         // Probe for the backend
-        if let Some(mut info) = probe_backend(cwd, backend_type) {
+        if let Some(mut info) = probe_backend(cwd, backend_type, config) {
             info.is_primary = backends.is_empty();
             backends.push(info);
         }
@@ -133,10 +153,17 @@ fn detect_with_config(cwd: &Path, config: &BackendsConfig) -> Vec<BackendInfo> {
 }
 
 /// Probe for a specific backend type.
-fn probe_backend(cwd: &Path, backend_type: BackendType) -> Option<BackendInfo> {
+fn probe_backend(
+    cwd: &Path,
+    backend_type: BackendType,
+    config: &BackendsConfig,
+) -> Option<BackendInfo> {
     match backend_type {
         BackendType::Config => probe_config(cwd),
         BackendType::TotalRecall => probe_total_recall(cwd),
+        BackendType::Sqlite => probe_sqlite(cwd),
+        BackendType::Mcp => probe_mcp(config),
+        BackendType::Process => probe_process(config),
         BackendType::Markdown => probe_markdown(cwd),
     }
 }
@@ -190,6 +217,56 @@ fn probe_total_recall(cwd: &Path) -> Option<BackendInfo> {
     }
 }
 
+/// Probe for a configured MCP memory server.
+///
+/// Available when `[backends.mcp] command` is set; the command is reported
+/// as the backend location. Reachability is checked by `ping`, not here, so
+/// discovery never launches the server.
+fn probe_mcp(config: &BackendsConfig) -> Option<BackendInfo> {
+    if !config.mcp.is_configured() {
+        return None;
+    }
+
+    Some(BackendInfo::new(
+        BackendType::Mcp,
+        Some(PathBuf::from(&config.mcp.command)),
+        false,
+    ))
+}
+
+/// Probe for a configured process plugin.
+///
+/// Available when `[backends.process] command` is set; like MCP, the plugin
+/// is not launched during discovery.
+fn probe_process(config: &BackendsConfig) -> Option<BackendInfo> {
+    if !config.process.is_configured() {
+        return None;
+    }
+
+    Some(BackendInfo::new(
+        BackendType::Process,
+        Some(PathBuf::from(&config.process.command)),
+        false,
+    ))
+}
+
+/// Probe for the SQLite backend.
+///
+/// Available whenever Grove is built with the `sqlite-backend` feature; the
+/// database is created on first write. Without the feature the backend is
+/// skipped so discovery continues to the next entry.
+fn probe_sqlite(cwd: &Path) -> Option<BackendInfo> {
+    if cfg!(feature = "sqlite-backend") {
+        Some(BackendInfo::new(
+            BackendType::Sqlite,
+            Some(project_learnings_db_path(cwd)),
+            false,
+        ))
+    } else {
+        None
+    }
+}
+
 /// Probe for the built-in markdown backend.
 ///
 /// The markdown backend is always available. This function checks whether
@@ -255,6 +332,8 @@ pub fn create_default_backend(cwd: &Path) -> Result<PathBuf> {
 /// 1. Detects available backends in the project
 /// 2. Selects the primary backend (first detected in discovery order)
 /// 3. Creates and returns the appropriate backend instance
+/// 4. With the `tantivy-search` feature, wraps it so the project's search
+///    index under `.grove/index/` is updated as learnings change
 ///
 /// **Scope routing** happens inside each backend:
 /// - Project/Team → configured storage location
@@ -270,41 +349,230 @@ pub fn create_default_backend(cwd: &Path) -> Result<PathBuf> {
 ///
 /// A boxed memory backend ready for use.
 pub fn create_primary_backend(cwd: &Path, config: Option<&Config>) -> Box<dyn MemoryBackend> {
+    let backends_config = config.map(|c| &c.backends).cloned().unwrap_or_default();
+
+    // An explicit mirror list takes precedence over single-backend discovery
+    if !backends_config.mirror.is_empty() {
+        if let Some(mirror) = create_mirror_backend(cwd, &backends_config) {
+            return with_derived_indexes(cwd, with_org_layer(cwd, mirror, &backends_config));
+        }
+    }
+
     let backends = detect_backends(cwd, config);
 
     // Find the primary backend
     let primary = backends.into_iter().find(|b| b.is_primary);
 
-    match primary {
-        Some(info) => match info.backend_type {
-            BackendType::TotalRecall => {
-                // Total Recall backend with markdown fallback
-                // If Total Recall write fails, fall back to markdown
-                let memory_dir = info.path.unwrap_or_else(|| cwd.join("memory"));
-                let tr_backend = Box::new(TotalRecallBackend::new(&memory_dir, cwd));
-                let md_path = project_learnings_path(cwd);
-                let md_backend = Box::new(MarkdownBackend::new(&md_path));
-                Box::new(FallbackBackend::new(tr_backend, md_backend))
-            }
-            BackendType::Config => {
-                // Config should no longer be returned by probe_config (it returns the actual type)
-                // but kept for exhaustiveness and future compatibility
-                let path = project_learnings_path(cwd);
-                Box::new(MarkdownBackend::new(&path))
-            }
-            BackendType::Markdown => {
-                let path = info.path.unwrap_or_else(|| project_learnings_path(cwd));
-                Box::new(MarkdownBackend::new(&path))
-            }
-        },
+    let backend: Box<dyn MemoryBackend> = match primary {
+        Some(info) => create_backend(cwd, info, &backends_config),
         None => {
             // No backends detected (shouldn't happen, but fallback to markdown)
             let path = project_learnings_path(cwd);
             Box::new(MarkdownBackend::new(&path))
         }
+    };
+
+    with_derived_indexes(cwd, with_org_layer(cwd, backend, &backends_config))
+}
+
+/// Keep every file derived from the corpus in step with backend changes.
+///
+/// The hook trigger index is dropped on any change and rebuilt on next use;
+/// the search index is updated in place.
+fn with_derived_indexes(cwd: &Path, backend: Box<dyn MemoryBackend>) -> Box<dyn MemoryBackend> {
+    let backend = Box::new(InvalidatingBackend::new(
+        backend,
+        vec![crate::config::project_trigger_index_path(cwd)],
+    ));
+    with_search_index(cwd, backend)
+}
+
+/// Keep the project's persistent search index in step with backend changes.
+#[cfg(feature = "tantivy-search")]
+fn with_search_index(cwd: &Path, backend: Box<dyn MemoryBackend>) -> Box<dyn MemoryBackend> {
+    Box::new(crate::backends::IndexedBackend::new(
+        backend,
+        crate::config::project_index_dir(cwd),
+    ))
+}
+
+/// Without the `tantivy-search` feature there is no index to maintain.
+#[cfg(not(feature = "tantivy-search"))]
+fn with_search_index(_cwd: &Path, backend: Box<dyn MemoryBackend>) -> Box<dyn MemoryBackend> {
+    backend
+}
+
+/// Layer the read-only org learnings under a backend when configured.
+///
+/// A configured path that does not exist is skipped with a warning, so a
+/// missing checkout never blocks the project's own learnings.
+fn with_org_layer(
+    cwd: &Path,
+    backend: Box<dyn MemoryBackend>,
+    config: &BackendsConfig,
+) -> Box<dyn MemoryBackend> {
+    if !config.org.is_configured() {
+        return backend;
+    }
+
+    let org = OrgBackend::new(config.org.resolve(cwd));
+    if !org.ping() {
+        tracing::warn!(
+            "Org learnings path {} not found; searching project learnings only",
+            org.root().display()
+        );
+        return backend;
+    }
+
+    Box::new(LayeredBackend::new(backend, vec![Box::new(org)]))
+}
+
+/// Create the backend instance for a detected backend.
+///
+/// External backends (Total Recall, MCP, process plugins) are wrapped in a
+/// [`FallbackBackend`] so writes land in markdown when they are unavailable.
+pub fn create_backend(
+    cwd: &Path,
+    info: BackendInfo,
+    config: &BackendsConfig,
+) -> Box<dyn MemoryBackend> {
+    match info.backend_type {
+        // Total Recall, MCP servers, and process plugins get a markdown
+        // fallback: if their write fails, the learning lands in markdown
+        BackendType::TotalRecall | BackendType::Mcp | BackendType::Process => {
+            with_markdown_fallback(cwd, create_unwrapped_backend(cwd, info, config))
+        }
+        BackendType::Config | BackendType::Sqlite | BackendType::Markdown => {
+            create_unwrapped_backend(cwd, info, config)
+        }
     }
 }
 
+/// Create a specific backend by name, without fallback.
+///
+/// Used where the caller must talk to exactly the named backend (e.g.
+/// `grove backends migrate`), so a failed write is reported instead of
+/// landing silently in markdown.
+///
+/// # Errors
+///
+/// Returns an error if the name is unknown or the backend is not available.
+pub fn create_named_backend(
+    cwd: &Path,
+    name: &str,
+    config: &BackendsConfig,
+) -> Result<Box<dyn MemoryBackend>> {
+    let backend_type = BackendType::parse(name)
+        .filter(|t| *t != BackendType::Config)
+        .ok_or_else(|| GroveError::backend(format!("Unknown backend '{}'", name)))?;
+
+    let info = probe_backend(cwd, backend_type, config).ok_or_else(|| {
+        GroveError::backend(format!("Backend '{}' is not available", backend_type))
+    })?;
+
+    Ok(create_unwrapped_backend(cwd, info, config))
+}
+
+/// Create the bare backend instance for a detected backend, without fallback.
+fn create_unwrapped_backend(
+    cwd: &Path,
+    info: BackendInfo,
+    config: &BackendsConfig,
+) -> Box<dyn MemoryBackend> {
+    match info.backend_type {
+        BackendType::TotalRecall => {
+            let memory_dir = info.path.unwrap_or_else(|| cwd.join("memory"));
+            Box::new(TotalRecallBackend::new(&memory_dir, cwd))
+        }
+        BackendType::Config => {
+            // Config should no longer be returned by probe_config (it returns the actual type)
+            // but kept for exhaustiveness and future compatibility
+            let path = project_learnings_path(cwd);
+            Box::new(MarkdownBackend::new(&path))
+        }
+        BackendType::Sqlite => create_sqlite_backend(cwd),
+        BackendType::Mcp => Box::new(McpBackend::new(&config.mcp, cwd)),
+        BackendType::Process => Box::new(ProcessBackend::new(&config.process, cwd)),
+        BackendType::Markdown => {
+            let path = info.path.unwrap_or_else(|| project_learnings_path(cwd));
+            Box::new(MarkdownBackend::new(&path))
+        }
+    }
+}
+
+/// Create a mirror over the backends listed in `[backends] mirror`.
+///
+/// Each name is probed like a discovery entry. Unknown, disabled, or
+/// undetected names are skipped with a warning. Returns `None` when no
+/// mirror targets resolve, so normal discovery applies.
+fn create_mirror_backend(cwd: &Path, config: &BackendsConfig) -> Option<Box<dyn MemoryBackend>> {
+    let mut members: Vec<Box<dyn MemoryBackend>> = Vec::new();
+
+    for name in &config.mirror {
+        if let Some(false) = config.overrides.get(name) {
+            continue;
+        }
+
+        let Some(backend_type) = BackendType::parse(name) else {
+            tracing::warn!("Unknown mirror backend '{}'; skipping", name);
+            continue;
+        };
+
+        match probe_backend(cwd, backend_type, config) {
+            Some(info) => members.push(create_unwrapped_backend(cwd, info, config)),
+            None => tracing::warn!("Mirror backend '{}' not available; skipping", name),
+        }
+    }
+
+    if members.is_empty() {
+        return None;
+    }
+
+    Some(Box::new(MirrorBackend::new(members)))
+}
+
+/// Run a live health check against an external backend.
+///
+/// Returns `Some(healthy)` for backends that run as separate processes (MCP
+/// servers and process plugins), pinging them directly rather than through
+/// the markdown fallback. Returns `None` for file-based backends, whose
+/// availability is already known from discovery.
+pub fn ping_backend(cwd: &Path, info: &BackendInfo, config: &BackendsConfig) -> Option<bool> {
+    match info.backend_type {
+        BackendType::Mcp => Some(McpBackend::new(&config.mcp, cwd).ping()),
+        BackendType::Process => Some(ProcessBackend::new(&config.process, cwd).ping()),
+        BackendType::Config
+        | BackendType::TotalRecall
+        | BackendType::Sqlite
+        | BackendType::Markdown => None,
+    }
+}
+
+/// Wrap a backend so failed writes fall back to the project markdown file.
+fn with_markdown_fallback(cwd: &Path, primary: Box<dyn MemoryBackend>) -> Box<dyn MemoryBackend> {
+    let md_path = project_learnings_path(cwd);
+    let md_backend = Box::new(MarkdownBackend::new(&md_path));
+    Box::new(FallbackBackend::new(primary, md_backend))
+}
+
+/// Create the SQLite backend at `.grove/learnings.db`.
+///
+/// The path is always derived from the project root because a `[backends]
+/// primary = "sqlite"` entry reports the config file as its location.
+#[cfg(feature = "sqlite-backend")]
+fn create_sqlite_backend(cwd: &Path) -> Box<dyn MemoryBackend> {
+    Box::new(crate::backends::SqliteBackend::new(
+        project_learnings_db_path(cwd),
+    ))
+}
+
+/// Fall back to markdown when SQLite support is not compiled in.
+#[cfg(not(feature = "sqlite-backend"))]
+fn create_sqlite_backend(cwd: &Path) -> Box<dyn MemoryBackend> {
+    tracing::warn!("sqlite backend requires the sqlite-backend feature; falling back to markdown");
+    Box::new(MarkdownBackend::new(project_learnings_path(cwd)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -318,6 +586,9 @@ mod tests {
         assert_eq!(BackendType::Config.as_str(), "config");
         assert_eq!(BackendType::TotalRecall.as_str(), "total-recall");
         assert_eq!(BackendType::Markdown.as_str(), "markdown");
+        assert_eq!(BackendType::Sqlite.as_str(), "sqlite");
+        assert_eq!(BackendType::Mcp.as_str(), "mcp");
+        assert_eq!(BackendType::Process.as_str(), "process");
     }
 
     #[test]
@@ -337,6 +608,11 @@ mod tests {
         );
         assert_eq!(BackendType::parse("markdown"), Some(BackendType::Markdown));
         assert_eq!(BackendType::parse("md"), Some(BackendType::Markdown));
+        assert_eq!(BackendType::parse("sqlite"), Some(BackendType::Sqlite));
+        assert_eq!(BackendType::parse("sqlite3"), Some(BackendType::Sqlite));
+        assert_eq!(BackendType::parse("mcp"), Some(BackendType::Mcp));
+        assert_eq!(BackendType::parse("process"), Some(BackendType::Process));
+        assert_eq!(BackendType::parse("plugin"), Some(BackendType::Process));
         assert_eq!(BackendType::parse("unknown"), None);
     }
 
@@ -601,6 +877,7 @@ mod tests {
             backends: BackendsConfig {
                 discovery: vec!["markdown".to_string()],
                 overrides: HashMap::new(),
+                ..Default::default()
             },
             ..Config::default()
         };
@@ -624,6 +901,7 @@ mod tests {
                     m.insert("markdown".to_string(), false);
                     m
                 },
+                ..Default::default()
             },
             ..Config::default()
         };
@@ -643,6 +921,7 @@ mod tests {
             backends: BackendsConfig {
                 discovery: vec!["unknown".to_string(), "markdown".to_string()],
                 overrides: HashMap::new(),
+                ..Default::default()
             },
             ..Config::default()
         };
@@ -747,6 +1026,7 @@ mod tests {
             backends: BackendsConfig {
                 discovery: vec![],
                 overrides: HashMap::new(),
+                ..Default::default()
             },
             ..Config::default()
         };
@@ -772,8 +1052,346 @@ mod tests {
         assert!(result.is_none());
     }
 
+    // probe_mcp tests
+
+    #[test]
+    fn test_probe_mcp_requires_command() {
+        assert!(probe_mcp(&BackendsConfig::default()).is_none());
+
+        let mut config = BackendsConfig::default();
+        config.mcp.command = "mcp-memory-server".to_string();
+
+        let info = probe_mcp(&config).unwrap();
+        assert_eq!(info.backend_type, BackendType::Mcp);
+        assert_eq!(info.path, Some(PathBuf::from("mcp-memory-server")));
+    }
+
+    #[test]
+    fn test_detect_backends_skips_unconfigured_mcp() {
+        let dir = TempDir::new().unwrap();
+
+        let config = Config {
+            backends: BackendsConfig {
+                discovery: vec!["mcp".to_string(), "markdown".to_string()],
+                ..Default::default()
+            },
+            ..Config::default()
+        };
+
+        let backends = detect_backends(dir.path(), Some(&config));
+
+        assert_eq!(backends.len(), 1);
+        assert_eq!(backends[0].backend_type, BackendType::Markdown);
+    }
+
+    #[test]
+    fn test_create_primary_backend_mcp_falls_back_to_markdown() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join(".grove")).unwrap();
+
+        let mut config = Config::default();
+        config.backends.discovery = vec!["mcp".to_string(), "markdown".to_string()];
+        config.backends.mcp.command = "/nonexistent/grove-mcp-server".to_string();
+
+        let backend = create_primary_backend(dir.path(), Some(&config));
+        assert_eq!(backend.name(), "mcp");
+
+        // Server is unreachable, so the write lands in markdown
+        let learning = crate::core::CompoundLearning::new(
+            crate::core::LearningCategory::Pattern,
+            "Use builder pattern",
+            "Builders keep constructors readable",
+            crate::core::LearningScope::Project,
+            crate::core::Confidence::High,
+            vec![crate::core::WriteGateCriterion::BehaviorChanging],
+            vec!["rust".to_string()],
+            "session-1",
+        );
+        let result = backend.write(&learning).unwrap();
+        assert!(result.success);
+        assert!(result.location.contains("(fallback)"));
+        assert!(project_learnings_path(dir.path()).exists());
+    }
+
+    // probe_process tests
+
+    #[test]
+    fn test_probe_process_requires_command() {
+        assert!(probe_process(&BackendsConfig::default()).is_none());
+
+        let mut config = BackendsConfig::default();
+        config.process.command = "kb-plugin".to_string();
+
+        let info = probe_process(&config).unwrap();
+        assert_eq!(info.backend_type, BackendType::Process);
+        assert_eq!(info.path, Some(PathBuf::from("kb-plugin")));
+    }
+
+    #[test]
+    fn test_create_primary_backend_process_falls_back_to_markdown() {
+        let dir = TempDir::new().unwrap();
+
+        let mut config = Config::default();
+        config.backends.discovery = vec!["process".to_string(), "markdown".to_string()];
+        config.backends.process.command = "/nonexistent/grove-plugin".to_string();
+
+        let backend = create_primary_backend(dir.path(), Some(&config));
+        assert_eq!(backend.name(), "process");
+        assert!(backend.ping()); // markdown fallback is available
+    }
+
+    #[test]
+    fn test_ping_backend_only_checks_external_backends() {
+        let dir = TempDir::new().unwrap();
+        let mut config = BackendsConfig::default();
+        config.process.command = "/nonexistent/grove-plugin".to_string();
+
+        let process = BackendInfo::new(BackendType::Process, None, true);
+        assert_eq!(ping_backend(dir.path(), &process, &config), Some(false));
+
+        let markdown = BackendInfo::new(BackendType::Markdown, None, false);
+        assert_eq!(ping_backend(dir.path(), &markdown, &config), None);
+    }
+
+    // mirror tests
+
+    #[test]
+    fn test_create_primary_backend_mirror_writes_everywhere() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join(".grove")).unwrap();
+
+        // Create Total Recall structure
+        fs::create_dir_all(dir.path().join("memory")).unwrap();
+        fs::create_dir_all(dir.path().join("rules")).unwrap();
+        fs::write(dir.path().join("rules/total-recall.md"), "# Total Recall").unwrap();
+
+        let mut config = Config::default();
+        config.backends.mirror = vec!["total-recall".to_string(), "markdown".to_string()];
+
+        let backend = create_primary_backend(dir.path(), Some(&config));
+        assert_eq!(backend.name(), "mirror");
+
+        let learning = crate::core::CompoundLearning::new(
+            crate::core::LearningCategory::Pattern,
+            "Use builder pattern",
+            "Builders keep constructors readable",
+            crate::core::LearningScope::Project,
+            crate::core::Confidence::High,
+            vec![crate::core::WriteGateCriterion::BehaviorChanging],
+            vec!["rust".to_string()],
+            "session-1",
+        );
+        let result = backend.write(&learning).unwrap();
+        assert!(result.success);
+        assert!(!result.is_partial());
+
+        let md = MarkdownBackend::new(project_learnings_path(dir.path()));
+        assert_eq!(md.parse_learnings().unwrap().len(), 1);
+        let memory_files: Vec<_> = fs::read_dir(dir.path().join("memory/daily"))
+            .unwrap()
+            .collect();
+        assert_eq!(memory_files.len(), 1);
+    }
+
+    #[test]
+    fn test_create_primary_backend_mirror_skips_unavailable() {
+        let dir = TempDir::new().unwrap();
+
+        // Total Recall is not set up, so only markdown is mirrored
+        let mut config = Config::default();
+        config.backends.mirror = vec![
+            "total-recall".to_string(),
+            "bogus".to_string(),
+            "markdown".to_string(),
+        ];
+
+        let backend = create_primary_backend(dir.path(), Some(&config));
+        assert_eq!(backend.name(), "mirror");
+    }
+
+    #[test]
+    fn test_create_primary_backend_mirror_none_resolved_uses_discovery() {
+        let dir = TempDir::new().unwrap();
+
+        let mut config = Config::default();
+        config.backends.mirror = vec!["total-recall".to_string()];
+
+        let backend = create_primary_backend(dir.path(), Some(&config));
+        assert_eq!(backend.name(), "markdown");
+    }
+
+    // org layer tests
+
+    #[test]
+    fn test_create_primary_backend_layers_org_learnings() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join(".grove")).unwrap();
+        let org_dir = dir.path().join("org-learnings");
+        fs::create_dir_all(&org_dir).unwrap();
+        let org =
+            MarkdownBackend::with_paths(org_dir.join("learnings.md"), org_dir.join("personal.md"));
+        let learning = crate::core::CompoundLearning::new(
+            crate::core::LearningCategory::Convention,
+            "Rotate API keys quarterly",
+            "Security policy applies to every service",
+            crate::core::LearningScope::Team,
+            crate::core::Confidence::High,
+            vec![crate::core::WriteGateCriterion::BehaviorChanging],
+            vec!["security".to_string()],
+            "session-1",
+        );
+        org.write(&learning).unwrap();
+
+        let mut config = Config::default();
+        config.backends.org.path = "org-learnings".to_string();
+
+        let backend = create_primary_backend(dir.path(), Some(&config));
+        assert_eq!(backend.name(), "markdown");
+
+        let results = backend
+            .search(
+                &crate::backends::SearchQuery::new(),
+                &crate::backends::SearchFilters::default(),
+            )
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].source, crate::backends::LearningSource::Org);
+        assert!(backend.list_all().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_create_primary_backend_layers_org_from_config_file() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join(".grove")).unwrap();
+        fs::write(
+            dir.path().join(".grove").join("config.toml"),
+            "[backends.org]\npath = \"org-learnings\"\n",
+        )
+        .unwrap();
+        let org_dir = dir.path().join("org-learnings");
+        fs::create_dir_all(&org_dir).unwrap();
+        let org =
+            MarkdownBackend::with_paths(org_dir.join("learnings.md"), org_dir.join("personal.md"));
+        let learning = crate::core::CompoundLearning::new(
+            crate::core::LearningCategory::Convention,
+            "Rotate API keys quarterly",
+            "Security policy applies to every service",
+            crate::core::LearningScope::Team,
+            crate::core::Confidence::High,
+            vec![crate::core::WriteGateCriterion::BehaviorChanging],
+            vec!["security".to_string()],
+            "session-1",
+        );
+        org.write(&learning).unwrap();
+
+        let config = Config::load_from_cwd(dir.path());
+        assert_eq!(config.backends.org.path, "org-learnings");
+
+        let backend = create_primary_backend(dir.path(), Some(&config));
+        let results = backend
+            .search(
+                &crate::backends::SearchQuery::new(),
+                &crate::backends::SearchFilters::default(),
+            )
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].source, crate::backends::LearningSource::Org);
+    }
+
+    #[test]
+    fn test_create_primary_backend_missing_org_path_is_skipped() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join(".grove")).unwrap();
+
+        let mut config = Config::default();
+        config.backends.org.path = "does-not-exist".to_string();
+
+        let backend = create_primary_backend(dir.path(), Some(&config));
+        assert_eq!(backend.name(), "markdown");
+        assert!(backend
+            .search(
+                &crate::backends::SearchQuery::new(),
+                &crate::backends::SearchFilters::default()
+            )
+            .unwrap()
+            .is_empty());
+    }
+
+    // probe_sqlite tests
+
+    #[test]
+    fn test_probe_sqlite_follows_feature() {
+        let dir = TempDir::new().unwrap();
+
+        let result = probe_sqlite(dir.path());
+
+        if cfg!(feature = "sqlite-backend") {
+            let info = result.unwrap();
+            assert_eq!(info.backend_type, BackendType::Sqlite);
+            assert!(info.path.unwrap().ends_with(".grove/learnings.db"));
+        } else {
+            assert!(result.is_none());
+        }
+    }
+
+    #[test]
+    fn test_detect_backends_sqlite_in_discovery() {
+        let dir = TempDir::new().unwrap();
+
+        let config = Config {
+            backends: BackendsConfig {
+                discovery: vec!["sqlite".to_string(), "markdown".to_string()],
+                overrides: HashMap::new(),
+                ..Default::default()
+            },
+            ..Config::default()
+        };
+
+        let backends = detect_backends(dir.path(), Some(&config));
+
+        let expected = if cfg!(feature = "sqlite-backend") {
+            BackendType::Sqlite
+        } else {
+            BackendType::Markdown
+        };
+        assert_eq!(backends[0].backend_type, expected);
+        assert!(backends[0].is_primary);
+    }
+
     // create_primary_backend tests
 
+    #[test]
+    fn test_create_primary_backend_sqlite_from_config() {
+        let dir = TempDir::new().unwrap();
+        let grove_dir = dir.path().join(".grove");
+        fs::create_dir_all(&grove_dir).unwrap();
+        fs::write(
+            grove_dir.join("config.toml"),
+            "[backends]\nprimary = \"sqlite\"\n",
+        )
+        .unwrap();
+
+        let config = Config {
+            backends: BackendsConfig {
+                discovery: vec!["config".to_string(), "markdown".to_string()],
+                overrides: HashMap::new(),
+                ..Default::default()
+            },
+            ..Config::default()
+        };
+
+        let backend = create_primary_backend(dir.path(), Some(&config));
+
+        // Without the feature, selecting sqlite falls back to markdown
+        let expected = if cfg!(feature = "sqlite-backend") {
+            "sqlite"
+        } else {
+            "markdown"
+        };
+        assert_eq!(backend.name(), expected);
+        assert!(backend.ping());
+    }
+
     #[test]
     fn test_create_primary_backend_markdown_default() {
         let dir = TempDir::new().unwrap();
@@ -818,6 +1436,7 @@ mod tests {
                     m.insert("total-recall".to_string(), false);
                     m
                 },
+                ..Default::default()
             },
             ..Config::default()
         };
@@ -842,6 +1461,7 @@ mod tests {
             backends: BackendsConfig {
                 discovery: vec!["markdown".to_string()],
                 overrides: HashMap::new(),
+                ..Default::default()
             },
             ..Config::default()
         };
@@ -1008,6 +1628,7 @@ mod tests {
                 backends: BackendsConfig {
                     discovery: vec!["markdown".to_string(), "total-recall".to_string()],
                     overrides: HashMap::new(),
+                    ..Default::default()
                 },
                 ..Config::default()
             };
@@ -2,7 +2,7 @@
 //!
 //! This module handles auto-detection of:
 //! - Ticketing systems (tissue, beads, tasks, session)
-//! - Memory backends (markdown, total-recall)
+//! - Memory backends (markdown, total-recall, sqlite, mcp, process)
 //!
 //! Discovery order is configurable via the Grove config file.
 //! Individual systems can be enabled or disabled via overrides.
@@ -11,8 +11,8 @@ pub mod backends;
 pub mod tickets;
 
 pub use backends::{
-    create_default_backend, create_primary_backend, detect_backends, probe_markdown, BackendInfo,
-    BackendType,
+    create_backend, create_default_backend, create_named_backend, create_primary_backend,
+    detect_backends, ping_backend, probe_markdown, BackendInfo, BackendType,
 };
 pub use tickets::{
     detect_ticketing_system, extract_title_keywords, match_close_command, probe_beads,
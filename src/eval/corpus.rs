@@ -39,6 +39,8 @@ pub struct SessionContext {
 pub struct SessionSummary {
     /// JSONL filename (UUID, without .jsonl extension).
     pub session_id: String,
+    /// Path to the JSONL transcript file.
+    pub transcript_path: PathBuf,
     /// From cwd field in first JSONL entry.
     pub project_cwd: PathBuf,
     /// First user message timestamp (if available).
@@ -524,6 +526,7 @@ pub fn parse_session_transcript(path: &Path) -> Option<SessionSummary> {
 
     Some(SessionSummary {
         session_id,
+        transcript_path: path.to_path_buf(),
         project_cwd,
         timestamp,
         user_turns,
@@ -563,7 +566,7 @@ pub fn condense_transcript(pairs: &[(String, String)], max_chars: usize) -> Stri
         .enumerate()
         .map(|(i, (_, a))| (i, a.len()))
         .collect();
-    indexed.sort_by(|a, b| b.1.cmp(&a.1));
+    indexed.sort_by_key(|i| std::cmp::Reverse(i.1));
 
     // Greedily select pairs that fit within budget
     let mut selected_indices = Vec::new();
@@ -1039,6 +1042,13 @@ cache_path = "/tmp/other-cache.json"
                 context_files: None,
                 relevance_context: None,
                 status: LearningStatus::Active,
+                relations: Vec::new(),
+                applies_to: None,
+                expires_at: None,
+                review_by: None,
+                examples: Vec::new(),
+                provenance: None,
+                guard: None,
             })
             .collect();
 
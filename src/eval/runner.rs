@@ -12,10 +12,10 @@ use {
     super::metrics::{compute_metrics_with_ci, JudgeStats, RecallData},
     crate::backends::SearchQuery,
     crate::hooks::{
-        apply_adaptive_threshold, apply_dynamic_k, build_tantivy_query_string_boosted,
-        build_tantivy_query_string_boosted_with_params, extract_tool_input_keywords_v2,
-        extract_user_intent_keywords, intent_overlap_ratio, learning_matches_intent,
-        rerank_with_llm,
+        apply_adaptive_threshold, apply_diversity, apply_dynamic_k,
+        build_tantivy_query_string_boosted, build_tantivy_query_string_boosted_with_params,
+        extract_tool_input_keywords_v2, extract_user_intent_keywords, intent_overlap_ratio,
+        learning_matches_intent, rerank_with_llm, LocalReranker,
     },
     crate::search::TantivySearchIndex,
     crate::stats::scoring::{recency, recency_weight, reference_boost, CompositeScore, Strategy},
@@ -25,7 +25,8 @@ use {
 /// Configurable boost parameters for eval benchmarking.
 ///
 /// Controls the BM25 per-term boost factors and dynamic-K ratio so the eval
-/// runner can sweep alternatives without production code changes.
+/// runner can sweep alternatives without production code changes. Hybrid
+/// configs also use the reciprocal rank fusion constant and embedding weight.
 #[derive(Debug, Clone)]
 pub struct BoostParams {
     /// Short name for this parameter set (e.g. "boosted-v2").
@@ -36,6 +37,12 @@ pub struct BoostParams {
     pub tag_boost: f64,
     /// Ratio of top score below which learnings are excluded (production default: 0.3).
     pub dynamic_k_ratio: f64,
+    /// Reciprocal rank fusion constant for hybrid configs (production default: 60.0).
+    pub rrf_k: f64,
+    /// Weight of the embedding ranking for hybrid configs (production default: 1.0).
+    pub embedding_weight: f64,
+    /// MMR trade-off for diversity-aware selection (production default: off).
+    pub mmr_lambda: Option<f64>,
 }
 
 impl Default for BoostParams {
@@ -46,6 +53,9 @@ impl Default for BoostParams {
             keyword_boost: 2.0,
             tag_boost: 1.5,
             dynamic_k_ratio: 0.3,
+            rrf_k: 60.0,
+            embedding_weight: 1.0,
+            mmr_lambda: None,
         }
     }
 }
@@ -53,10 +63,52 @@ impl Default for BoostParams {
 impl BoostParams {
     /// Parse inline parameters from a `key=value,...` string.
     ///
-    /// Valid keys: `kw` (keyword_boost), `tag` (tag_boost), `dk` (dynamic_k_ratio).
-    /// Unspecified keys default to production values.
+    /// Valid keys: `kw` (keyword_boost), `tag` (tag_boost), `dk` (dynamic_k_ratio),
+    /// `mmr` (mmr_lambda). Unspecified keys default to production values.
     pub fn parse(params_str: &str) -> crate::Result<Self> {
+        let mut result = Self::parse_keys(params_str, &["kw", "tag", "dk", "mmr"])?;
+
+        // Build a descriptive name from actual values
+        result.name = format!(
+            "boosted(kw={:.1},tag={:.1},dk={:.2}{})",
+            result.keyword_boost,
+            result.tag_boost,
+            result.dynamic_k_ratio,
+            result.mmr_suffix()
+        );
+
+        Ok(result)
+    }
+
+    /// Parse inline hybrid parameters from a `key=value,...` string.
+    ///
+    /// Valid keys: `k` (rrf_k), `emb` (embedding_weight), plus the boosted
+    /// keys `kw`, `tag`, `dk`, and `mmr`. Unspecified keys default to production values.
+    pub fn parse_hybrid(params_str: &str) -> crate::Result<Self> {
+        let mut result = Self::parse_keys(params_str, &["k", "emb", "kw", "tag", "dk", "mmr"])?;
+        result.name = format!(
+            "hybrid(k={:.0},emb={:.1},kw={:.1},tag={:.1},dk={:.2}{})",
+            result.rrf_k,
+            result.embedding_weight,
+            result.keyword_boost,
+            result.tag_boost,
+            result.dynamic_k_ratio,
+            result.mmr_suffix()
+        );
+        Ok(result)
+    }
+
+    /// `,mmr=F` for config names when diversity selection is on.
+    fn mmr_suffix(&self) -> String {
+        self.mmr_lambda
+            .map(|lambda| format!(",mmr={:.2}", lambda))
+            .unwrap_or_default()
+    }
+
+    /// Parse `key=value,...`, accepting only the keys in `valid`.
+    fn parse_keys(params_str: &str, valid: &[&str]) -> crate::Result<Self> {
         let mut result = Self::default();
+        let valid_list = valid.join(", ");
 
         for part in params_str.split(',') {
             let part = part.trim();
@@ -65,8 +117,8 @@ impl BoostParams {
             }
             let (key, val) = part.split_once('=').ok_or_else(|| {
                 crate::GroveError::config(format!(
-                    "Invalid boost param '{}': expected key=value (keys: kw, tag, dk)",
-                    part
+                    "Invalid boost param '{}': expected key=value (keys: {})",
+                    part, valid_list
                 ))
             })?;
             let val: f64 = val.parse().map_err(|_| {
@@ -76,24 +128,21 @@ impl BoostParams {
                 ))
             })?;
             match key.trim() {
-                "kw" => result.keyword_boost = val,
-                "tag" => result.tag_boost = val,
-                "dk" => result.dynamic_k_ratio = val,
+                "kw" if valid.contains(&"kw") => result.keyword_boost = val,
+                "tag" if valid.contains(&"tag") => result.tag_boost = val,
+                "dk" if valid.contains(&"dk") => result.dynamic_k_ratio = val,
+                "k" if valid.contains(&"k") => result.rrf_k = val,
+                "emb" if valid.contains(&"emb") => result.embedding_weight = val,
+                "mmr" if valid.contains(&"mmr") => result.mmr_lambda = Some(val),
                 _ => {
                     return Err(crate::GroveError::config(format!(
-                        "Unknown boost param key '{}': valid keys are kw, tag, dk",
-                        key
+                        "Unknown boost param key '{}': valid keys are {}",
+                        key, valid_list
                     )));
                 }
             }
         }
 
-        // Build a descriptive name from actual values
-        result.name = format!(
-            "boosted(kw={:.1},tag={:.1},dk={:.2})",
-            result.keyword_boost, result.tag_boost, result.dynamic_k_ratio
-        );
-
         Ok(result)
     }
 }
@@ -113,6 +162,10 @@ pub enum BenchmarkConfig {
     Bm25AdaptiveRerank,
     /// BM25 with per-term boost + adaptive + LLM reranking.
     Bm25BoostedAdaptiveRerank,
+    /// BM25 + adaptive + local embedding reranking (`rerank.model = "local"`).
+    Bm25AdaptiveLocalRerank,
+    /// BM25 with per-term boost + adaptive + local embedding reranking.
+    Bm25BoostedAdaptiveLocalRerank,
     /// BM25 + adaptive but flat 90-day half-life (ablation control).
     Bm25FlatRecency,
     /// BM25 with custom boost params + adaptive threshold.
@@ -128,6 +181,9 @@ pub enum BenchmarkConfig {
     /// BM25 boosted + adaptive threshold + intent keywords as score boost.
     /// Applies intent boost *before* dynamic K so it influences selection.
     Bm25IntentBoost,
+    /// BM25 boosted fused with embedding similarity (reciprocal rank fusion)
+    /// + adaptive threshold. Embeddings come from `[gate.semantic_dedup]`.
+    Hybrid(BoostParams),
 }
 
 impl BenchmarkConfig {
@@ -137,8 +193,11 @@ impl BenchmarkConfig {
     /// - `"boosted-adaptive"` — production defaults (kw=2.0, tag=1.5, dk=0.3)
     /// - `"boosted(dk=0.35)"` — override dynamic_k_ratio only
     /// - `"boosted(kw=1.5,tag=1.0,dk=0.35)"` — override all boost params
+    /// - `"hybrid(k=30,emb=0.5)"` — hybrid retrieval with custom fusion params
+    /// - `"boosted(mmr=0.7)"` — diversity-aware (MMR) selection after dynamic K
     ///
-    /// Unspecified params in `boosted(...)` default to production values.
+    /// Unspecified params in `boosted(...)` and `hybrid(...)` default to
+    /// production values.
     pub fn from_name(name: &str) -> crate::Result<Self> {
         // Check for parameterized syntax: boosted(key=val,...)
         if let Some(params_str) = name
@@ -149,6 +208,15 @@ impl BenchmarkConfig {
             return Ok(Self::Bm25BoostedCustom(params));
         }
 
+        // Check for parameterized syntax: hybrid(key=val,...)
+        if let Some(params_str) = name
+            .strip_prefix("hybrid(")
+            .and_then(|s| s.strip_suffix(')'))
+        {
+            let params = BoostParams::parse_hybrid(params_str)?;
+            return Ok(Self::Hybrid(params));
+        }
+
         // Check for heuristic-enriched(N) syntax (must come before heuristic(N))
         if let Some(threshold_str) = name
             .strip_prefix("heuristic-enriched(")
@@ -184,17 +252,25 @@ impl BenchmarkConfig {
             "boosted-adaptive" => Ok(Self::Bm25BoostedAdaptive),
             "adaptive-rerank" => Ok(Self::Bm25AdaptiveRerank),
             "boosted-adaptive-rerank" => Ok(Self::Bm25BoostedAdaptiveRerank),
+            "adaptive-local-rerank" => Ok(Self::Bm25AdaptiveLocalRerank),
+            "boosted-adaptive-local-rerank" => Ok(Self::Bm25BoostedAdaptiveLocalRerank),
             "flat-recency" => Ok(Self::Bm25FlatRecency),
             "heuristic" => Ok(Self::Bm25Heuristic(50)),
             "corpus-enriched" => Ok(Self::Bm25CorpusEnriched),
             "heuristic-enriched" => Ok(Self::Bm25HeuristicEnriched(50)),
             "adaptive-dk" => Ok(Self::Bm25AdaptiveDk),
             "intent-boost" => Ok(Self::Bm25IntentBoost),
+            "hybrid" => Ok(Self::Hybrid(BoostParams {
+                name: "hybrid".to_string(),
+                ..BoostParams::default()
+            })),
             _ => Err(crate::GroveError::config(format!(
                 "Unknown benchmark config: '{}'. Valid: bm25, adaptive, intent-filter, \
-                 boosted-adaptive, adaptive-rerank, boosted-adaptive-rerank, flat-recency, \
+                 boosted-adaptive, adaptive-rerank, boosted-adaptive-rerank, \
+                 adaptive-local-rerank, boosted-adaptive-local-rerank, flat-recency, \
                  heuristic, heuristic(N), corpus-enriched, heuristic-enriched, \
-                 heuristic-enriched(N), adaptive-dk, intent-boost, boosted(kw=F,tag=F,dk=F)",
+                 heuristic-enriched(N), adaptive-dk, intent-boost, boosted(kw=F,tag=F,dk=F), \
+                 hybrid, hybrid(k=F,emb=F,kw=F,tag=F,dk=F)",
                 name
             ))),
         }
@@ -209,6 +285,8 @@ impl BenchmarkConfig {
             Self::Bm25BoostedAdaptive => "boosted-adaptive".to_string(),
             Self::Bm25AdaptiveRerank => "adaptive-rerank".to_string(),
             Self::Bm25BoostedAdaptiveRerank => "boosted-adaptive-rerank".to_string(),
+            Self::Bm25AdaptiveLocalRerank => "adaptive-local-rerank".to_string(),
+            Self::Bm25BoostedAdaptiveLocalRerank => "boosted-adaptive-local-rerank".to_string(),
             Self::Bm25FlatRecency => "flat-recency".to_string(),
             Self::Bm25BoostedCustom(params) => params.name.clone(),
             Self::Bm25Heuristic(threshold) => format!("heuristic({})", threshold),
@@ -218,6 +296,7 @@ impl BenchmarkConfig {
             }
             Self::Bm25AdaptiveDk => "adaptive-dk".to_string(),
             Self::Bm25IntentBoost => "intent-boost".to_string(),
+            Self::Hybrid(params) => params.name.clone(),
         }
     }
 
@@ -237,8 +316,10 @@ impl BenchmarkConfig {
             self,
             Self::Bm25BoostedAdaptive
                 | Self::Bm25BoostedAdaptiveRerank
+                | Self::Bm25BoostedAdaptiveLocalRerank
                 | Self::Bm25BoostedCustom(_)
                 | Self::Bm25IntentBoost
+                | Self::Hybrid(_)
         )
     }
 
@@ -250,6 +331,14 @@ impl BenchmarkConfig {
         )
     }
 
+    #[cfg(feature = "tantivy-search")]
+    fn uses_local_rerank(&self) -> bool {
+        matches!(
+            self,
+            Self::Bm25AdaptiveLocalRerank | Self::Bm25BoostedAdaptiveLocalRerank
+        )
+    }
+
     #[cfg(feature = "tantivy-search")]
     fn uses_flat_recency(&self) -> bool {
         matches!(self, Self::Bm25FlatRecency)
@@ -259,7 +348,20 @@ impl BenchmarkConfig {
     #[cfg(feature = "tantivy-search")]
     fn boost_params(&self) -> Option<&BoostParams> {
         match self {
-            Self::Bm25BoostedCustom(params) => Some(params),
+            Self::Bm25BoostedCustom(params) | Self::Hybrid(params) => Some(params),
+            _ => None,
+        }
+    }
+
+    /// Return the fusion settings if this is a hybrid config.
+    #[cfg(feature = "tantivy-search")]
+    fn hybrid_config(&self) -> Option<crate::config::HybridConfig> {
+        match self {
+            Self::Hybrid(params) => Some(crate::config::HybridConfig {
+                rrf_k: params.rrf_k,
+                embedding_weight: params.embedding_weight,
+                ..crate::config::HybridConfig::default()
+            }),
             _ => None,
         }
     }
@@ -365,6 +467,27 @@ fn surface_learnings(
         HashSet::new()
     };
 
+    // Hybrid configs rank the corpus by embedding similarity as well, using the
+    // provider from `[gate.semantic_dedup]`; learning embeddings are computed
+    // once and reused across sessions.
+    let hybrid_config = config.hybrid_config();
+    let embedder = match hybrid_config {
+        Some(_) => Some(crate::core::embeddings::create_provider(
+            &crate::config::Config::load().gate.semantic_dedup,
+        )?),
+        None => None,
+    };
+    let mut embedding_cache = crate::core::embeddings::EmbeddingCache::default();
+    // Local rerank configs load the fastembed model once and keep learning
+    // embeddings in memory across sessions.
+    let mut local_reranker = if config.uses_local_rerank() {
+        Some(LocalReranker::fastembed(None)?)
+    } else {
+        None
+    };
+    let corpus_refs: Vec<&crate::core::learning::CompoundLearning> =
+        corpus.learnings.iter().collect();
+
     let mut pairs: Vec<SurfacedPair> = Vec::new();
     let mut sessions_evaluated = 0usize;
     let mut sessions_suppressed = 0usize;
@@ -432,24 +555,67 @@ fn surface_learnings(
             }
         };
 
-        if bm25_results.is_empty() {
+        // Relevance per learning: fused ranks for hybrid, normalized BM25 otherwise
+        let relevances: Vec<(String, f64)> = if let Some(ref hybrid) = hybrid_config {
+            let embedding_ids = match embedder {
+                Some(ref provider) => {
+                    let query = SearchQuery {
+                        keywords: effective_keywords.clone(),
+                        files: ctx.file_paths.clone(),
+                        tags: Vec::new(),
+                        ticket_id: None,
+                    };
+                    let mut ids = crate::search::hybrid::embedding_ranking(
+                        &crate::search::hybrid::query_text(&query),
+                        &corpus_refs,
+                        provider.as_ref(),
+                        &mut embedding_cache,
+                        hybrid.min_similarity,
+                    )?;
+                    ids.truncate(20);
+                    ids
+                }
+                None => Vec::new(),
+            };
+
+            let bm25_ids: Vec<String> = bm25_results.iter().map(|r| r.id.clone()).collect();
+            let mut fused: Vec<(String, f64)> =
+                crate::search::hybrid::fused_relevance(&bm25_ids, &embedding_ids, hybrid)
+                    .into_iter()
+                    .collect();
+            fused.sort_by(|a, b| {
+                b.1.partial_cmp(&a.1)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then_with(|| a.0.cmp(&b.0))
+            });
+            fused
+        } else {
+            let max_bm25 = bm25_results
+                .iter()
+                .map(|r| r.score)
+                .fold(f32::NEG_INFINITY, f32::max);
+            bm25_results
+                .iter()
+                .map(|r| {
+                    let relevance = if max_bm25 < f32::EPSILON {
+                        1.0
+                    } else {
+                        (r.score / max_bm25) as f64
+                    };
+                    (r.id.clone(), relevance)
+                })
+                .collect()
+        };
+
+        if relevances.is_empty() {
             continue;
         }
 
-        let max_bm25 = bm25_results
-            .iter()
-            .map(|r| r.score)
-            .fold(f32::NEG_INFINITY, f32::max);
-
-        let mut scored: Vec<CompositeScore> = bm25_results
+        let mut scored: Vec<CompositeScore> = relevances
             .iter()
-            .filter_map(|r| {
-                let learning = learning_hash.get(&r.id)?;
-                let relevance = if max_bm25 < f32::EPSILON {
-                    1.0
-                } else {
-                    (r.score / max_bm25) as f64
-                };
+            .filter_map(|(id, relevance)| {
+                let learning = learning_hash.get(id)?;
+                let relevance = *relevance;
                 let half_life = if config.uses_flat_recency() {
                     flat_half_life
                 } else {
@@ -529,7 +695,17 @@ fn surface_learnings(
                     } else {
                         retrieval_config.dynamic_k_ratio
                     };
-                    apply_dynamic_k(passed, dk, top_n)
+                    match config.boost_params().and_then(|p| p.mmr_lambda) {
+                        // Hybrid configs compare cached embeddings; others fall
+                        // back to lexical similarity.
+                        Some(lambda) => apply_diversity(
+                            apply_dynamic_k(passed, dk, usize::MAX),
+                            lambda,
+                            top_n,
+                            &embedding_cache,
+                        ),
+                        None => apply_dynamic_k(passed, dk, top_n),
+                    }
                 }
             }
         } else {
@@ -560,18 +736,28 @@ fn surface_learnings(
             filtered
         };
 
-        let final_results = if config.uses_rerank() && !after_intent.is_empty() {
+        let reranks = config.uses_rerank() || local_reranker.is_some();
+        let final_results = if reranks && !after_intent.is_empty() {
             let tool_input_str = serde_json::to_string(&first_tc.tool_input).unwrap_or_default();
             let git_files: Vec<String> = ctx.file_paths.iter().take(10).cloned().collect();
-            let mut reranked = rerank_with_llm(
-                after_intent,
-                &rerank_config,
-                &judge_ctx.api_url,
-                &first_tc.tool_name,
-                &tool_input_str,
-                "",
-                &git_files,
-            );
+            let mut reranked = match local_reranker.as_mut() {
+                Some(reranker) => reranker.rerank(
+                    after_intent,
+                    &first_tc.tool_name,
+                    &tool_input_str,
+                    "",
+                    &git_files,
+                ),
+                None => rerank_with_llm(
+                    after_intent,
+                    &rerank_config,
+                    &judge_ctx.api_url,
+                    &first_tc.tool_name,
+                    &tool_input_str,
+                    "",
+                    &git_files,
+                ),
+            };
             reranked.truncate(top_n);
             reranked
         } else {
@@ -994,11 +1180,17 @@ mod tests {
             ("boosted-adaptive", "boosted-adaptive"),
             ("adaptive-rerank", "adaptive-rerank"),
             ("boosted-adaptive-rerank", "boosted-adaptive-rerank"),
+            ("adaptive-local-rerank", "adaptive-local-rerank"),
+            (
+                "boosted-adaptive-local-rerank",
+                "boosted-adaptive-local-rerank",
+            ),
             ("flat-recency", "flat-recency"),
             ("heuristic", "heuristic(50)"),
             ("corpus-enriched", "corpus-enriched"),
             ("adaptive-dk", "adaptive-dk"),
             ("intent-boost", "intent-boost"),
+            ("hybrid", "hybrid"),
         ];
         for (input, expected_name) in cases {
             let config = BenchmarkConfig::from_name(input).unwrap();
@@ -1023,6 +1215,10 @@ mod tests {
             msg.contains("adaptive-rerank"),
             "Should list adaptive-rerank: {msg}"
         );
+        assert!(
+            msg.contains("adaptive-local-rerank"),
+            "Should list adaptive-local-rerank: {msg}"
+        );
         assert!(
             msg.contains("flat-recency"),
             "Should list flat-recency: {msg}"
@@ -1044,6 +1240,10 @@ mod tests {
             msg.contains("intent-boost"),
             "Should list intent-boost: {msg}"
         );
+        assert!(
+            msg.contains("hybrid(k=F,emb=F,kw=F,tag=F,dk=F)"),
+            "Should list hybrid syntax: {msg}"
+        );
     }
 
     #[test]
@@ -1095,6 +1295,55 @@ mod tests {
         assert!(BenchmarkConfig::from_name("boosted(dk=abc)").is_err());
     }
 
+    #[test]
+    fn hybrid_inline_parses_fusion_params() {
+        let config = BenchmarkConfig::from_name("hybrid(k=30,emb=0.5,dk=0.4)").unwrap();
+        assert_eq!(config.name(), "hybrid(k=30,emb=0.5,kw=2.0,tag=1.5,dk=0.40)");
+        if let BenchmarkConfig::Hybrid(params) = &config {
+            assert!((params.rrf_k - 30.0).abs() < f64::EPSILON);
+            assert!((params.embedding_weight - 0.5).abs() < f64::EPSILON);
+            assert!((params.keyword_boost - 2.0).abs() < f64::EPSILON);
+        } else {
+            panic!("Expected Hybrid variant");
+        }
+    }
+
+    #[test]
+    fn mmr_param_parses_for_boosted_and_hybrid() {
+        let config = BenchmarkConfig::from_name("boosted(mmr=0.7)").unwrap();
+        assert_eq!(config.name(), "boosted(kw=2.0,tag=1.5,dk=0.30,mmr=0.70)");
+        if let BenchmarkConfig::Bm25BoostedCustom(params) = &config {
+            assert_eq!(params.mmr_lambda, Some(0.7));
+        } else {
+            panic!("Expected Bm25BoostedCustom variant");
+        }
+
+        let config = BenchmarkConfig::from_name("hybrid(mmr=0.5)").unwrap();
+        assert_eq!(
+            config.name(),
+            "hybrid(k=60,emb=1.0,kw=2.0,tag=1.5,dk=0.30,mmr=0.50)"
+        );
+        assert_eq!(BoostParams::default().mmr_lambda, None);
+    }
+
+    #[test]
+    fn hybrid_keys_rejected_for_boosted() {
+        let err = BenchmarkConfig::from_name("boosted(emb=0.5)").unwrap_err();
+        assert!(format!("{err}").contains("valid keys are kw, tag, dk"));
+    }
+
+    #[cfg(feature = "tantivy-search")]
+    #[test]
+    fn hybrid_defaults_match_retrieval_config() {
+        let config = BenchmarkConfig::from_name("hybrid").unwrap();
+        assert!(config.uses_boosted_query());
+        let hybrid = config.hybrid_config().unwrap();
+        let defaults = crate::config::HybridConfig::default();
+        assert!((hybrid.rrf_k - defaults.rrf_k).abs() < f64::EPSILON);
+        assert!((hybrid.embedding_weight - defaults.embedding_weight).abs() < f64::EPSILON);
+        assert!(BenchmarkConfig::Bm25Only.hybrid_config().is_none());
+    }
+
     #[test]
     fn eval_runner_strategy_matches_config_default() {
         // The eval runner hardcodes Strategy::Moderate (line ~151 of run_benchmark).
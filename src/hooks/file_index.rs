@@ -0,0 +1,403 @@
+//! File-triggered learning lookup.
+//!
+//! Maps a file the agent is about to edit to the learnings whose
+//! `context_files` or `applies_to.paths` point at it. Plain paths are keyed
+//! by file or directory, and globs by their literal directory prefix, so a
+//! lookup walks the file's ancestors instead of testing every learning.
+//!
+//! Hooks read the index from `.grove/trigger-index.json` ([`TriggerIndex`]),
+//! which holds only the learnings that carry triggers: files to edit, or
+//! command guards for Bash calls. It is rebuilt from the
+//! backend when the learning stores' modification stamps change, and deleted
+//! by [`InvalidatingBackend`](crate::backends::InvalidatingBackend) whenever
+//! Grove changes the corpus.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use serde::{Deserialize, Serialize};
+
+use crate::backends::{LearningSource, SearchResult};
+use crate::config::Config;
+use crate::core::CompoundLearning;
+use crate::stats::scoring::path_matches;
+
+/// Bumped whenever the persisted layout changes.
+const TRIGGER_INDEX_VERSION: u32 = 2;
+
+/// Index of learnings keyed by the files and directories they are about.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FileTriggerIndex {
+    /// Learning indices keyed by context file or directory.
+    paths: HashMap<String, Vec<usize>>,
+    /// Glob patterns keyed by their literal directory prefix (`""` for
+    /// patterns that can match at any depth).
+    globs: HashMap<String, Vec<(String, usize)>>,
+}
+
+impl FileTriggerIndex {
+    /// Index `learnings` by their context files and path globs.
+    ///
+    /// Absolute context files under `root` are indexed relative to it;
+    /// lookups return positions in `learnings`.
+    pub fn build<'a>(
+        learnings: impl IntoIterator<Item = &'a CompoundLearning>,
+        root: &Path,
+    ) -> Self {
+        let mut index = Self::default();
+        for (i, learning) in learnings.into_iter().enumerate() {
+            for entry in learning.context_files.iter().flatten() {
+                let entry = relative_path(root, entry);
+                if is_glob(&entry) {
+                    index.add_glob(entry, i);
+                } else if !entry.is_empty() {
+                    index.paths.entry(entry).or_default().push(i);
+                }
+            }
+            // `applies_to.paths` are always patterns, matched as in applicability
+            for pattern in learning.applies_to.iter().flat_map(|a| a.paths.iter()) {
+                index.add_glob(relative_path(root, pattern), i);
+            }
+        }
+        index
+    }
+
+    fn add_glob(&mut self, pattern: String, learning: usize) {
+        if !pattern.is_empty() {
+            self.globs
+                .entry(literal_prefix(&pattern))
+                .or_default()
+                .push((pattern, learning));
+        }
+    }
+
+    /// Whether no learning has context files or path globs.
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty() && self.globs.is_empty()
+    }
+
+    /// Indices of learnings about a project-relative `file`, in ascending order.
+    pub fn lookup(&self, file: &str) -> Vec<usize> {
+        let file = file.trim_start_matches("./");
+        let mut matches = Vec::new();
+
+        // The file itself, then each enclosing directory up to the root
+        let ancestors = std::iter::successors(Some(file), |path| {
+            (!path.is_empty()).then(|| path.rsplit_once('/').map_or("", |(dir, _)| dir))
+        });
+        for path in ancestors {
+            if let Some(ids) = self.paths.get(path) {
+                matches.extend(ids);
+            }
+            if let Some(globs) = self.globs.get(path) {
+                matches.extend(
+                    globs
+                        .iter()
+                        .filter(|(pattern, _)| path_matches(pattern, file))
+                        .map(|(_, i)| *i),
+                );
+            }
+        }
+
+        matches.sort_unstable();
+        matches.dedup();
+        matches
+    }
+}
+
+/// Size and modification time of a learning store when an index was built.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceStamp {
+    /// Path of the store file.
+    pub path: String,
+    /// Size in bytes (0 when missing).
+    pub len: u64,
+    /// Modification time in nanoseconds since the epoch (0 when missing).
+    pub modified: u128,
+}
+
+impl SourceStamp {
+    /// Stamp `path` as it is now.
+    fn of(path: &Path) -> Self {
+        let metadata = std::fs::metadata(path).ok();
+        Self {
+            path: path.display().to_string(),
+            len: metadata.as_ref().map_or(0, |m| m.len()),
+            modified: metadata
+                .and_then(|m| m.modified().ok())
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map_or(0, |d| d.as_nanos()),
+        }
+    }
+}
+
+/// Stamp the local learning stores: the project and personal markdown and
+/// SQLite files, and the org corpus files when configured.
+///
+/// Learnings held by external backends (Total Recall, MCP, process plugins)
+/// have no local file; changes made to them outside Grove are picked up after
+/// the next change Grove makes.
+pub fn source_stamps(cwd: &Path, config: &Config) -> Vec<SourceStamp> {
+    let mut paths: Vec<PathBuf> = vec![
+        crate::config::project_learnings_path(cwd),
+        crate::config::project_learnings_db_path(cwd),
+    ];
+    if let Some(home) = crate::config::grove_home() {
+        paths.push(home.join("personal-learnings.md"));
+        paths.push(home.join("personal-learnings.db"));
+    }
+    if config.backends.org.is_configured() {
+        let org = crate::backends::OrgBackend::new(config.backends.org.resolve(cwd));
+        paths.extend(org.learning_files().unwrap_or_default());
+    }
+    paths.iter().map(|path| SourceStamp::of(path)).collect()
+}
+
+/// A learning carrying triggers, with where it came from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggeredLearning {
+    /// The learning.
+    pub learning: CompoundLearning,
+    /// Where the learning came from.
+    pub source: LearningSource,
+}
+
+/// Persisted index of the active learnings that carry hook triggers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerIndex {
+    /// Layout version ([`TRIGGER_INDEX_VERSION`]).
+    version: u32,
+    /// Stamps of the learning stores at build time.
+    stamps: Vec<SourceStamp>,
+    /// Learnings with context files, path globs, or command guards.
+    learnings: Vec<TriggeredLearning>,
+    /// File lookup into `learnings`.
+    files: FileTriggerIndex,
+    /// Positions in `learnings` of the learnings with command guards.
+    guards: Vec<usize>,
+}
+
+impl TriggerIndex {
+    /// Build the index from active search results, keeping only learnings
+    /// that carry triggers.
+    pub fn build(results: Vec<SearchResult>, root: &Path, stamps: Vec<SourceStamp>) -> Self {
+        let learnings: Vec<TriggeredLearning> = results
+            .into_iter()
+            .filter(|r| has_file_trigger(&r.learning) || has_guard(&r.learning))
+            .map(|r| TriggeredLearning {
+                learning: r.learning,
+                source: r.source,
+            })
+            .collect();
+        let files = FileTriggerIndex::build(learnings.iter().map(|t| &t.learning), root);
+        let guards = learnings
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| has_guard(&t.learning))
+            .map(|(i, _)| i)
+            .collect();
+        Self {
+            version: TRIGGER_INDEX_VERSION,
+            stamps,
+            learnings,
+            files,
+            guards,
+        }
+    }
+
+    /// Load the index at `path` if it was built from stores matching `stamps`.
+    ///
+    /// Returns `None` when the file is missing, unreadable, from another
+    /// layout version, or stale.
+    pub fn load(path: &Path, stamps: &[SourceStamp]) -> Option<Self> {
+        let content = std::fs::read_to_string(path).ok()?;
+        let index: Self = serde_json::from_str(&content).ok()?;
+        (index.version == TRIGGER_INDEX_VERSION && index.stamps == stamps).then_some(index)
+    }
+
+    /// Save the index atomically (temp file + rename). Fail-open: errors are
+    /// logged but not propagated.
+    pub fn save(&self, path: &Path) {
+        let json = match serde_json::to_string(self) {
+            Ok(json) => json,
+            Err(e) => {
+                tracing::warn!("Failed to serialize trigger index: {}", e);
+                return;
+            }
+        };
+        let tmp_path = path.with_extension("json.tmp");
+        let result = std::fs::write(&tmp_path, json).and_then(|_| std::fs::rename(&tmp_path, path));
+        if let Err(e) = result {
+            tracing::warn!("Failed to save trigger index {}: {}", path.display(), e);
+            let _ = std::fs::remove_file(&tmp_path);
+        }
+    }
+
+    /// Learnings about a project-relative `file`.
+    pub fn lookup_file(&self, file: &str) -> Vec<&TriggeredLearning> {
+        self.files
+            .lookup(file)
+            .into_iter()
+            .filter_map(|i| self.learnings.get(i))
+            .collect()
+    }
+
+    /// Learnings with command guards.
+    pub fn guarded(&self) -> impl Iterator<Item = &TriggeredLearning> {
+        self.guards.iter().filter_map(|&i| self.learnings.get(i))
+    }
+}
+
+/// Whether a learning has a command guard with at least one pattern.
+fn has_guard(learning: &CompoundLearning) -> bool {
+    learning.guard.as_ref().is_some_and(|g| !g.is_empty())
+}
+
+/// Whether a learning has context files or path globs to trigger on.
+fn has_file_trigger(learning: &CompoundLearning) -> bool {
+    learning
+        .context_files
+        .as_ref()
+        .is_some_and(|f| !f.is_empty())
+        || learning
+            .applies_to
+            .as_ref()
+            .is_some_and(|a| !a.paths.is_empty())
+}
+
+/// Make `path` relative to `root` when it lies under it, without a leading
+/// `./` or trailing `/`.
+pub fn relative_path(root: &Path, path: &str) -> String {
+    let path = Path::new(path)
+        .strip_prefix(root)
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|_| path.to_string());
+    path.trim_start_matches("./")
+        .trim_end_matches('/')
+        .to_string()
+}
+
+fn is_glob(path: &str) -> bool {
+    path.contains(['*', '?'])
+}
+
+/// Directory part of a glob before its first wildcard segment.
+///
+/// Patterns without a `/` match file names at any depth, so they key to `""`.
+fn literal_prefix(pattern: &str) -> String {
+    if !pattern.contains('/') {
+        return String::new();
+    }
+    pattern
+        .split('/')
+        .take_while(|segment| !is_glob(segment))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::learning::{
+        AppliesTo, CommandGuard, Confidence, GuardSeverity, LearningCategory, LearningScope,
+        WriteGateCriterion,
+    };
+
+    fn learning(id: &str) -> CompoundLearning {
+        CompoundLearning::new(
+            LearningCategory::Pitfall,
+            format!("Summary {}", id),
+            "detail",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::BehaviorChanging],
+            vec![],
+            "test-session",
+        )
+        .with_id(id)
+    }
+
+    #[test]
+    fn test_lookup_matches_files_directories_and_globs() {
+        let root = Path::new("/work/project");
+        let learnings = vec![
+            learning("file").with_context_files(vec!["/work/project/src/db/pool.rs".to_string()]),
+            learning("dir").with_context_files(vec!["./src/db/".to_string()]),
+            learning("glob").with_context_files(vec!["migrations/**/*.sql".to_string()]),
+            learning("name").with_applies_to(AppliesTo {
+                paths: vec!["*.sql".to_string(), "Cargo.toml".to_string()],
+                ..Default::default()
+            }),
+            learning("none"),
+        ];
+        let index = FileTriggerIndex::build(&learnings, root);
+        assert!(!index.is_empty());
+
+        assert_eq!(index.lookup("src/db/pool.rs"), vec![0, 1]);
+        assert_eq!(index.lookup("./src/db/schema.rs"), vec![1]);
+        assert_eq!(index.lookup("migrations/2026/001_init.sql"), vec![2, 3]);
+        assert_eq!(index.lookup("seeds/users.sql"), vec![3]);
+        assert_eq!(index.lookup("crates/core/Cargo.toml"), vec![3]);
+        assert!(index.lookup("src/main.rs").is_empty());
+        assert!(index.lookup("src/dbx/pool.rs").is_empty());
+    }
+
+    #[test]
+    fn test_trigger_index_roundtrip_and_staleness() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        let store = root.join("learnings.md");
+        std::fs::write(&store, "v1").unwrap();
+        let stamps = vec![SourceStamp::of(&store)];
+
+        let results = vec![
+            SearchResult::new(
+                learning("file").with_context_files(vec!["src/db/pool.rs".to_string()]),
+                1.0,
+            ),
+            SearchResult::new(learning("plain"), 1.0).with_source(LearningSource::Org),
+            SearchResult::new(
+                learning("guarded").with_guard(CommandGuard {
+                    commands: vec!["cargo publish".to_string()],
+                    severity: GuardSeverity::Block,
+                }),
+                1.0,
+            )
+            .with_source(LearningSource::Org),
+        ];
+        let path = root.join("trigger-index.json");
+        TriggerIndex::build(results, root, stamps.clone()).save(&path);
+
+        let index = TriggerIndex::load(&path, &stamps).expect("fresh index loads");
+        assert_eq!(index.learnings.len(), 2, "only learnings with triggers");
+        let found = index.lookup_file("src/db/pool.rs");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].learning.id, "file");
+        assert!(index.lookup_file("src/main.rs").is_empty());
+        let guarded: Vec<_> = index.guarded().collect();
+        assert_eq!(guarded.len(), 1);
+        assert_eq!(guarded[0].learning.id, "guarded");
+        assert_eq!(guarded[0].source, LearningSource::Org);
+
+        std::fs::write(&store, "v2 with more bytes").unwrap();
+        assert!(TriggerIndex::load(&path, &[SourceStamp::of(&store)]).is_none());
+        assert!(TriggerIndex::load(&root.join("missing.json"), &stamps).is_none());
+    }
+
+    #[test]
+    fn test_literal_prefix_and_relative_path() {
+        assert_eq!(literal_prefix("migrations/**/*.sql"), "migrations");
+        assert_eq!(literal_prefix("src/api/*.rs"), "src/api");
+        assert_eq!(literal_prefix("**/Cargo.toml"), "");
+        assert_eq!(literal_prefix("*.tsx"), "");
+
+        let root = Path::new("/work/project");
+        assert_eq!(
+            relative_path(root, "/work/project/src/lib.rs"),
+            "src/lib.rs"
+        );
+        assert_eq!(relative_path(root, "./docs/"), "docs");
+        assert_eq!(relative_path(root, "/elsewhere/x.rs"), "/elsewhere/x.rs");
+    }
+}
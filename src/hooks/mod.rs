@@ -9,10 +9,13 @@
 //! - **stop**: Session exit gate
 //! - **session-end**: Session cleanup
 
+pub mod file_index;
 pub mod input;
 pub mod output;
 pub mod runner;
+pub mod template;
 
+pub use file_index::FileTriggerIndex;
 pub use input::{
     parse_input, HookInput, PostToolUseInput, PreToolUseInput, SessionEndInput, SessionEndReason,
     SessionStartInput, StopInput,
@@ -22,15 +25,16 @@ pub use output::{
     SessionEndOutput, SessionStartHookOutput, SessionStartOutput, StopDecision, StopOutput,
 };
 pub use runner::{
-    adaptive_dk_ratio, apply_adaptive_threshold, apply_dynamic_k, extract_tool_input_keywords,
-    extract_tool_input_keywords_v2, extract_tool_input_keywords_v2_with_options,
-    extract_user_intent_keywords, intent_overlap_ratio, learning_matches_intent, HookRunner,
-    HookType,
+    adaptive_dk_ratio, apply_adaptive_threshold, apply_diversity, apply_dynamic_k,
+    extract_tool_input_keywords, extract_tool_input_keywords_v2,
+    extract_tool_input_keywords_v2_with_options, extract_user_intent_keywords,
+    intent_overlap_ratio, learning_matches_intent, HookRunner, HookType,
 };
+pub use template::{render_project_template, Template, TemplateKind};
 
 // Re-export benchmark-only functions within the crate
 #[cfg(feature = "tantivy-search")]
 pub(crate) use runner::{
     build_tantivy_query_string_boosted, build_tantivy_query_string_boosted_with_params,
-    enrich_query_with_corpus_vocabulary, extract_corpus_vocabulary, rerank_with_llm,
+    enrich_query_with_corpus_vocabulary, extract_corpus_vocabulary, rerank_with_llm, LocalReranker,
 };
@@ -168,7 +168,7 @@ fn load_learnings(path: &Path) -> Vec<CompoundLearning> {
 ///
 /// For each keyword, check if it appears as a whole word in the learning's
 /// summary or detail text. This mirrors the KEYWORD scoring path in
-/// `MarkdownBackend::compute_relevance`.
+/// `markdown::compute_relevance`.
 fn match_keywords_to_learnings(
     keywords: &[String],
     learnings: &[CompoundLearning],
@@ -2573,7 +2573,7 @@ fn replay_tantivy_comparison() {
 
         // Track keyword matches (top N by keyword count)
         let mut keyword_sorted = keyword_matched.clone();
-        keyword_sorted.sort_by(|a, b| b.matched_keywords.len().cmp(&a.matched_keywords.len()));
+        keyword_sorted.sort_by_key(|r| std::cmp::Reverse(r.matched_keywords.len()));
         for m in keyword_sorted.iter().take(top_n) {
             *keyword_match_counts.entry(m.id.clone()).or_insert(0) += 1;
             total_keyword_matches += 1;
@@ -3720,7 +3720,7 @@ fn replay_specificity_audit() {
     }
 
     let mut tag_vec: Vec<(String, usize)> = tag_counts.into_iter().collect();
-    tag_vec.sort_by(|a, b| b.1.cmp(&a.1));
+    tag_vec.sort_by_key(|t| std::cmp::Reverse(t.1));
 
     eprintln!("\n  Most common tags:");
     for (tag, count) in tag_vec.iter().take(15) {
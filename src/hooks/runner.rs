@@ -3,21 +3,26 @@
 //! This module implements the hook dispatch and individual hook handlers.
 //! Hooks integrate with Claude Code at key points in the session lifecycle.
 
+use std::collections::HashSet;
 use std::io;
 use std::path::Path;
 
-use crate::backends::{SearchFilters, SearchQuery};
-use crate::config::{project_stats_log_path, Config};
+use crate::backends::markdown::format_examples;
+use crate::backends::{LearningSource, MemoryBackend, SearchFilters, SearchQuery};
+use crate::config::{find_project_root, project_stats_log_path, Config};
 use crate::core::gate::Gate;
 use crate::core::state::{
     EventType, GateStatus, SessionState, SkipDecider, TicketCloseIntent, TicketContext,
 };
-use crate::core::InjectedLearning;
+use crate::core::{
+    CompoundLearning, GuardSeverity, InjectedLearning, InjectionExplanation, RelationKind,
+};
 use crate::discovery::{
     create_primary_backend, detect_backends, detect_ticketing_system, extract_title_keywords,
     match_close_command, query_active_tickets, TicketingSystem,
 };
 use crate::error::{GroveError, Result};
+use crate::hooks::file_index::{relative_path, source_stamps, TriggerIndex};
 use crate::hooks::input::{
     parse_input, HookInput, PostToolUseInput, PreToolUseInput, SessionEndInput, SessionStartInput,
     StopInput, TaskCompletedInput, UserPromptSubmitInput,
@@ -25,8 +30,12 @@ use crate::hooks::input::{
 use crate::hooks::output::{
     PreToolUseOutput, SessionEndOutput, SessionStartOutput, StopOutput, UserPromptSubmitOutput,
 };
-use crate::stats::scoring::{recency, recency_weight, reference_boost, CompositeScore, Strategy};
-use crate::stats::{StatsCacheManager, StatsLogger};
+use crate::hooks::template::{render_project_template, TemplateKind};
+use crate::stats::scoring::{
+    is_applicable, recency, recency_weight, reference_boost, ApplicabilityContext, CompositeScore,
+    Strategy,
+};
+use crate::stats::{StatsCache, StatsCacheManager, StatsLogger};
 use crate::storage::SessionStore;
 use tracing::{debug, warn};
 
@@ -78,7 +87,12 @@ pub struct HookRunner<S: SessionStore> {
 
 impl<S: SessionStore> HookRunner<S> {
     /// Create a new hook runner.
-    pub fn new(store: S, config: Config) -> Self {
+    ///
+    /// Subprocess backend and embedding request timeouts are capped so a
+    /// slow server can't push the hook past its own timeout.
+    pub fn new(store: S, mut config: Config) -> Self {
+        config.backends.cap_timeouts_for_hooks();
+        config.gate.semantic_dedup.cap_timeout_for_hooks();
         Self { store, config }
     }
 
@@ -144,6 +158,9 @@ impl<S: SessionStore> HookRunner<S> {
 
         // Create or load session
         let mut session = self.get_or_create_session(&hook_input.common)?;
+        if hook_input.model.is_some() {
+            session.model = hook_input.model.clone();
+        }
         session.add_trace(EventType::SessionStart, None);
 
         // Discover ticketing system
@@ -205,6 +222,11 @@ impl<S: SessionStore> HookRunner<S> {
             }
         }
 
+        // No tool is in use yet, so tool-scoped learnings wait for PreToolUse
+        let applicability = ApplicabilityContext::new()
+            .files(git_files.clone())
+            .branch(extract_git_branch(cwd));
+
         let query = if git_files.is_empty() && git_keywords.is_empty() {
             SearchQuery::new()
         } else {
@@ -216,8 +238,10 @@ impl<S: SessionStore> HookRunner<S> {
             cwd,
             &session,
             &query,
+            &applicability,
             Some(&hook_input.common.transcript_path),
         );
+        let mut injected_learnings = Vec::new();
         if let Some(context) =
             self.build_injection_context(cwd, &mut session, &top_learnings, false)
         {
@@ -225,16 +249,21 @@ impl<S: SessionStore> HookRunner<S> {
                 EventType::LearningsInjected,
                 Some(format!("count: {}", top_learnings.len())),
             );
-            additional_context = Some(context);
+            additional_context = Some(context.text);
+            injected_learnings = context.learnings;
         }
 
         // Correction propagation: check for recently corrected learnings
         // and inject notices at session-start (best-effort)
-        let correction_notices = self.get_correction_notices(cwd, &session);
-        if !correction_notices.is_empty() {
+        let corrected_ids = self.get_correction_notices(cwd, &session);
+        if !corrected_ids.is_empty() {
             let notice = format!(
                 "[CORRECTION NOTICE] The following learnings have been corrected since you may have last seen them:\n{}",
-                correction_notices.join("\n")
+                corrected_ids
+                    .iter()
+                    .map(|id| format!("- Learning ID: {}", id))
+                    .collect::<Vec<_>>()
+                    .join("\n")
             );
             // Append correction notices to any existing context (e.g., injected learnings)
             // rather than overwriting it
@@ -244,7 +273,7 @@ impl<S: SessionStore> HookRunner<S> {
             });
             session.add_trace(
                 EventType::CorrectionNotice,
-                Some(format!("count: {}", correction_notices.len())),
+                Some(format!("count: {}", corrected_ids.len())),
             );
         }
 
@@ -300,6 +329,20 @@ impl<S: SessionStore> HookRunner<S> {
             );
         }
 
+        // A project template replaces the assembled context
+        if additional_context.is_some() {
+            let vars = serde_json::json!({
+                "hook": TemplateKind::SessionStart.hook_name(),
+                "session_id": session.id,
+                "learnings": injected_learnings,
+                "corrections": corrected_ids,
+                "gate": gate_template_vars(&session),
+            });
+            if let Some(text) = render_project_template(cwd, TemplateKind::SessionStart, &vars) {
+                additional_context = Some(text);
+            }
+        }
+
         // Decay check is handled separately via grove maintain (Stage 2)
         // We don't run it here to keep session-start fast
 
@@ -353,6 +396,8 @@ impl<S: SessionStore> HookRunner<S> {
         // Deferred injection: on the first tool call after SessionStart,
         // extract keywords from tool_input and augment retrieval.
         let mut deferred_context: Option<String> = None;
+        let file_trigger = self.config.context.file_triggers
+            && FILE_EDIT_TOOLS.contains(&hook_input.tool_name.as_str());
 
         if session.gate.deferred_injection_pending {
             // Clear flag immediately (fail-open: even if injection fails)
@@ -371,33 +416,55 @@ impl<S: SessionStore> HookRunner<S> {
                 let query = SearchQuery::new()
                     .files(git_files.clone())
                     .keywords(git_keywords);
+                let applicability = tool_applicability_context(
+                    cwd,
+                    &git_files,
+                    &hook_input.tool_name,
+                    &hook_input.tool_input,
+                );
 
                 // Retrieve and score learnings with augmented query
                 let top_learnings = self.retrieve_and_score_learnings(
                     cwd,
                     &session,
                     &query,
+                    &applicability,
                     Some(&hook_input.common.transcript_path),
                 );
 
-                // LLM reranking: optionally rerank candidates using Haiku
+                // Reranking: optionally rerank candidates using Haiku, or
+                // locally by embedding similarity (`rerank.model = "local"`)
                 let top_learnings =
                     if self.config.retrieval.rerank.enabled && !top_learnings.is_empty() {
                         let tool_input_str =
                             serde_json::to_string(&hook_input.tool_input).unwrap_or_default();
                         let git_branch = extract_git_branch(cwd);
-                        let reranked = rerank_with_llm(
-                            top_learnings,
-                            &self.config.retrieval.rerank,
-                            &self.config.judge.api_url,
-                            &hook_input.tool_name,
-                            &tool_input_str,
-                            &git_branch,
-                            &git_files,
-                        );
+                        let (reranked, method) =
+                            if self.config.retrieval.rerank.model == LOCAL_RERANK_MODEL {
+                                let reranked = rerank_locally(
+                                    cwd,
+                                    top_learnings,
+                                    &hook_input.tool_name,
+                                    &tool_input_str,
+                                    &git_branch,
+                                    &git_files,
+                                );
+                                (reranked, "Local")
+                            } else {
+                                let reranked = rerank_with_llm(
+                                    top_learnings,
+                                    &self.config.retrieval.rerank,
+                                    &self.config.judge.api_url,
+                                    &hook_input.tool_name,
+                                    &tool_input_str,
+                                    &git_branch,
+                                    &git_files,
+                                );
+                                (reranked, "LLM")
+                            };
                         session.add_trace(
                             EventType::DeferredInjection,
-                            Some("LLM reranking applied".to_string()),
+                            Some(format!("{} reranking applied", method)),
                         );
                         reranked
                     } else {
@@ -416,7 +483,13 @@ impl<S: SessionStore> HookRunner<S> {
                             top_learnings.len()
                         )),
                     );
-                    deferred_context = Some(context);
+                    deferred_context = Some(render_injection_template(
+                        cwd,
+                        TemplateKind::PreToolUse,
+                        &session,
+                        context,
+                        serde_json::json!({ "tool_name": hook_input.tool_name, "file_path": "" }),
+                    ));
                 } else {
                     session.add_trace(
                         EventType::DeferredInjection,
@@ -432,23 +505,54 @@ impl<S: SessionStore> HookRunner<S> {
 
             // Save session with cleared flag (before falling through to ticket-close check)
             self.save_session(&session);
-        } else if hook_input.tool_name != "Bash" {
+        } else if hook_input.tool_name != "Bash" && !file_trigger {
             // Performance optimization: after the deferred flag is cleared,
-            // only Bash tools need further processing (ticket-close detection).
-            // Non-Bash tools can return immediately.
+            // only Bash tools (ticket-close detection) and file edits
+            // (file-triggered injection) need further processing.
+            // Other tools can return immediately.
             let output = PreToolUseOutput::allow();
             return crate::hooks::output::to_json(&output);
         }
 
-        // Check for ticket close command
-        // Note: match_close_command gates on tool_name == "Bash" internally,
-        // so this only matches Bash commands.
+        // File-triggered injection: learnings about the file being edited,
+        // at any point in the session
+        if file_trigger {
+            if let Some(context) = self.build_file_triggered_context(&hook_input, &mut session) {
+                deferred_context = Some(match deferred_context.take() {
+                    Some(ctx) => format!("{}\n\n{}", ctx, context),
+                    None => context,
+                });
+            }
+        }
+
         let command = hook_input
             .tool_input
             .get("command")
             .and_then(|v| v.as_str())
             .unwrap_or("");
 
+        // Command guards: warn about, or block, Bash commands that past
+        // learnings flag as risky
+        if self.config.context.command_guards && hook_input.tool_name == "Bash" {
+            match self.check_command_guards(&hook_input, command, &mut session) {
+                Some(GuardOutcome::Block(reason)) => {
+                    return crate::hooks::output::to_json(&PreToolUseOutput::deny_with_reason(
+                        reason,
+                    ));
+                }
+                Some(GuardOutcome::Warn(context)) => {
+                    deferred_context = Some(match deferred_context.take() {
+                        Some(ctx) => format!("{}\n\n{}", ctx, context),
+                        None => context,
+                    });
+                }
+                None => {}
+            }
+        }
+
+        // Check for ticket close command
+        // Note: match_close_command gates on tool_name == "Bash" internally,
+        // so this only matches Bash commands.
         if let Some(close_pattern) = match_close_command(&hook_input.tool_name, command) {
             // Extract ticket ID from command (simplified extraction)
             let ticket_id = extract_ticket_id(command).unwrap_or_else(|| "unknown".to_string());
@@ -731,7 +835,7 @@ impl<S: SessionStore> HookRunner<S> {
                     session.add_trace(EventType::GateBlocked, None);
                     self.save_session(&session);
 
-                    let reason = format!(
+                    let default_reason = format!(
                         "Grove gate is blocking exit: reflection required before this session can end.\n\
                          Run `grove reflect --session-id {sid}` to capture learnings, \
                          or `grove skip <reason> --session-id {sid}` to skip reflection.\n\n\
@@ -755,13 +859,24 @@ impl<S: SessionStore> HookRunner<S> {
                          \n\
                          Categories: Pattern, Pitfall, Convention, Dependency, Process, Domain, Debugging\n\
                          Criteria (claim ≥1): behavior-changing, decision-rationale, stable-fact, explicit-request\n\
+                         {optional_fields}\n\
                          \n\
                          Quality tips:\n\
                          - Include project-specific terms (library names, APIs, file patterns) so the learning surfaces precisely\n\
                          - relevance_context controls WHEN this learning appears — include both triggers and exclusions\n\
                          - Avoid generic advice without concrete anchors — it will surface in every session",
-                        sid = session.id
+                        sid = session.id,
+                        optional_fields = REFLECT_OPTIONAL_FIELDS
                     );
+                    let vars = serde_json::json!({
+                        "hook": TemplateKind::StopBlock.hook_name(),
+                        "session_id": session.id,
+                        "gate": gate_template_vars(&session),
+                        "default_reason": default_reason,
+                    });
+                    let cwd = Path::new(&hook_input.common.cwd);
+                    let reason = render_project_template(cwd, TemplateKind::StopBlock, &vars)
+                        .unwrap_or(default_reason);
                     let output = StopOutput::block_with_reason(reason);
                     return crate::hooks::output::to_json(&output);
                 }
@@ -913,12 +1028,14 @@ impl<S: SessionStore> HookRunner<S> {
              \n\
              Categories: Pattern, Pitfall, Convention, Dependency, Process, Domain, Debugging\n\
              Criteria (claim ≥1): behavior-changing, decision-rationale, stable-fact, explicit-request\n\
+             {optional_fields}\n\
              \n\
              Quality tips:\n\
              - Include project-specific terms (library names, APIs, file patterns) so the learning surfaces precisely\n\
              - relevance_context controls WHEN this learning appears — include both triggers and exclusions\n\
              - Avoid generic advice without concrete anchors — it will surface in every session",
-            sid = session.id
+            sid = session.id,
+            optional_fields = REFLECT_OPTIONAL_FIELDS
         );
         let output = StopOutput::block_with_reason(reason);
         crate::hooks::output::to_json(&output)
@@ -970,6 +1087,9 @@ impl<S: SessionStore> HookRunner<S> {
         let (git_files, mut git_keywords) = extract_git_context(cwd);
         git_keywords.extend(keywords);
 
+        let applicability = ApplicabilityContext::new()
+            .files(git_files.clone())
+            .branch(extract_git_branch(cwd));
         let query = SearchQuery::new().files(git_files).keywords(git_keywords);
 
         // Retrieve and score learnings
@@ -977,6 +1097,7 @@ impl<S: SessionStore> HookRunner<S> {
             cwd,
             &session,
             &query,
+            &applicability,
             Some(&hook_input.common.transcript_path),
         );
 
@@ -988,6 +1109,13 @@ impl<S: SessionStore> HookRunner<S> {
                 Some(format!("injected new learnings: {}", top_learnings.len())),
             );
             self.save_session(&session);
+            let context = render_injection_template(
+                cwd,
+                TemplateKind::UserPromptSubmit,
+                &session,
+                context,
+                serde_json::json!({ "prompt": hook_input.prompt }),
+            );
             return crate::hooks::output::to_json(&UserPromptSubmitOutput::with_context(context));
         }
 
@@ -1003,15 +1131,284 @@ impl<S: SessionStore> HookRunner<S> {
     // Helpers
     // =========================================================================
 
+    /// Recency weight and reference boost for a learning.
+    ///
+    /// The reference boost comes from the learning's hit rate in `cache`;
+    /// learnings never surfaced get the neutral boost.
+    fn recency_and_reference(
+        &self,
+        learning: &CompoundLearning,
+        cache: Option<&StatsCache>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> (f64, f64) {
+        let (surfaced, referenced) = cache
+            .and_then(|c| c.learnings.get(&learning.id))
+            .map(|stats| (stats.surfaced, stats.referenced))
+            .unwrap_or((0, 0));
+
+        let half_life = self
+            .config
+            .retrieval
+            .half_life_for_category(&learning.category);
+        let lambda = recency::lambda_from_half_life(half_life);
+        let recency = recency_weight(learning.timestamp, now, lambda);
+        let hit_rate = if surfaced == 0 {
+            None
+        } else {
+            Some(referenced as f64 / surfaced as f64)
+        };
+        (recency, reference_boost(hit_rate))
+    }
+
+    /// Load the persisted trigger index, rebuilding it from the backend when
+    /// it is missing or the learning stores changed since it was built.
+    fn load_trigger_index(&self, cwd: &Path, root: &Path) -> Option<TriggerIndex> {
+        let path = crate::config::project_trigger_index_path(cwd);
+        let stamps = source_stamps(cwd, &self.config);
+        if let Some(index) = TriggerIndex::load(&path, &stamps) {
+            return Some(index);
+        }
+
+        let backend = create_primary_backend(cwd, Some(&self.config));
+        let results = backend
+            .search(&SearchQuery::new(), &SearchFilters::active_only())
+            .ok()?;
+        let index = TriggerIndex::build(results, root, stamps);
+        index.save(&path);
+        Some(index)
+    }
+
+    /// Build the file-triggered injection for an Edit/Write tool call.
+    ///
+    /// Looks the target file up in the persisted [`TriggerIndex`] of active
+    /// learnings and injects the matches not yet surfaced in this session, highest score
+    /// first and capped at `retrieval.max_injections`. Matches skip the
+    /// relevance and selection thresholds: a learning about the file being
+    /// edited is relevant by definition. Saves the session when anything is
+    /// injected.
+    fn build_file_triggered_context(
+        &self,
+        input: &PreToolUseInput,
+        session: &mut SessionState,
+    ) -> Option<String> {
+        let cwd = Path::new(&input.common.cwd);
+        let path = ["file_path", "notebook_path"]
+            .iter()
+            .find_map(|key| input.tool_input.get(*key).and_then(|v| v.as_str()))?;
+        let root = find_project_root(cwd);
+        let file = relative_path(&root, path);
+
+        let index = self.load_trigger_index(cwd, &root)?;
+        let matched = index.lookup_file(&file);
+        if matched.is_empty() {
+            return None;
+        }
+
+        let now = chrono::Utc::now();
+        let applicability =
+            tool_applicability_context(cwd, &[], &input.tool_name, &input.tool_input);
+        let stats_path = project_stats_log_path(cwd);
+        let cache_path = cwd.join(".grove").join("stats-cache.json");
+        let cache = StatsCacheManager::new(&cache_path, &stats_path)
+            .load_or_rebuild()
+            .ok();
+        let strategy = Strategy::parse(&self.config.retrieval.strategy).unwrap_or_default();
+
+        let mut scored: Vec<CompositeScore> = matched
+            .into_iter()
+            .filter(|r| !r.learning.is_expired(now))
+            .filter(|r| is_applicable(&r.learning, &applicability))
+            .filter(|r| {
+                !session
+                    .gate
+                    .injected_learnings
+                    .iter()
+                    .any(|il| il.learning_id == r.learning.id)
+            })
+            .map(|triggered| {
+                let (recency, reference) =
+                    self.recency_and_reference(&triggered.learning, cache.as_ref(), now);
+                CompositeScore::new(
+                    triggered.learning.clone(),
+                    1.0,
+                    recency,
+                    reference,
+                    strategy,
+                )
+                .with_source(triggered.source)
+            })
+            .collect();
+        if scored.is_empty() {
+            return None;
+        }
+
+        scored.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        scored.truncate(self.config.retrieval.max_injections as usize);
+        let candidates = scored.len();
+        for (i, cs) in scored.iter_mut().enumerate() {
+            cs.explanation = Some(InjectionExplanation {
+                scoring_backend: "file".to_string(),
+                strategy: strategy.as_str().to_string(),
+                files: vec![file.clone()],
+                relevance: cs.relevance,
+                recency: cs.recency,
+                reference: cs.reference,
+                score: cs.score,
+                rank: i + 1,
+                candidates,
+                trigger_file: Some(file.clone()),
+                ..Default::default()
+            });
+        }
+
+        let context = self.build_injection_context(cwd, session, &scored, true)?;
+        session.add_trace(
+            EventType::FileInjection,
+            Some(format!(
+                "injected for {} via {} (learnings: {})",
+                file,
+                input.tool_name,
+                context.learnings.len()
+            )),
+        );
+        self.save_session(session);
+
+        Some(render_injection_template(
+            cwd,
+            TemplateKind::PreToolUse,
+            session,
+            context,
+            serde_json::json!({ "tool_name": input.tool_name, "file_path": file }),
+        ))
+    }
+
+    /// Check a Bash command against the command guards of active learnings.
+    ///
+    /// Guards come from the persisted [`TriggerIndex`], so commands are
+    /// checked without loading the corpus, and not at all when no learning
+    /// declares a guard. Every match is logged as a `guard_fired` stats event; matching
+    /// learnings are recorded as injected once per session. Returns `Block`
+    /// when any matching guard has `block` severity, otherwise `Warn` with
+    /// the guarding learnings as context, or `None` when nothing matches.
+    fn check_command_guards(
+        &self,
+        input: &PreToolUseInput,
+        command: &str,
+        session: &mut SessionState,
+    ) -> Option<GuardOutcome> {
+        if command.trim().is_empty() {
+            return None;
+        }
+        let cwd = Path::new(&input.common.cwd);
+        let index = self.load_trigger_index(cwd, &find_project_root(cwd))?;
+        let mut guarded = index.guarded().peekable();
+        guarded.peek()?;
+
+        let now = chrono::Utc::now();
+        let applicability =
+            tool_applicability_context(cwd, &[], &input.tool_name, &input.tool_input);
+        let matches: Vec<(CompoundLearning, String, GuardSeverity)> = guarded
+            .map(|t| &t.learning)
+            .filter(|l| !l.is_expired(now) && is_applicable(l, &applicability))
+            .filter_map(|l| {
+                let guard = l.guard.as_ref()?;
+                let pattern = guard.matching_pattern(command)?.to_string();
+                Some((l.clone(), pattern, guard.severity))
+            })
+            .collect();
+        if matches.is_empty() {
+            return None;
+        }
+
+        let logger = StatsLogger::new(project_stats_log_path(cwd));
+        let mut blocks = Vec::new();
+        for (learning, pattern, severity) in &matches {
+            let _ = logger.append_guard_fired(&learning.id, &session.id, pattern, *severity);
+            session.add_trace(
+                EventType::CommandGuard,
+                Some(format!(
+                    "{} matched '{}' ({})",
+                    learning.id,
+                    pattern,
+                    severity.as_str()
+                )),
+            );
+
+            let already_injected = session
+                .gate
+                .injected_learnings
+                .iter()
+                .any(|il| il.learning_id == learning.id);
+            if !already_injected {
+                let _ = logger.append_surfaced(&learning.id, &session.id, Some(learning.category));
+                session.gate.injected_learnings.push(
+                    InjectedLearning::new(&learning.id, 1.0).with_explanation(Some(
+                        InjectionExplanation {
+                            scoring_backend: "guard".to_string(),
+                            relevance: 1.0,
+                            score: 1.0,
+                            rank: 1,
+                            candidates: 1,
+                            trigger_command: Some(command.to_string()),
+                            ..Default::default()
+                        },
+                    )),
+                );
+            }
+
+            let header = format!(
+                "\n### {} [{}]\n",
+                learning.category.display_name(),
+                learning.id
+            );
+            if let Some(block) =
+                render_learning_block(&header, &learning.summary, &learning.detail, None)
+            {
+                blocks.push(format!("{}*Guard: `{}`*\n", block.text, pattern));
+            }
+        }
+        self.save_session(session);
+
+        let blocking: Vec<String> = matches
+            .iter()
+            .filter(|(_, _, severity)| *severity == GuardSeverity::Block)
+            .map(|(learning, _, _)| format!("`grove edit {}`", learning.id))
+            .collect();
+        if !blocking.is_empty() {
+            return Some(GuardOutcome::Block(format!(
+                "Blocked by a Grove command guard. A past learning flags this command:\n{}\n\
+                 If the command is still needed, explain why and ask the user to run it. \
+                 To change the guard, run {}.",
+                blocks.join(""),
+                blocking.join(" or ")
+            )));
+        }
+
+        Some(GuardOutcome::Warn(format!(
+            "## Command Guard\n\nThis command matches a guard from past work. \
+             Check the learning below before relying on its result:\n{}\n---\n\
+             *When a learning above helps your work, run: `grove ref <ID> --session-id {}`*\n",
+            blocks.join(""),
+            session.id
+        )))
+    }
+
     /// Retrieve and score learnings from the backend.
     ///
     /// Shared helper used by both SessionStart and PreToolUse deferred injection.
+    /// Learnings whose `applies_to` predicates don't hold in `applicability`, and
+    /// learnings past their expiry date, are dropped before scoring.
     /// Returns scored learnings sorted by score descending, capped at the configured limit.
     fn retrieve_and_score_learnings(
         &self,
         cwd: &Path,
         _session: &SessionState,
         query: &SearchQuery,
+        applicability: &ApplicabilityContext,
         transcript_path: Option<&Path>,
     ) -> Vec<CompositeScore> {
         let max_injections = self.config.retrieval.max_injections;
@@ -1026,14 +1423,36 @@ impl<S: SessionStore> HookRunner<S> {
         let cache_manager = StatsCacheManager::new(&cache_path, &stats_path);
         let cache = cache_manager.load_or_rebuild().ok();
 
-        let results = match backend.search(query, &filters) {
-            Ok(r) => r,
+        let now = chrono::Utc::now();
+        let results: Vec<_> = match backend.search(query, &filters) {
+            Ok(r) => r
+                .into_iter()
+                .filter(|r| !r.learning.is_expired(now))
+                .filter(|r| is_applicable(&r.learning, applicability))
+                .collect(),
             Err(_) => return Vec::new(),
         };
 
-        // Optionally rescore with Tantivy BM25
+        // The query as scored (with corpus enrichment), kept for explanations
+        #[cfg_attr(not(feature = "tantivy-search"), allow(unused_mut))]
+        let mut scored_query = query.clone();
+        let scoring_backend = if cfg!(feature = "tantivy-search")
+            && matches!(
+                self.config.retrieval.scoring_backend.as_str(),
+                "bm25" | "hybrid"
+            ) {
+            self.config.retrieval.scoring_backend.as_str()
+        } else {
+            "keyword"
+        };
+
+        // Optionally rescore with Tantivy BM25 (and fuse with embeddings for hybrid)
         #[cfg(feature = "tantivy-search")]
-        let results = if self.config.retrieval.scoring_backend == "bm25" {
+        let results = if matches!(
+            self.config.retrieval.scoring_backend.as_str(),
+            "bm25" | "hybrid"
+        ) {
+            let hybrid = self.config.retrieval.scoring_backend == "hybrid";
             // Fetch all active learnings for corpus-size heuristic and enrichment
             let all_active = backend
                 .search(&SearchQuery::new(), &SearchFilters::active_only())
@@ -1073,16 +1492,54 @@ impl<S: SessionStore> HookRunner<S> {
                 query.clone()
             };
 
-            rescore_with_tantivy(results, &effective_query, profile)
+            scored_query = effective_query.clone();
+
+            // Hybrid scores every applicable learning: the embedding ranking can
+            // surface learnings that share no keywords with the query.
+            let candidates = if hybrid {
+                all_active
+                    .iter()
+                    .filter(|r| !r.learning.is_expired(now))
+                    .filter(|r| is_applicable(&r.learning, applicability))
+                    .map(|r| {
+                        crate::backends::SearchResult::new(r.learning.clone(), 0.0)
+                            .with_source(r.source)
+                    })
+                    .collect()
+            } else {
+                results
+            };
+
+            let all_learnings: Vec<_> = all_active.into_iter().map(|r| r.learning).collect();
+            let project_index = open_project_index(cwd, &all_learnings);
+            let rescored = rescore_with_tantivy(
+                candidates,
+                &effective_query,
+                profile,
+                project_index.as_ref().map(|i| i.index()),
+            );
+
+            if hybrid {
+                fuse_with_embeddings(
+                    cwd,
+                    rescored,
+                    &effective_query,
+                    &self.config.retrieval.hybrid,
+                    &self.config.gate.semantic_dedup,
+                )
+            } else {
+                rescored
+            }
         } else {
             results
         };
 
         #[cfg(not(feature = "tantivy-search"))]
-        if self.config.retrieval.scoring_backend == "bm25" {
+        if self.config.retrieval.scoring_backend != "keyword" {
             warn!(
-                "scoring_backend=\"bm25\" configured but tantivy-search feature is not enabled; \
-                 falling back to keyword scoring"
+                "scoring_backend=\"{}\" configured but tantivy-search feature is not enabled; \
+                 falling back to keyword scoring",
+                self.config.retrieval.scoring_backend
             );
         }
 
@@ -1093,13 +1550,13 @@ impl<S: SessionStore> HookRunner<S> {
             strategy = Strategy::Moderate;
         }
 
-        let now = chrono::Utc::now();
         let min_threshold = strategy.min_relevance_threshold();
 
         let mut scored: Vec<CompositeScore> = results
             .into_iter()
             .filter_map(|result| {
-                let qualifies = if result.relevance >= min_threshold && result.relevance > 0.0 {
+                let matched = result.relevance >= min_threshold && result.relevance > 0.0;
+                let qualifies = if matched {
                     true
                 } else if strategy.includes_recent_without_match() {
                     let days_old = (now - result.learning.timestamp).num_days();
@@ -1112,32 +1569,29 @@ impl<S: SessionStore> HookRunner<S> {
                     return None;
                 }
 
-                let (surfaced, referenced) = cache
-                    .as_ref()
-                    .and_then(|c| c.learnings.get(&result.learning.id))
-                    .map(|stats| (stats.surfaced, stats.referenced))
-                    .unwrap_or((0, 0));
-
-                let half_life = self
-                    .config
-                    .retrieval
-                    .half_life_for_category(&result.learning.category);
-                let lambda = recency::lambda_from_half_life(half_life);
-                let recency = recency_weight(result.learning.timestamp, now, lambda);
-                let hit_rate = if surfaced == 0 {
-                    None
-                } else {
-                    Some(referenced as f64 / surfaced as f64)
-                };
-                let ref_boost = reference_boost(hit_rate);
+                let (recency, ref_boost) =
+                    self.recency_and_reference(&result.learning, cache.as_ref(), now);
 
-                Some(CompositeScore::new(
+                let mut cs = CompositeScore::new(
                     result.learning,
                     result.relevance,
                     recency,
                     ref_boost,
                     strategy,
-                ))
+                )
+                .with_source(result.source);
+                cs.explanation = Some(InjectionExplanation {
+                    scoring_backend: scoring_backend.to_string(),
+                    strategy: strategy.as_str().to_string(),
+                    relevance: cs.relevance,
+                    recency: cs.recency,
+                    reference: cs.reference,
+                    score: cs.score,
+                    min_relevance: min_threshold,
+                    recent_without_match: !matched,
+                    ..Default::default()
+                });
+                Some(cs)
             })
             .collect();
 
@@ -1148,6 +1602,12 @@ impl<S: SessionStore> HookRunner<S> {
         });
 
         let scored_count = scored.len();
+        for (i, cs) in scored.iter_mut().enumerate() {
+            if let Some(ref mut explanation) = cs.explanation {
+                explanation.rank = i + 1;
+                explanation.candidates = scored_count;
+            }
+        }
 
         // Compute top/median/gap for logging before adaptive threshold consumes the vec.
         let top_score = scored.first().map(|s| s.score).unwrap_or(0.0);
@@ -1191,7 +1651,31 @@ impl<S: SessionStore> HookRunner<S> {
         } else {
             self.config.retrieval.dynamic_k_ratio
         };
-        let qualified = apply_dynamic_k(scored, dk_ratio, effective_limit);
+        // Diversity: pick among everything dynamic K keeps, trading relevance
+        // against similarity to learnings already picked (MMR).
+        let diversity = &self.config.retrieval.diversity;
+        let mut qualified = if diversity.enabled {
+            let candidates = apply_dynamic_k(scored, dk_ratio, usize::MAX);
+            let embeddings = if diversity.use_embeddings {
+                load_cached_embeddings(cwd, &self.config.gate.semantic_dedup)
+            } else {
+                crate::core::embeddings::EmbeddingCache::default()
+            };
+            apply_diversity(candidates, diversity.lambda, effective_limit, &embeddings)
+        } else {
+            apply_dynamic_k(scored, dk_ratio, effective_limit)
+        };
+        for cs in &mut qualified {
+            if let Some(ref mut explanation) = cs.explanation {
+                explanation.keywords = scored_query.keywords.clone();
+                explanation.tags = scored_query.tags.clone();
+                explanation.files = scored_query.files.clone();
+                explanation.min_confidence = self.config.retrieval.min_confidence_threshold;
+                explanation.min_score_gap = self.config.retrieval.min_score_gap;
+                explanation.dynamic_k_ratio = dk_ratio;
+                explanation.dynamic_k_cutoff = top_score * dk_ratio;
+            }
+        }
 
         if qualified.len() < scored_count {
             debug!(
@@ -1222,6 +1706,16 @@ impl<S: SessionStore> HookRunner<S> {
                                 intent_cfg.min_overlap,
                             )
                         })
+                        .map(|mut cs| {
+                            if let Some(ref mut explanation) = cs.explanation {
+                                explanation.intent_overlap = Some(intent_overlap_ratio(
+                                    &cs.learning.summary,
+                                    &cs.learning.detail,
+                                    &keywords,
+                                ));
+                            }
+                            cs
+                        })
                         .collect();
                     if filtered.len() < pre_filter_count {
                         debug!(
@@ -1231,12 +1725,17 @@ impl<S: SessionStore> HookRunner<S> {
                             keywords.len()
                         );
                     }
-                    return filtered;
+                    return resolve_relations(
+                        backend.as_ref(),
+                        filtered,
+                        applicability,
+                        effective_limit,
+                    );
                 }
             }
         }
 
-        qualified
+        resolve_relations(backend.as_ref(), qualified, applicability, effective_limit)
     }
 
     /// Build injection context string from scored learnings, recording surfaced events.
@@ -1248,13 +1747,23 @@ impl<S: SessionStore> HookRunner<S> {
     /// The `only_new` parameter controls whether to include already-injected learnings
     /// in the output. When `true` (deferred injection), only new learnings are included.
     /// When `false` (session start), all learnings are included.
+    ///
+    /// Learnings fill `retrieval.token_budget` in rank order, trimmed in stages
+    /// when they don't fit (see [`render_learning_block`]); a learning that
+    /// doesn't fit even as its summary is left out and not recorded. Examples
+    /// of untrimmed learnings are appended in rank order while they fit within
+    /// `retrieval.example_token_budget`. The tokens used are added to the
+    /// session and logged as an injected event.
+    ///
+    /// Returns the built-in text along with the injected learnings as
+    /// template variables.
     fn build_injection_context(
         &self,
         cwd: &Path,
         session: &mut SessionState,
         scored_learnings: &[CompositeScore],
         only_new: bool,
-    ) -> Option<String> {
+    ) -> Option<InjectionContext> {
         if scored_learnings.is_empty() {
             return None;
         }
@@ -1263,6 +1772,12 @@ impl<S: SessionStore> HookRunner<S> {
         let logger = StatsLogger::new(&stats_path);
         let mut context_parts = Vec::new();
         let mut has_content = false;
+        let mut example_budget = self.config.retrieval.example_token_budget;
+        let token_budget = self.config.retrieval.token_budget;
+        let mut used_tokens = 0;
+        let mut injected_count = 0;
+        let mut trimmed_count = 0;
+        let mut template_learnings = Vec::new();
 
         context_parts.push("## Relevant Learnings\n".to_string());
         context_parts.push("The following learnings from past work may be relevant:\n".to_string());
@@ -1283,25 +1798,77 @@ impl<S: SessionStore> HookRunner<S> {
                 continue;
             }
 
-            has_content = true;
-
-            context_parts.push(format!(
-                "\n### {} [{}]\n**{}**\n{}\n",
+            // Org learnings come from the shared corpus, not this project
+            let origin = match cs.source {
+                LearningSource::Org => " (org)",
+                LearningSource::Project => "",
+            };
+            let header = format!(
+                "\n### {} [{}]{}\n",
                 learning.category.display_name(),
                 learning.id,
-                learning.summary,
-                learning.detail
-            ));
+                origin
+            );
+            let remaining = (token_budget > 0).then(|| token_budget.saturating_sub(used_tokens));
+            let Some(block) =
+                render_learning_block(&header, &learning.summary, &learning.detail, remaining)
+            else {
+                debug!(
+                    "Token budget: leaving out {} ({} of {} tokens used)",
+                    learning.id, used_tokens, token_budget
+                );
+                continue;
+            };
+
+            has_content = true;
+            used_tokens += block.tokens;
+            injected_count += 1;
+            context_parts.push(block.text);
+
+            let mut examples = Vec::new();
+            if block.trimmed {
+                trimmed_count += 1;
+            } else {
+                for example in &learning.examples {
+                    let block = format_examples(std::slice::from_ref(example));
+                    let tokens = estimate_tokens(&block);
+                    if tokens <= example_budget {
+                        example_budget -= tokens;
+                        context_parts.push(format!("\n{}\n", block));
+                        examples.push(block);
+                    }
+                }
+            }
+
+            let round = |value: f64| (value * 1000.0).round() / 1000.0;
+            template_learnings.push(serde_json::json!({
+                "id": learning.id,
+                "category": learning.category.display_name(),
+                "summary": learning.summary,
+                "detail": block.detail,
+                "trimmed": block.trimmed,
+                "examples": examples.join("\n\n"),
+                "tags": learning.tags,
+                "scope": learning.scope,
+                "origin": match cs.source {
+                    LearningSource::Org => "org",
+                    LearningSource::Project => "project",
+                },
+                "score": round(cs.score),
+                "relevance": round(cs.relevance),
+                "recency": round(cs.recency),
+                "reference": round(cs.reference),
+            }));
 
             if !already_injected {
                 // Record surfaced event only once per session
                 let _ = logger.append_surfaced(&learning.id, &session.id, Some(learning.category));
 
                 // Add to session's injected learnings
-                session
-                    .gate
-                    .injected_learnings
-                    .push(InjectedLearning::new(&learning.id, cs.score));
+                session.gate.injected_learnings.push(
+                    InjectedLearning::new(&learning.id, cs.score)
+                        .with_explanation(cs.explanation.clone()),
+                );
             }
         }
 
@@ -1309,6 +1876,15 @@ impl<S: SessionStore> HookRunner<S> {
             return None;
         }
 
+        session.gate.injected_tokens += used_tokens;
+        let _ = logger.append_injected(
+            &session.id,
+            used_tokens,
+            token_budget,
+            injected_count,
+            trimmed_count,
+        );
+
         // Add citation guidance
         context_parts.push("\n---\n".to_string());
         context_parts.push(format!("*Session: {}*\n", session.id));
@@ -1317,7 +1893,10 @@ impl<S: SessionStore> HookRunner<S> {
             session.id
         ));
 
-        Some(context_parts.join(""))
+        Some(InjectionContext {
+            text: context_parts.join(""),
+            learnings: template_learnings,
+        })
     }
 
     /// Get or create a session based on hook input.
@@ -1343,6 +1922,8 @@ impl<S: SessionStore> HookRunner<S> {
     /// previously surfaced have been corrected, we inject a notice at
     /// session-start to inform the agent of the correction.
     ///
+    /// Returns the IDs of corrected learnings.
+    ///
     /// Best-effort: if cache is unavailable, returns empty list.
     fn get_correction_notices(&self, cwd: &Path, session: &SessionState) -> Vec<String> {
         let stats_log_path = project_stats_log_path(cwd);
@@ -1365,7 +1946,7 @@ impl<S: SessionStore> HookRunner<S> {
         let mut notices = Vec::new();
         for learning in &session.gate.injected_learnings {
             if corrected_ids.contains(&learning.learning_id) {
-                notices.push(format!("- Learning ID: {}", learning.learning_id));
+                notices.push(learning.learning_id.clone());
             }
         }
 
@@ -1381,11 +1962,110 @@ impl<S: SessionStore> HookRunner<S> {
 // Tantivy BM25 rescoring (feature-gated)
 // =========================================================================
 
+/// Open the project's persistent search index, rebuilding it when stale.
+///
+/// `learnings` is every active learning the backend returns; when their
+/// content hashes no longer match the index manifest (for example after
+/// `learnings.md` was edited by hand), the index is rebuilt from them.
+///
+/// Fail-open: returns `None` when there is nothing to index or the index
+/// can't be opened or rebuilt, and the caller falls back to an in-memory index.
+#[cfg(feature = "tantivy-search")]
+fn open_project_index(
+    cwd: &Path,
+    learnings: &[crate::core::CompoundLearning],
+) -> Option<crate::search::ProjectIndex> {
+    use crate::search::ProjectIndex;
+
+    if learnings.is_empty() {
+        return None;
+    }
+
+    let dir = crate::config::project_index_dir(cwd);
+    let result = ProjectIndex::open(&dir).and_then(|mut index| {
+        if index.sync(learnings)? {
+            debug!(
+                "Rebuilt search index at {} ({} learnings)",
+                dir.display(),
+                index.index().num_docs()
+            );
+        }
+        Ok(index)
+    });
+
+    match result {
+        Ok(index) => Some(index),
+        Err(e) => {
+            warn!("Search index unavailable (fail-open): {}", e);
+            None
+        }
+    }
+}
+
+/// Fuse BM25-scored results with an embedding ranking for hybrid retrieval.
+///
+/// Embeds the query with the configured provider, ranks the results by
+/// similarity (sharing the semantic dedup embedding cache), and replaces each
+/// result's relevance with its reciprocal rank fusion score.
+///
+/// Fail-open: any embedding error returns the BM25 results unchanged.
+#[cfg(feature = "tantivy-search")]
+fn fuse_with_embeddings(
+    cwd: &Path,
+    results: Vec<crate::backends::SearchResult>,
+    query: &SearchQuery,
+    config: &crate::config::HybridConfig,
+    embeddings: &crate::config::SemanticDedupConfig,
+) -> Vec<crate::backends::SearchResult> {
+    use crate::core::embeddings::{create_provider, EmbeddingCache};
+    use crate::search::hybrid::{embedding_ranking, fuse_results, query_text};
+
+    let provider = match create_provider(embeddings) {
+        Ok(provider) => provider,
+        Err(e) => {
+            warn!(
+                "Embedding provider unavailable, using bm25 only (fail-open): {}",
+                e
+            );
+            return results;
+        }
+    };
+
+    let mut cache =
+        EmbeddingCache::load(&crate::config::project_grove_dir(cwd), &provider.model_id());
+    let learnings: Vec<_> = results.iter().map(|r| &r.learning).collect();
+    let ranking = match embedding_ranking(
+        &query_text(query),
+        &learnings,
+        provider.as_ref(),
+        &mut cache,
+        config.min_similarity,
+    ) {
+        Ok(ranking) => ranking,
+        Err(e) => {
+            warn!(
+                "Embedding ranking failed, using bm25 only (fail-open): {}",
+                e
+            );
+            return results;
+        }
+    };
+    cache.save();
+
+    debug!(
+        "Hybrid retrieval: {} embedding match(es) of {} candidates",
+        ranking.len(),
+        results.len()
+    );
+    fuse_results(results, &ranking, config)
+}
+
 /// Rescore search results using Tantivy BM25 relevance.
 ///
-/// Builds an in-memory Tantivy index from the learnings in the search results,
-/// constructs a query string from the SearchQuery fields, and replaces each
-/// result's relevance score with the normalized BM25 score.
+/// Searches `index` (the project's persistent index) when given, otherwise
+/// builds an in-memory Tantivy index from the learnings in the search results.
+/// Constructs a query string from the SearchQuery fields and replaces each
+/// result's relevance score with the BM25 score normalized across the results.
 ///
 /// The `profile` parameter controls which BM25 variant is used:
 /// - `Standard` → plain BM25 (better precision for large corpora)
@@ -1393,29 +2073,39 @@ impl<S: SessionStore> HookRunner<S> {
 ///
 /// Fail-open: any Tantivy error returns original results unchanged.
 #[cfg(feature = "tantivy-search")]
-fn rescore_with_tantivy(
+pub(crate) fn rescore_with_tantivy(
     results: Vec<crate::backends::SearchResult>,
     query: &SearchQuery,
     profile: crate::config::RetrievalProfile,
+    index: Option<&crate::search::TantivySearchIndex>,
 ) -> Vec<crate::backends::SearchResult> {
     use crate::config::RetrievalProfile;
     use crate::search::TantivySearchIndex;
 
-    let learnings: Vec<_> = results.iter().map(|r| r.learning.clone()).collect();
-
-    // Build in-memory index from the learnings
-    let index = match TantivySearchIndex::in_memory() {
-        Ok(idx) => idx,
-        Err(e) => {
-            warn!("Tantivy index creation failed (fail-open): {}", e);
-            return results;
+    // Without a persistent index, build an in-memory one from the learnings
+    let in_memory;
+    let index = match index {
+        Some(index) => index,
+        None => {
+            let learnings: Vec<_> = results.iter().map(|r| r.learning.clone()).collect();
+            in_memory = match TantivySearchIndex::in_memory() {
+                Ok(idx) => idx,
+                Err(e) => {
+                    warn!("Tantivy index creation failed (fail-open): {}", e);
+                    return results;
+                }
+            };
+            if let Err(e) = in_memory.index_learnings(&learnings) {
+                warn!("Tantivy indexing failed (fail-open): {}", e);
+                return results;
+            }
+            &in_memory
         }
     };
 
-    if let Err(e) = index.index_learnings(&learnings) {
-        warn!("Tantivy indexing failed (fail-open): {}", e);
-        return results;
-    }
+    // The persistent index holds the whole corpus; rank all of it so every
+    // candidate gets a score, then keep only the candidates.
+    let limit = (index.num_docs() as usize).max(results.len());
 
     // Search with Tantivy — boosted or plain depending on profile
     let tantivy_results = match profile {
@@ -1424,7 +2114,7 @@ fn rescore_with_tantivy(
             if boosted_query.trim().is_empty() {
                 return results;
             }
-            match index.search_boosted(&boosted_query, results.len()) {
+            match index.search_boosted(&boosted_query, limit) {
                 Ok(r) => r,
                 Err(e) => {
                     warn!("Tantivy boosted search failed (fail-open): {}", e);
@@ -1437,7 +2127,7 @@ fn rescore_with_tantivy(
             if query_string.trim().is_empty() {
                 return results;
             }
-            match index.search(&query_string, results.len()) {
+            match index.search(&query_string, limit) {
                 Ok(r) => r,
                 Err(e) => {
                     warn!("Tantivy search failed (fail-open): {}", e);
@@ -1447,6 +2137,13 @@ fn rescore_with_tantivy(
         }
     };
 
+    let candidates: std::collections::HashSet<&str> =
+        results.iter().map(|r| r.learning.id.as_str()).collect();
+    let tantivy_results: Vec<_> = tantivy_results
+        .into_iter()
+        .filter(|r| candidates.contains(r.id.as_str()))
+        .collect();
+
     // Normalize BM25 scores to [0.0, 1.0]
     let score_map = normalize_bm25_scores(&tantivy_results);
 
@@ -1897,6 +2594,31 @@ pub fn apply_adaptive_threshold(
     Some(scored)
 }
 
+/// Apply typed relations, loading the active corpus only when a selected
+/// learning has relations. Dependencies pass the same expiry and
+/// `applies_to` filters as retrieval. Fail-open: a failed corpus load skips
+/// dependencies.
+fn resolve_relations(
+    backend: &dyn MemoryBackend,
+    selected: Vec<CompositeScore>,
+    applicability: &ApplicabilityContext,
+    max_count: usize,
+) -> Vec<CompositeScore> {
+    if selected.iter().all(|cs| cs.learning.relations.is_empty()) {
+        return selected;
+    }
+
+    let now = chrono::Utc::now();
+    let corpus: Vec<crate::backends::SearchResult> = backend
+        .search(&SearchQuery::new(), &SearchFilters::active_only())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|r| !r.learning.is_expired(now))
+        .filter(|r| is_applicable(&r.learning, applicability))
+        .collect();
+    apply_relations(selected, &corpus, max_count)
+}
+
 /// Apply dynamic K: filter learnings below a ratio of the top score.
 ///
 /// Only learnings with `score >= top_score * ratio` are retained, up to `max_count`.
@@ -1925,19 +2647,189 @@ pub fn apply_dynamic_k(
         .collect()
 }
 
-/// Compute an adaptive dynamic_k_ratio based on score distribution and stats.
-///
-/// Designed for gradual self-calibration. Defaults to off (`adaptive_dk: false`)
-/// until a repo accumulates enough stats for the data-driven levels to work.
+/// Select up to `max_count` learnings by maximal marginal relevance (MMR).
 ///
-/// Level 1 (per-query): Gentle CV-based nudge (±0.03 max). Intentionally mild
-/// so it doesn't dominate on small corpora where it can't be validated.
+/// Each pick maximizes `lambda * relevance - (1 - lambda) * similarity`,
+/// where relevance is the score relative to the top score and similarity is
+/// the highest similarity to an already-picked learning: the cosine of the
+/// two embeddings when both are in `embeddings`, otherwise
+/// [`learning_similarity`]. `lambda = 1.0` keeps the score order.
 ///
-/// Level 2 (corpus-maturity): The primary adaptive mechanism. Uses stats cache
-/// hit rates (≥20 surfaced learnings required). Low hit rates (noisy corpus) →
-/// tighter. High hit rates (healthy corpus) → looser.
+/// # Arguments
 ///
-/// Level 3 (per-category feedback): Fine-grained adjustment per learning category.
+/// * `candidates` - Learnings sorted by score descending
+/// * `lambda` - Trade-off between relevance (1.0) and diversity (0.0)
+/// * `max_count` - Maximum number of learnings to return
+/// * `embeddings` - Cached summary embeddings (may be empty)
+pub fn apply_diversity(
+    candidates: Vec<CompositeScore>,
+    lambda: f64,
+    max_count: usize,
+    embeddings: &crate::core::embeddings::EmbeddingCache,
+) -> Vec<CompositeScore> {
+    let lambda = lambda.clamp(0.0, 1.0);
+    let top_score = candidates.first().map(|s| s.score).unwrap_or(0.0);
+    let similarity =
+        |a: &CompoundLearning, b: &CompoundLearning| match (embeddings.get(a), embeddings.get(b)) {
+            (Some(va), Some(vb)) => crate::core::cosine_similarity(va, vb).max(0.0),
+            _ => learning_similarity(a, b),
+        };
+
+    let mut remaining = candidates;
+    let mut selected: Vec<CompositeScore> = Vec::new();
+    while selected.len() < max_count && !remaining.is_empty() {
+        let mut best: Option<(usize, f64, f64)> = None;
+        for (i, cs) in remaining.iter().enumerate() {
+            let relevance = if top_score > f64::EPSILON {
+                cs.score / top_score
+            } else {
+                1.0
+            };
+            let max_similarity = selected
+                .iter()
+                .map(|s| similarity(&cs.learning, &s.learning))
+                .fold(0.0, f64::max);
+            let mmr = lambda * relevance - (1.0 - lambda) * max_similarity;
+            // Strictly greater: ties keep the higher-scored candidate
+            if best.is_none_or(|(_, best_mmr, _)| mmr > best_mmr) {
+                best = Some((i, mmr, max_similarity));
+            }
+        }
+
+        let Some((i, _, max_similarity)) = best else {
+            break;
+        };
+        let mut cs = remaining.remove(i);
+        if let Some(ref mut explanation) = cs.explanation {
+            explanation.diversity_similarity = Some(max_similarity);
+        }
+        selected.push(cs);
+    }
+    selected
+}
+
+/// Lexical similarity of two learnings in [0.0, 1.0].
+///
+/// Weighted overlap: tags (Jaccard, 0.5), same category (0.2), and summary
+/// words of 4+ characters (Jaccard, 0.3).
+pub fn learning_similarity(a: &CompoundLearning, b: &CompoundLearning) -> f64 {
+    fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+        let union = a.union(b).count();
+        if union == 0 {
+            return 0.0;
+        }
+        a.intersection(b).count() as f64 / union as f64
+    }
+    fn tags(learning: &CompoundLearning) -> HashSet<String> {
+        learning.tags.iter().map(|t| t.to_lowercase()).collect()
+    }
+    fn terms(learning: &CompoundLearning) -> HashSet<String> {
+        learning
+            .summary
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .map(|w| w.to_lowercase())
+            .filter(|w| w.len() >= 4)
+            .collect()
+    }
+
+    let category = if a.category == b.category { 1.0 } else { 0.0 };
+    0.5 * jaccard(&tags(a), &tags(b)) + 0.2 * category + 0.3 * jaccard(&terms(a), &terms(b))
+}
+
+/// Load the cached embeddings of the configured provider's model.
+///
+/// Reads `.grove/embeddings.json` only; nothing is embedded, so learnings
+/// without a cached vector fall back to lexical similarity.
+fn load_cached_embeddings(
+    cwd: &Path,
+    config: &crate::config::SemanticDedupConfig,
+) -> crate::core::embeddings::EmbeddingCache {
+    match crate::core::embeddings::provider_model_id(config) {
+        Some(model_id) => crate::core::embeddings::EmbeddingCache::load(
+            &crate::config::project_grove_dir(cwd),
+            &model_id,
+        ),
+        None => crate::core::embeddings::EmbeddingCache::default(),
+    }
+}
+
+/// Apply typed relations to the selected learnings.
+///
+/// Learnings superseded by another selected learning are dropped. Each
+/// remaining learning's `depends_on` targets are pulled in from `corpus`
+/// (right after the dependent, with zeroed scores) unless already selected,
+/// so a learning is never injected without the learnings it builds on.
+/// Dependencies count against `max_count`: a learning whose dependencies
+/// don't fit is left out along with them.
+pub fn apply_relations(
+    selected: Vec<CompositeScore>,
+    corpus: &[crate::backends::SearchResult],
+    max_count: usize,
+) -> Vec<CompositeScore> {
+    let superseded: HashSet<String> = selected
+        .iter()
+        .flat_map(|cs| cs.learning.relation_targets(RelationKind::Supersedes))
+        .map(String::from)
+        .collect();
+
+    let kept: Vec<CompositeScore> = selected
+        .into_iter()
+        .filter(|cs| !superseded.contains(&cs.learning.id))
+        .collect();
+
+    let mut seen: HashSet<String> = kept.iter().map(|cs| cs.learning.id.clone()).collect();
+    let mut result = Vec::with_capacity(kept.len());
+    for cs in kept {
+        let mut dependencies: Vec<CompositeScore> = Vec::new();
+        for id in cs.learning.relation_targets(RelationKind::DependsOn) {
+            if superseded.contains(id)
+                || seen.contains(id)
+                || dependencies.iter().any(|d| d.learning.id == id)
+            {
+                continue;
+            }
+            let Some(dependency) = corpus.iter().find(|r| r.learning.id == id) else {
+                continue;
+            };
+            dependencies.push(CompositeScore {
+                learning: dependency.learning.clone(),
+                relevance: 0.0,
+                recency: 0.0,
+                reference: 0.0,
+                score: 0.0,
+                source: dependency.source,
+                explanation: cs.explanation.as_ref().map(|e| InjectionExplanation {
+                    scoring_backend: e.scoring_backend.clone(),
+                    strategy: e.strategy.clone(),
+                    dependency_of: Some(cs.learning.id.clone()),
+                    ..Default::default()
+                }),
+            });
+        }
+        if result.len() + 1 + dependencies.len() > max_count {
+            seen.remove(&cs.learning.id);
+            continue;
+        }
+        seen.extend(dependencies.iter().map(|d| d.learning.id.clone()));
+        result.push(cs);
+        result.extend(dependencies);
+    }
+    result
+}
+
+/// Compute an adaptive dynamic_k_ratio based on score distribution and stats.
+///
+/// Designed for gradual self-calibration. Defaults to off (`adaptive_dk: false`)
+/// until a repo accumulates enough stats for the data-driven levels to work.
+///
+/// Level 1 (per-query): Gentle CV-based nudge (±0.03 max). Intentionally mild
+/// so it doesn't dominate on small corpora where it can't be validated.
+///
+/// Level 2 (corpus-maturity): The primary adaptive mechanism. Uses stats cache
+/// hit rates (≥20 surfaced learnings required). Low hit rates (noisy corpus) →
+/// tighter. High hit rates (healthy corpus) → looser.
+///
+/// Level 3 (per-category feedback): Fine-grained adjustment per learning category.
 /// Categories with high dismiss rates (>50%) get tighter dk for their learnings.
 ///
 /// Returns the adjusted ratio clamped to [0.15, 0.6].
@@ -2028,6 +2920,141 @@ pub fn adaptive_dk_ratio(
     ratio.clamp(0.15, 0.6)
 }
 
+/// Optional candidate fields listed in the reflection prompts of the stop
+/// and task-completed gates.
+const REFLECT_OPTIONAL_FIELDS: &str = "\
+    Relations (optional): \"relations\": [{\"kind\": \"supersedes\", \"target\": \"cl_...\"}] — kinds: supersedes, refines, contradicts, related, depends_on\n\
+    Applies to (optional): \"applies_to\": {\"paths\": [\"migrations/**\"], \"languages\": [\"sql\"], \"branches\": [\"release/*\"], \"tools\": [\"terraform\"]} — surfaced only when all listed predicates match\n\
+    Dates (optional): \"expires_at\": \"2026-12-31\" for facts with a known end, \"review_by\": \"2026-06-30\" for facts that need re-checking\n\
+    Examples (optional): \"examples\": [{\"kind\": \"bad\", \"language\": \"ruby\", \"code\": \"User.all.each { |u| u.posts.count }\", \"caption\": \"N+1 query\"}] — kinds: good, bad, command; put snippets here instead of in detail\n\
+    Guard (optional): \"guard\": {\"commands\": [\"cargo publish\", \"/git push .*--force/\"], \"severity\": \"warn\"} — shown before matching Bash commands; \"block\" denies them\n\
+    Author (optional): top-level \"agent\": \"main\" (or your subagent name) and \"model\" record who wrote the reflection";
+
+/// Tools whose target file can trigger just-in-time injection.
+const FILE_EDIT_TOOLS: &[&str] = &["Edit", "MultiEdit", "Write", "NotebookEdit"];
+
+/// Rough token estimate for injected text (~4 chars per token, rounded up).
+fn estimate_tokens(text: &str) -> usize {
+    text.len().div_ceil(4)
+}
+
+/// Shortest detail excerpt worth injecting, in bytes; below this a learning
+/// is rendered as its summary only.
+const MIN_DETAIL_EXCERPT: usize = 80;
+
+/// A learning's context block, rendered to fit the token budget.
+struct RenderedBlock {
+    /// The block text.
+    text: String,
+    /// Estimated tokens of `text`.
+    tokens: usize,
+    /// The detail as included: whole, an excerpt ending in "…", or empty.
+    detail: String,
+    /// Whether the detail was truncated or left out.
+    trimmed: bool,
+}
+
+/// Result of checking a Bash command against command guards.
+enum GuardOutcome {
+    /// Let the command run, with the guarding learnings as context.
+    Warn(String),
+    /// Deny the command with this reason.
+    Block(String),
+}
+
+/// Injected learnings, rendered as built-in text and as template variables.
+struct InjectionContext {
+    /// The built-in context text.
+    text: String,
+    /// One object per injected learning, in rank order.
+    learnings: Vec<serde_json::Value>,
+}
+
+/// Template variables describing the session's gate.
+fn gate_template_vars(session: &SessionState) -> serde_json::Value {
+    let ticket = session.gate.ticket.as_ref();
+    serde_json::json!({
+        "status": session.gate.status,
+        "blocking": session.gate.status.requires_reflection(),
+        "block_count": session.gate.block_count,
+        "ticket_id": ticket.map(|t| t.ticket_id.as_str()).unwrap_or(""),
+        "ticket_title": ticket.map(|t| t.title.as_str()).unwrap_or(""),
+    })
+}
+
+/// Render an injection with the project's template for `kind`, falling back
+/// to the built-in text.
+///
+/// `extra` holds hook-specific variables merged into the template context.
+fn render_injection_template(
+    cwd: &Path,
+    kind: TemplateKind,
+    session: &SessionState,
+    context: InjectionContext,
+    extra: serde_json::Value,
+) -> String {
+    let mut vars = serde_json::json!({
+        "hook": kind.hook_name(),
+        "session_id": session.id,
+        "learnings": context.learnings,
+        "gate": gate_template_vars(session),
+    });
+    if let (Some(vars), serde_json::Value::Object(extra)) = (vars.as_object_mut(), extra) {
+        vars.extend(extra);
+    }
+    render_project_template(cwd, kind, &vars).unwrap_or(context.text)
+}
+
+/// Render a learning's context block within `remaining` tokens (`None` for
+/// no limit).
+///
+/// Tries, in order: the full detail, the summary with the detail truncated to
+/// fit, and the summary alone. Returns `None` when not even the summary fits.
+fn render_learning_block(
+    header: &str,
+    summary: &str,
+    detail: &str,
+    remaining: Option<usize>,
+) -> Option<RenderedBlock> {
+    let block = |text: String, detail: String, trimmed: bool| RenderedBlock {
+        tokens: estimate_tokens(&text),
+        text,
+        detail,
+        trimmed,
+    };
+
+    let summary_only = format!("{}**{}**\n", header, summary);
+    let full = format!("{}{}\n", summary_only, detail);
+    let Some(remaining) = remaining else {
+        return Some(block(full, detail.to_string(), false));
+    };
+    if estimate_tokens(&full) <= remaining {
+        return Some(block(full, detail.to_string(), false));
+    }
+
+    // Bytes left for the excerpt, reserving room for the trailing "…\n"
+    let excerpt_bytes = (remaining * 4).saturating_sub(summary_only.len() + "…\n".len());
+    if excerpt_bytes >= MIN_DETAIL_EXCERPT {
+        let mut end = excerpt_bytes.min(detail.len());
+        while !detail.is_char_boundary(end) {
+            end -= 1;
+        }
+        // Prefer to cut between words
+        let cut = detail[..end]
+            .rfind(char::is_whitespace)
+            .filter(|&i| i >= end / 2)
+            .unwrap_or(end);
+        let excerpt = detail[..cut].trim_end();
+        return Some(block(
+            format!("{}{}…\n", summary_only, excerpt),
+            format!("{}…", excerpt),
+            true,
+        ));
+    }
+
+    (estimate_tokens(&summary_only) <= remaining).then(|| block(summary_only, String::new(), true))
+}
+
 /// Read input from stdin with a size limit to prevent memory exhaustion.
 fn read_stdin() -> Result<String> {
     use std::io::Read as _;
@@ -2144,6 +3171,50 @@ fn extract_git_branch(cwd: &Path) -> String {
     String::new()
 }
 
+/// Build the applicability context for a tool call.
+///
+/// Adds the tool's target file (relative to the project root) to the changed
+/// files, and records the tool name plus, for Bash, the program being run.
+fn tool_applicability_context(
+    cwd: &Path,
+    git_files: &[String],
+    tool_name: &str,
+    tool_input: &serde_json::Value,
+) -> ApplicabilityContext {
+    let mut files = git_files.to_vec();
+    let root = find_project_root(cwd);
+    for key in ["file_path", "notebook_path", "path"] {
+        if let Some(path) = tool_input.get(key).and_then(|v| v.as_str()) {
+            let relative = Path::new(path)
+                .strip_prefix(&root)
+                .map(|p| p.to_string_lossy().to_string())
+                .unwrap_or_else(|_| path.to_string());
+            if !files.contains(&relative) {
+                files.push(relative);
+            }
+        }
+    }
+
+    let mut tools = vec![tool_name.to_string()];
+    if tool_name == "Bash" {
+        let program = tool_input
+            .get("command")
+            .and_then(|v| v.as_str())
+            .and_then(|command| {
+                command
+                    .split_whitespace()
+                    .find(|word| !word.contains('=') && *word != "sudo")
+            })
+            .map(|word| word.rsplit('/').next().unwrap_or(word).to_string());
+        tools.extend(program);
+    }
+
+    ApplicabilityContext::new()
+        .files(files)
+        .branch(extract_git_branch(cwd))
+        .tools(tools)
+}
+
 /// Extract keywords from the user's first real message in a transcript JSONL file.
 ///
 /// Used by the intent filter (post-retrieval) when `retrieval.intent_filter.enabled`
@@ -2852,6 +3923,11 @@ pub(crate) fn rerank_with_llm(
 
     // Pair candidates with LLM scores and re-sort
     let mut paired: Vec<(CompositeScore, f64)> = candidates.into_iter().zip(scores).collect();
+    for (cs, llm_score) in &mut paired {
+        if let Some(ref mut explanation) = cs.explanation {
+            explanation.rerank_score = Some(*llm_score);
+        }
+    }
     paired.sort_by(|a, b| {
         b.1.partial_cmp(&a.1)
             .unwrap_or(std::cmp::Ordering::Equal)
@@ -2865,6 +3941,232 @@ pub(crate) fn rerank_with_llm(
     paired.into_iter().map(|(cs, _)| cs).collect()
 }
 
+// =========================================================================
+// Local Reranking
+// =========================================================================
+
+/// `rerank.model` value that selects the local embedding reranker.
+pub(crate) const LOCAL_RERANK_MODEL: &str = "local";
+
+/// Reranks candidates by embedding similarity to the session context.
+///
+/// Runs fastembed's local model instead of an LLM call, so reranking fits
+/// within the hook timeout. Learnings are embedded by summary, sharing the
+/// embedding cache used by semantic dedup and hybrid retrieval.
+pub(crate) struct LocalReranker {
+    provider: Box<dyn crate::core::embeddings::EmbeddingProvider>,
+    cache: crate::core::embeddings::EmbeddingCache,
+}
+
+impl LocalReranker {
+    /// Create a reranker backed by the local fastembed model, caching learning
+    /// embeddings in `grove_dir` (in memory when `None`).
+    ///
+    /// Fails when grove was built without the `semantic-dedup` feature or the
+    /// model cannot be loaded.
+    #[cfg(feature = "semantic-dedup")]
+    pub(crate) fn fastembed(grove_dir: Option<&Path>) -> Result<Self> {
+        let provider = crate::core::embeddings::FastEmbedProvider::new()?;
+        Ok(Self::with_fastembed(provider, grove_dir))
+    }
+
+    /// Like [`LocalReranker::fastembed`], but fails instead of downloading
+    /// the model when it is not in the fastembed cache yet.
+    #[cfg(feature = "semantic-dedup")]
+    pub(crate) fn fastembed_if_cached(grove_dir: Option<&Path>) -> Result<Self> {
+        let provider = crate::core::embeddings::FastEmbedProvider::new_if_cached()?;
+        Ok(Self::with_fastembed(provider, grove_dir))
+    }
+
+    /// Wrap a fastembed provider with the embedding cache in `grove_dir`.
+    #[cfg(feature = "semantic-dedup")]
+    fn with_fastembed(
+        provider: crate::core::embeddings::FastEmbedProvider,
+        grove_dir: Option<&Path>,
+    ) -> Self {
+        use crate::core::embeddings::{EmbeddingCache, FASTEMBED_MODEL_ID};
+
+        let cache = match grove_dir {
+            Some(dir) => EmbeddingCache::load(dir, FASTEMBED_MODEL_ID),
+            None => {
+                let mut cache = EmbeddingCache::default();
+                cache.model_id = FASTEMBED_MODEL_ID.to_string();
+                cache
+            }
+        };
+        Self::new(Box::new(provider), cache)
+    }
+
+    /// Create a reranker backed by the local fastembed model.
+    ///
+    /// Always fails: grove was built without the `semantic-dedup` feature.
+    #[cfg(not(feature = "semantic-dedup"))]
+    pub(crate) fn fastembed(_grove_dir: Option<&Path>) -> Result<Self> {
+        Err(GroveError::config(
+            "rerank.model = \"local\" requires grove built with the semantic-dedup feature",
+        ))
+    }
+
+    /// Create a reranker backed by an already downloaded fastembed model.
+    ///
+    /// Always fails: grove was built without the `semantic-dedup` feature.
+    #[cfg(not(feature = "semantic-dedup"))]
+    pub(crate) fn fastembed_if_cached(grove_dir: Option<&Path>) -> Result<Self> {
+        Self::fastembed(grove_dir)
+    }
+
+    /// Create a reranker with a specific embedding provider and cache.
+    #[cfg_attr(not(feature = "semantic-dedup"), allow(dead_code))]
+    pub(crate) fn new(
+        provider: Box<dyn crate::core::embeddings::EmbeddingProvider>,
+        cache: crate::core::embeddings::EmbeddingCache,
+    ) -> Self {
+        Self { provider, cache }
+    }
+
+    /// Rerank candidates by cosine similarity between the session context and
+    /// each learning's summary.
+    ///
+    /// Similarity is mapped onto the LLM reranker's 1-5 scale and recorded as
+    /// the rerank score; candidates are re-sorted by (rerank score descending,
+    /// original score descending).
+    ///
+    /// Fail-open: returns the original ordering if embedding fails.
+    pub(crate) fn rerank(
+        &mut self,
+        candidates: Vec<CompositeScore>,
+        tool_name: &str,
+        tool_input_excerpt: &str,
+        git_branch: &str,
+        git_files: &[String],
+    ) -> Vec<CompositeScore> {
+        use crate::core::embeddings::cosine_similarity;
+
+        let query = local_rerank_query(tool_name, tool_input_excerpt, git_branch, git_files);
+        if candidates.is_empty() || query.is_empty() {
+            return candidates;
+        }
+
+        let uncached: Vec<&CompoundLearning> = candidates
+            .iter()
+            .map(|cs| &cs.learning)
+            .filter(|l| self.cache.get(l).is_none())
+            .collect();
+        let texts: Vec<&str> = std::iter::once(query.as_str())
+            .chain(uncached.iter().map(|l| l.summary.as_str()))
+            .collect();
+        let mut embeddings = match self.provider.embed(&texts) {
+            Ok(embeddings) if embeddings.len() == texts.len() => embeddings.into_iter(),
+            Ok(_) => return candidates,
+            Err(e) => {
+                warn!("Local rerank: embedding failed (fail-open): {}", e);
+                return candidates;
+            }
+        };
+        let query_embedding = embeddings.next().unwrap_or_default();
+        for (learning, embedding) in uncached.iter().zip(embeddings) {
+            self.cache.insert(learning, embedding);
+        }
+
+        let mut paired: Vec<(CompositeScore, f64)> = candidates
+            .into_iter()
+            .map(|mut cs| {
+                let similarity = self
+                    .cache
+                    .get(&cs.learning)
+                    .map_or(0.0, |e| cosine_similarity(&query_embedding, e));
+                let score = 1.0 + 4.0 * similarity.clamp(0.0, 1.0);
+                if let Some(ref mut explanation) = cs.explanation {
+                    explanation.rerank_score = Some(score);
+                }
+                (cs, score)
+            })
+            .collect();
+        paired.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| {
+                    b.0.score
+                        .partial_cmp(&a.0.score)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+        });
+
+        paired.into_iter().map(|(cs, _)| cs).collect()
+    }
+
+    /// Save learning embeddings computed while reranking.
+    pub(crate) fn save(&self) {
+        self.cache.save();
+    }
+}
+
+/// Rerank candidates with the local reranker, caching embeddings in the
+/// project's `.grove` directory.
+///
+/// Never downloads the model: hooks have seconds to finish, so a model not
+/// yet in the fastembed cache counts as unavailable.
+///
+/// Fail-open: returns the original ordering if the model is unavailable.
+fn rerank_locally(
+    cwd: &Path,
+    candidates: Vec<CompositeScore>,
+    tool_name: &str,
+    tool_input_excerpt: &str,
+    git_branch: &str,
+    git_files: &[String],
+) -> Vec<CompositeScore> {
+    let grove_dir = crate::config::project_grove_dir(cwd);
+    match LocalReranker::fastembed_if_cached(Some(&grove_dir)) {
+        Ok(mut reranker) => {
+            let reranked = reranker.rerank(
+                candidates,
+                tool_name,
+                tool_input_excerpt,
+                git_branch,
+                git_files,
+            );
+            reranker.save();
+            reranked
+        }
+        Err(e) => {
+            warn!("Local rerank unavailable, keeping order (fail-open): {}", e);
+            candidates
+        }
+    }
+}
+
+/// Text describing the session context for the local reranker.
+///
+/// Uses the string values of a JSON tool input (a command, a file path, a
+/// pattern) rather than the raw JSON, followed by the branch and file names.
+fn local_rerank_query(
+    tool_name: &str,
+    tool_input_excerpt: &str,
+    git_branch: &str,
+    git_files: &[String],
+) -> String {
+    let tool_input = match serde_json::from_str::<serde_json::Value>(tool_input_excerpt) {
+        Ok(serde_json::Value::Object(map)) => map
+            .values()
+            .filter_map(|v| v.as_str())
+            .collect::<Vec<_>>()
+            .join(" "),
+        _ => tool_input_excerpt.to_string(),
+    };
+    let tool_input = crate::core::judge::truncate_str(&tool_input, 500);
+
+    let mut parts = Vec::new();
+    if !tool_input.trim().is_empty() {
+        parts.push(format!("{} {}", tool_name, tool_input.trim()));
+    }
+    if !git_branch.is_empty() {
+        parts.push(git_branch.replace(['/', '-', '_'], " "));
+    }
+    parts.extend(git_files.iter().take(10).cloned());
+    parts.join("\n")
+}
+
 /// Infer domain keywords from file path clustering.
 ///
 /// Currently benchmark-only (not wired into production paths). Awaiting
@@ -4068,14 +5370,12 @@ pub fn extract_tool_input_keywords_v2_with_options(
                 keywords.extend(extract_words(url));
             }
         }
-        "Task" => {
-            // R3: Task tool sends natural language prompts. Use the NLP
-            // extractor which applies both the CLI noise filter and the
-            // English stopword filter to prevent retrieval explosion.
-            if task_tool_support {
-                if let Some(prompt) = tool_input.get("prompt").and_then(|v| v.as_str()) {
-                    keywords.extend(extract_words_nlp(prompt));
-                }
+        // R3: Task tool sends natural language prompts. Use the NLP
+        // extractor which applies both the CLI noise filter and the
+        // English stopword filter to prevent retrieval explosion.
+        "Task" if task_tool_support => {
+            if let Some(prompt) = tool_input.get("prompt").and_then(|v| v.as_str()) {
+                keywords.extend(extract_words_nlp(prompt));
             }
         }
         _ => {
@@ -4334,6 +5634,45 @@ mod tests {
 
         let output: StopOutput = serde_json::from_str(&result.unwrap()).unwrap();
         assert_eq!(output.decision, StopDecision::Block);
+        let reason = output.reason.unwrap();
+        assert!(reason.contains(&format!(
+            "explicit-request\n{}\n\nQuality tips",
+            REFLECT_OPTIONAL_FIELDS
+        )));
+    }
+
+    #[test]
+    fn test_stop_block_reason_uses_project_template() {
+        let runner = test_runner();
+        let dir = tempfile::TempDir::new().unwrap();
+        let templates = dir.path().join(".grove").join("templates");
+        std::fs::create_dir_all(&templates).unwrap();
+        std::fs::write(
+            templates.join("stop-block.hbs"),
+            "{{#if gate.blocking}}Gate {{gate.status}}: run grove reflect --session-id {{session_id}}{{/if}}",
+        )
+        .unwrap();
+
+        let input = serde_json::json!({
+            "session_id": "stop-template-test",
+            "transcript_path": "/tmp/transcript.jsonl",
+            "cwd": dir.path(),
+        })
+        .to_string();
+        runner
+            .run_with_input(HookType::SessionStart, &input)
+            .unwrap();
+        let mut session = runner.store.get("stop-template-test").unwrap().unwrap();
+        session.gate.status = GateStatus::Pending;
+        runner.store.put(&session).unwrap();
+
+        let output: StopOutput =
+            serde_json::from_str(&runner.run_with_input(HookType::Stop, &input).unwrap()).unwrap();
+        assert_eq!(output.decision, StopDecision::Block);
+        assert_eq!(
+            output.reason.as_deref(),
+            Some("Gate blocked: run grove reflect --session-id stop-template-test")
+        );
     }
 
     #[test]
@@ -4618,8 +5957,12 @@ mod tests {
         // Should return a block decision
         let output: StopOutput = serde_json::from_str(&result).unwrap();
         assert_eq!(output.decision, StopDecision::Block);
-        assert!(output.reason.is_some());
-        assert!(output.reason.unwrap().contains("reflection required"));
+        let reason = output.reason.unwrap();
+        assert!(reason.contains("reflection required"));
+        assert!(reason.contains(&format!(
+            "explicit-request\n{}\n\nQuality tips",
+            REFLECT_OPTIONAL_FIELDS
+        )));
     }
 
     #[test]
@@ -4791,7 +6134,9 @@ mod tests {
 
         // Create a learnings.md file with one learning
         // Use recent timestamp so it passes category-specific decay (Pitfall: 60d half-life)
-        let learnings_content = r#"# Grove Learnings
+        let created = (chrono::Utc::now() - chrono::Duration::days(7)).format("%Y-%m-%dT%H:%M:%SZ");
+        let learnings_content = format!(
+            r#"# Grove Learnings
 
 ---
 ## cl_test_001
@@ -4802,12 +6147,13 @@ mod tests {
 **Tags:** #testing
 **Session:** test-session
 **Criteria:** Behavior Changing
-**Created:** 2026-03-01T00:00:00Z
+**Created:** {created}
 
 This is a test learning that will be injected and also flagged as corrected.
 
 ---
-"#;
+"#
+        );
         std::fs::write(grove_dir.join("learnings.md"), learnings_content).unwrap();
 
         // Create stats log with surfacing, reference, and correction events.
@@ -5235,11 +6581,13 @@ This is a test learning that will be injected and also flagged as corrected.
             learning_id: "L001".to_string(),
             score: 0.8,
             outcome: InjectionOutcome::Pending, // Not referenced
+            explanation: None,
         });
         session.gate.injected_learnings.push(InjectedLearning {
             learning_id: "L002".to_string(),
             score: 0.7,
             outcome: InjectionOutcome::Pending, // Not referenced
+            explanation: None,
         });
         runner.store.put(&session).unwrap();
 
@@ -5291,11 +6639,13 @@ This is a test learning that will be injected and also flagged as corrected.
             learning_id: "L001".to_string(),
             score: 0.8,
             outcome: InjectionOutcome::Pending, // Not referenced - should be dismissed
+            explanation: None,
         });
         session.gate.injected_learnings.push(InjectedLearning {
             learning_id: "L002".to_string(),
             score: 0.7,
             outcome: InjectionOutcome::Referenced, // Referenced - should NOT be dismissed
+            explanation: None,
         });
         runner.store.put(&session).unwrap();
 
@@ -5354,6 +6704,7 @@ This is a test learning that will be injected and also flagged as corrected.
             learning_id: "L001".to_string(),
             score: 0.8,
             outcome: InjectionOutcome::Pending,
+            explanation: None,
         });
         runner.store.put(&session).unwrap();
 
@@ -5425,11 +6776,13 @@ This is a test learning that will be injected and also flagged as corrected.
             learning_id: "L001".to_string(),
             score: 0.8,
             outcome: InjectionOutcome::Pending, // Not referenced - should be dismissed
+            explanation: None,
         });
         session.gate.injected_learnings.push(InjectedLearning {
             learning_id: "L002".to_string(),
             score: 0.7,
             outcome: InjectionOutcome::Referenced, // Referenced - should NOT be dismissed
+            explanation: None,
         });
         runner.store.put(&session).unwrap();
 
@@ -5497,11 +6850,13 @@ This is a test learning that will be injected and also flagged as corrected.
             learning_id: "L001".to_string(),
             score: 0.8,
             outcome: InjectionOutcome::Pending,
+            explanation: None,
         });
         session.gate.injected_learnings.push(InjectedLearning {
             learning_id: "L002".to_string(),
             score: 0.7,
             outcome: InjectionOutcome::Referenced,
+            explanation: None,
         });
         runner.store.put(&session).unwrap();
 
@@ -5554,11 +6909,13 @@ This is a test learning that will be injected and also flagged as corrected.
             learning_id: "L001".to_string(),
             score: 0.8,
             outcome: InjectionOutcome::Pending,
+            explanation: None,
         });
         session.gate.injected_learnings.push(InjectedLearning {
             learning_id: "L002".to_string(),
             score: 0.7,
             outcome: InjectionOutcome::Referenced,
+            explanation: None,
         });
         runner.store.put(&session).unwrap();
 
@@ -9004,8 +10361,12 @@ Watch for GROVE-42 deployment race condition.
     fn test_rescore_with_tantivy_empty() {
         let results: Vec<crate::backends::SearchResult> = Vec::new();
         let query = SearchQuery::new();
-        let rescored =
-            super::rescore_with_tantivy(results, &query, crate::config::RetrievalProfile::Standard);
+        let rescored = super::rescore_with_tantivy(
+            results,
+            &query,
+            crate::config::RetrievalProfile::Standard,
+            None,
+        );
         assert!(rescored.is_empty());
     }
 
@@ -9034,6 +10395,7 @@ Watch for GROVE-42 deployment race condition.
             results.clone(),
             &query,
             crate::config::RetrievalProfile::Standard,
+            None,
         );
         // Empty query → original results returned unchanged
         assert_eq!(rescored.len(), 1);
@@ -9088,8 +10450,12 @@ Watch for GROVE-42 deployment race condition.
             "tracking".to_string(),
         ];
 
-        let rescored =
-            super::rescore_with_tantivy(results, &query, crate::config::RetrievalProfile::Standard);
+        let rescored = super::rescore_with_tantivy(
+            results,
+            &query,
+            crate::config::RetrievalProfile::Standard,
+            None,
+        );
         assert_eq!(rescored.len(), 2);
 
         // BM25 scores should replace the original 0.5 scores
@@ -9127,21 +10493,79 @@ Watch for GROVE-42 deployment race condition.
         );
     }
 
-    // =========================================================================
-    // Adaptive threshold + dynamic K tests
-    // =========================================================================
+    #[test]
+    #[cfg(feature = "tantivy-search")]
+    fn test_rescore_with_persistent_index_scores_candidates_only() {
+        use crate::backends::SearchResult;
+        use crate::core::{
+            learning::CompoundLearning, Confidence, LearningCategory, LearningScope,
+            WriteGateCriterion,
+        };
 
-    /// Helper to create a CompositeScore with a specific final score.
-    fn make_scored(id: &str, score: f64) -> CompositeScore {
-        use crate::core::learning::{
-            Confidence, LearningCategory, LearningScope, WriteGateCriterion,
+        let learning = |id: &str, summary: &str| {
+            CompoundLearning::new(
+                LearningCategory::Pitfall,
+                summary,
+                "Details for the persistent index test.",
+                LearningScope::Project,
+                Confidence::High,
+                vec![WriteGateCriterion::BehaviorChanging],
+                vec!["tissue".to_string()],
+                "test-session",
+            )
+            .with_id(id)
         };
-        let learning = CompoundLearning::new(
-            LearningCategory::Pattern,
-            format!("Learning {}", id),
-            "detail",
-            LearningScope::Project,
-            Confidence::High,
+        let corpus = vec![
+            learning("cl_best", "Run tissue sync before tissue status"),
+            learning("cl_candidate", "Tissue issues need a priority"),
+            learning("cl_other", "Check the build before committing"),
+        ];
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let index = super::open_project_index(temp.path(), &corpus).unwrap();
+        assert!(crate::search::ProjectIndex::exists(
+            &crate::config::project_index_dir(temp.path())
+        ));
+        assert_eq!(index.index().num_docs(), 3);
+
+        // Only one candidate: the better-matching corpus learning is ignored
+        let results = vec![SearchResult::new(corpus[1].clone(), 0.5)];
+        let mut query = SearchQuery::new();
+        query.keywords = vec!["tissue".to_string()];
+
+        let rescored = super::rescore_with_tantivy(
+            results,
+            &query,
+            crate::config::RetrievalProfile::Standard,
+            Some(index.index()),
+        );
+        assert_eq!(rescored.len(), 1);
+        assert_eq!(rescored[0].learning.id, "cl_candidate");
+        assert!((rescored[0].relevance - 1.0).abs() < f64::EPSILON);
+
+        // A hand edit is picked up on the next open
+        let mut edited = corpus.clone();
+        edited[2].summary = "Tissue tickets close on merge".to_string();
+        let index = super::open_project_index(temp.path(), &edited).unwrap();
+        let hits = index.index().search("merge", 10).unwrap();
+        assert_eq!(hits[0].id, "cl_other");
+    }
+
+    // =========================================================================
+    // Adaptive threshold + dynamic K tests
+    // =========================================================================
+
+    /// Helper to create a CompositeScore with a specific final score.
+    fn make_scored(id: &str, score: f64) -> CompositeScore {
+        use crate::core::learning::{
+            Confidence, LearningCategory, LearningScope, WriteGateCriterion,
+        };
+        let learning = CompoundLearning::new(
+            LearningCategory::Pattern,
+            format!("Learning {}", id),
+            "detail",
+            LearningScope::Project,
+            Confidence::High,
             vec![WriteGateCriterion::BehaviorChanging],
             vec!["test".to_string()],
             "test-session",
@@ -9281,6 +10705,737 @@ Watch for GROVE-42 deployment race condition.
         assert_eq!(result[1].learning.id, "b");
     }
 
+    fn make_diverse_candidates() -> Vec<CompositeScore> {
+        use crate::core::learning::LearningCategory;
+        let mut a = make_scored("a", 0.9);
+        a.learning.summary = "Never hold a mutex guard across an await point".to_string();
+        a.learning.tags = vec!["rust".to_string(), "async".to_string()];
+        let mut b = make_scored("b", 0.85);
+        b.learning.summary = "Do not hold a mutex guard across await".to_string();
+        b.learning.tags = vec!["rust".to_string(), "async".to_string()];
+        let mut c = make_scored("c", 0.6);
+        c.learning.summary = "Prefer small focused commits".to_string();
+        c.learning.tags = vec!["git".to_string()];
+        c.learning.category = LearningCategory::Convention;
+        vec![a, b, c]
+    }
+
+    #[test]
+    fn test_learning_similarity_weights_tags_category_and_terms() {
+        let candidates = make_diverse_candidates();
+        let near_duplicate = learning_similarity(&candidates[0].learning, &candidates[1].learning);
+        let unrelated = learning_similarity(&candidates[0].learning, &candidates[2].learning);
+        assert!(near_duplicate > 0.8, "{near_duplicate}");
+        assert!(unrelated.abs() < f64::EPSILON, "{unrelated}");
+        assert!(
+            (learning_similarity(&candidates[0].learning, &candidates[0].learning) - 1.0).abs()
+                < f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_diversity_skips_near_duplicates() {
+        let embeddings = crate::core::embeddings::EmbeddingCache::default();
+        let result = apply_diversity(make_diverse_candidates(), 0.5, 2, &embeddings);
+        let ids: Vec<&str> = result.iter().map(|cs| cs.learning.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "c"]);
+
+        // lambda = 1.0 keeps the score order
+        let result = apply_diversity(make_diverse_candidates(), 1.0, 2, &embeddings);
+        let ids: Vec<&str> = result.iter().map(|cs| cs.learning.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b"]);
+
+        assert!(apply_diversity(Vec::new(), 0.5, 2, &embeddings).is_empty());
+    }
+
+    #[test]
+    fn test_diversity_prefers_embeddings_and_records_similarity() {
+        let mut candidates = make_diverse_candidates();
+        for cs in &mut candidates {
+            cs.explanation = Some(InjectionExplanation::default());
+        }
+        // Embeddings disagree with the lexical overlap: b is unrelated to a, c is identical
+        let mut embeddings = crate::core::embeddings::EmbeddingCache::default();
+        for (cs, vector) in candidates
+            .iter()
+            .zip([vec![1.0, 0.0], vec![0.0, 1.0], vec![1.0, 0.0]])
+        {
+            embeddings.insert(&cs.learning, vector);
+        }
+
+        let result = apply_diversity(candidates, 0.5, 3, &embeddings);
+        let ids: Vec<&str> = result.iter().map(|cs| cs.learning.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b", "c"]);
+        let similarities: Vec<f64> = result
+            .iter()
+            .map(|cs| {
+                cs.explanation
+                    .as_ref()
+                    .unwrap()
+                    .diversity_similarity
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(similarities, vec![0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_apply_relations_drops_superseded() {
+        use crate::core::learning::LearningRelation;
+        let mut newer = make_scored("b", 0.9);
+        newer.learning.relations = vec![LearningRelation::new(RelationKind::Supersedes, "a")];
+        let scored = vec![newer, make_scored("a", 0.8), make_scored("c", 0.7)];
+
+        let result = apply_relations(scored, &[], 10);
+        let ids: Vec<&str> = result.iter().map(|cs| cs.learning.id.as_str()).collect();
+        assert_eq!(ids, vec!["b", "c"]);
+    }
+
+    #[test]
+    fn test_apply_relations_pulls_in_dependencies() {
+        use crate::core::learning::LearningRelation;
+        let mut dependent = make_scored("a", 0.9);
+        dependent.learning.relations = vec![
+            LearningRelation::new(RelationKind::DependsOn, "base"),
+            LearningRelation::new(RelationKind::DependsOn, "c"),
+            LearningRelation::new(RelationKind::DependsOn, "missing"),
+        ];
+        dependent.explanation = Some(InjectionExplanation {
+            relevance: 0.9,
+            score: 0.9,
+            rank: 1,
+            ..Default::default()
+        });
+        let corpus = vec![
+            crate::backends::SearchResult::new(make_scored("base", 0.1).learning, 0.0)
+                .with_source(LearningSource::Org),
+            crate::backends::SearchResult::new(make_scored("c", 0.1).learning, 0.0),
+        ];
+        let scored = vec![dependent, make_scored("c", 0.7)];
+
+        let result = apply_relations(scored.clone(), &corpus, 10);
+        let ids: Vec<&str> = result.iter().map(|cs| cs.learning.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "base", "c"]);
+        assert_eq!(result[1].score, 0.0);
+        assert_eq!(result[1].source, LearningSource::Org);
+        let explanation = result[1].explanation.as_ref().unwrap();
+        assert_eq!(explanation.dependency_of.as_deref(), Some("a"));
+        assert_eq!(explanation.score, 0.0);
+        assert_eq!(explanation.rank, 0);
+
+        // Dependencies count against the cap
+        let result = apply_relations(scored.clone(), &corpus, 2);
+        let ids: Vec<&str> = result.iter().map(|cs| cs.learning.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "base"]);
+        // "a" and "base" don't fit in one slot, so "a" is left out with its
+        // dependency
+        let result = apply_relations(scored, &corpus, 1);
+        let ids: Vec<&str> = result.iter().map(|cs| cs.learning.id.as_str()).collect();
+        assert_eq!(ids, vec!["c"]);
+    }
+
+    #[test]
+    fn test_resolve_relations_skips_expired_and_inapplicable_dependencies() {
+        use crate::backends::MarkdownBackend;
+        use crate::core::learning::LearningRelation;
+        use crate::core::AppliesTo;
+        let dir = tempfile::TempDir::new().unwrap();
+        let backend = MarkdownBackend::new(dir.path().join(".grove").join("learnings.md"));
+        let expired = make_scored("cl_20260101_001", 0.1)
+            .learning
+            .with_expires_at(chrono::Utc::now() - chrono::Duration::days(1));
+        let scoped = make_scored("cl_20260101_002", 0.1)
+            .learning
+            .with_applies_to(AppliesTo {
+                paths: vec!["migrations/**".to_string()],
+                ..Default::default()
+            });
+        let base = make_scored("cl_20260101_003", 0.1).learning;
+        for learning in [&expired, &scoped, &base] {
+            backend.write(learning).unwrap();
+        }
+
+        let mut dependent = make_scored("cl_20260101_004", 0.9);
+        dependent.learning.relations = [&expired, &scoped, &base]
+            .iter()
+            .map(|l| LearningRelation::new(RelationKind::DependsOn, &l.id))
+            .collect();
+        let applicability = ApplicabilityContext::new().files(vec!["src/lib.rs".to_string()]);
+
+        let result = resolve_relations(&backend, vec![dependent], &applicability, 5);
+        let ids: Vec<&str> = result.iter().map(|cs| cs.learning.id.as_str()).collect();
+        assert_eq!(ids, vec!["cl_20260101_004", "cl_20260101_003"]);
+    }
+
+    #[test]
+    fn test_tool_applicability_context() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join(".grove")).unwrap();
+        let file = dir.path().join("migrations").join("001.sql");
+
+        let context = tool_applicability_context(
+            dir.path(),
+            &["src/lib.rs".to_string()],
+            "Edit",
+            &serde_json::json!({"file_path": file.to_string_lossy()}),
+        );
+        assert_eq!(context.files, vec!["src/lib.rs", "migrations/001.sql"]);
+        assert_eq!(context.tools, vec!["Edit"]);
+
+        let context = tool_applicability_context(
+            dir.path(),
+            &[],
+            "Bash",
+            &serde_json::json!({"command": "TF_LOG=debug /usr/bin/terraform plan"}),
+        );
+        assert_eq!(context.tools, vec!["Bash", "terraform"]);
+    }
+
+    #[test]
+    fn test_pre_tool_use_injects_learnings_for_edited_file_once() {
+        use crate::backends::MarkdownBackend;
+        let dir = tempfile::TempDir::new().unwrap();
+        let backend = MarkdownBackend::new(dir.path().join(".grove").join("learnings.md"));
+        let mut pool = make_scored("cl_20260101_001", 0.9).learning;
+        pool.summary = "Pool checkouts must be returned before await".to_string();
+        let pool = pool.with_context_files(vec!["src/db/pool.rs".to_string()]);
+        let mut db = make_scored("cl_20260101_002", 0.9).learning;
+        db.summary = "Queries in src/db go through the query builder".to_string();
+        let db = db.with_context_files(vec!["src/db/".to_string()]);
+        let mut other = make_scored("cl_20260101_003", 0.9).learning;
+        other.summary = "Unrelated learning about the CLI".to_string();
+        let other = other.with_context_files(vec!["src/cli.rs".to_string()]);
+        for learning in [&pool, &db, &other] {
+            backend.write(learning).unwrap();
+        }
+
+        let runner = test_runner();
+        let mut session = SessionState::new("file-trigger", dir.path().to_string_lossy(), "");
+        session.add_trace(EventType::SessionStart, None);
+        runner.store.put(&session).unwrap();
+
+        let edit = |file: &str| -> PreToolUseOutput {
+            let input = serde_json::json!({
+                "session_id": "file-trigger",
+                "transcript_path": "/tmp/transcript.jsonl",
+                "cwd": dir.path(),
+                "tool_name": "Edit",
+                "tool_input": {
+                    "file_path": dir.path().join(file),
+                    "old_string": "a",
+                    "new_string": "b",
+                },
+            });
+            let output = runner
+                .run_with_input(HookType::PreToolUse, &input.to_string())
+                .unwrap();
+            serde_json::from_str(&output).unwrap()
+        };
+        let context = |output: &PreToolUseOutput| {
+            output
+                .hook_specific_output
+                .as_ref()
+                .and_then(|o| o.additional_context.clone())
+        };
+
+        // Deep into the session: the file's and its directory's learnings
+        let text = context(&edit("src/db/pool.rs")).expect("file-triggered context");
+        assert!(text.contains("[cl_20260101_001]"));
+        assert!(text.contains("[cl_20260101_002]"));
+        assert!(!text.contains("cl_20260101_003"));
+
+        // Each learning at most once per session
+        assert!(context(&edit("src/db/schema.rs")).is_none());
+        assert!(context(&edit("README.md")).is_none());
+
+        let session = runner.store.get("file-trigger").unwrap().unwrap();
+        let injected: Vec<_> = session
+            .gate
+            .injected_learnings
+            .iter()
+            .map(|il| il.learning_id.as_str())
+            .collect();
+        assert_eq!(injected.len(), 2);
+        let explanation = session.gate.injected_learnings[0]
+            .explanation
+            .as_ref()
+            .unwrap();
+        assert_eq!(explanation.trigger_file.as_deref(), Some("src/db/pool.rs"));
+        assert!(session
+            .trace
+            .iter()
+            .any(|t| t.event_type == EventType::FileInjection));
+
+        let events = StatsLogger::new(project_stats_log_path(dir.path()))
+            .read_all()
+            .unwrap();
+        let surfaced = events
+            .iter()
+            .filter(|e| e.data.event_name() == "surfaced")
+            .count();
+        assert_eq!(surfaced, 2);
+    }
+
+    #[test]
+    fn test_file_triggers_use_persisted_index_until_corpus_changes() {
+        use crate::backends::MarkdownBackend;
+        let dir = tempfile::TempDir::new().unwrap();
+        let backend = MarkdownBackend::new(dir.path().join(".grove").join("learnings.md"));
+        let mut pool = make_scored("cl_20260101_001", 0.9).learning;
+        pool.summary = "Pool checkouts must be returned before await".to_string();
+        backend
+            .write(&pool.with_context_files(vec!["src/db/pool.rs".to_string()]))
+            .unwrap();
+
+        let runner = test_runner();
+        let mut session = SessionState::new("trigger-index", dir.path().to_string_lossy(), "");
+        session.add_trace(EventType::SessionStart, None);
+        runner.store.put(&session).unwrap();
+        let edit = |file: &str| -> Option<String> {
+            let input = serde_json::json!({
+                "session_id": "trigger-index",
+                "transcript_path": "/tmp/transcript.jsonl",
+                "cwd": dir.path(),
+                "tool_name": "Write",
+                "tool_input": { "file_path": file, "content": "" },
+            });
+            let output = runner
+                .run_with_input(HookType::PreToolUse, &input.to_string())
+                .unwrap();
+            serde_json::from_str::<PreToolUseOutput>(&output)
+                .unwrap()
+                .hook_specific_output
+                .and_then(|o| o.additional_context)
+        };
+
+        // The first lookup builds and persists the index
+        assert!(edit("src/main.rs").is_none());
+        let index_path = crate::config::project_trigger_index_path(dir.path());
+        let persisted = std::fs::read_to_string(&index_path).unwrap();
+        assert!(persisted.contains("cl_20260101_001"));
+
+        // A learning added to the store outside Grove invalidates it
+        let mut api = make_scored("cl_20260101_002", 0.9).learning;
+        api.summary = "API handlers validate input with the shared extractor".to_string();
+        backend
+            .write(&api.with_context_files(vec!["src/api/".to_string()]))
+            .unwrap();
+        let text = edit("src/api/users.rs").expect("rebuilt index finds new learning");
+        assert!(text.contains("[cl_20260101_002]"));
+
+        // Writes through Grove drop the index
+        let mut cli = make_scored("cl_20260101_003", 0.9).learning;
+        cli.summary = "CLI flags are documented in the README".to_string();
+        create_primary_backend(dir.path(), Some(&runner.config))
+            .write(&cli)
+            .unwrap();
+        assert!(!index_path.exists());
+    }
+
+    #[test]
+    fn test_pre_tool_use_command_guards_warn_and_block() {
+        use crate::backends::MarkdownBackend;
+        use crate::core::{CommandGuard, GuardSeverity};
+        let dir = tempfile::TempDir::new().unwrap();
+        let backend = MarkdownBackend::new(dir.path().join(".grove").join("learnings.md"));
+        let mut migrate = make_scored("cl_20260101_001", 0.9).learning;
+        migrate.summary = "Run migrations against the staging database first".to_string();
+        let migrate = migrate.with_guard(CommandGuard {
+            commands: vec!["diesel migration run".to_string()],
+            severity: GuardSeverity::Warn,
+        });
+        let mut publish = make_scored("cl_20260101_002", 0.9).learning;
+        publish.summary = "Publishing needs the release checklist done first".to_string();
+        let publish = publish.with_guard(CommandGuard {
+            commands: vec!["/cargo publish( |$)/".to_string()],
+            severity: GuardSeverity::Block,
+        });
+        for learning in [&migrate, &publish] {
+            backend.write(learning).unwrap();
+        }
+
+        let runner = test_runner();
+        let mut session = SessionState::new("guards", dir.path().to_string_lossy(), "");
+        session.add_trace(EventType::SessionStart, None);
+        runner.store.put(&session).unwrap();
+
+        let bash = |command: &str| -> PreToolUseOutput {
+            let input = serde_json::json!({
+                "session_id": "guards",
+                "transcript_path": "/tmp/transcript.jsonl",
+                "cwd": dir.path(),
+                "tool_name": "Bash",
+                "tool_input": { "command": command },
+            });
+            let output = runner
+                .run_with_input(HookType::PreToolUse, &input.to_string())
+                .unwrap();
+            serde_json::from_str(&output).unwrap()
+        };
+
+        let output = bash("cd db && diesel migration run");
+        assert!(output.is_allowed());
+        let context = output
+            .hook_specific_output
+            .as_ref()
+            .and_then(|o| o.additional_context.clone())
+            .expect("guard context");
+        assert!(context.contains("## Command Guard"));
+        assert!(context.contains("[cl_20260101_001]"));
+        assert!(!context.contains("cl_20260101_002"));
+
+        // Warnings repeat for every matching command
+        assert!(bash("diesel migration run").is_allowed());
+
+        let output = bash("cargo publish --allow-dirty");
+        assert!(!output.is_allowed());
+        let reason = output
+            .hook_specific_output
+            .and_then(|o| o.permission_decision_reason)
+            .unwrap();
+        assert!(reason.contains("Publishing needs the release checklist"));
+        assert!(reason.contains("grove edit cl_20260101_002"));
+
+        let output = bash("cargo test");
+        assert!(output
+            .hook_specific_output
+            .and_then(|o| o.additional_context)
+            .is_none());
+
+        let session = runner.store.get("guards").unwrap().unwrap();
+        assert_eq!(session.gate.injected_learnings.len(), 2);
+        let explanation = session.gate.injected_learnings[0]
+            .explanation
+            .as_ref()
+            .unwrap();
+        assert_eq!(
+            explanation.trigger_command.as_deref(),
+            Some("cd db && diesel migration run")
+        );
+        let guard_traces = session
+            .trace
+            .iter()
+            .filter(|t| t.event_type == EventType::CommandGuard)
+            .count();
+        assert_eq!(guard_traces, 3);
+
+        let events = StatsLogger::new(project_stats_log_path(dir.path()))
+            .read_all()
+            .unwrap();
+        let count = |name: &str| {
+            events
+                .iter()
+                .filter(|e| e.data.event_name() == name)
+                .count()
+        };
+        assert_eq!(count("guard_fired"), 3);
+        assert_eq!(count("surfaced"), 2);
+
+        // Guards are read from the persisted trigger index, which picks up
+        // new guards once the store changes
+        assert!(crate::config::project_trigger_index_path(dir.path()).exists());
+        let mut tests = make_scored("cl_20260101_003", 0.9).learning;
+        tests.summary = "Run the test suite with the integration feature".to_string();
+        let tests = tests.with_guard(CommandGuard {
+            commands: vec!["cargo test".to_string()],
+            severity: GuardSeverity::Warn,
+        });
+        backend.write(&tests).unwrap();
+        let context = bash("cargo test")
+            .hook_specific_output
+            .and_then(|o| o.additional_context)
+            .expect("new guard context");
+        assert!(context.contains("[cl_20260101_003]"));
+    }
+
+    #[test]
+    fn test_command_guards_skip_when_no_learning_has_a_guard() {
+        use crate::backends::MarkdownBackend;
+        let dir = tempfile::TempDir::new().unwrap();
+        let backend = MarkdownBackend::new(dir.path().join(".grove").join("learnings.md"));
+        backend
+            .write(&make_scored("cl_20260101_001", 0.9).learning)
+            .unwrap();
+
+        let runner = test_runner();
+        let input: PreToolUseInput = serde_json::from_value(serde_json::json!({
+            "session_id": "no-guards",
+            "transcript_path": "/tmp/transcript.jsonl",
+            "cwd": dir.path(),
+            "tool_name": "Bash",
+            "tool_input": { "command": "cargo publish" },
+        }))
+        .unwrap();
+        let mut session = SessionState::new("no-guards", dir.path().to_string_lossy(), "");
+        assert!(runner
+            .check_command_guards(&input, "cargo publish", &mut session)
+            .is_none());
+        assert!(session.trace.is_empty());
+        assert!(!project_stats_log_path(dir.path()).exists());
+    }
+
+    #[test]
+    fn test_retrieve_filters_inapplicable_learnings() {
+        use crate::backends::MarkdownBackend;
+        use crate::core::AppliesTo;
+        let dir = tempfile::TempDir::new().unwrap();
+        let backend = MarkdownBackend::new(dir.path().join(".grove").join("learnings.md"));
+        let mut scoped = make_scored("cl_20260101_001", 0.9).learning;
+        scoped.summary = "Never edit applied database migrations".to_string();
+        let scoped = scoped.with_applies_to(AppliesTo {
+            paths: vec!["migrations/**".to_string()],
+            ..Default::default()
+        });
+        let mut general = make_scored("cl_20260101_002", 0.9).learning;
+        general.summary = "Back up the database before schema changes".to_string();
+        backend.write(&scoped).unwrap();
+        backend.write(&general).unwrap();
+
+        let mut config = Config::default();
+        config.retrieval.scoring_backend = "keyword".to_string();
+        let runner = HookRunner::new(crate::storage::MemorySessionStore::new(), config);
+        let session = SessionState::new("applies-to", dir.path().to_string_lossy(), "");
+        let query = SearchQuery::new().keywords(vec!["database".to_string()]);
+
+        let ids = |context: &ApplicabilityContext| -> Vec<String> {
+            runner
+                .retrieve_and_score_learnings(dir.path(), &session, &query, context, None)
+                .into_iter()
+                .map(|cs| cs.learning.id)
+                .collect()
+        };
+        assert_eq!(ids(&ApplicabilityContext::new()), vec!["cl_20260101_002"]);
+
+        let in_migrations =
+            ApplicabilityContext::new().files(vec!["migrations/002_users.sql".to_string()]);
+        let found = ids(&in_migrations);
+        assert!(found.contains(&"cl_20260101_001".to_string()));
+        assert!(found.contains(&"cl_20260101_002".to_string()));
+    }
+
+    #[test]
+    fn test_retrieve_excludes_expired_learnings() {
+        use crate::backends::MarkdownBackend;
+        let dir = tempfile::TempDir::new().unwrap();
+        let backend = MarkdownBackend::new(dir.path().join(".grove").join("learnings.md"));
+        let now = chrono::Utc::now();
+        let mut expired = make_scored("cl_20260101_001", 0.9).learning;
+        expired.summary = "Use the legacy database proxy for reports".to_string();
+        let expired = expired.with_expires_at(now - chrono::Duration::days(1));
+        let mut current = make_scored("cl_20260101_002", 0.9).learning;
+        current.summary = "Back up the database before schema changes".to_string();
+        let current = current.with_expires_at(now + chrono::Duration::days(30));
+        backend.write(&expired).unwrap();
+        backend.write(&current).unwrap();
+
+        let mut config = Config::default();
+        config.retrieval.scoring_backend = "keyword".to_string();
+        let runner = HookRunner::new(crate::storage::MemorySessionStore::new(), config);
+        let session = SessionState::new("expiry", dir.path().to_string_lossy(), "");
+        let query = SearchQuery::new().keywords(vec!["database".to_string()]);
+
+        let ids: Vec<String> = runner
+            .retrieve_and_score_learnings(
+                dir.path(),
+                &session,
+                &query,
+                &ApplicabilityContext::new(),
+                None,
+            )
+            .into_iter()
+            .map(|cs| cs.learning.id)
+            .collect();
+        assert_eq!(ids, vec!["cl_20260101_002"]);
+    }
+
+    #[test]
+    fn test_injection_context_includes_examples_within_budget() {
+        use crate::core::{ExampleKind, LearningExample};
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut first = make_scored("cl_20260101_001", 0.9);
+        first.learning = first.learning.with_examples(vec![LearningExample::new(
+            ExampleKind::Command,
+            "cargo test --workspace",
+        )
+        .with_language("sh")
+        .with_caption("Run the full suite")]);
+        let mut second = make_scored("cl_20260101_002", 0.8);
+        second.learning = second.learning.with_examples(vec![LearningExample::new(
+            ExampleKind::Bad,
+            "x".repeat(400),
+        )]);
+
+        let mut config = Config::default();
+        config.retrieval.example_token_budget = 50;
+        let runner = HookRunner::new(crate::storage::MemorySessionStore::new(), config);
+        let mut session = SessionState::new("examples", dir.path().to_string_lossy(), "");
+
+        let context = runner
+            .build_injection_context(dir.path(), &mut session, &[first, second], false)
+            .unwrap()
+            .text;
+        assert!(context.contains(
+            "**Example (command):** Run the full suite\n```sh\ncargo test --workspace\n```"
+        ));
+        assert!(!context.contains("**Example (bad):**"));
+
+        // A zero budget leaves examples out entirely
+        let mut config = Config::default();
+        config.retrieval.example_token_budget = 0;
+        let runner = HookRunner::new(crate::storage::MemorySessionStore::new(), config);
+        let mut session = SessionState::new("no-examples", dir.path().to_string_lossy(), "");
+        let context = runner
+            .build_injection_context(
+                dir.path(),
+                &mut session,
+                &[make_scored("cl_20260101_003", 0.9)],
+                false,
+            )
+            .unwrap()
+            .text;
+        assert!(!context.contains("**Example"));
+    }
+
+    #[test]
+    fn test_injection_template_renders_learning_vars_with_fallback() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut first = make_scored("cl_20260101_001", 0.9);
+        first.learning.tags = vec!["locks".to_string(), "async".to_string()];
+        let second = make_scored("cl_20260101_002", 0.8);
+
+        let runner = HookRunner::new(crate::storage::MemorySessionStore::new(), Config::default());
+        let mut session = SessionState::new("template", dir.path().to_string_lossy(), "");
+        let context = runner
+            .build_injection_context(dir.path(), &mut session, &[first, second], true)
+            .unwrap();
+        let builtin = context.text.clone();
+
+        let templates = dir.path().join(".grove").join("templates");
+        std::fs::create_dir_all(&templates).unwrap();
+        std::fs::write(
+            templates.join("pre-tool-use.hbs"),
+            "Before {{tool_name}}:\n{{#each learnings}}\n- [{{id}}] {{summary}} ({{score}}; {{#each tags}}{{this}}{{#unless @last}}, {{/unless}}{{/each}})\n{{/each}}\n",
+        )
+        .unwrap();
+        let rendered = render_injection_template(
+            dir.path(),
+            TemplateKind::PreToolUse,
+            &session,
+            context,
+            serde_json::json!({ "tool_name": "Edit" }),
+        );
+        assert_eq!(
+            rendered,
+            "Before Edit:\n\
+             - [cl_20260101_001] Learning cl_20260101_001 (0.9; locks, async)\n\
+             - [cl_20260101_002] Learning cl_20260101_002 (0.8; test)\n"
+        );
+
+        // A template that fails to render falls back to the built-in text
+        std::fs::write(templates.join("pre-tool-use.hbs"), "{{#each learnings}}").unwrap();
+        let context = runner
+            .build_injection_context(
+                dir.path(),
+                &mut session,
+                &[make_scored("cl_20260101_003", 0.7)],
+                true,
+            )
+            .unwrap();
+        let builtin_second = context.text.clone();
+        let rendered = render_injection_template(
+            dir.path(),
+            TemplateKind::PreToolUse,
+            &session,
+            context,
+            serde_json::json!({}),
+        );
+        assert_eq!(rendered, builtin_second);
+        assert!(builtin.contains("## Relevant Learnings"));
+    }
+
+    #[test]
+    fn test_render_learning_block_trims_in_stages() {
+        let header = "\n### Pattern [cl_1]\n";
+        let summary = "Hold no locks across await";
+        let detail = "word ".repeat(100);
+
+        let full = render_learning_block(header, summary, &detail, None).unwrap();
+        assert!(!full.trimmed);
+        assert!(full.text.ends_with(&format!("{}\n", detail)));
+
+        // Enough room for part of the detail: cut between words
+        let truncated = render_learning_block(header, summary, &detail, Some(60)).unwrap();
+        assert!(truncated.trimmed);
+        assert!(truncated.tokens <= 60);
+        assert!(truncated
+            .text
+            .contains("**Hold no locks across await**\nword"));
+        assert!(truncated.text.ends_with("word…\n"));
+
+        // Too little room for a useful excerpt: summary only
+        let summary_only = render_learning_block(header, summary, &detail, Some(20)).unwrap();
+        assert!(summary_only.trimmed);
+        assert_eq!(
+            summary_only.text,
+            "\n### Pattern [cl_1]\n**Hold no locks across await**\n"
+        );
+
+        assert!(render_learning_block(header, summary, &detail, Some(5)).is_none());
+    }
+
+    #[test]
+    fn test_injection_context_fills_token_budget_in_rank_order() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut first = make_scored("cl_20260101_001", 0.9);
+        first.learning.detail = "first ".repeat(100);
+        let mut second = make_scored("cl_20260101_002", 0.8);
+        second.learning.detail = "second ".repeat(100);
+        let mut third = make_scored("cl_20260101_003", 0.7);
+        third.learning.detail = "third ".repeat(100);
+
+        let mut config = Config::default();
+        config.retrieval.token_budget = 220;
+        let runner = HookRunner::new(crate::storage::MemorySessionStore::new(), config);
+        let mut session = SessionState::new("budget", dir.path().to_string_lossy(), "");
+
+        let context = runner
+            .build_injection_context(dir.path(), &mut session, &[first, second, third], false)
+            .unwrap()
+            .text;
+        assert!(context.contains(&format!("{}\n", "first ".repeat(100))));
+        assert!(context.contains("second second"));
+        assert!(context.contains("…"));
+        assert!(!context.contains("third"));
+
+        let injected: Vec<&str> = session
+            .gate
+            .injected_learnings
+            .iter()
+            .map(|il| il.learning_id.as_str())
+            .collect();
+        assert_eq!(injected, vec!["cl_20260101_001", "cl_20260101_002"]);
+        assert!(session.gate.injected_tokens > 200 && session.gate.injected_tokens <= 220);
+
+        let events = StatsLogger::new(project_stats_log_path(dir.path()))
+            .read_all()
+            .unwrap();
+        let injected_event = events
+            .iter()
+            .rev()
+            .find(|e| matches!(&e.data, crate::stats::StatsEventType::Injected { session_id, .. } if session_id == "budget"))
+            .unwrap();
+        assert_eq!(
+            injected_event.data,
+            crate::stats::StatsEventType::injected(
+                "budget",
+                session.gate.injected_tokens,
+                220,
+                2,
+                1
+            )
+        );
+    }
+
     // --- adaptive_dk_ratio tests ---
 
     #[test]
@@ -9947,6 +12102,7 @@ Watch for GROVE-42 deployment race condition.
             dir.path(),
             &session,
             &query,
+            &ApplicabilityContext::new(),
             Some(&transcript_path),
         );
         // The learning matched the query keyword "database", so it should be returned
@@ -10002,6 +12158,7 @@ behavior_changing: true\nstatus: active\n---\n";
             dir.path(),
             &session,
             &query,
+            &ApplicabilityContext::new(),
             Some(&transcript_path),
         );
 
@@ -10038,7 +12195,13 @@ behavior_changing: true\nstatus: active\n---\n";
         let session = SessionState::new("no-transcript", dir.path().to_string_lossy(), "");
 
         // Pass None for transcript_path — should not filter
-        let results = runner.retrieve_and_score_learnings(dir.path(), &session, &query, None);
+        let results = runner.retrieve_and_score_learnings(
+            dir.path(),
+            &session,
+            &query,
+            &ApplicabilityContext::new(),
+            None,
+        );
         // Should complete without error; no filtering applied
         let _ = results;
     }
@@ -10075,6 +12238,7 @@ behavior_changing: true\nstatus: active\n---\n";
             dir.path(),
             &session,
             &query,
+            &ApplicabilityContext::new(),
             Some(&transcript_path),
         );
         // Should complete without error; no filtering applied
@@ -10115,6 +12279,7 @@ behavior_changing: true\nstatus: active\n---\n";
             dir.path(),
             &session,
             &query,
+            &ApplicabilityContext::new(),
             Some(&transcript_path),
         );
 
@@ -10477,7 +12642,13 @@ behavior_changing: true\nstatus: active\n---\n";
         // backend.search(empty, active_only) call for corpus size.
         // If corpus_size computation regresses to results.len(), the
         // profile would incorrectly be SmallCorpus instead of Standard.
-        let results = runner.retrieve_and_score_learnings(dir.path(), &session, &query, None);
+        let results = runner.retrieve_and_score_learnings(
+            dir.path(),
+            &session,
+            &query,
+            &ApplicabilityContext::new(),
+            None,
+        );
 
         // Verify the function completes and returns the matching learnings
         // (exact count depends on BM25 scoring, but should be <= 55)
@@ -10519,10 +12690,186 @@ behavior_changing: true\nstatus: active\n---\n";
         let session = SessionState::new("small-corpus-test", dir.path().to_string_lossy(), "");
 
         // Exercises SmallCorpus (boosted) BM25 path
-        let results = runner.retrieve_and_score_learnings(dir.path(), &session, &query, None);
+        let results = runner.retrieve_and_score_learnings(
+            dir.path(),
+            &session,
+            &query,
+            &ApplicabilityContext::new(),
+            None,
+        );
         assert!(results.len() <= 10);
     }
 
+    /// Without an embedding provider (fastembed needs the `semantic-dedup`
+    /// feature), `scoring_backend = "hybrid"` scores the same candidates as bm25.
+    #[test]
+    #[cfg(all(feature = "tantivy-search", not(feature = "semantic-dedup")))]
+    fn hybrid_without_embeddings_falls_back_to_bm25() {
+        use crate::backends::MarkdownBackend;
+        let dir = tempfile::TempDir::new().unwrap();
+        let backend = MarkdownBackend::new(dir.path().join(".grove").join("learnings.md"));
+        let mut indexing = make_scored("cl_20260101_001", 0.9).learning;
+        indexing.summary = "Add database indexes on frequently queried columns".to_string();
+        let mut commits = make_scored("cl_20260101_002", 0.9).learning;
+        commits.summary = "Prefer small commits".to_string();
+        backend.write(&indexing).unwrap();
+        backend.write(&commits).unwrap();
+
+        let query =
+            SearchQuery::new().keywords(vec!["database".to_string(), "indexing".to_string()]);
+        let session = SessionState::new("hybrid-test", dir.path().to_string_lossy(), "");
+        let ids = |backend: &str| {
+            let mut config = Config::default();
+            config.retrieval.scoring_backend = backend.to_string();
+            let runner = HookRunner::new(crate::storage::MemorySessionStore::new(), config);
+            runner
+                .retrieve_and_score_learnings(
+                    dir.path(),
+                    &session,
+                    &query,
+                    &ApplicabilityContext::new(),
+                    None,
+                )
+                .into_iter()
+                .map(|s| s.learning.id)
+                .collect::<Vec<_>>()
+        };
+
+        let hybrid = ids("hybrid");
+        assert!(hybrid.contains(&"cl_20260101_001".to_string()));
+        assert_eq!(hybrid, ids("bm25"));
+    }
+
+    #[test]
+    fn injected_learnings_record_score_explanation() {
+        use crate::backends::MarkdownBackend;
+        let dir = tempfile::TempDir::new().unwrap();
+        let backend = MarkdownBackend::new(dir.path().join(".grove").join("learnings.md"));
+        let mut indexing = make_scored("cl_20260101_001", 0.9).learning;
+        indexing.summary = "Add database indexes on frequently queried columns".to_string();
+        indexing.tags = vec!["database".to_string()];
+        backend.write(&indexing).unwrap();
+
+        let runner = HookRunner::new(crate::storage::MemorySessionStore::new(), Config::default());
+        let query = SearchQuery::new()
+            .tags(vec!["database".to_string()])
+            .keywords(vec!["database".to_string()]);
+        let mut session = SessionState::new("explain-test", dir.path().to_string_lossy(), "");
+        let scored = runner.retrieve_and_score_learnings(
+            dir.path(),
+            &session,
+            &query,
+            &ApplicabilityContext::new(),
+            None,
+        );
+        assert_eq!(scored.len(), 1);
+
+        runner
+            .build_injection_context(dir.path(), &mut session, &scored, false)
+            .unwrap();
+        let explanation = session.gate.injected_learnings[0]
+            .explanation
+            .clone()
+            .expect("explanation recorded");
+        let config = Config::default();
+        assert!(explanation.keywords.contains(&"database".to_string()));
+        assert_eq!(explanation.tags, vec!["database"]);
+        assert_eq!(explanation.strategy, config.retrieval.strategy);
+        assert_eq!(explanation.score, scored[0].score);
+        assert!(explanation.relevance >= explanation.min_relevance);
+        assert!(!explanation.recent_without_match);
+        assert_eq!((explanation.rank, explanation.candidates), (1, 1));
+        assert_eq!(
+            explanation.dynamic_k_ratio,
+            config.retrieval.dynamic_k_ratio
+        );
+        assert!(explanation.score >= explanation.dynamic_k_cutoff);
+        assert!(explanation.rerank_score.is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "tantivy-search")]
+    fn hybrid_with_hashing_provider_caches_embeddings_by_model() {
+        use crate::backends::MarkdownBackend;
+        use crate::core::embeddings::EmbeddingCache;
+        let dir = tempfile::TempDir::new().unwrap();
+        let grove_dir = dir.path().join(".grove");
+        let backend = MarkdownBackend::new(grove_dir.join("learnings.md"));
+        let mut retries = make_scored("cl_20260101_001", 0.9).learning;
+        retries.summary = "Retry network requests with exponential backoff".to_string();
+        let mut commits = make_scored("cl_20260101_002", 0.9).learning;
+        commits.summary = "Prefer small commits".to_string();
+        backend.write(&retries).unwrap();
+        backend.write(&commits).unwrap();
+
+        let mut config = Config::default();
+        config.retrieval.scoring_backend = "hybrid".to_string();
+        config.gate.semantic_dedup.provider = "hashing".to_string();
+        let runner = HookRunner::new(crate::storage::MemorySessionStore::new(), config);
+        let session = SessionState::new("hybrid-hashing", dir.path().to_string_lossy(), "");
+        let query = SearchQuery::new().keywords(vec!["network".to_string(), "retry".to_string()]);
+
+        let ids: Vec<String> = runner
+            .retrieve_and_score_learnings(
+                dir.path(),
+                &session,
+                &query,
+                &ApplicabilityContext::new(),
+                None,
+            )
+            .into_iter()
+            .map(|cs| cs.learning.id)
+            .collect();
+        assert_eq!(ids.first().map(String::as_str), Some("cl_20260101_001"));
+
+        let cache = EmbeddingCache::load(&grove_dir, "hashing:256");
+        assert_eq!(cache.entries.len(), 2, "every candidate embedded once");
+        assert!(
+            EmbeddingCache::load(&grove_dir, "fastembed:all-MiniLM-L6-v2")
+                .entries
+                .is_empty()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "tantivy-search")]
+    fn hybrid_keeps_org_source_of_candidates() {
+        use crate::backends::MarkdownBackend;
+        let dir = tempfile::TempDir::new().unwrap();
+        let grove_dir = dir.path().join(".grove");
+        let mut project = make_scored("cl_20260101_001", 0.9).learning;
+        project.summary = "Retry network requests with exponential backoff".to_string();
+        MarkdownBackend::new(grove_dir.join("learnings.md"))
+            .write(&project)
+            .unwrap();
+        let org_path = dir.path().join("org.md");
+        let mut org = make_scored("cl_20260101_900", 0.9).learning;
+        org.summary = "Retry network calls through the shared client".to_string();
+        MarkdownBackend::new(&org_path).write(&org).unwrap();
+
+        let mut config = Config::default();
+        config.retrieval.scoring_backend = "hybrid".to_string();
+        config.gate.semantic_dedup.provider = "hashing".to_string();
+        config.backends.org.path = org_path.to_string_lossy().to_string();
+        let runner = HookRunner::new(crate::storage::MemorySessionStore::new(), config);
+        let session = SessionState::new("hybrid-org", dir.path().to_string_lossy(), "");
+        let query = SearchQuery::new().keywords(vec!["network".to_string(), "retry".to_string()]);
+
+        let sources: Vec<(String, LearningSource)> = runner
+            .retrieve_and_score_learnings(
+                dir.path(),
+                &session,
+                &query,
+                &ApplicabilityContext::new(),
+                None,
+            )
+            .into_iter()
+            .map(|cs| (cs.learning.id, cs.source))
+            .collect();
+        assert!(sources.contains(&("cl_20260101_001".to_string(), LearningSource::Project)));
+        assert!(sources.contains(&("cl_20260101_900".to_string(), LearningSource::Org)));
+    }
+
     // =========================================================================
     // HookType::UserPromptSubmit parse tests
     // =========================================================================
@@ -10913,6 +13260,74 @@ behavior_changing: true\nstatus: active\n---\n";
         assert_eq!(result_ids, original_ids);
     }
 
+    #[test]
+    fn test_local_reranker_orders_by_similarity() {
+        use crate::core::embeddings::{EmbeddingCache, HashingEmbeddingProvider};
+
+        let mut reranker = LocalReranker::new(
+            Box::new(HashingEmbeddingProvider::new(256)),
+            EmbeddingCache::default(),
+        );
+        let mut candidates = vec![
+            make_composite_score("cl_001", "Always validate form input", 0.9),
+            make_composite_score("cl_002", "Run database migrations in a transaction", 0.5),
+        ];
+        for cs in &mut candidates {
+            cs.explanation = Some(InjectionExplanation::default());
+        }
+
+        let tool_input = serde_json::json!({ "command": "diesel migrations run in transaction" });
+        let result = reranker.rerank(
+            candidates,
+            "Bash",
+            &tool_input.to_string(),
+            "feature/db-migrations",
+            &["migrations/2026_init.sql".to_string()],
+        );
+
+        let ids: Vec<&str> = result.iter().map(|c| c.learning.id.as_str()).collect();
+        assert_eq!(ids, vec!["cl_002", "cl_001"]);
+        let rerank = |i: usize| {
+            result[i]
+                .explanation
+                .as_ref()
+                .and_then(|e| e.rerank_score)
+                .unwrap()
+        };
+        assert!(rerank(0) > rerank(1));
+        assert!((1.0..=5.0).contains(&rerank(1)));
+        // Learning summaries stay cached for the next rerank
+        assert_eq!(reranker.cache.entries.len(), 2);
+    }
+
+    #[test]
+    fn test_local_rerank_query_uses_tool_input_values() {
+        let query = local_rerank_query(
+            "Bash",
+            r#"{"command":"cargo test","description":"Run tests"}"#,
+            "fix/flaky-test",
+            &["src/lib.rs".to_string()],
+        );
+        assert!(query.starts_with("Bash cargo test Run tests"));
+        assert!(query.contains("fix flaky test"));
+        assert!(query.contains("src/lib.rs"));
+        assert!(!query.contains('{'));
+        assert!(local_rerank_query("Read", "", "", &[]).is_empty());
+    }
+
+    #[cfg(not(feature = "semantic-dedup"))]
+    #[test]
+    fn test_rerank_locally_without_model_fails_open() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let candidates = vec![
+            make_composite_score("cl_001", "Learning A", 0.8),
+            make_composite_score("cl_002", "Learning B", 0.6),
+        ];
+        let result = rerank_locally(dir.path(), candidates, "Read", "src/main.rs", "main", &[]);
+        let ids: Vec<&str> = result.iter().map(|c| c.learning.id.as_str()).collect();
+        assert_eq!(ids, vec!["cl_001", "cl_002"]);
+    }
+
     // =========================================================================
     // Corpus vocabulary extraction tests
     // =========================================================================
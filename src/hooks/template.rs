@@ -0,0 +1,542 @@
+//! User-defined templates for hook output.
+//!
+//! Projects can replace the built-in text of the session-start injection,
+//! the PreToolUse deferred injection, the UserPromptSubmit injection, and the
+//! Stop block reason with templates in `.grove/templates/`:
+//!
+//! | File | Replaces |
+//! |------|----------|
+//! | `session-start.hbs` | Session-start context (learnings, correction notices, gate notice) |
+//! | `pre-tool-use.hbs` | Deferred injection on the first tool call, and file-triggered injection |
+//! | `user-prompt-submit.hbs` | Injection on each user prompt |
+//! | `stop-block.hbs` | Reason given when the gate blocks exit |
+//!
+//! Templates use a small Handlebars subset:
+//!
+//! | Syntax | Meaning |
+//! |--------|---------|
+//! | `{{name}}`, `{{gate.status}}` | insert a value (no escaping) |
+//! | `{{#if x}}...{{else}}...{{/if}}` | render when `x` is truthy (non-empty, non-zero, true) |
+//! | `{{#unless x}}...{{/unless}}` | render when `x` is falsy |
+//! | `{{#each list}}...{{else}}...{{/each}}` | render per item; `{{this}}`, `{{@index}}`, `{{@first}}`, `{{@last}}`, `{{../name}}` |
+//! | `{{! comment }}` | ignored |
+//!
+//! Block tags alone on a line take the line with them. Unknown variables are
+//! errors, so a typo falls back to the built-in text instead of rendering a
+//! blank.
+
+use std::path::Path;
+
+use serde_json::Value;
+use tracing::warn;
+
+use crate::error::{GroveError, Result};
+
+/// The hook output a template replaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateKind {
+    /// Session-start context.
+    SessionStart,
+    /// PreToolUse deferred and file-triggered injection.
+    PreToolUse,
+    /// UserPromptSubmit injection.
+    UserPromptSubmit,
+    /// Stop hook block reason.
+    StopBlock,
+}
+
+impl TemplateKind {
+    /// Template file name in `.grove/templates/`.
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Self::SessionStart => "session-start.hbs",
+            Self::PreToolUse => "pre-tool-use.hbs",
+            Self::UserPromptSubmit => "user-prompt-submit.hbs",
+            Self::StopBlock => "stop-block.hbs",
+        }
+    }
+
+    /// Hook name exposed to templates as `{{hook}}`.
+    pub fn hook_name(&self) -> &'static str {
+        match self {
+            Self::SessionStart => "session-start",
+            Self::PreToolUse => "pre-tool-use",
+            Self::UserPromptSubmit => "user-prompt-submit",
+            Self::StopBlock => "stop",
+        }
+    }
+}
+
+/// Render the project's template for `kind`, if it has one.
+///
+/// Returns `None` when there is no template or it fails to parse or render;
+/// failures are logged and the caller uses its built-in text (fail-open).
+pub fn render_project_template(cwd: &Path, kind: TemplateKind, vars: &Value) -> Option<String> {
+    let path = crate::config::project_templates_dir(cwd).join(kind.file_name());
+    let source = std::fs::read_to_string(&path).ok()?;
+    match Template::parse(&source).and_then(|t| t.render(vars)) {
+        Ok(text) => Some(text),
+        Err(e) => {
+            warn!(
+                "Template {} failed, using built-in text (fail-open): {}",
+                path.display(),
+                e
+            );
+            None
+        }
+    }
+}
+
+/// A parsed template.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Text(String),
+    Var(String),
+    If {
+        path: String,
+        negate: bool,
+        then: Vec<Node>,
+        otherwise: Vec<Node>,
+    },
+    Each {
+        path: String,
+        body: Vec<Node>,
+        otherwise: Vec<Node>,
+    },
+}
+
+/// A lexed piece of template source.
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Text(String),
+    Tag(String),
+}
+
+impl Segment {
+    /// Block tags (`#`, `/`, `else`, comments) may stand alone on a line.
+    fn is_block_tag(&self) -> bool {
+        match self {
+            Segment::Tag(tag) => {
+                tag.starts_with('#')
+                    || tag.starts_with('/')
+                    || tag.starts_with('!')
+                    || tag == "else"
+            }
+            Segment::Text(_) => false,
+        }
+    }
+}
+
+/// An open block while parsing.
+struct OpenBlock {
+    helper: String,
+    path: String,
+    then: Vec<Node>,
+    otherwise: Option<Vec<Node>>,
+}
+
+impl Template {
+    /// Parse template source.
+    pub fn parse(source: &str) -> Result<Self> {
+        let segments = strip_standalone(lex(source)?);
+
+        let mut stack: Vec<OpenBlock> = Vec::new();
+        let mut nodes: Vec<Node> = Vec::new();
+        // Nodes of the innermost open block branch, or the top level
+        fn current<'a>(stack: &'a mut [OpenBlock], nodes: &'a mut Vec<Node>) -> &'a mut Vec<Node> {
+            match stack.last_mut() {
+                Some(block) => block.otherwise.as_mut().unwrap_or(&mut block.then),
+                None => nodes,
+            }
+        }
+
+        for segment in segments {
+            let tag = match segment {
+                Segment::Text(text) => {
+                    if !text.is_empty() {
+                        current(&mut stack, &mut nodes).push(Node::Text(text));
+                    }
+                    continue;
+                }
+                Segment::Tag(tag) => tag,
+            };
+
+            if tag.starts_with('!') {
+                continue;
+            }
+            if let Some(open) = tag.strip_prefix('#') {
+                let (helper, path) = open.split_once(char::is_whitespace).ok_or_else(|| {
+                    GroveError::config(format!("{{{{{}}}}} needs a variable", tag))
+                })?;
+                if !matches!(helper, "if" | "unless" | "each") {
+                    return Err(GroveError::config(format!(
+                        "Unknown block {{{{#{}}}}} (expected #if, #unless, or #each)",
+                        helper
+                    )));
+                }
+                stack.push(OpenBlock {
+                    helper: helper.to_string(),
+                    path: path.trim().to_string(),
+                    then: Vec::new(),
+                    otherwise: None,
+                });
+            } else if tag == "else" {
+                match stack.last_mut() {
+                    Some(block) if block.otherwise.is_none() => block.otherwise = Some(Vec::new()),
+                    _ => return Err(GroveError::config("{{else}} outside a block")),
+                }
+            } else if let Some(close) = tag.strip_prefix('/') {
+                let block = stack.pop().ok_or_else(|| {
+                    GroveError::config(format!("Unexpected {{{{/{}}}}}", close.trim()))
+                })?;
+                if close.trim() != block.helper {
+                    return Err(GroveError::config(format!(
+                        "{{{{#{}}}}} closed by {{{{/{}}}}}",
+                        block.helper,
+                        close.trim()
+                    )));
+                }
+                let otherwise = block.otherwise.unwrap_or_default();
+                let node = match block.helper.as_str() {
+                    "each" => Node::Each {
+                        path: block.path,
+                        body: block.then,
+                        otherwise,
+                    },
+                    helper => Node::If {
+                        path: block.path,
+                        negate: helper == "unless",
+                        then: block.then,
+                        otherwise,
+                    },
+                };
+                current(&mut stack, &mut nodes).push(node);
+            } else {
+                current(&mut stack, &mut nodes).push(Node::Var(tag));
+            }
+        }
+
+        if let Some(block) = stack.last() {
+            return Err(GroveError::config(format!(
+                "Unclosed {{{{#{} {}}}}}",
+                block.helper, block.path
+            )));
+        }
+        Ok(Self { nodes })
+    }
+
+    /// Render the template with `vars` as the root context.
+    pub fn render(&self, vars: &Value) -> Result<String> {
+        let mut out = String::new();
+        let mut scopes = vec![Scope {
+            value: vars,
+            meta: None,
+        }];
+        render_nodes(&self.nodes, &mut scopes, &mut out)?;
+        Ok(out)
+    }
+}
+
+/// One level of the context stack: the root, or an `#each` item.
+struct Scope<'a> {
+    value: &'a Value,
+    /// Loop position: (index, length).
+    meta: Option<(usize, usize)>,
+}
+
+fn render_nodes<'a>(
+    nodes: &'a [Node],
+    scopes: &mut Vec<Scope<'a>>,
+    out: &mut String,
+) -> Result<()> {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Var(path) => match lookup(scopes, path)? {
+                Value::Null => {}
+                Value::String(s) => out.push_str(&s),
+                Value::Bool(b) => out.push_str(&b.to_string()),
+                Value::Number(n) => out.push_str(&n.to_string()),
+                Value::Array(_) | Value::Object(_) => {
+                    return Err(GroveError::config(format!(
+                        "{{{{{}}}}} is a list or object; use {{{{#each {}}}}}",
+                        path, path
+                    )));
+                }
+            },
+            Node::If {
+                path,
+                negate,
+                then,
+                otherwise,
+            } => {
+                let branch = if is_truthy(&lookup(scopes, path)?) != *negate {
+                    then
+                } else {
+                    otherwise
+                };
+                render_nodes(branch, scopes, out)?;
+            }
+            Node::Each {
+                path,
+                body,
+                otherwise,
+            } => {
+                let items = match resolve(scopes, path)? {
+                    Value::Array(items) => items,
+                    Value::Null => {
+                        render_nodes(otherwise, scopes, out)?;
+                        continue;
+                    }
+                    _ => {
+                        return Err(GroveError::config(format!(
+                            "{{{{#each {}}}}} needs a list",
+                            path
+                        )));
+                    }
+                };
+                if items.is_empty() {
+                    render_nodes(otherwise, scopes, out)?;
+                }
+                for (i, item) in items.iter().enumerate() {
+                    scopes.push(Scope {
+                        value: item,
+                        meta: Some((i, items.len())),
+                    });
+                    let result = render_nodes(body, scopes, out);
+                    scopes.pop();
+                    result?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Look up a variable, copying loop metadata values.
+fn lookup(scopes: &[Scope<'_>], path: &str) -> Result<Value> {
+    if let Some(meta) = path.strip_prefix('@') {
+        let (index, len) = scopes
+            .iter()
+            .rev()
+            .find_map(|s| s.meta)
+            .ok_or_else(|| GroveError::config(format!("{{{{{}}}}} outside #each", path)))?;
+        return match meta {
+            "index" => Ok(Value::from(index)),
+            "first" => Ok(Value::Bool(index == 0)),
+            "last" => Ok(Value::Bool(index + 1 == len)),
+            _ => Err(GroveError::config(format!("Unknown variable '{}'", path))),
+        };
+    }
+    resolve(scopes, path).cloned()
+}
+
+/// Resolve a variable path against the scope stack.
+///
+/// `this` is the innermost scope and each `../` steps out one scope; a plain
+/// name is looked up from the innermost scope outward.
+fn resolve<'a>(scopes: &[Scope<'a>], path: &str) -> Result<&'a Value> {
+    let unknown = || GroveError::config(format!("Unknown variable '{}'", path));
+
+    let mut rest = path;
+    let mut depth = 0;
+    while let Some(stripped) = rest.strip_prefix("../") {
+        rest = stripped;
+        depth += 1;
+    }
+    let mut parts: Vec<&str> = rest.split('.').collect();
+
+    let explicit = depth > 0 || parts.first() == Some(&"this");
+    if parts.first() == Some(&"this") {
+        parts.remove(0);
+    }
+
+    let walk = |start: &'a Value| -> Option<&'a Value> {
+        parts.iter().try_fold(start, |value, part| value.get(*part))
+    };
+
+    if explicit {
+        let scope = scopes
+            .len()
+            .checked_sub(depth + 1)
+            .and_then(|i| scopes.get(i))
+            .ok_or_else(unknown)?;
+        return walk(scope.value).ok_or_else(unknown);
+    }
+    scopes
+        .iter()
+        .rev()
+        .find(|scope| scope.value.get(parts[0]).is_some())
+        .and_then(|scope| walk(scope.value))
+        .ok_or_else(unknown)
+}
+
+/// Handlebars truthiness: empty strings and lists, zero, false, and null are falsy.
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64().is_some_and(|n| n != 0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(_) => true,
+    }
+}
+
+/// Split source into text and `{{...}}` tags.
+fn lex(source: &str) -> Result<Vec<Segment>> {
+    let mut segments = Vec::new();
+    let mut rest = source;
+    while let Some(start) = rest.find("{{") {
+        segments.push(Segment::Text(rest[..start].to_string()));
+        let after = &rest[start + 2..];
+        let end = after.find("}}").ok_or_else(|| {
+            let line = source[..source.len() - rest.len() + start]
+                .lines()
+                .count()
+                .max(1);
+            GroveError::config(format!("Unclosed '{{{{' on line {}", line))
+        })?;
+        segments.push(Segment::Tag(after[..end].trim().to_string()));
+        rest = &after[end + 2..];
+    }
+    segments.push(Segment::Text(rest.to_string()));
+    Ok(segments)
+}
+
+/// Drop the lines of block tags that stand alone on their line.
+fn strip_standalone(mut segments: Vec<Segment>) -> Vec<Segment> {
+    let text = |segment: Option<&Segment>| match segment {
+        Some(Segment::Text(text)) => Some(text.clone()),
+        _ => None,
+    };
+
+    // Decide on the original source first, then trim
+    let last = segments.len().saturating_sub(1);
+    let standalone: Vec<usize> = (0..segments.len())
+        .filter(|&i| segments[i].is_block_tag())
+        .filter(|&i| {
+            let before = i
+                .checked_sub(1)
+                .and_then(|j| text(segments.get(j)))
+                .is_some_and(|t| {
+                    let tail = t.rsplit('\n').next().unwrap_or("");
+                    tail.trim().is_empty() && (t.contains('\n') || i == 1)
+                });
+            let after = text(segments.get(i + 1)).is_some_and(|t| {
+                let head = t.split('\n').next().unwrap_or("");
+                head.trim().is_empty() && (t.contains('\n') || i + 1 == last)
+            });
+            before && after
+        })
+        .collect();
+
+    for i in standalone {
+        if let Segment::Text(before) = &mut segments[i - 1] {
+            let keep = before.rfind('\n').map(|n| n + 1).unwrap_or(0);
+            before.truncate(keep);
+        }
+        if let Segment::Text(after) = &mut segments[i + 1] {
+            let drop = after.find('\n').map(|n| n + 1).unwrap_or(after.len());
+            after.drain(..drop);
+        }
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn render(source: &str, vars: &Value) -> Result<String> {
+        Template::parse(source)?.render(vars)
+    }
+
+    #[test]
+    fn test_render_variables_and_paths() {
+        let vars = json!({"session_id": "s1", "gate": {"status": "pending", "blocks": 2}});
+        assert_eq!(
+            render("{{ session_id }}: {{gate.status}} x{{gate.blocks}}", &vars).unwrap(),
+            "s1: pending x2"
+        );
+    }
+
+    #[test]
+    fn test_render_each_with_metadata_and_parent_scope() {
+        let vars = json!({
+            "session_id": "s1",
+            "learnings": [{"id": "a", "tags": ["x", "y"]}, {"id": "b", "tags": []}],
+        });
+        let source = "{{#each learnings}}{{@index}}:{{id}}[{{#each tags}}{{this}}{{#unless @last}},{{/unless}}{{else}}-{{/each}}]({{../session_id}}) {{/each}}";
+        assert_eq!(render(source, &vars).unwrap(), "0:a[x,y](s1) 1:b[-](s1) ");
+    }
+
+    #[test]
+    fn test_render_if_else_truthiness() {
+        let source = "{{#if list}}some{{else}}none{{/if}}";
+        assert_eq!(render(source, &json!({"list": [1]})).unwrap(), "some");
+        assert_eq!(render(source, &json!({"list": []})).unwrap(), "none");
+        assert_eq!(render(source, &json!({"list": ""})).unwrap(), "none");
+        assert_eq!(render(source, &json!({"list": 0})).unwrap(), "none");
+    }
+
+    #[test]
+    fn test_standalone_block_lines_are_removed() {
+        let source = "## Learnings\n{{! one per line }}\n{{#each learnings}}\n- {{summary}}\n{{/each}}\nDone\n";
+        let vars = json!({"learnings": [{"summary": "a"}, {"summary": "b"}]});
+        assert_eq!(
+            render(source, &vars).unwrap(),
+            "## Learnings\n- a\n- b\nDone\n"
+        );
+    }
+
+    #[test]
+    fn test_errors_for_unknown_variables_and_bad_blocks() {
+        let vars = json!({"learnings": [], "name": "x"});
+        let err = |source: &str| render(source, &vars).unwrap_err().to_string();
+
+        assert!(err("{{nmae}}").contains("Unknown variable 'nmae'"));
+        assert!(err("{{#each learnings}}{{/if}}").contains("closed by"));
+        assert!(err("{{#each learnings}}").contains("Unclosed"));
+        assert!(err("{{/each}}").contains("Unexpected"));
+        assert!(err("{{#with name}}{{/with}}").contains("Unknown block"));
+        assert!(err("{{learnings}}").contains("use {{#each learnings}}"));
+        assert!(err("{{#each name}}{{/each}}").contains("needs a list"));
+        assert!(err("hello {{name").contains("Unclosed '{{'"));
+    }
+
+    #[test]
+    fn test_render_project_template_falls_back_on_error() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let templates = dir.path().join(".grove").join("templates");
+        std::fs::create_dir_all(&templates).unwrap();
+        let vars = json!({"session_id": "s1"});
+
+        assert_eq!(
+            render_project_template(dir.path(), TemplateKind::StopBlock, &vars),
+            None
+        );
+
+        std::fs::write(
+            templates.join("stop-block.hbs"),
+            "Reflect on {{session_id}}",
+        )
+        .unwrap();
+        assert_eq!(
+            render_project_template(dir.path(), TemplateKind::StopBlock, &vars).as_deref(),
+            Some("Reflect on s1")
+        );
+
+        std::fs::write(templates.join("stop-block.hbs"), "{{missing}}").unwrap();
+        assert_eq!(
+            render_project_template(dir.path(), TemplateKind::StopBlock, &vars),
+            None
+        );
+    }
+}
@@ -41,7 +41,7 @@ pub use util::{read_to_string_limited, read_to_string_with_limit, MAX_FILE_SIZE}
 // CLI commands
 pub use cli::retroflect as retroflect_cmd;
 pub use cli::{
-    BackendsCommand, CleanCommand, DebugCommand, InitCommand, ListCommand, MaintainCommand,
-    ObserveCommand, RefCommand, ReflectCommand, SearchCommand, SkipCommand, StatsCommand,
-    TicketsCommand, TraceCommand,
+    BackendsCommand, CleanCommand, DebugCommand, EditCommand, ExportCommand, HistoryCommand,
+    InitCommand, ListCommand, MaintainCommand, MigrateCommand, ObserveCommand, RefCommand,
+    ReflectCommand, SearchCommand, SkipCommand, StatsCommand, TicketsCommand, TraceCommand,
 };
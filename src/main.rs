@@ -6,7 +6,7 @@ use std::io::Write;
 use std::path::Path;
 use std::process::ExitCode;
 
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 
 use grove::config::{grove_home, Config};
 use grove::error::exit_codes;
@@ -55,13 +55,16 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     // ── User commands (alphabetical) ────────────────────────────────────
-    /// [User] Show discovered memory backends
+    /// [User] Show discovered memory backends (or migrate between them)
     Backends {
+        /// Action to perform (default: show backends)
+        #[command(subcommand)]
+        action: Option<BackendsAction>,
         /// Output as JSON
-        #[arg(long, short)]
+        #[arg(long, short, global = true)]
         json: bool,
         /// Suppress output
-        #[arg(long, short)]
+        #[arg(long, short, global = true)]
         quiet: bool,
     },
 
@@ -84,6 +87,92 @@ enum Commands {
         dry_run: bool,
     },
 
+    /// [User] Show the field-level diff of one revision of a learning
+    Diff {
+        /// Learning ID
+        id: String,
+        /// Revision number (defaults to the latest)
+        #[arg(long)]
+        rev: Option<u32>,
+        /// Output as JSON
+        #[arg(long, short)]
+        json: bool,
+        /// Suppress output
+        #[arg(long, short)]
+        quiet: bool,
+    },
+
+    /// [User] Edit a learning in $EDITOR (or apply JSON edits from stdin)
+    Edit {
+        /// Learning ID to edit
+        id: String,
+        /// Output as JSON
+        #[arg(long, short)]
+        json: bool,
+        /// Suppress output
+        #[arg(long, short)]
+        quiet: bool,
+    },
+
+    /// [User] Export all learnings and their stats to a JSONL file
+    Export {
+        /// File to write (default: stdout)
+        #[arg(long, short)]
+        output: Option<String>,
+        /// Only export learnings with this scope
+        #[arg(long, value_enum)]
+        scope: Option<ScopeArg>,
+        /// Output as JSON
+        #[arg(long, short)]
+        json: bool,
+        /// Suppress output
+        #[arg(long, short)]
+        quiet: bool,
+    },
+
+    /// [User] List the revision history of a learning
+    History {
+        /// Learning ID
+        id: String,
+        /// Output as JSON
+        #[arg(long, short)]
+        json: bool,
+        /// Suppress output
+        #[arg(long, short)]
+        quiet: bool,
+    },
+
+    /// [User] Import learnings and stats from a `grove export` file
+    Import {
+        /// Export file to read
+        file: String,
+        /// Only import learnings with this scope
+        #[arg(long, value_enum)]
+        scope: Option<ScopeArg>,
+        /// What to do when an imported ID already exists
+        #[arg(long, value_enum, default_value = "remap")]
+        on_collision: CollisionArg,
+        /// Output as JSON
+        #[arg(long, short)]
+        json: bool,
+        /// Suppress output
+        #[arg(long, short)]
+        quiet: bool,
+    },
+
+    /// [User] Inspect or rebuild the persistent search index
+    Index {
+        /// Action to perform
+        #[command(subcommand)]
+        action: IndexAction,
+        /// Output as JSON
+        #[arg(long, short, global = true)]
+        json: bool,
+        /// Suppress output
+        #[arg(long, short, global = true)]
+        quiet: bool,
+    },
+
     /// [User] Initialize Grove configuration
     Init {
         /// Output as JSON
@@ -129,6 +218,8 @@ enum Commands {
         /// Show rejected candidates instead of accepted learnings
         #[arg(long, conflicts_with = "stale")]
         rejections: bool,
+        #[command(flatten)]
+        provenance: ProvenanceArgs,
     },
 
     /// [User] Maintain learnings (archive, restore, consolidate)
@@ -196,7 +287,9 @@ enum Commands {
 
     /// [User/Agent] Search for learnings
     Search {
-        /// Search query
+        /// Search query: free text plus field filters such as tag:rust,
+        /// category:pitfall, scope:personal, file:src/hooks/*, status:archived,
+        /// created:>2026-01-01, ticket:PROJ-12, "quoted phrases", and -negation
         query: String,
         /// Output as JSON
         #[arg(long, short)]
@@ -210,6 +303,9 @@ enum Commands {
         /// Include archived learnings
         #[arg(long)]
         include_archived: bool,
+        /// Show per-field score contributions and BM25 rescoring
+        #[arg(long)]
+        explain: bool,
     },
 
     /// [User] Display quality statistics and insights
@@ -232,6 +328,8 @@ enum Commands {
         /// Filter stats to a specific grove version (e.g., "0.9.0" or "pre:0.9.0")
         #[arg(long)]
         version: Option<String>,
+        #[command(flatten)]
+        provenance: ProvenanceArgs,
     },
 
     /// [User] Show detected ticketing system
@@ -364,6 +462,9 @@ enum Commands {
         /// Filter by event type
         #[arg(long)]
         event_type: Option<String>,
+        /// Show why each injected learning was selected
+        #[arg(long)]
+        explain_injections: bool,
     },
 
     // ── Internal ──────────────────────────────────────────────────────
@@ -502,6 +603,98 @@ impl From<SortByArg> for grove::cli::list::SortBy {
     }
 }
 
+/// Command that produced a learning.
+#[derive(Clone, Copy, ValueEnum)]
+enum OriginArg {
+    /// Captured by grove reflect
+    Reflect,
+    /// Mined by grove retroflect
+    Retroflect,
+    /// Merged by grove maintain consolidate
+    Consolidate,
+}
+
+impl From<OriginArg> for grove::core::ProvenanceOrigin {
+    fn from(arg: OriginArg) -> Self {
+        match arg {
+            OriginArg::Reflect => grove::core::ProvenanceOrigin::Reflect,
+            OriginArg::Retroflect => grove::core::ProvenanceOrigin::Retroflect,
+            OriginArg::Consolidate => grove::core::ProvenanceOrigin::Consolidate,
+        }
+    }
+}
+
+/// Provenance filters shared by list and stats.
+#[derive(Args, Clone, Default)]
+struct ProvenanceArgs {
+    /// Only learnings produced by this command
+    #[arg(long, value_enum)]
+    origin: Option<OriginArg>,
+    /// Only learnings whose git author contains this text
+    #[arg(long)]
+    author: Option<String>,
+    /// Only learnings whose agent contains this text
+    #[arg(long)]
+    agent: Option<String>,
+    /// Only learnings whose model contains this text
+    #[arg(long)]
+    model: Option<String>,
+}
+
+impl From<ProvenanceArgs> for grove::core::ProvenanceFilter {
+    fn from(args: ProvenanceArgs) -> Self {
+        Self {
+            origin: args.origin.map(Into::into),
+            author: args.author,
+            agent: args.agent,
+            model: args.model,
+        }
+    }
+}
+
+/// Learning scope filter for export and import.
+#[derive(Clone, Copy, ValueEnum)]
+enum ScopeArg {
+    /// Project learnings
+    Project,
+    /// Team learnings
+    Team,
+    /// Personal learnings
+    Personal,
+    /// Ephemeral learnings
+    Ephemeral,
+}
+
+impl From<ScopeArg> for grove::core::LearningScope {
+    fn from(arg: ScopeArg) -> Self {
+        match arg {
+            ScopeArg::Project => grove::core::LearningScope::Project,
+            ScopeArg::Team => grove::core::LearningScope::Team,
+            ScopeArg::Personal => grove::core::LearningScope::Personal,
+            ScopeArg::Ephemeral => grove::core::LearningScope::Ephemeral,
+        }
+    }
+}
+
+/// ID collision handling for import.
+#[derive(Clone, Copy, Default, ValueEnum)]
+enum CollisionArg {
+    /// Import under a new ID
+    #[default]
+    Remap,
+    /// Keep the existing learning and skip the imported one
+    Keep,
+}
+
+impl From<CollisionArg> for grove::cli::export::CollisionPolicy {
+    fn from(arg: CollisionArg) -> Self {
+        match arg {
+            CollisionArg::Remap => grove::cli::export::CollisionPolicy::Remap,
+            CollisionArg::Keep => grove::cli::export::CollisionPolicy::Keep,
+        }
+    }
+}
+
 impl From<HookEvent> for HookType {
     fn from(event: HookEvent) -> Self {
         match event {
@@ -516,6 +709,30 @@ impl From<HookEvent> for HookType {
     }
 }
 
+#[derive(Subcommand)]
+enum BackendsAction {
+    /// Copy all project learnings from one backend to another
+    Migrate {
+        /// Source backend (e.g. markdown)
+        #[arg(long)]
+        from: String,
+        /// Target backend (e.g. total-recall)
+        #[arg(long)]
+        to: String,
+        /// Show what would be migrated without writing
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum IndexAction {
+    /// Rebuild the index from the current learnings
+    Rebuild,
+    /// Show whether the index matches the current learnings
+    Status,
+}
+
 #[derive(Subcommand)]
 enum MaintainAction {
     /// List stale learnings
@@ -639,7 +856,8 @@ fn run() -> Result<ExitCode, Box<dyn std::error::Error>> {
             quiet,
             limit,
             include_archived,
-        } => run_search(&query, json, quiet, limit, include_archived, &cwd),
+            explain,
+        } => run_search(&query, json, quiet, limit, include_archived, explain, &cwd),
         Commands::List {
             json,
             quiet,
@@ -651,6 +869,7 @@ fn run() -> Result<ExitCode, Box<dyn std::error::Error>> {
             sort,
             asc,
             rejections,
+            provenance,
         } => run_list(
             json,
             quiet,
@@ -662,6 +881,7 @@ fn run() -> Result<ExitCode, Box<dyn std::error::Error>> {
             sort,
             asc,
             rejections,
+            provenance,
             &cwd,
         ),
         Commands::Stats {
@@ -671,14 +891,38 @@ fn run() -> Result<ExitCode, Box<dyn std::error::Error>> {
             rebuild,
             update_config,
             version,
-        } => run_stats(json, quiet, detailed, rebuild, update_config, version, &cwd),
+            provenance,
+        } => run_stats(
+            json,
+            quiet,
+            detailed,
+            rebuild,
+            update_config,
+            version,
+            provenance,
+            &cwd,
+        ),
         Commands::Maintain {
             action,
             json,
             quiet,
         } => run_maintain(action, json, quiet, &cwd),
         Commands::Init { json, quiet, force } => run_init(json, quiet, force, &cwd),
-        Commands::Backends { json, quiet } => run_backends(json, quiet, &cwd),
+        Commands::Index {
+            action,
+            json,
+            quiet,
+        } => run_index(action, json, quiet, &cwd),
+        Commands::Backends {
+            action: Some(BackendsAction::Migrate { from, to, dry_run }),
+            json,
+            quiet,
+        } => run_migrate(&from, &to, dry_run, json, quiet, &cwd),
+        Commands::Backends {
+            action: None,
+            json,
+            quiet,
+        } => run_backends(json, quiet, &cwd),
         Commands::Tickets { json, quiet } => run_tickets(json, quiet, &cwd),
         Commands::Sessions { json, quiet, limit } => run_sessions(json, quiet, limit),
         Commands::Debug {
@@ -692,7 +936,15 @@ fn run() -> Result<ExitCode, Box<dyn std::error::Error>> {
             quiet,
             limit,
             event_type,
-        } => run_trace(&session_id, json, quiet, limit, event_type),
+            explain_injections,
+        } => run_trace(
+            &session_id,
+            json,
+            quiet,
+            limit,
+            event_type,
+            explain_injections,
+        ),
         Commands::Clean {
             json,
             quiet,
@@ -700,6 +952,27 @@ fn run() -> Result<ExitCode, Box<dyn std::error::Error>> {
             orphans,
             dry_run,
         } => run_clean(json, quiet, before, orphans, dry_run),
+        Commands::Edit { id, json, quiet } => run_edit(&id, json, quiet, &cwd),
+        Commands::Export {
+            output,
+            scope,
+            json,
+            quiet,
+        } => run_export(output.as_deref(), scope, json, quiet, &cwd),
+        Commands::Import {
+            file,
+            scope,
+            on_collision,
+            json,
+            quiet,
+        } => run_import(&file, scope, on_collision, json, quiet, &cwd),
+        Commands::History { id, json, quiet } => run_history(&id, json, quiet, &cwd),
+        Commands::Diff {
+            id,
+            rev,
+            json,
+            quiet,
+        } => run_diff(&id, rev, json, quiet, &cwd),
         Commands::Review { json, quiet, count } => run_review(json, quiet, count, &cwd),
         Commands::Retroflect {
             project,
@@ -894,6 +1167,7 @@ fn run_search(
     quiet: bool,
     limit: Option<usize>,
     include_archived: bool,
+    explain: bool,
     cwd: &Path,
 ) -> Result<ExitCode, Box<dyn std::error::Error>> {
     use grove::cli::search::{SearchCommand, SearchOptions};
@@ -902,12 +1176,13 @@ fn run_search(
     let config = Config::load();
     let backend = create_primary_backend(cwd, Some(&config));
 
-    let cmd = SearchCommand::new(backend);
+    let cmd = SearchCommand::new(backend).with_retrieval(config.retrieval.clone());
     let options = SearchOptions {
         json,
         quiet,
         limit,
         include_archived,
+        explain,
     };
 
     let output = cmd.run(query, &options);
@@ -932,6 +1207,7 @@ fn run_list(
     sort: SortByArg,
     asc: bool,
     rejections: bool,
+    provenance: ProvenanceArgs,
     cwd: &Path,
 ) -> Result<ExitCode, Box<dyn std::error::Error>> {
     use grove::cli::list::{ListCommand, ListOptions, SortOrder};
@@ -965,6 +1241,7 @@ fn run_list(
         sort_by: sort.into(),
         sort_order: if asc { SortOrder::Asc } else { SortOrder::Desc },
         rejections,
+        provenance: provenance.into(),
     };
 
     let output = cmd.run(&options);
@@ -977,6 +1254,7 @@ fn run_list(
     Ok(success_to_exit_code(output.success))
 }
 
+#[allow(clippy::too_many_arguments)]
 fn run_stats(
     json: bool,
     quiet: bool,
@@ -984,6 +1262,7 @@ fn run_stats(
     rebuild: bool,
     update_config: bool,
     version: Option<String>,
+    provenance: ProvenanceArgs,
     cwd: &Path,
 ) -> Result<ExitCode, Box<dyn std::error::Error>> {
     use grove::cli::stats::{StatsCommand, StatsOptions};
@@ -998,6 +1277,7 @@ fn run_stats(
         rebuild,
         update_config,
         version,
+        provenance: provenance.into(),
     };
 
     let output = cmd.run(&options);
@@ -1142,6 +1422,77 @@ fn run_backends(
     Ok(success_to_exit_code(output.success))
 }
 
+fn run_index(
+    action: IndexAction,
+    json: bool,
+    quiet: bool,
+    cwd: &Path,
+) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    use grove::cli::index::{IndexCommand, IndexOptions};
+    use grove::config::project_index_dir;
+    use grove::create_primary_backend;
+
+    let config = Config::load();
+    let cmd = IndexCommand::new(
+        create_primary_backend(cwd, Some(&config)),
+        project_index_dir(cwd),
+    );
+    let options = IndexOptions { json, quiet };
+
+    let output = match action {
+        IndexAction::Rebuild => cmd.run_rebuild(),
+        IndexAction::Status => cmd.run_status(),
+    };
+    let formatted = cmd.format_output(&output, &options);
+
+    if !formatted.is_empty() {
+        println!("{}", formatted);
+    }
+
+    Ok(success_to_exit_code(output.success))
+}
+
+fn run_migrate(
+    from: &str,
+    to: &str,
+    dry_run: bool,
+    json: bool,
+    quiet: bool,
+    cwd: &Path,
+) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    use grove::cli::migrate::{MigrateCommand, MigrateOptions};
+    use grove::discovery::{create_named_backend, BackendType};
+
+    let config = Config::load();
+
+    // Normalize aliases so checkpoints and output use canonical names
+    let canonical = |name: &str| {
+        BackendType::parse(name)
+            .map(|t| t.as_str().to_string())
+            .unwrap_or_else(|| name.to_string())
+    };
+    let (from, to) = (canonical(from), canonical(to));
+
+    let source = create_named_backend(cwd, &from, &config.backends)?;
+    let target = create_named_backend(cwd, &to, &config.backends)?;
+
+    let cmd = MigrateCommand::new(source, target, from, to);
+    let options = MigrateOptions {
+        json,
+        quiet,
+        dry_run,
+    };
+
+    let output = cmd.run(cwd, &options);
+    let formatted = cmd.format_output(&output, &options);
+
+    if !formatted.is_empty() {
+        println!("{}", formatted);
+    }
+
+    Ok(success_to_exit_code(output.success))
+}
+
 fn run_tickets(
     json: bool,
     quiet: bool,
@@ -1221,6 +1572,7 @@ fn run_trace(
     quiet: bool,
     limit: Option<usize>,
     event_type: Option<String>,
+    explain_injections: bool,
 ) -> Result<ExitCode, Box<dyn std::error::Error>> {
     use grove::cli::trace::{TraceCommand, TraceOptions};
 
@@ -1232,6 +1584,7 @@ fn run_trace(
         quiet,
         limit,
         event_type,
+        explain_injections,
     };
 
     let output = cmd.run(session_id, &options);
@@ -1279,6 +1632,140 @@ fn run_clean(
     Ok(success_to_exit_code(output.success))
 }
 
+fn run_edit(
+    id: &str,
+    json: bool,
+    quiet: bool,
+    cwd: &Path,
+) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    use grove::cli::edit::{EditCommand, EditOptions};
+    use grove::create_primary_backend;
+
+    let config = Config::load();
+    let backend = create_primary_backend(cwd, Some(&config));
+
+    let cmd = EditCommand::new(backend, config);
+    let options = EditOptions { json, quiet };
+
+    let output = cmd.run(id, &options, cwd);
+    let formatted = cmd.format_output(&output, &options);
+
+    if !formatted.is_empty() {
+        println!("{}", formatted);
+    }
+
+    Ok(success_to_exit_code(output.success))
+}
+
+fn run_export(
+    output_path: Option<&str>,
+    scope: Option<ScopeArg>,
+    json: bool,
+    quiet: bool,
+    cwd: &Path,
+) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    use grove::cli::export::{ExportCommand, ExportOptions};
+    use grove::create_primary_backend;
+
+    let config = Config::load();
+    let cmd = ExportCommand::new(create_primary_backend(cwd, Some(&config)));
+    let options = ExportOptions {
+        json,
+        quiet,
+        scope: scope.map(Into::into),
+        ..Default::default()
+    };
+
+    let output = cmd.run_export(cwd, output_path.map(Path::new), &options);
+    let formatted = cmd.format_export(&output, &options);
+
+    if output.success && output.path.is_none() {
+        // The export itself goes to stdout, byte for byte
+        print!("{}", formatted);
+    } else if !formatted.is_empty() {
+        println!("{}", formatted);
+    }
+
+    Ok(success_to_exit_code(output.success))
+}
+
+fn run_import(
+    file: &str,
+    scope: Option<ScopeArg>,
+    on_collision: CollisionArg,
+    json: bool,
+    quiet: bool,
+    cwd: &Path,
+) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    use grove::cli::export::{ExportCommand, ExportOptions};
+    use grove::create_primary_backend;
+
+    let config = Config::load();
+    let cmd = ExportCommand::new(create_primary_backend(cwd, Some(&config)));
+    let options = ExportOptions {
+        json,
+        quiet,
+        scope: scope.map(Into::into),
+        on_collision: on_collision.into(),
+    };
+
+    let output = cmd.run_import(cwd, Path::new(file), &options);
+    let formatted = cmd.format_import(&output, &options);
+
+    if !formatted.is_empty() {
+        println!("{}", formatted);
+    }
+
+    Ok(success_to_exit_code(output.success))
+}
+
+fn run_history(
+    id: &str,
+    json: bool,
+    quiet: bool,
+    cwd: &Path,
+) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    use grove::cli::history::{HistoryCommand, HistoryOptions};
+    use grove::create_primary_backend;
+
+    let config = Config::load();
+    let cmd = HistoryCommand::new(create_primary_backend(cwd, Some(&config)));
+    let options = HistoryOptions { json, quiet };
+
+    let output = cmd.run_history(id);
+    let formatted = cmd.format_history(&output, &options);
+
+    if !formatted.is_empty() {
+        println!("{}", formatted);
+    }
+
+    Ok(success_to_exit_code(output.success))
+}
+
+fn run_diff(
+    id: &str,
+    rev: Option<u32>,
+    json: bool,
+    quiet: bool,
+    cwd: &Path,
+) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    use grove::cli::history::{HistoryCommand, HistoryOptions};
+    use grove::create_primary_backend;
+
+    let config = Config::load();
+    let cmd = HistoryCommand::new(create_primary_backend(cwd, Some(&config)));
+    let options = HistoryOptions { json, quiet };
+
+    let output = cmd.run_diff(id, rev);
+    let formatted = cmd.format_diff(&output, &options);
+
+    if !formatted.is_empty() {
+        println!("{}", formatted);
+    }
+
+    Ok(success_to_exit_code(output.success))
+}
+
 fn run_review(
     json: bool,
     quiet: bool,
@@ -1493,17 +1980,20 @@ mod tests {
             "--limit",
             "10",
             "--include-archived",
+            "--explain",
         ]);
         match cli.command {
             Commands::Search {
                 query,
                 limit,
                 include_archived,
+                explain,
                 ..
             } => {
                 assert_eq!(query, "test query");
                 assert_eq!(limit, Some(10));
                 assert!(include_archived);
+                assert!(explain);
             }
             _ => panic!("Expected Search command"),
         }
@@ -1563,6 +2053,129 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parse_edit() {
+        let cli = Cli::parse_from(["grove", "edit", "cl_20260101_000", "--json"]);
+        match cli.command {
+            Commands::Edit { id, json, quiet } => {
+                assert_eq!(id, "cl_20260101_000");
+                assert!(json);
+                assert!(!quiet);
+            }
+            _ => panic!("Expected Edit command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_history() {
+        let cli = Cli::parse_from(["grove", "history", "cl_20260101_000"]);
+        match cli.command {
+            Commands::History { id, json, quiet } => {
+                assert_eq!(id, "cl_20260101_000");
+                assert!(!json);
+                assert!(!quiet);
+            }
+            _ => panic!("Expected History command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_diff_with_rev() {
+        let cli = Cli::parse_from(["grove", "diff", "cl_20260101_000", "--rev", "2"]);
+        match cli.command {
+            Commands::Diff { id, rev, .. } => {
+                assert_eq!(id, "cl_20260101_000");
+                assert_eq!(rev, Some(2));
+            }
+            _ => panic!("Expected Diff command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_backends_migrate() {
+        let cli = Cli::parse_from([
+            "grove",
+            "backends",
+            "migrate",
+            "--from",
+            "markdown",
+            "--to",
+            "total-recall",
+            "--dry-run",
+        ]);
+        match cli.command {
+            Commands::Backends {
+                action: Some(BackendsAction::Migrate { from, to, dry_run }),
+                ..
+            } => {
+                assert_eq!(from, "markdown");
+                assert_eq!(to, "total-recall");
+                assert!(dry_run);
+            }
+            _ => panic!("Expected Backends migrate command"),
+        }
+
+        let cli = Cli::parse_from(["grove", "backends", "--json"]);
+        assert!(matches!(
+            cli.command,
+            Commands::Backends {
+                action: None,
+                json: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_cli_parse_index() {
+        let cli = Cli::parse_from(["grove", "index", "rebuild"]);
+        assert!(matches!(
+            cli.command,
+            Commands::Index {
+                action: IndexAction::Rebuild,
+                json: false,
+                ..
+            }
+        ));
+
+        let cli = Cli::parse_from(["grove", "index", "status", "--json"]);
+        assert!(matches!(
+            cli.command,
+            Commands::Index {
+                action: IndexAction::Status,
+                json: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_cli_parse_export_import() {
+        let cli = Cli::parse_from(["grove", "export", "-o", "backup.jsonl", "--scope", "team"]);
+        match cli.command {
+            Commands::Export { output, scope, .. } => {
+                assert_eq!(output.as_deref(), Some("backup.jsonl"));
+                assert!(matches!(scope, Some(ScopeArg::Team)));
+            }
+            _ => panic!("Expected Export command"),
+        }
+
+        let cli = Cli::parse_from(["grove", "import", "backup.jsonl", "--on-collision", "keep"]);
+        match cli.command {
+            Commands::Import {
+                file,
+                scope,
+                on_collision,
+                ..
+            } => {
+                assert_eq!(file, "backup.jsonl");
+                assert!(scope.is_none());
+                assert!(matches!(on_collision, CollisionArg::Keep));
+            }
+            _ => panic!("Expected Import command"),
+        }
+    }
+
     #[test]
     fn test_cli_parse_maintain_list() {
         let cli = Cli::parse_from(["grove", "maintain", "list"]);
@@ -1687,17 +2300,20 @@ mod tests {
             "5",
             "--event-type",
             "GateBlocked",
+            "--explain-injections",
         ]);
         match cli.command {
             Commands::Trace {
                 session_id,
                 limit,
                 event_type,
+                explain_injections,
                 ..
             } => {
                 assert_eq!(session_id, "sess-1");
                 assert_eq!(limit, Some(5));
                 assert_eq!(event_type, Some("GateBlocked".to_string()));
+                assert!(explain_injections);
             }
             _ => panic!("Expected Trace command"),
         }
@@ -1729,6 +2345,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parse_provenance_filters() {
+        let cli = Cli::parse_from([
+            "grove",
+            "list",
+            "--origin",
+            "retroflect",
+            "--author",
+            "jane",
+            "--model",
+            "sonnet",
+        ]);
+        match cli.command {
+            Commands::List { provenance, .. } => {
+                let filter: grove::core::ProvenanceFilter = provenance.into();
+                assert_eq!(
+                    filter.origin,
+                    Some(grove::core::ProvenanceOrigin::Retroflect)
+                );
+                assert_eq!(filter.author.as_deref(), Some("jane"));
+                assert_eq!(filter.model.as_deref(), Some("sonnet"));
+                assert_eq!(filter.agent, None);
+            }
+            _ => panic!("Expected List command"),
+        }
+
+        let cli = Cli::parse_from(["grove", "stats", "--agent", "main"]);
+        match cli.command {
+            Commands::Stats { provenance, .. } => {
+                assert_eq!(provenance.agent.as_deref(), Some("main"));
+            }
+            _ => panic!("Expected Stats command"),
+        }
+    }
+
     #[test]
     fn test_cli_parse_stats() {
         let cli = Cli::parse_from(["grove", "stats", "--detailed", "--rebuild"]);
@@ -0,0 +1,283 @@
+//! Hybrid lexical + embedding retrieval.
+//!
+//! Fuses the BM25 ranking from [`TantivySearchIndex`](crate::search::TantivySearchIndex)
+//! with a ranking by cosine similarity between the query and each learning's
+//! embedding, using reciprocal rank fusion (RRF). RRF only looks at ranks, so
+//! the two score scales never need to be calibrated against each other.
+//!
+//! Embeddings come from the provider configured under `[gate.semantic_dedup]`
+//! (see [`create_provider`](crate::core::embeddings::create_provider)).
+
+use std::collections::HashMap;
+
+use crate::backends::{SearchQuery, SearchResult};
+use crate::config::HybridConfig;
+
+/// Fuse several rankings with weighted reciprocal rank fusion.
+///
+/// Each ranking lists IDs best first. An ID at 1-based rank `r` in a ranking
+/// with weight `w` contributes `w / (k + r)`; contributions are summed.
+pub fn reciprocal_rank_fusion(rankings: &[(&[String], f64)], k: f64) -> HashMap<String, f64> {
+    let mut fused: HashMap<String, f64> = HashMap::new();
+    for (ranking, weight) in rankings {
+        for (i, id) in ranking.iter().enumerate() {
+            *fused.entry(id.clone()).or_default() += weight / (k + (i + 1) as f64);
+        }
+    }
+    fused
+}
+
+/// Fuse BM25 and embedding rankings into relevance scores in (0.0, 1.0].
+///
+/// Scores are max-ratio normalized, so the best fused learning gets 1.0.
+/// Learnings in neither ranking are absent from the map.
+pub fn fused_relevance(
+    bm25_ranking: &[String],
+    embedding_ranking: &[String],
+    config: &HybridConfig,
+) -> HashMap<String, f64> {
+    let mut fused = reciprocal_rank_fusion(
+        &[
+            (bm25_ranking, config.bm25_weight),
+            (embedding_ranking, config.embedding_weight),
+        ],
+        config.rrf_k,
+    );
+
+    let max = fused.values().copied().fold(0.0_f64, f64::max);
+    if max > 0.0 {
+        for score in fused.values_mut() {
+            *score /= max;
+        }
+    }
+    fused
+}
+
+/// Replace each result's BM25 relevance with its fused hybrid relevance.
+///
+/// The BM25 ranking is read from the current relevance scores (results with
+/// relevance 0 did not match lexically and are left out of it). Results in
+/// neither ranking get relevance 0.
+pub fn fuse_results(
+    results: Vec<SearchResult>,
+    embedding_ranking: &[String],
+    config: &HybridConfig,
+) -> Vec<SearchResult> {
+    let mut lexical: Vec<&SearchResult> = results.iter().filter(|r| r.relevance > 0.0).collect();
+    lexical.sort_by(|a, b| {
+        b.relevance
+            .partial_cmp(&a.relevance)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let bm25_ranking: Vec<String> = lexical.iter().map(|r| r.learning.id.clone()).collect();
+
+    let fused = fused_relevance(&bm25_ranking, embedding_ranking, config);
+    results
+        .into_iter()
+        .map(|mut r| {
+            r.relevance = fused.get(&r.learning.id).copied().unwrap_or(0.0);
+            r
+        })
+        .collect()
+}
+
+/// Plain-text form of a query for embedding.
+///
+/// Joins keywords, tags, the ticket ID, and the meaningful segments of file
+/// paths (directory and file names without extensions).
+pub fn query_text(query: &SearchQuery) -> String {
+    let mut terms: Vec<String> = query.keywords.clone();
+    terms.extend(query.tags.iter().cloned());
+    for path in &query.files {
+        terms.extend(
+            path.split(['/', '\\', '.', '-', '_'])
+                .filter(|s| s.len() >= 3)
+                .map(String::from),
+        );
+    }
+    if let Some(ref ticket) = query.ticket_id {
+        terms.push(ticket.clone());
+    }
+    terms.join(" ")
+}
+
+/// Rank learnings by cosine similarity between `query` and their embeddings.
+///
+/// Learnings are embedded by summary (sharing the semantic dedup cache);
+/// missing embeddings are computed in one batch and added to `cache`. Only
+/// learnings at or above `min_similarity` are ranked, best first.
+pub fn embedding_ranking(
+    query: &str,
+    learnings: &[&crate::core::CompoundLearning],
+    provider: &dyn crate::core::embeddings::EmbeddingProvider,
+    cache: &mut crate::core::embeddings::EmbeddingCache,
+    min_similarity: f64,
+) -> crate::error::Result<Vec<String>> {
+    use crate::core::embeddings::cosine_similarity;
+
+    if query.trim().is_empty() || learnings.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let uncached: Vec<&crate::core::CompoundLearning> = learnings
+        .iter()
+        .copied()
+        .filter(|l| cache.get(l).is_none())
+        .collect();
+    if !uncached.is_empty() {
+        let texts: Vec<&str> = uncached.iter().map(|l| l.summary.as_str()).collect();
+        for (learning, embedding) in uncached.iter().zip(provider.embed(&texts)?) {
+            cache.insert(learning, embedding);
+        }
+    }
+
+    let query_embedding = match provider.embed(&[query])?.into_iter().next() {
+        Some(embedding) => embedding,
+        None => return Ok(Vec::new()),
+    };
+
+    let mut ranked: Vec<(String, f64)> = learnings
+        .iter()
+        .filter_map(|l| {
+            let similarity = cosine_similarity(&query_embedding, cache.get(l)?);
+            (similarity >= min_similarity).then(|| (l.id.clone(), similarity))
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    Ok(ranked.into_iter().map(|(id, _)| id).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        CompoundLearning, Confidence, LearningCategory, LearningScope, WriteGateCriterion,
+    };
+
+    fn ids(names: &[&str]) -> Vec<String> {
+        names.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn result(id: &str, relevance: f64) -> SearchResult {
+        let learning = CompoundLearning::new(
+            LearningCategory::Pattern,
+            "Summary",
+            "Detail",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::BehaviorChanging],
+            vec![],
+            "test-session",
+        )
+        .with_id(id);
+        SearchResult::new(learning, relevance)
+    }
+
+    #[test]
+    fn test_rrf_rewards_agreement() {
+        let bm25 = ids(&["a", "b", "c"]);
+        let embedding = ids(&["b", "d"]);
+        let fused = reciprocal_rank_fusion(&[(&bm25, 1.0), (&embedding, 1.0)], 60.0);
+
+        assert!((fused["a"] - 1.0 / 61.0).abs() < 1e-12);
+        assert!((fused["b"] - (1.0 / 62.0 + 1.0 / 61.0)).abs() < 1e-12);
+        assert!(fused["b"] > fused["a"], "found by both rankings wins");
+        assert!(fused["d"] < fused["a"]);
+        assert_eq!(fused.len(), 4);
+    }
+
+    #[test]
+    fn test_fuse_results_normalizes_and_zeroes_unranked() {
+        let results = vec![
+            result("lexical", 0.9),
+            result("both", 0.4),
+            result("semantic", 0.0),
+            result("neither", 0.0),
+        ];
+        let config = HybridConfig::default();
+        let fused = fuse_results(results, &ids(&["semantic", "both"]), &config);
+
+        let score = |id: &str| {
+            fused
+                .iter()
+                .find(|r| r.learning.id == id)
+                .unwrap()
+                .relevance
+        };
+        assert!((score("both") - 1.0).abs() < f64::EPSILON);
+        assert!(score("lexical") > 0.0 && score("lexical") < 1.0);
+        assert!(score("semantic") > 0.0);
+        assert_eq!(score("neither"), 0.0);
+
+        // With the embedding ranking weighted out, only BM25 order counts
+        let config = HybridConfig {
+            embedding_weight: 0.0,
+            ..HybridConfig::default()
+        };
+        let fused = fuse_results(fused, &ids(&["semantic", "both"]), &config);
+        let best = fused
+            .iter()
+            .max_by(|a, b| a.relevance.partial_cmp(&b.relevance).unwrap())
+            .unwrap();
+        assert_eq!(best.learning.id, "both");
+    }
+
+    #[test]
+    fn test_embedding_ranking_orders_by_similarity_and_caches() {
+        use crate::core::embeddings::{EmbeddingCache, EmbeddingProvider};
+
+        /// Embeds text as counts of a tiny fixed vocabulary.
+        struct VocabProvider;
+        impl EmbeddingProvider for VocabProvider {
+            fn embed(&self, texts: &[&str]) -> crate::error::Result<Vec<Vec<f32>>> {
+                let vocab = ["retry", "network", "database", "timeout"];
+                Ok(texts
+                    .iter()
+                    .map(|t| {
+                        vocab
+                            .iter()
+                            .map(|w| t.to_lowercase().matches(w).count() as f32)
+                            .collect()
+                    })
+                    .collect())
+            }
+
+            fn model_id(&self) -> String {
+                "vocab".to_string()
+            }
+        }
+
+        let mut network = result("network", 0.0).learning;
+        network.summary = "Retry network calls on timeout".to_string();
+        let mut database = result("database", 0.0).learning;
+        database.summary = "Database migrations need a timeout".to_string();
+        let mut unrelated = result("unrelated", 0.0).learning;
+        unrelated.summary = "Prefer small commits".to_string();
+
+        let mut cache = EmbeddingCache::default();
+        let ranking = embedding_ranking(
+            "network retry",
+            &[&database, &unrelated, &network],
+            &VocabProvider,
+            &mut cache,
+            0.3,
+        )
+        .unwrap();
+
+        assert_eq!(ranking, ids(&["network"]));
+        assert_eq!(cache.entries.len(), 3, "learning embeddings are cached");
+    }
+
+    #[test]
+    fn test_query_text_includes_path_segments() {
+        let query = SearchQuery::new()
+            .keywords(vec!["retry".to_string()])
+            .tags(vec!["network".to_string()])
+            .files(vec!["src/http/client_pool.rs".to_string()])
+            .ticket_id("T-42");
+        assert_eq!(
+            query_text(&query),
+            "retry network src http client pool T-42"
+        );
+    }
+}
@@ -33,9 +33,26 @@
 //! - **Stemming**: "tracking" matches "track", "writes" matches "write"
 //! - **Fuzzy fallback**: When few results found, retries with typo tolerance
 //! - **BM25 scoring**: Results ranked by relevance with field boosts
+//!
+//! # Persistent Index
+//!
+//! [`ProjectIndex`] keeps a project's index on disk under `.grove/index/`,
+//! updated incrementally as learnings are written and rebuilt when its
+//! manifest no longer matches the backend.
+//!
+//! # Hybrid Retrieval
+//!
+//! [`hybrid`] fuses BM25 with embedding similarity by reciprocal rank fusion
+//! for `scoring_backend = "hybrid"`.
 
+#[cfg(feature = "tantivy-search")]
+pub mod hybrid;
+#[cfg(feature = "tantivy-search")]
+pub mod project_index;
 #[cfg(feature = "tantivy-search")]
 pub mod tantivy_backend;
 
+#[cfg(feature = "tantivy-search")]
+pub use project_index::{IndexDrift, IndexManifest, ProjectIndex};
 #[cfg(feature = "tantivy-search")]
 pub use tantivy_backend::{escape_query_term, TantivySearchIndex, TantivySearchResult};
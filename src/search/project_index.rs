@@ -0,0 +1,373 @@
+//! On-disk search index for a project's learnings.
+//!
+//! The index lives under `.grove/index/`: Tantivy segments in `tantivy/`
+//! and a `manifest.json` that records a content hash for every indexed
+//! learning. Writes through [`IndexedBackend`](crate::backends::IndexedBackend)
+//! keep it current one learning at a time. When learnings change behind
+//! Grove's back (for example `learnings.md` edited by hand), the hashes no
+//! longer match the backend and [`ProjectIndex::sync`] rebuilds the index.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::{CompoundLearning, LearningStatus};
+use crate::error::{GroveError, Result};
+use crate::search::TantivySearchIndex;
+
+/// Bump when the indexed fields or schema change, forcing a rebuild.
+pub const INDEX_FORMAT_VERSION: u32 = 1;
+
+/// Manifest file name inside the index directory.
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// Subdirectory holding the Tantivy segments.
+const TANTIVY_DIR: &str = "tantivy";
+
+/// What the on-disk index believes it contains.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IndexManifest {
+    /// Index format version (see [`INDEX_FORMAT_VERSION`]).
+    #[serde(default)]
+    pub format_version: u32,
+    /// Grove version that last wrote the index.
+    #[serde(default)]
+    pub grove_version: String,
+    /// When the index was last rebuilt from scratch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub built_at: Option<DateTime<Utc>>,
+    /// When the index was last changed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+    /// Content hash of every indexed learning, keyed by learning ID.
+    #[serde(default)]
+    pub documents: BTreeMap<String, String>,
+}
+
+/// How the index differs from a set of learnings.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IndexDrift {
+    /// Active learnings the index does not contain.
+    pub missing: usize,
+    /// Indexed learnings whose content has changed.
+    pub changed: usize,
+    /// Indexed learnings that are gone or no longer active.
+    pub removed: usize,
+}
+
+impl IndexDrift {
+    /// Whether the index matches the learnings exactly.
+    pub fn is_empty(&self) -> bool {
+        self.missing == 0 && self.changed == 0 && self.removed == 0
+    }
+}
+
+/// A project's persistent search index and its manifest.
+pub struct ProjectIndex {
+    dir: PathBuf,
+    index: TantivySearchIndex,
+    manifest: IndexManifest,
+}
+
+impl ProjectIndex {
+    /// Whether an index has been built in `dir`.
+    pub fn exists(dir: &Path) -> bool {
+        dir.join(MANIFEST_FILE).exists()
+    }
+
+    /// Open the index in `dir`, creating it if needed.
+    ///
+    /// An index that cannot be opened (corrupt or written by an incompatible
+    /// Tantivy version) is wiped and recreated empty, so the next
+    /// [`sync`](Self::sync) rebuilds it.
+    pub fn open(dir: &Path) -> Result<Self> {
+        let tantivy_dir = dir.join(TANTIVY_DIR);
+        let manifest = read_manifest(&dir.join(MANIFEST_FILE));
+
+        let (index, manifest) = match TantivySearchIndex::persistent(&tantivy_dir) {
+            Ok(index) => (index, manifest),
+            Err(e) => {
+                tracing::warn!("Search index unreadable, recreating: {}", e);
+                let _ = fs::remove_dir_all(&tantivy_dir);
+                (
+                    TantivySearchIndex::persistent(&tantivy_dir)?,
+                    IndexManifest::default(),
+                )
+            }
+        };
+
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            index,
+            manifest,
+        })
+    }
+
+    /// The underlying Tantivy index.
+    pub fn index(&self) -> &TantivySearchIndex {
+        &self.index
+    }
+
+    /// The index manifest.
+    pub fn manifest(&self) -> &IndexManifest {
+        &self.manifest
+    }
+
+    /// The index directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Compare the index against `learnings` (only active ones are indexed).
+    pub fn drift(&self, learnings: &[CompoundLearning]) -> IndexDrift {
+        let current = fingerprint(learnings);
+        let mut drift = IndexDrift::default();
+        for (id, hash) in &current {
+            match self.manifest.documents.get(id) {
+                None => drift.missing += 1,
+                Some(indexed) if indexed != hash => drift.changed += 1,
+                Some(_) => {}
+            }
+        }
+        drift.removed = self
+            .manifest
+            .documents
+            .keys()
+            .filter(|id| !current.contains_key(*id))
+            .count();
+        drift
+    }
+
+    /// Whether the index is up to date with `learnings`.
+    pub fn is_current(&self, learnings: &[CompoundLearning]) -> bool {
+        self.manifest.format_version == INDEX_FORMAT_VERSION
+            && self.index.num_docs() == self.manifest.documents.len() as u64
+            && self.drift(learnings).is_empty()
+    }
+
+    /// Rebuild the index if it no longer matches `learnings`.
+    ///
+    /// Returns `true` when a rebuild happened.
+    pub fn sync(&mut self, learnings: &[CompoundLearning]) -> Result<bool> {
+        if self.is_current(learnings) {
+            return Ok(false);
+        }
+        self.rebuild(learnings)?;
+        Ok(true)
+    }
+
+    /// Drop everything and index the active `learnings` from scratch.
+    pub fn rebuild(&mut self, learnings: &[CompoundLearning]) -> Result<()> {
+        let active: Vec<CompoundLearning> = learnings
+            .iter()
+            .filter(|l| l.status == LearningStatus::Active)
+            .cloned()
+            .collect();
+
+        self.index.clear()?;
+        self.index.index_learnings(&active)?;
+
+        let now = Utc::now();
+        self.manifest = IndexManifest {
+            format_version: INDEX_FORMAT_VERSION,
+            grove_version: env!("CARGO_PKG_VERSION").to_string(),
+            built_at: Some(now),
+            updated_at: Some(now),
+            documents: fingerprint(&active),
+        };
+        self.save_manifest()
+    }
+
+    /// Add or replace one learning, or drop it when it is not active.
+    pub fn upsert(&mut self, learning: &CompoundLearning) -> Result<()> {
+        if learning.status != LearningStatus::Active {
+            return self.remove(&learning.id);
+        }
+
+        self.index.index_learnings(std::slice::from_ref(learning))?;
+        self.manifest
+            .documents
+            .insert(learning.id.clone(), content_hash(learning));
+        self.touch();
+        self.save_manifest()
+    }
+
+    /// Remove one learning from the index.
+    pub fn remove(&mut self, learning_id: &str) -> Result<()> {
+        self.index.delete_learnings(&[learning_id])?;
+        self.manifest.documents.remove(learning_id);
+        self.touch();
+        self.save_manifest()
+    }
+
+    fn touch(&mut self) {
+        self.manifest.updated_at = Some(Utc::now());
+        self.manifest.grove_version = env!("CARGO_PKG_VERSION").to_string();
+    }
+
+    /// Write the manifest atomically (temp file + rename).
+    fn save_manifest(&self) -> Result<()> {
+        let path = self.dir.join(MANIFEST_FILE);
+        let content = serde_json::to_string_pretty(&self.manifest)
+            .map_err(|e| GroveError::serde(format!("Failed to serialize manifest: {}", e)))?;
+
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, content).map_err(|e| {
+            GroveError::backend(format!("Failed to write {}: {}", temp_path.display(), e))
+        })?;
+        fs::rename(&temp_path, &path).map_err(|e| {
+            GroveError::backend(format!(
+                "Failed to rename {} to {}: {}",
+                temp_path.display(),
+                path.display(),
+                e
+            ))
+        })
+    }
+}
+
+/// Read a manifest, treating a missing or unparseable file as empty.
+fn read_manifest(path: &Path) -> IndexManifest {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Content hashes of the active learnings, keyed by ID.
+fn fingerprint(learnings: &[CompoundLearning]) -> BTreeMap<String, String> {
+    learnings
+        .iter()
+        .filter(|l| l.status == LearningStatus::Active)
+        .map(|l| (l.id.clone(), content_hash(l)))
+        .collect()
+}
+
+/// Hash the fields that feed the index.
+///
+/// Uses 64-bit FNV-1a so the value is stable across Rust releases and can
+/// be stored on disk.
+pub fn content_hash(learning: &CompoundLearning) -> String {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let category = format!("{:?}", learning.category);
+    let tags = learning.tags.join("\u{1f}");
+    let fields = [
+        learning.id.as_str(),
+        learning.summary.as_str(),
+        learning.detail.as_str(),
+        tags.as_str(),
+        category.as_str(),
+        learning.relevance_context.as_deref().unwrap_or_default(),
+    ];
+
+    let mut hash = OFFSET;
+    for field in fields {
+        for byte in field.bytes().chain(std::iter::once(0)) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(PRIME);
+        }
+    }
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Confidence, LearningCategory, LearningScope, WriteGateCriterion};
+    use tempfile::TempDir;
+
+    fn learning(id: &str, summary: &str) -> CompoundLearning {
+        CompoundLearning::new(
+            LearningCategory::Pitfall,
+            summary,
+            "Detail text for the index.",
+            LearningScope::Project,
+            Confidence::High,
+            vec![WriteGateCriterion::BehaviorChanging],
+            vec!["rust".to_string()],
+            "test-session",
+        )
+        .with_id(id)
+    }
+
+    #[test]
+    fn test_sync_builds_then_detects_hand_edits() {
+        let dir = TempDir::new().unwrap();
+        let mut learnings = vec![
+            learning("cl_001", "Use atomic writes for session files"),
+            learning("cl_002", "Avoid blocking calls in hooks"),
+        ];
+
+        let mut index = ProjectIndex::open(dir.path()).unwrap();
+        assert!(index.sync(&learnings).unwrap(), "first sync builds");
+        assert!(ProjectIndex::exists(dir.path()));
+        assert_eq!(index.index().num_docs(), 2);
+
+        // Reopening with the same learnings is current
+        let mut index = ProjectIndex::open(dir.path()).unwrap();
+        assert!(!index.sync(&learnings).unwrap());
+
+        // A hand edit changes the content hash and triggers a rebuild
+        learnings[1].summary = "Never block on network calls in hooks".to_string();
+        assert_eq!(
+            index.drift(&learnings),
+            IndexDrift {
+                missing: 0,
+                changed: 1,
+                removed: 0
+            }
+        );
+        assert!(index.sync(&learnings).unwrap());
+        let results = index.index().search("network", 10).unwrap();
+        assert_eq!(results[0].id, "cl_002");
+    }
+
+    #[test]
+    fn test_incremental_updates_keep_index_current() {
+        let dir = TempDir::new().unwrap();
+        let mut learnings = vec![learning("cl_001", "Use atomic writes for session files")];
+
+        let mut index = ProjectIndex::open(dir.path()).unwrap();
+        index.rebuild(&learnings).unwrap();
+
+        let added = learning("cl_002", "Avoid blocking calls in hooks");
+        index.upsert(&added).unwrap();
+        learnings.push(added);
+        assert!(index.is_current(&learnings));
+
+        let mut archived = learnings[0].clone();
+        archived.status = LearningStatus::Archived;
+        index.upsert(&archived).unwrap();
+        learnings[0] = archived;
+        assert!(index.is_current(&learnings));
+        assert_eq!(index.index().num_docs(), 1);
+
+        index.remove("cl_002").unwrap();
+        assert_eq!(
+            index.drift(&learnings),
+            IndexDrift {
+                missing: 1,
+                changed: 0,
+                removed: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_content_hash_tracks_indexed_fields() {
+        let a = learning("cl_001", "Use atomic writes");
+        let mut b = a.clone();
+        assert_eq!(content_hash(&a), content_hash(&b));
+
+        b.confidence = Confidence::Low;
+        assert_eq!(content_hash(&a), content_hash(&b), "not indexed");
+
+        b.tags.push("io".to_string());
+        assert_ne!(content_hash(&a), content_hash(&b));
+    }
+}
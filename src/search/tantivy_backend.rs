@@ -188,14 +188,7 @@ impl TantivySearchIndex {
     /// If a learning with the same ID already exists, it will be replaced.
     /// This prevents duplicate documents when re-indexing.
     pub fn index_learnings(&self, learnings: &[CompoundLearning]) -> Result<()> {
-        let mut writer: IndexWriter = self.index.writer(INDEX_WRITER_HEAP_SIZE).map_err(|e| {
-            GroveError::backend(format!(
-                "Failed to allocate {}MB for index writer: {}. \
-                     System may be memory constrained.",
-                INDEX_WRITER_HEAP_SIZE / 1_000_000,
-                e
-            ))
-        })?;
+        let writer = self.writer()?;
 
         for learning in learnings {
             // Delete any existing document with this ID (upsert behavior)
@@ -219,11 +212,47 @@ impl TantivySearchIndex {
                 .map_err(|e| GroveError::backend(format!("Failed to add document: {}", e)))?;
         }
 
+        self.commit(writer)
+    }
+
+    /// Remove learnings from the index by ID.
+    ///
+    /// IDs that are not indexed are ignored.
+    pub fn delete_learnings(&self, ids: &[&str]) -> Result<()> {
+        let writer = self.writer()?;
+        for id in ids {
+            writer.delete_term(Term::from_field_text(self.id_field, id));
+        }
+        self.commit(writer)
+    }
+
+    /// Remove every document from the index.
+    pub fn clear(&self) -> Result<()> {
+        let writer = self.writer()?;
+        writer
+            .delete_all_documents()
+            .map_err(|e| GroveError::backend(format!("Failed to clear index: {}", e)))?;
+        self.commit(writer)
+    }
+
+    /// Open a writer with Grove's heap budget.
+    fn writer(&self) -> Result<IndexWriter> {
+        self.index.writer(INDEX_WRITER_HEAP_SIZE).map_err(|e| {
+            GroveError::backend(format!(
+                "Failed to allocate {}MB for index writer: {}. \
+                     System may be memory constrained.",
+                INDEX_WRITER_HEAP_SIZE / 1_000_000,
+                e
+            ))
+        })
+    }
+
+    /// Commit pending changes and reload the reader to see them.
+    fn commit(&self, mut writer: IndexWriter) -> Result<()> {
         writer
             .commit()
             .map_err(|e| GroveError::backend(format!("Failed to commit: {}", e)))?;
 
-        // Reload the reader to see the new documents
         self.reader
             .reload()
             .map_err(|e| GroveError::backend(format!("Failed to reload reader: {}", e)))?;
@@ -686,6 +715,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_delete_learnings_and_clear() {
+        let index = TantivySearchIndex::in_memory().unwrap();
+        let learnings = sample_learnings();
+        index.index_learnings(&learnings).unwrap();
+
+        index
+            .delete_learnings(&[learnings[0].id.as_str(), "cl_missing"])
+            .unwrap();
+        assert_eq!(index.num_docs(), 2);
+        let results = index.search("tissue", 10).unwrap();
+        assert!(results.iter().all(|r| r.id != learnings[0].id));
+
+        index.clear().unwrap();
+        assert_eq!(index.num_docs(), 0);
+    }
+
     #[test]
     fn test_search_by_category() {
         let index = TantivySearchIndex::in_memory().unwrap();
@@ -10,7 +10,7 @@ use std::path::{Path, PathBuf};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::core::LearningCategory;
+use crate::core::{GuardSeverity, LearningCategory};
 use crate::error::{GroveError, Result};
 use crate::stats::{StatsEvent, StatsEventType, StatsLogger};
 use crate::util::sync_parent_dir;
@@ -62,6 +62,12 @@ pub struct StatsCache {
     /// Recent rejected candidates for retrospective miss detection.
     #[serde(default)]
     pub recent_rejected: Vec<RejectedCandidateSummary>,
+    /// Injection size statistics.
+    #[serde(default)]
+    pub injections: InjectionStats,
+    /// Command guard statistics.
+    #[serde(default)]
+    pub guards: GuardStats,
 }
 
 impl Default for StatsCache {
@@ -78,6 +84,8 @@ impl Default for StatsCache {
             skipped_tickets: std::collections::HashSet::new(),
             skipped_files: std::collections::HashSet::new(),
             recent_rejected: Vec::new(),
+            injections: InjectionStats::default(),
+            guards: GuardStats::default(),
         }
     }
 }
@@ -286,6 +294,42 @@ impl StatsCache {
             StatsEventType::Retroflect { .. } => {
                 // Retroflect events are tracked but don't affect the cache aggregates yet.
             }
+
+            StatsEventType::Edited { .. } | StatsEventType::Revised { .. } => {
+                // Edits and revisions change content, not usage, so aggregates are unaffected.
+            }
+
+            StatsEventType::Injected {
+                session_id,
+                tokens,
+                budget,
+                learnings: _,
+                trimmed,
+            } => {
+                let injections = &mut self.injections;
+                injections.count += 1;
+                injections.total_tokens += *tokens as u64;
+                injections.trimmed += *trimmed as u32;
+                if *budget > 0 {
+                    injections.budget_tokens += *budget as u64;
+                    injections.budgeted_tokens += *tokens as u64;
+                }
+                *injections.by_session.entry(session_id.clone()).or_insert(0) += *tokens as u64;
+            }
+
+            StatsEventType::GuardFired {
+                learning_id,
+                session_id: _,
+                pattern: _,
+                severity,
+            } => {
+                let guards = &mut self.guards;
+                match severity {
+                    GuardSeverity::Warn => guards.warned += 1,
+                    GuardSeverity::Block => guards.blocked += 1,
+                }
+                *guards.by_learning.entry(learning_id.clone()).or_insert(0) += 1;
+            }
         }
     }
 
@@ -573,6 +617,67 @@ pub struct WriteGateStats {
     pub(crate) specificity_sum: f64,
 }
 
+/// Injection size statistics.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct InjectionStats {
+    /// Number of injections.
+    pub count: u32,
+    /// Approximate tokens of learning text injected, across all injections.
+    pub total_tokens: u64,
+    /// Learnings injected with trimmed detail.
+    pub trimmed: u32,
+    /// Sum of the token budgets of budgeted injections.
+    pub budget_tokens: u64,
+    /// Tokens injected by budgeted injections.
+    pub budgeted_tokens: u64,
+    /// Tokens injected per session.
+    pub by_session: HashMap<String, u64>,
+}
+
+impl InjectionStats {
+    /// Average tokens injected per session.
+    pub fn avg_tokens_per_session(&self) -> f64 {
+        if self.by_session.is_empty() {
+            return 0.0;
+        }
+        self.total_tokens as f64 / self.by_session.len() as f64
+    }
+
+    /// Average tokens per injection.
+    pub fn avg_tokens_per_injection(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        self.total_tokens as f64 / self.count as f64
+    }
+
+    /// Fraction of the token budget used by budgeted injections, if any.
+    pub fn budget_used(&self) -> Option<f64> {
+        if self.budget_tokens == 0 {
+            return None;
+        }
+        Some(self.budgeted_tokens as f64 / self.budget_tokens as f64)
+    }
+}
+
+/// Command guard statistics.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct GuardStats {
+    /// Guard matches that warned before the command ran.
+    pub warned: u32,
+    /// Guard matches that blocked the command.
+    pub blocked: u32,
+    /// Guard matches per learning.
+    pub by_learning: HashMap<String, u32>,
+}
+
+impl GuardStats {
+    /// Total guard matches.
+    pub fn total(&self) -> u32 {
+        self.warned + self.blocked
+    }
+}
+
 /// Cross-pollination edge (learning referenced outside origin ticket).
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct CrossPollinationEdge {
@@ -1047,6 +1152,59 @@ mod tests {
         assert_eq!(cache.reflections.skipped, 2);
     }
 
+    #[test]
+    fn test_injection_tracking() {
+        let events = vec![
+            StatsEvent::new(StatsEventType::injected("s1", 600, 1500, 3, 0)),
+            StatsEvent::new(StatsEventType::injected("s1", 300, 1500, 1, 1)),
+            StatsEvent::new(StatsEventType::injected("s2", 900, 0, 5, 0)),
+        ];
+
+        let cache = StatsCache::from_events(&events);
+        let injections = &cache.injections;
+
+        assert_eq!(injections.count, 3);
+        assert_eq!(injections.total_tokens, 1800);
+        assert_eq!(injections.trimmed, 1);
+        assert_eq!(injections.by_session.get("s1"), Some(&900));
+        assert!((injections.avg_tokens_per_session() - 900.0).abs() < f64::EPSILON);
+        assert!((injections.avg_tokens_per_injection() - 600.0).abs() < f64::EPSILON);
+        // Only the budgeted injections count toward budget use
+        assert!((injections.budget_used().unwrap() - 0.3).abs() < f64::EPSILON);
+        assert_eq!(InjectionStats::default().budget_used(), None);
+    }
+
+    #[test]
+    fn test_guard_tracking() {
+        let events = vec![
+            StatsEvent::new(StatsEventType::guard_fired(
+                "L001",
+                "s1",
+                "git push --force",
+                GuardSeverity::Block,
+            )),
+            StatsEvent::new(StatsEventType::guard_fired(
+                "L001",
+                "s2",
+                "git push --force",
+                GuardSeverity::Block,
+            )),
+            StatsEvent::new(StatsEventType::guard_fired(
+                "L002",
+                "s2",
+                "/rm -rf/",
+                GuardSeverity::Warn,
+            )),
+        ];
+
+        let cache = StatsCache::from_events(&events);
+
+        assert_eq!(cache.guards.warned, 1);
+        assert_eq!(cache.guards.blocked, 2);
+        assert_eq!(cache.guards.total(), 3);
+        assert_eq!(cache.guards.by_learning.get("L001"), Some(&2));
+    }
+
     #[test]
     fn test_write_gate_stats() {
         let events = vec![
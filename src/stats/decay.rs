@@ -8,8 +8,11 @@
 //! 2. If now - last_verified > passive_duration_days → archive
 //! 3. Learnings with hit_rate > immunity_hit_rate are immune
 //! 4. Decay checks are throttled to once per day
+//!
+//! Learnings past their `expires_at` date are archived with the `expired`
+//! reason regardless of hit rate.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use chrono::{DateTime, Duration, Utc};
 
@@ -27,12 +30,23 @@ pub enum DecayResult {
     Immune,
     /// Learning is already archived.
     AlreadyArchived,
+    /// Learning should be archived because its expiry date has passed.
+    Expired,
 }
 
 impl DecayResult {
     /// Whether this result means the learning should be archived.
     pub fn should_archive(&self) -> bool {
-        matches!(self, Self::Decayed)
+        matches!(self, Self::Decayed | Self::Expired)
+    }
+
+    /// Reason logged with the archived event, if the learning is archived.
+    pub fn archive_reason(&self) -> Option<&'static str> {
+        match self {
+            Self::Decayed => Some("passive_decay"),
+            Self::Expired => Some("expired"),
+            _ => None,
+        }
     }
 }
 
@@ -150,7 +164,7 @@ pub fn should_run_decay_check(last_decay_check: Option<DateTime<Utc>>, now: Date
 /// * `now` - Current timestamp
 pub fn run_decay_evaluation(
     cache: &StatsCache,
-    learning_timestamps: &std::collections::HashMap<String, DateTime<Utc>>,
+    learning_timestamps: &HashMap<String, DateTime<Utc>>,
     config: &DecayConfig,
     now: DateTime<Utc>,
 ) -> Vec<String> {
@@ -171,24 +185,50 @@ pub fn run_decay_evaluation(
     decayed
 }
 
+/// Find learnings whose expiry date has passed.
+///
+/// Learnings already archived in the cache are skipped. Learnings without
+/// stats are still reported, since expiry doesn't depend on usage.
+///
+/// # Arguments
+/// * `cache` - The stats cache with learning stats
+/// * `learning_expiries` - Map of learning_id to expires_at timestamp
+/// * `now` - Current timestamp
+pub fn find_expired(
+    cache: &StatsCache,
+    learning_expiries: &HashMap<String, DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> Vec<String> {
+    let mut expired: Vec<String> = learning_expiries
+        .iter()
+        .filter(|(_, expires_at)| **expires_at <= now)
+        .filter(|(id, _)| !cache.learnings.get(*id).is_some_and(|s| s.archived))
+        .map(|(id, _)| id.clone())
+        .collect();
+    expired.sort();
+    expired
+}
+
 /// Run decay and log archived events.
 ///
 /// This is the main entry point for decay processing. It:
 /// 1. Checks if a decay check should run (throttling)
-/// 2. Evaluates all learnings for decay
-/// 3. Logs archived events for decayed learnings
-/// 4. Returns the list of decayed learning IDs
+/// 2. Archives learnings past their expiry date (reason `expired`)
+/// 3. Evaluates the remaining learnings for decay (reason `passive_decay`)
+/// 4. Returns the list of archived learning IDs
 ///
 /// # Arguments
 /// * `cache` - The stats cache (mutable for updating last_decay_check)
 /// * `learning_timestamps` - Map of learning_id to created_at
+/// * `learning_expiries` - Map of learning_id to expires_at
 /// * `config` - Decay configuration
 /// * `logger` - Stats logger for appending events
 /// * `now` - Current timestamp
 /// * `force` - If true, skip throttle check (for maintenance)
 pub fn run_decay_and_log(
     cache: &mut StatsCache,
-    learning_timestamps: &std::collections::HashMap<String, DateTime<Utc>>,
+    learning_timestamps: &HashMap<String, DateTime<Utc>>,
+    learning_expiries: &HashMap<String, DateTime<Utc>>,
     config: &DecayConfig,
     logger: &StatsLogger,
     now: DateTime<Utc>,
@@ -199,12 +239,23 @@ pub fn run_decay_and_log(
         return Ok(vec![]);
     }
 
-    // Run evaluation
-    let decayed = run_decay_evaluation(cache, learning_timestamps, config, now);
+    // Expiry takes precedence over passive decay
+    let expired = find_expired(cache, learning_expiries, now);
+    let decayed: Vec<String> = run_decay_evaluation(cache, learning_timestamps, config, now)
+        .into_iter()
+        .filter(|id| !expired.contains(id))
+        .collect();
+
+    let archived = expired
+        .iter()
+        .map(|id| (id, DecayResult::Expired))
+        .chain(decayed.iter().map(|id| (id, DecayResult::Decayed)));
 
-    // Log archived events for each decayed learning
-    for learning_id in &decayed {
-        logger.append_archived(learning_id, "passive_decay")?;
+    // Log archived events for each archived learning
+    for (learning_id, result) in archived {
+        if let Some(reason) = result.archive_reason() {
+            logger.append_archived(learning_id, reason)?;
+        }
 
         // Mark as archived in the cache
         if let Some(stats) = cache.learnings.get_mut(learning_id) {
@@ -215,7 +266,7 @@ pub fn run_decay_and_log(
     // Update last decay check
     cache.set_last_decay_check(now);
 
-    Ok(decayed)
+    Ok(expired.into_iter().chain(decayed).collect())
 }
 
 /// Get learnings approaching decay threshold.
@@ -224,7 +275,7 @@ pub fn run_decay_and_log(
 /// Useful for generating decay warnings in the insights engine.
 pub fn get_decay_warnings(
     cache: &StatsCache,
-    learning_timestamps: &std::collections::HashMap<String, DateTime<Utc>>,
+    learning_timestamps: &HashMap<String, DateTime<Utc>>,
     config: &DecayConfig,
     warning_days: u32,
     now: DateTime<Utc>,
@@ -303,7 +354,7 @@ pub fn get_immune_learnings(cache: &StatsCache, config: &DecayConfig) -> HashSet
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashMap;
+    use crate::stats::StatsEventType;
     use tempfile::TempDir;
 
     fn default_config() -> DecayConfig {
@@ -593,8 +644,16 @@ mod tests {
         let mut timestamps = HashMap::new();
         timestamps.insert("L001".to_string(), now - Duration::days(100));
 
-        let decayed =
-            run_decay_and_log(&mut cache, &timestamps, &config, &logger, now, true).unwrap();
+        let decayed = run_decay_and_log(
+            &mut cache,
+            &timestamps,
+            &HashMap::new(),
+            &config,
+            &logger,
+            now,
+            true,
+        )
+        .unwrap();
 
         assert_eq!(decayed.len(), 1);
         assert!(decayed.contains(&"L001".to_string()));
@@ -627,18 +686,82 @@ mod tests {
         timestamps.insert("L001".to_string(), now - Duration::days(100));
 
         // Without force, should be throttled
-        let decayed =
-            run_decay_and_log(&mut cache, &timestamps, &config, &logger, now, false).unwrap();
+        let decayed = run_decay_and_log(
+            &mut cache,
+            &timestamps,
+            &HashMap::new(),
+            &config,
+            &logger,
+            now,
+            false,
+        )
+        .unwrap();
 
         assert!(decayed.is_empty());
 
         // With force, should run
-        let decayed =
-            run_decay_and_log(&mut cache, &timestamps, &config, &logger, now, true).unwrap();
+        let decayed = run_decay_and_log(
+            &mut cache,
+            &timestamps,
+            &HashMap::new(),
+            &config,
+            &logger,
+            now,
+            true,
+        )
+        .unwrap();
 
         assert_eq!(decayed.len(), 1);
     }
 
+    #[test]
+    fn test_run_decay_and_log_archives_expired() {
+        let temp = TempDir::new().unwrap();
+        let log_path = temp.path().join("stats.log");
+        let logger = StatsLogger::new(&log_path);
+
+        let now = Utc::now();
+        let config = default_config();
+
+        // L001 is immune to decay but expired; L002 has no stats and is expired;
+        // L003 expires in the future
+        let mut cache = StatsCache::new();
+        cache
+            .learnings
+            .insert("L001".to_string(), make_stats(None, None, 0.9, false));
+
+        let mut expiries = HashMap::new();
+        expiries.insert("L001".to_string(), now - Duration::days(1));
+        expiries.insert("L002".to_string(), now - Duration::days(1));
+        expiries.insert("L003".to_string(), now + Duration::days(1));
+
+        let archived = run_decay_and_log(
+            &mut cache,
+            &HashMap::new(),
+            &expiries,
+            &config,
+            &logger,
+            now,
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(archived, vec!["L001", "L002"]);
+        assert!(cache.learnings.get("L001").unwrap().archived);
+
+        let events = logger.read_all().unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(events.iter().all(|e| matches!(
+            &e.data,
+            StatsEventType::Archived { reason, .. } if reason == "expired"
+        )));
+
+        // Already archived learnings are not archived again
+        assert!(find_expired(&cache, &expiries, now)
+            .iter()
+            .all(|id| id != "L001"));
+    }
+
     // Warning tests
 
     #[test]
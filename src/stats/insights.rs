@@ -13,6 +13,7 @@
 //! - WriteGateTooStrict: Write gate pass rate < 0.5
 //! - WriteGateTooLoose: Write gate pass rate > 0.95 but hit rate < 0.3
 //! - SkipMiss: Skipped session on ticket later produced learnings
+//! - ReviewDue: Learnings past their review-by date
 
 use std::collections::HashMap;
 
@@ -77,6 +78,8 @@ pub enum InsightKind {
     SkipMiss,
     /// Learning surfaced many times but rarely or never referenced.
     OverSurfacedLearning,
+    /// Learnings past their review-by date.
+    ReviewDue,
 }
 
 impl InsightKind {
@@ -93,6 +96,7 @@ impl InsightKind {
             Self::WriteGateTooLoose => "Write Gate Too Loose",
             Self::SkipMiss => "Skip Miss",
             Self::OverSurfacedLearning => "Over-Surfaced Learning",
+            Self::ReviewDue => "Review Due",
         }
     }
 }
@@ -687,7 +691,7 @@ pub fn generate_over_surfaced_learning_insight(
     }
 
     // Sort by surfaced count descending (worst offenders first)
-    over_surfaced.sort_by(|a, b| b.1.surfaced.cmp(&a.1.surfaced));
+    over_surfaced.sort_by_key(|o| std::cmp::Reverse(o.1.surfaced));
 
     let count = over_surfaced.len();
     let (worst_id, worst_stats) = over_surfaced[0];
@@ -715,6 +719,54 @@ pub fn generate_over_surfaced_learning_insight(
     ))
 }
 
+/// Generate a review-due insight.
+///
+/// Flags learnings whose review-by date has passed. Learnings archived in the
+/// cache are skipped.
+pub fn generate_review_due_insight(
+    cache: &StatsCache,
+    learning_review_dates: &HashMap<String, DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> Option<Insight> {
+    let mut due: Vec<(&String, &DateTime<Utc>)> = learning_review_dates
+        .iter()
+        .filter(|(_, review_by)| **review_by <= now)
+        .filter(|(id, _)| !cache.learnings.get(*id).is_some_and(|s| s.archived))
+        .collect();
+
+    if due.is_empty() {
+        return None;
+    }
+
+    // Most overdue first
+    due.sort_by_key(|(id, review_by)| (**review_by, (*id).clone()));
+
+    let count = due.len();
+    let (oldest_id, oldest_date) = due[0];
+
+    let message = if count == 1 {
+        format!(
+            "Learning '{}' was due for review on {}",
+            oldest_id,
+            oldest_date.format("%Y-%m-%d")
+        )
+    } else {
+        format!(
+            "{} learnings past their review date (oldest: '{}', due {})",
+            count,
+            oldest_id,
+            oldest_date.format("%Y-%m-%d")
+        )
+    };
+
+    Some(Insight::new(
+        InsightKind::ReviewDue,
+        message,
+        "Run `grove maintain list` and confirm, edit, or archive them",
+        2, // Medium priority
+    ))
+}
+
 /// Generate all insights.
 ///
 /// Returns a list of insights sorted by priority.
@@ -725,6 +777,7 @@ pub fn generate_all(
     learning_categories: &HashMap<String, LearningCategory>,
     learning_criteria: &HashMap<String, Vec<WriteGateCriterion>>,
     learning_context_files: &HashMap<String, Vec<String>>,
+    learning_review_dates: &HashMap<String, DateTime<Utc>>,
     decay_config: &DecayConfig,
     insight_config: &InsightConfig,
     now: DateTime<Utc>,
@@ -822,6 +875,11 @@ pub fn generate_all(
         insights.push(insight);
     }
 
+    // Review due
+    if let Some(insight) = generate_review_due_insight(cache, learning_review_dates, now) {
+        insights.push(insight);
+    }
+
     // Sort by priority (lower number = higher priority)
     insights.sort_by_key(|i| i.priority);
 
@@ -836,6 +894,7 @@ pub fn has_insights(
     learning_categories: &HashMap<String, LearningCategory>,
     learning_criteria: &HashMap<String, Vec<WriteGateCriterion>>,
     learning_context_files: &HashMap<String, Vec<String>>,
+    learning_review_dates: &HashMap<String, DateTime<Utc>>,
     decay_config: &DecayConfig,
     insight_config: &InsightConfig,
     now: DateTime<Utc>,
@@ -846,6 +905,7 @@ pub fn has_insights(
         learning_categories,
         learning_criteria,
         learning_context_files,
+        learning_review_dates,
         decay_config,
         insight_config,
         now,
@@ -1050,6 +1110,7 @@ mod tests {
             &categories,
             &criteria,
             &context_files,
+            &HashMap::new(),
             &decay_config,
             &insight_config,
             now,
@@ -1093,6 +1154,7 @@ mod tests {
             &categories,
             &criteria,
             &context_files,
+            &HashMap::new(),
             &decay_config,
             &insight_config,
             now,
@@ -1130,6 +1192,7 @@ mod tests {
             &categories,
             &criteria,
             &context_files,
+            &HashMap::new(),
             &decay_config,
             &insight_config,
             now,
@@ -1570,6 +1633,7 @@ mod tests {
             &categories,
             &criteria,
             &context_files,
+            &HashMap::new(),
             &decay_config,
             &insight_config,
             now,
@@ -1752,6 +1816,7 @@ mod tests {
             &categories,
             &criteria,
             &context_files,
+            &HashMap::new(),
             &decay_config,
             &insight_config,
             now,
@@ -1993,6 +2058,7 @@ mod tests {
             &categories,
             &criteria,
             &context_files,
+            &HashMap::new(),
             &decay_config,
             &insight_config,
             now,
@@ -2141,6 +2207,7 @@ mod tests {
             &categories,
             &criteria,
             &context_files,
+            &HashMap::new(),
             &decay_config,
             &insight_config,
             now,
@@ -2183,6 +2250,7 @@ mod tests {
             &categories,
             &criteria,
             &context_files,
+            &HashMap::new(),
             &decay_config,
             &insight_config,
             now,
@@ -2431,6 +2499,7 @@ mod tests {
             &categories,
             &criteria,
             &context_files,
+            &HashMap::new(),
             &decay_config,
             &insight_config,
             now,
@@ -2682,6 +2751,7 @@ mod tests {
             &categories,
             &criteria,
             &context_files,
+            &HashMap::new(),
             &decay_config,
             &insight_config,
             now,
@@ -2946,6 +3016,7 @@ mod tests {
             &categories,
             &criteria,
             &context_files,
+            &HashMap::new(),
             &decay_config,
             &insight_config,
             now,
@@ -2958,4 +3029,40 @@ mod tests {
             "Expected OverSurfacedLearning insight in generate_all output"
         );
     }
+
+    // =========================================================================
+    // Review due tests
+    // =========================================================================
+
+    #[test]
+    fn test_review_due_none_when_not_due() {
+        let now = Utc::now();
+        let cache = StatsCache::new();
+        let mut review_dates = HashMap::new();
+        review_dates.insert("L001".to_string(), now + Duration::days(1));
+
+        assert!(generate_review_due_insight(&cache, &review_dates, now).is_none());
+    }
+
+    #[test]
+    fn test_review_due_reports_oldest_and_skips_archived() {
+        let now = Utc::now();
+        let mut cache = StatsCache::new();
+        let mut archived = make_learning_stats();
+        archived.archived = true;
+        cache.learnings.insert("L003".to_string(), archived);
+
+        let mut review_dates = HashMap::new();
+        review_dates.insert("L001".to_string(), now - Duration::days(2));
+        review_dates.insert("L002".to_string(), now - Duration::days(30));
+        review_dates.insert("L003".to_string(), now - Duration::days(60));
+
+        let insight = generate_review_due_insight(&cache, &review_dates, now).unwrap();
+        assert_eq!(insight.kind, InsightKind::ReviewDue);
+        assert!(insight
+            .message
+            .starts_with("2 learnings past their review date"));
+        assert!(insight.message.contains("oldest: 'L002'"));
+        assert!(insight.suggestion.contains("grove maintain list"));
+    }
 }
@@ -14,12 +14,12 @@ pub mod scoring;
 pub mod tracker;
 
 pub use cache::{
-    AggregateStats, CategoryStats, CrossPollinationEdge, LearningStats, ReflectionStats,
-    RejectedCandidateSummary, StatsCache, StatsCacheManager, WriteGateStats,
+    AggregateStats, CategoryStats, CrossPollinationEdge, GuardStats, InjectionStats, LearningStats,
+    ReflectionStats, RejectedCandidateSummary, StatsCache, StatsCacheManager, WriteGateStats,
 };
 pub use decay::{
-    evaluate as evaluate_decay, get_decay_warnings, get_immune_learnings, run_decay_and_log,
-    run_decay_evaluation, should_run_decay_check, DecayResult,
+    evaluate as evaluate_decay, find_expired, get_decay_warnings, get_immune_learnings,
+    run_decay_and_log, run_decay_evaluation, should_run_decay_check, DecayResult,
 };
 pub use insights::{
     generate_all as generate_insights, generate_cross_pollination_insight, generate_decay_warning,
@@ -28,5 +28,7 @@ pub use insights::{
 pub use recommendations::{
     apply_safe_recommendations, generate_recommendations, ConfigRecommendation, Recommendations,
 };
-pub use scoring::{rank, rank_learnings, score, weights, ScoredLearning};
+pub use scoring::{
+    is_applicable, rank, rank_learnings, score, weights, ApplicabilityContext, ScoredLearning,
+};
 pub use tracker::{StatsEvent, StatsEventType, StatsLogger, STATS_SCHEMA_VERSION};
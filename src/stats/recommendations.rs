@@ -364,10 +364,8 @@ pub fn apply_safe_recommendations(config: &Config, recommendations: &Recommendat
 
     for rec in &recommendations.safe {
         match rec.config_key.as_str() {
-            "retrieval.strategy" => {
-                if RetrievalConfig::is_valid_strategy(&rec.recommended_value) {
-                    new_config.retrieval.strategy = rec.recommended_value.clone();
-                }
+            "retrieval.strategy" if RetrievalConfig::is_valid_strategy(&rec.recommended_value) => {
+                new_config.retrieval.strategy = rec.recommended_value.clone();
             }
             "gate.auto_skip.line_threshold" => {
                 if let Ok(value) = rec.recommended_value.parse::<u32>() {
@@ -41,14 +41,21 @@
 //!
 //! Range: 0.1 (never referenced) to 1.0 (always referenced).
 //!
+//! ## Applicability
+//!
+//! Learnings may declare `applies_to` predicates (path globs, languages,
+//! branches, tools). These are a hard filter, not a score component: a
+//! learning whose predicates don't match the [`ApplicabilityContext`] is
+//! never surfaced, however relevant it is.
+//!
 //! ## Strategy Modes
 //!
 //! - **Conservative**: Favor proven learnings (higher reference boost weight)
 //! - **Moderate**: Balanced approach (default)
 //! - **Aggressive**: Favor recent learnings (higher recency weight)
 
-use crate::backends::SearchQuery;
-use crate::core::CompoundLearning;
+use crate::backends::{LearningSource, SearchQuery};
+use crate::core::{CompoundLearning, InjectionExplanation};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -275,25 +282,42 @@ mod diminishing {
 ///
 /// Returns 0.0 if no criteria match.
 pub fn score(query: &SearchQuery, learning: &CompoundLearning) -> f64 {
+    score_breakdown(query, learning).score
+}
+
+/// Per-field contributions behind a [`score`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScoreBreakdown {
+    /// Contribution of tag matches (after diminishing returns).
+    pub tags: f64,
+    /// Contribution of file path overlap (after diminishing returns).
+    pub files: f64,
+    /// Contribution of keyword matches (after diminishing returns).
+    pub keywords: f64,
+    /// Domain mismatch multiplier (1.0 when there is no mismatch).
+    pub domain_penalty: f64,
+    /// Final score: contributions summed, capped at 1.0, times the penalty.
+    pub score: f64,
+}
+
+/// Calculate a [`score`] along with how each field contributed to it.
+pub fn score_breakdown(query: &SearchQuery, learning: &CompoundLearning) -> ScoreBreakdown {
+    let mut breakdown = ScoreBreakdown {
+        domain_penalty: 1.0,
+        ..ScoreBreakdown::default()
+    };
     if query.is_empty() {
-        return 0.0;
+        return breakdown;
     }
 
-    let mut signals = Vec::new();
-
     // Score tag matches
     let tag_score = score_tags(&query.tags, &learning.tags);
-    if tag_score > 0.0 {
-        signals.push(tag_score);
-    }
 
     // Score file path overlaps
-    if let Some(context_files) = &learning.context_files {
-        let file_score = score_files(&query.files, context_files);
-        if file_score > 0.0 {
-            signals.push(file_score);
-        }
-    }
+    let file_score = match &learning.context_files {
+        Some(context_files) => score_files(&query.files, context_files),
+        None => 0.0,
+    };
 
     // Score keyword matches (best of summary and detail)
     let mut keyword_score = score_keywords(&query.keywords, &learning.summary);
@@ -303,30 +327,34 @@ pub fn score(query: &SearchQuery, learning: &CompoundLearning) -> f64 {
             keyword_score = keyword_score.max(weights::KEYWORD_DETAIL);
         }
     }
-    if keyword_score > 0.0 {
-        signals.push(keyword_score);
-    }
 
+    let mut signals: Vec<(f64, &mut f64)> = vec![
+        (tag_score, &mut breakdown.tags),
+        (file_score, &mut breakdown.files),
+        (keyword_score, &mut breakdown.keywords),
+    ];
+    signals.retain(|(score, _)| *score > 0.0);
     if signals.is_empty() {
-        return 0.0;
+        return breakdown;
     }
 
     // Sort descending so strongest signal is first
-    signals.sort_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+    signals.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
 
     let multipliers = [1.0, diminishing::SECONDARY, diminishing::TERTIARY];
-    let combined: f64 = signals
-        .iter()
-        .zip(multipliers.iter())
-        .map(|(score, mult)| score * mult)
-        .sum();
+    let mut combined = 0.0;
+    for ((score, slot), mult) in signals.into_iter().zip(multipliers.iter()) {
+        *slot = score * mult;
+        combined += score * mult;
+    }
 
-    let raw = combined.min(1.0);
+    let raw: f64 = combined.min(1.0);
 
     // Apply domain mismatch penalty: if the learning has tags in a domain
     // group and no query keyword/tag indicates that domain, reduce the score.
-    let penalty = domain_penalty(&query.keywords, &query.tags, &learning.tags);
-    raw * penalty
+    breakdown.domain_penalty = domain_penalty(&query.keywords, &query.tags, &learning.tags);
+    breakdown.score = raw * breakdown.domain_penalty;
+    breakdown
 }
 
 /// Compute domain mismatch penalty.
@@ -515,6 +543,198 @@ fn score_keywords(keywords: &[String], summary: &str) -> f64 {
     0.0
 }
 
+/// What the session is working on, for matching `applies_to` predicates.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApplicabilityContext {
+    /// Files being worked on, relative to the project root.
+    pub files: Vec<String>,
+    /// Current git branch, if known.
+    pub branch: Option<String>,
+    /// Tools in use (e.g. the hook's tool name and the Bash program).
+    pub tools: Vec<String>,
+}
+
+impl ApplicabilityContext {
+    /// Create an empty context.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the files being worked on.
+    pub fn files(mut self, files: Vec<String>) -> Self {
+        self.files = files;
+        self
+    }
+
+    /// Set the current branch (an empty name is treated as unknown).
+    pub fn branch(mut self, branch: impl Into<String>) -> Self {
+        let branch = branch.into();
+        self.branch = (!branch.is_empty()).then_some(branch);
+        self
+    }
+
+    /// Set the tools in use.
+    pub fn tools(mut self, tools: Vec<String>) -> Self {
+        self.tools = tools;
+        self
+    }
+
+    /// Languages of the files being worked on, inferred from extensions.
+    pub fn languages(&self) -> Vec<&'static str> {
+        let mut languages: Vec<&'static str> = self
+            .files
+            .iter()
+            .filter_map(|f| language_for_path(f))
+            .collect();
+        languages.sort_unstable();
+        languages.dedup();
+        languages
+    }
+}
+
+/// Check whether a learning's `applies_to` predicates hold in a context.
+///
+/// Learnings without predicates always apply. Otherwise every non-empty
+/// predicate needs at least one match; a predicate the context can't answer
+/// (no files, unknown branch, no tool yet) does not match.
+pub fn is_applicable(learning: &CompoundLearning, context: &ApplicabilityContext) -> bool {
+    let Some(ref applies_to) = learning.applies_to else {
+        return true;
+    };
+
+    let paths_match = applies_to.paths.is_empty()
+        || applies_to
+            .paths
+            .iter()
+            .any(|pattern| context.files.iter().any(|f| path_matches(pattern, f)));
+
+    let languages_match = applies_to.languages.is_empty() || {
+        let languages = context.languages();
+        applies_to
+            .languages
+            .iter()
+            .any(|l| languages.iter().any(|c| c.eq_ignore_ascii_case(l)))
+    };
+
+    let branches_match = applies_to.branches.is_empty()
+        || context.branch.as_deref().is_some_and(|branch| {
+            applies_to
+                .branches
+                .iter()
+                .any(|pattern| glob_matches(pattern, branch))
+        });
+
+    let tools_match = applies_to.tools.is_empty()
+        || applies_to
+            .tools
+            .iter()
+            .any(|t| context.tools.iter().any(|c| c.eq_ignore_ascii_case(t)));
+
+    paths_match && languages_match && branches_match && tools_match
+}
+
+/// Match a path glob against a project-relative file path.
+///
+/// Patterns without a `/` match the file name at any depth (like
+/// `.gitignore`), so `*.tsx` matches `src/app/page.tsx`.
+pub(crate) fn path_matches(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.trim_start_matches("./");
+    let path = path.trim_start_matches("./");
+    if pattern.contains('/') {
+        glob_matches(pattern, path)
+    } else {
+        glob_matches(pattern, path.rsplit('/').next().unwrap_or(path))
+    }
+}
+
+/// Match a glob: `*` and `?` stay within a path segment, `**` crosses them.
+///
+/// Results are memoized per (pattern, text) position, so patterns with many
+/// `**` stay polynomial instead of backtracking exponentially.
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    struct Matcher<'a> {
+        pattern: &'a [u8],
+        text: &'a [u8],
+        memo: Vec<Option<bool>>,
+    }
+
+    impl Matcher<'_> {
+        fn matches(&mut self, p: usize, t: usize) -> bool {
+            let key = p * (self.text.len() + 1) + t;
+            if let Some(known) = self.memo[key] {
+                return known;
+            }
+
+            let text_len = self.text.len();
+            let result = match &self.pattern[p..] {
+                [] => t == text_len,
+                [b'*', b'*', b'/', ..] => (t..=text_len)
+                    .any(|i| (i == t || self.text[i - 1] == b'/') && self.matches(p + 3, i)),
+                [b'*', b'*', ..] => (t..=text_len).any(|i| self.matches(p + 2, i)),
+                [b'*', ..] => (t..=text_len)
+                    .take_while(|&i| i == t || self.text[i - 1] != b'/')
+                    .any(|i| self.matches(p + 1, i)),
+                [b'?', ..] => {
+                    matches!(self.text.get(t), Some(&c) if c != b'/') && self.matches(p + 1, t + 1)
+                }
+                [c, ..] => self.text.get(t) == Some(c) && self.matches(p + 1, t + 1),
+            };
+
+            self.memo[key] = Some(result);
+            result
+        }
+    }
+
+    let mut matcher = Matcher {
+        pattern: pattern.as_bytes(),
+        text: text.as_bytes(),
+        memo: vec![None; (pattern.len() + 1) * (text.len() + 1)],
+    };
+    matcher.matches(0, 0)
+}
+
+/// Language identifier for a file, from its extension.
+pub fn language_for_path(path: &str) -> Option<&'static str> {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    let (_, ext) = name.rsplit_once('.')?;
+    let language = match ext.to_lowercase().as_str() {
+        "rs" => "rust",
+        "py" | "pyi" => "python",
+        "ts" | "tsx" | "mts" | "cts" => "typescript",
+        "js" | "jsx" | "mjs" | "cjs" => "javascript",
+        "go" => "go",
+        "java" => "java",
+        "kt" | "kts" => "kotlin",
+        "swift" => "swift",
+        "rb" => "ruby",
+        "php" => "php",
+        "cs" => "csharp",
+        "c" | "h" => "c",
+        "cc" | "cpp" | "cxx" | "hpp" | "hh" => "cpp",
+        "scala" => "scala",
+        "ex" | "exs" => "elixir",
+        "erl" | "hrl" => "erlang",
+        "hs" => "haskell",
+        "lua" => "lua",
+        "dart" => "dart",
+        "zig" => "zig",
+        "sh" | "bash" | "zsh" => "shell",
+        "sql" => "sql",
+        "tf" | "tfvars" => "terraform",
+        "html" | "htm" => "html",
+        "css" | "scss" | "sass" => "css",
+        "vue" => "vue",
+        "svelte" => "svelte",
+        "md" | "markdown" => "markdown",
+        "json" => "json",
+        "yaml" | "yml" => "yaml",
+        "toml" => "toml",
+        "proto" => "protobuf",
+        _ => return None,
+    };
+    Some(language)
+}
+
 /// A learning with its computed relevance score.
 #[derive(Debug, Clone)]
 pub struct ScoredLearning {
@@ -655,6 +875,10 @@ pub struct CompositeScore {
     pub reference: f64,
     /// The final composite score.
     pub score: f64,
+    /// Where the learning came from.
+    pub source: LearningSource,
+    /// Why the learning was selected (filled in by hook retrieval).
+    pub explanation: Option<InjectionExplanation>,
 }
 
 impl CompositeScore {
@@ -679,8 +903,16 @@ impl CompositeScore {
             recency,
             reference,
             score,
+            source: LearningSource::Project,
+            explanation: None,
         }
     }
+
+    /// Set where the learning came from.
+    pub fn with_source(mut self, source: LearningSource) -> Self {
+        self.source = source;
+        self
+    }
 }
 
 /// Calculate composite scores for learnings with full ranking.
@@ -1117,6 +1349,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_score_breakdown_attributes_contributions() {
+        let query = SearchQuery::new()
+            .files(vec!["src/main.rs".to_string()])
+            .keywords(vec!["error".to_string()]);
+        let learning = make_learning("Handle error", vec!["test"], Some(vec!["src/main.rs"]));
+
+        let breakdown = score_breakdown(&query, &learning);
+        assert_eq!(breakdown.tags, 0.0);
+        assert!((breakdown.files - weights::FILE_OVERLAP).abs() < f64::EPSILON);
+        assert!(
+            (breakdown.keywords - diminishing::SECONDARY * weights::KEYWORD_SUMMARY).abs()
+                < f64::EPSILON
+        );
+        assert_eq!(breakdown.domain_penalty, 1.0);
+        assert_eq!(breakdown.score, score(&query, &learning));
+    }
+
     #[test]
     fn test_score_empty_query() {
         let query = SearchQuery::new();
@@ -1143,6 +1393,89 @@ mod tests {
         );
     }
 
+    // Applicability tests
+
+    fn with_applies_to(applies_to: crate::core::AppliesTo) -> CompoundLearning {
+        make_learning("Scoped learning", vec![], None).with_applies_to(applies_to)
+    }
+
+    #[test]
+    fn test_glob_matches() {
+        assert!(glob_matches(
+            "migrations/**",
+            "migrations/2026/001_init.sql"
+        ));
+        assert!(glob_matches("src/**/*.rs", "src/main.rs"));
+        assert!(glob_matches("src/**/*.rs", "src/cli/search.rs"));
+        assert!(!glob_matches("src/*.rs", "src/cli/search.rs"));
+        assert!(glob_matches("release/*", "release/1.2"));
+        assert!(glob_matches("v?.x", "v1.x"));
+        assert!(!glob_matches("db/migrations/**", "migrations/001.sql"));
+        assert!(glob_matches("**/**/*.rs", "src/cli/search.rs"));
+        assert!(glob_matches("src/**/**/mod.rs", "src/cli/mod.rs"));
+    }
+
+    #[test]
+    fn test_glob_matches_pathological_pattern_is_fast() {
+        let path = format!("{}/{}", "a".repeat(200), "a".repeat(200));
+        let started = std::time::Instant::now();
+
+        assert!(!glob_matches("**a**a**a**a**b", &path));
+        assert!(glob_matches("**a**a**a**a**a", &path));
+        assert!(started.elapsed() < std::time::Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_path_matches_basename_patterns_at_any_depth() {
+        assert!(path_matches("*.tsx", "src/app/page.tsx"));
+        assert!(path_matches("./migrations/**", "migrations/001.sql"));
+        assert!(!path_matches("*.tsx", "src/app/page.ts"));
+    }
+
+    #[test]
+    fn test_is_applicable_without_predicates() {
+        let learning = make_learning("Unscoped learning", vec![], None);
+        assert!(is_applicable(&learning, &ApplicabilityContext::new()));
+    }
+
+    #[test]
+    fn test_is_applicable_requires_every_predicate() {
+        let learning = with_applies_to(crate::core::AppliesTo {
+            paths: vec!["migrations/**".to_string()],
+            branches: vec!["release/*".to_string()],
+            ..Default::default()
+        });
+        let context = ApplicabilityContext::new()
+            .files(vec!["migrations/001.sql".to_string()])
+            .branch("release/2.0");
+        assert!(is_applicable(&learning, &context));
+
+        assert!(!is_applicable(&learning, &context.clone().branch("main")));
+        assert!(!is_applicable(&learning, &context.clone().branch("")));
+        assert!(!is_applicable(
+            &learning,
+            &context.files(vec!["src/main.rs".to_string()])
+        ));
+    }
+
+    #[test]
+    fn test_is_applicable_languages_and_tools() {
+        let learning = with_applies_to(crate::core::AppliesTo {
+            languages: vec!["TypeScript".to_string()],
+            tools: vec!["npm".to_string()],
+            ..Default::default()
+        });
+        let context = ApplicabilityContext::new()
+            .files(vec!["web/App.tsx".to_string()])
+            .tools(vec!["Bash".to_string(), "npm".to_string()]);
+        assert_eq!(context.languages(), vec!["typescript"]);
+        assert!(is_applicable(&learning, &context));
+        assert!(!is_applicable(
+            &learning,
+            &context.tools(vec!["Edit".to_string()])
+        ));
+    }
+
     // Ranking tests
 
     #[test]
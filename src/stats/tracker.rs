@@ -10,7 +10,7 @@ use std::path::{Path, PathBuf};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::core::{LearningCategory, SkipDecider};
+use crate::core::{GuardSeverity, LearningCategory, RevisionSource, SkipDecider};
 use crate::error::{GroveError, Result};
 use crate::util::read_to_string_limited;
 
@@ -209,6 +209,54 @@ pub enum StatsEventType {
         /// The project path that was analyzed.
         project_path: String,
     },
+
+    /// A learning was edited in place.
+    Edited {
+        /// The learning that was edited.
+        learning_id: String,
+        /// Names of the fields that changed.
+        fields: Vec<String>,
+    },
+
+    /// A new revision of a learning was recorded.
+    Revised {
+        /// The learning that was revised.
+        learning_id: String,
+        /// Revision number assigned by the backend (absent if the backend keeps no history).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rev: Option<u32>,
+        /// What produced the revision.
+        source: RevisionSource,
+        /// Names of the fields that changed.
+        fields: Vec<String>,
+    },
+
+    /// Learnings were injected into a session's context.
+    Injected {
+        /// The session the learnings were injected into.
+        session_id: String,
+        /// Approximate tokens of learning text injected.
+        tokens: usize,
+        /// The `retrieval.token_budget` in effect (0 = no limit).
+        budget: usize,
+        /// Number of learnings injected.
+        learnings: usize,
+        /// Learnings injected with trimmed detail (truncated or summary only).
+        #[serde(default)]
+        trimmed: usize,
+    },
+
+    /// A command guard matched a Bash command about to run.
+    GuardFired {
+        /// The learning whose guard matched.
+        learning_id: String,
+        /// The session the command ran in.
+        session_id: String,
+        /// The guard pattern that matched.
+        pattern: String,
+        /// Whether the command was warned about or blocked.
+        severity: GuardSeverity,
+    },
 }
 
 impl StatsEventType {
@@ -390,6 +438,61 @@ impl StatsEventType {
         }
     }
 
+    /// Create an edited event.
+    pub fn edited(learning_id: impl Into<String>, fields: Vec<String>) -> Self {
+        Self::Edited {
+            learning_id: learning_id.into(),
+            fields,
+        }
+    }
+
+    /// Create a revised event.
+    pub fn revised(
+        learning_id: impl Into<String>,
+        rev: Option<u32>,
+        source: RevisionSource,
+        fields: Vec<String>,
+    ) -> Self {
+        Self::Revised {
+            learning_id: learning_id.into(),
+            rev,
+            source,
+            fields,
+        }
+    }
+
+    /// Create an injected event.
+    pub fn injected(
+        session_id: impl Into<String>,
+        tokens: usize,
+        budget: usize,
+        learnings: usize,
+        trimmed: usize,
+    ) -> Self {
+        Self::Injected {
+            session_id: session_id.into(),
+            tokens,
+            budget,
+            learnings,
+            trimmed,
+        }
+    }
+
+    /// Create a guard fired event.
+    pub fn guard_fired(
+        learning_id: impl Into<String>,
+        session_id: impl Into<String>,
+        pattern: impl Into<String>,
+        severity: GuardSeverity,
+    ) -> Self {
+        Self::GuardFired {
+            learning_id: learning_id.into(),
+            session_id: session_id.into(),
+            pattern: pattern.into(),
+            severity,
+        }
+    }
+
     /// Get the event name as a string.
     pub fn event_name(&self) -> &'static str {
         match self {
@@ -405,6 +508,54 @@ impl StatsEventType {
             Self::Rated { .. } => "rated",
             Self::ImplicitlyReferenced { .. } => "implicitly_referenced",
             Self::Retroflect { .. } => "retroflect",
+            Self::Edited { .. } => "edited",
+            Self::Revised { .. } => "revised",
+            Self::Injected { .. } => "injected",
+            Self::GuardFired { .. } => "guard_fired",
+        }
+    }
+
+    /// The learning this event is about, if any.
+    pub fn learning_id(&self) -> Option<&str> {
+        match self {
+            Self::Surfaced { learning_id, .. }
+            | Self::Referenced { learning_id, .. }
+            | Self::Dismissed { learning_id, .. }
+            | Self::Corrected { learning_id, .. }
+            | Self::Archived { learning_id, .. }
+            | Self::Restored { learning_id }
+            | Self::Rated { learning_id, .. }
+            | Self::ImplicitlyReferenced { learning_id, .. }
+            | Self::Edited { learning_id, .. }
+            | Self::Revised { learning_id, .. }
+            | Self::GuardFired { learning_id, .. } => Some(learning_id),
+            Self::Reflection { .. }
+            | Self::Skip { .. }
+            | Self::Rejected { .. }
+            | Self::Retroflect { .. }
+            | Self::Injected { .. } => None,
+        }
+    }
+
+    /// Mutable access to the learning this event is about, if any.
+    pub fn learning_id_mut(&mut self) -> Option<&mut String> {
+        match self {
+            Self::Surfaced { learning_id, .. }
+            | Self::Referenced { learning_id, .. }
+            | Self::Dismissed { learning_id, .. }
+            | Self::Corrected { learning_id, .. }
+            | Self::Archived { learning_id, .. }
+            | Self::Restored { learning_id }
+            | Self::Rated { learning_id, .. }
+            | Self::ImplicitlyReferenced { learning_id, .. }
+            | Self::Edited { learning_id, .. }
+            | Self::Revised { learning_id, .. }
+            | Self::GuardFired { learning_id, .. } => Some(learning_id),
+            Self::Reflection { .. }
+            | Self::Skip { .. }
+            | Self::Rejected { .. }
+            | Self::Retroflect { .. }
+            | Self::Injected { .. } => None,
         }
     }
 }
@@ -641,6 +792,56 @@ impl StatsLogger {
         self.append(&event)
     }
 
+    /// Append an edited event.
+    pub fn append_edited(&self, learning_id: impl Into<String>, fields: Vec<String>) -> Result<()> {
+        let event = StatsEvent::new(StatsEventType::edited(learning_id, fields));
+        self.append(&event)
+    }
+
+    /// Append a revised event.
+    pub fn append_revised(
+        &self,
+        learning_id: impl Into<String>,
+        rev: Option<u32>,
+        source: RevisionSource,
+        fields: Vec<String>,
+    ) -> Result<()> {
+        let event = StatsEvent::new(StatsEventType::revised(learning_id, rev, source, fields));
+        self.append(&event)
+    }
+
+    /// Append an injected event.
+    pub fn append_injected(
+        &self,
+        session_id: impl Into<String>,
+        tokens: usize,
+        budget: usize,
+        learnings: usize,
+        trimmed: usize,
+    ) -> Result<()> {
+        let event = StatsEvent::new(StatsEventType::injected(
+            session_id, tokens, budget, learnings, trimmed,
+        ));
+        self.append(&event)
+    }
+
+    /// Append a guard fired event.
+    pub fn append_guard_fired(
+        &self,
+        learning_id: impl Into<String>,
+        session_id: impl Into<String>,
+        pattern: impl Into<String>,
+        severity: GuardSeverity,
+    ) -> Result<()> {
+        let event = StatsEvent::new(StatsEventType::guard_fired(
+            learning_id,
+            session_id,
+            pattern,
+            severity,
+        ));
+        self.append(&event)
+    }
+
     /// Read all events from the log.
     pub fn read_all(&self) -> Result<Vec<StatsEvent>> {
         if !self.path.exists() {
@@ -1449,4 +1650,114 @@ mod tests {
             panic!("Expected ImplicitlyReferenced event");
         }
     }
+
+    #[test]
+    fn test_edited_serialization() {
+        let event = StatsEvent::new(StatsEventType::edited(
+            "L001",
+            vec!["summary".to_string(), "tags".to_string()],
+        ));
+        assert_eq!(event.data.event_name(), "edited");
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains("\"event\":\"edited\""));
+        assert!(json.contains("\"fields\":[\"summary\",\"tags\"]"));
+
+        // Round-trip
+        let parsed: StatsEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.data, event.data);
+    }
+
+    #[test]
+    fn test_logger_append_edited() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("stats.log");
+        let logger = StatsLogger::new(&path);
+
+        logger
+            .append_edited("L001", vec!["detail".to_string()])
+            .unwrap();
+
+        let events = logger.read_all().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].data,
+            StatsEventType::edited("L001", vec!["detail".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_revised_serialization() {
+        let event = StatsEvent::new(StatsEventType::revised(
+            "L001",
+            Some(3),
+            RevisionSource::Consolidate,
+            vec!["status".to_string()],
+        ));
+        assert_eq!(event.data.event_name(), "revised");
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains("\"event\":\"revised\""));
+        assert!(json.contains("\"rev\":3"));
+        assert!(json.contains("\"source\":\"consolidate\""));
+
+        let parsed: StatsEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.data, event.data);
+
+        // Backends without history omit the revision number
+        let event = StatsEvent::new(StatsEventType::revised(
+            "L001",
+            None,
+            RevisionSource::Edit,
+            vec![],
+        ));
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(!json.contains("\"rev\""));
+    }
+
+    #[test]
+    fn test_injected_serialization() {
+        let event = StatsEvent::new(StatsEventType::injected("session-1", 640, 1500, 4, 1));
+        assert_eq!(event.data.event_name(), "injected");
+        assert_eq!(event.data.learning_id(), None);
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains("\"event\":\"injected\""));
+        assert!(json.contains("\"tokens\":640"));
+        assert!(json.contains("\"budget\":1500"));
+
+        let parsed: StatsEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.data, event.data);
+    }
+
+    #[test]
+    fn test_guard_fired_serialization() {
+        let event = StatsEvent::new(StatsEventType::guard_fired(
+            "L001",
+            "session-1",
+            "git push --force",
+            GuardSeverity::Block,
+        ));
+        assert_eq!(event.data.event_name(), "guard_fired");
+        assert_eq!(event.data.learning_id(), Some("L001"));
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains("\"event\":\"guard_fired\""));
+        assert!(json.contains("\"severity\":\"block\""));
+
+        let parsed: StatsEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.data, event.data);
+    }
+
+    #[test]
+    fn test_event_learning_id() {
+        let mut event = StatsEventType::referenced("L001", "session-1", None);
+        assert_eq!(event.learning_id(), Some("L001"));
+
+        *event.learning_id_mut().unwrap() = "L002".to_string();
+        assert_eq!(event.learning_id(), Some("L002"));
+
+        let skip = StatsEventType::skip("session-1", "trivial", SkipDecider::Agent, 3, None);
+        assert_eq!(skip.learning_id(), None);
+    }
 }
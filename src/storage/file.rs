@@ -191,7 +191,7 @@ impl SessionStore for FileSessionStore {
         }
 
         // Sort by modification time (most recent first)
-        sessions.sort_by(|a, b| b.1.cmp(&a.1));
+        sessions.sort_by_key(|s| std::cmp::Reverse(s.1));
 
         // Take up to limit
         let sessions: Vec<SessionState> =
@@ -61,7 +61,7 @@ impl SessionStore for MemorySessionStore {
         let mut result: Vec<SessionState> = sessions.values().cloned().collect();
 
         // Sort by updated_at descending (most recent first)
-        result.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+        result.sort_by_key(|s| std::cmp::Reverse(s.updated_at));
 
         // Limit the results
         result.truncate(limit);